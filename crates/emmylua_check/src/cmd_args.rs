@@ -37,6 +37,31 @@ pub struct CmdArgs {
 
     #[structopt(long, help = "Treat warnings as errors")]
     pub warnings_as_errors: bool,
+
+    #[structopt(
+        long,
+        help = "Structural search pattern such as `foo.bar($x, $y)`, prints the matches instead of checking"
+    )]
+    pub search: Option<String>,
+
+    #[structopt(
+        long,
+        help = "Replacement for the --search pattern such as `foo:baz($y, $x)`"
+    )]
+    pub replace: Option<String>,
+
+    #[structopt(
+        long = "where",
+        help = "Type constraint for a --search placeholder such as `x=string`, may be repeated",
+        number_of_values = 1
+    )]
+    pub constraints: Vec<String>,
+
+    #[structopt(
+        long,
+        help = "Write the --replace result to the files instead of printing it"
+    )]
+    pub apply: bool,
 }
 
 #[derive(Debug, Clone)]
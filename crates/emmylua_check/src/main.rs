@@ -1,6 +1,7 @@
 mod cmd_args;
 mod init;
 mod output;
+mod structural_search;
 
 use cmd_args::CmdArgs;
 use emmylua_code_analysis::{DbIndex, FileId};
@@ -12,12 +13,16 @@ use tokio_util::sync::CancellationToken;
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error + Sync + Send>> {
     let cmd_args = CmdArgs::from_args();
-    let mut workspace = cmd_args.workspace;
+    let mut workspace = cmd_args.workspace.clone();
     if !workspace.is_absolute() {
         workspace = std::env::current_dir()?.join(workspace);
     }
 
-    let analysis = match init::load_workspace(workspace.clone(), cmd_args.config, cmd_args.ignore) {
+    let analysis = match init::load_workspace(
+        workspace.clone(),
+        cmd_args.config.clone(),
+        cmd_args.ignore.clone(),
+    ) {
         Some(analysis) => analysis,
         None => {
             eprintln!("Failed to load workspace");
@@ -25,9 +30,28 @@ async fn main() -> Result<(), Box<dyn Error + Sync + Send>> {
         }
     };
 
+    let search = match &cmd_args.search {
+        Some(pattern) => Some(structural_search::build_search(
+            &cmd_args,
+            pattern,
+            &analysis.get_emmyrc(),
+        )?),
+        None => None,
+    };
+
     let files = analysis.compilation.get_db().get_vfs().get_all_file_ids();
     let db = analysis.compilation.get_db();
     let need_check_files = get_need_check_ids(db, files, &workspace);
+    if let Some(search) = search {
+        structural_search::run_search(
+            &analysis,
+            &search,
+            &need_check_files,
+            &workspace,
+            cmd_args.apply,
+        )?;
+        return Ok(());
+    }
 
     let (sender, receiver) = tokio::sync::mpsc::channel(100);
     let analysis = Arc::new(analysis);
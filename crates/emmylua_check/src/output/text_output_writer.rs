@@ -62,6 +62,7 @@ impl OutputWriter for TextOutputWriter {
 
         println!("{}:", &out_string);
         let out = Color::Fixed(81);
+        let related_color = Color::Fixed(246);
         let document = db.get_vfs().get_document(&file_id).unwrap();
         let text = document.get_text();
         for diagnostic in diagnostics {
@@ -83,14 +84,36 @@ impl OutputWriter for TextOutputWriter {
                 },
                 _ => "".to_string(),
             };
+            // syntax errors carry a more specific code, e.g. `syntax-error(missing-then)`
+            let code = match diagnostic
+                .data
+                .as_ref()
+                .and_then(|data| data.get("parseErrorCode"))
+                .and_then(|parse_code| parse_code.as_str())
+            {
+                Some(parse_code) => format!("{}({})", code, parse_code),
+                None => code,
+            };
 
-            Report::build(kind, (file_path, span.0..span.1))
+            let mut report = Report::build(kind, (file_path, span.0..span.1))
                 .with_code(code)
                 .with_label(
                     Label::new((file_path, span.0..span.1))
                         .with_message(diagnostic.message)
                         .with_color(out),
-                )
+                );
+            // the related ranges of syntax errors are always in the same file
+            for related in diagnostic.related_information.unwrap_or_default() {
+                if let Some(related_span) = document.get_range_span(related.location.range) {
+                    report = report.with_label(
+                        Label::new((file_path, related_span.0..related_span.1))
+                            .with_message(related.message)
+                            .with_color(related_color),
+                    );
+                }
+            }
+
+            report
                 .finish()
                 .print((file_path, Source::from(text)))
                 .unwrap();
@@ -0,0 +1,105 @@
+use std::path::Path;
+
+use emmylua_code_analysis::{EmmyLuaAnalysis, Emmyrc, FileId, StructuralSearch};
+use emmylua_parser::LuaTextEdit;
+
+use crate::cmd_args::CmdArgs;
+
+pub fn build_search(
+    cmd_args: &CmdArgs,
+    pattern: &str,
+    emmyrc: &Emmyrc,
+) -> Result<StructuralSearch, String> {
+    let mut search = StructuralSearch::new(pattern, emmyrc).map_err(|err| err.to_string())?;
+    if let Some(replacement) = &cmd_args.replace {
+        search = search
+            .with_replacement(replacement)
+            .map_err(|err| err.to_string())?;
+    }
+    for constraint in &cmd_args.constraints {
+        let Some((placeholder, type_text)) = constraint.split_once('=') else {
+            return Err(format!(
+                "invalid constraint `{}`, expected `name=type`",
+                constraint
+            ));
+        };
+        search = search
+            .with_constraint(placeholder.trim(), type_text)
+            .map_err(|err| err.to_string())?;
+    }
+    Ok(search)
+}
+
+/// Prints every match, or with a replacement the old and new code, or writes the files when
+/// `apply` is set. Returns the number of matches.
+pub fn run_search(
+    analysis: &EmmyLuaAnalysis,
+    search: &StructuralSearch,
+    file_ids: &[FileId],
+    workspace: &Path,
+    apply: bool,
+) -> std::io::Result<usize> {
+    let db = analysis.compilation.get_db();
+    let mut match_count = 0;
+    let mut file_count = 0;
+    for file_id in file_ids {
+        let Some(semantic_model) = analysis.compilation.get_semantic_model(*file_id) else {
+            continue;
+        };
+        let matches = search.find_matches(&semantic_model);
+        if matches.is_empty() {
+            continue;
+        }
+        match_count += matches.len();
+        file_count += 1;
+
+        let Some(file_path) = db.get_vfs().get_file_path(file_id) else {
+            continue;
+        };
+        let display_path = file_path.strip_prefix(workspace).unwrap_or(file_path);
+        let document = semantic_model.get_document();
+        let text = document.get_text();
+        if apply && search.has_replacement() {
+            let new_text = LuaTextEdit::apply(text, &search.replace(&semantic_model));
+            std::fs::write(file_path, new_text)?;
+            let replaced = matches
+                .iter()
+                .filter(|structural_match| !structural_match.drops_comments)
+                .count();
+            println!("{}: {} replaced", display_path.display(), replaced);
+            continue;
+        }
+
+        for structural_match in &matches {
+            let (line, col) = document
+                .get_line_col(structural_match.range.start())
+                .unwrap_or_default();
+            println!("{}:{}:{}", display_path.display(), line + 1, col + 1);
+            let old_text = &text[structural_match.range];
+            match search.get_replacement(structural_match) {
+                Some(_) if structural_match.drops_comments => {
+                    for old_line in old_text.lines() {
+                        println!("  {}", old_line);
+                    }
+                    println!("  (not replaced, the replacement would drop a comment)");
+                }
+                Some(new_text) => {
+                    for old_line in old_text.lines() {
+                        println!("- {}", old_line);
+                    }
+                    for new_line in new_text.lines() {
+                        println!("+ {}", new_line);
+                    }
+                }
+                None => {
+                    for old_line in old_text.lines() {
+                        println!("  {}", old_line);
+                    }
+                }
+            }
+        }
+    }
+
+    eprintln!("{} matches in {} files", match_count, file_count);
+    Ok(match_count)
+}
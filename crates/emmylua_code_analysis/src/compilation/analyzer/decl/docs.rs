@@ -1,6 +1,7 @@
 use emmylua_parser::{
     LuaAstNode, LuaAstToken, LuaComment, LuaDocAttribute, LuaDocTag, LuaDocTagAlias,
     LuaDocTagClass, LuaDocTagEnum, LuaDocTagMeta, LuaDocTagNamespace, LuaDocTagUsing,
+    LuaTypeAliasStat,
 };
 use flagset::FlagSet;
 use rowan::TextRange;
@@ -83,6 +84,22 @@ pub fn analyze_doc_tag_alias(analyzer: &mut DeclAnalyzer, alias: LuaDocTagAlias)
     Some(())
 }
 
+// luau `type A = B` is declared like `---@alias A B`
+pub fn analyze_type_alias_stat(analyzer: &mut DeclAnalyzer, stat: LuaTypeAliasStat) -> Option<()> {
+    let name_token = stat.get_name_token()?;
+    let name = name_token.get_name_text().to_string();
+    let range = name_token.syntax().text_range();
+
+    add_type_decl(
+        analyzer,
+        &name,
+        range,
+        LuaDeclTypeKind::Alias,
+        LuaTypeAttribute::None.into(),
+    );
+    Some(())
+}
+
 pub fn analyze_doc_tag_namespace(
     analyzer: &mut DeclAnalyzer,
     namespace: LuaDocTagNamespace,
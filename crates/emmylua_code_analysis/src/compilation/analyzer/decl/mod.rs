@@ -71,6 +71,14 @@ fn walk_node_enter(analyzer: &mut DeclAnalyzer, node: LuaAst) {
             analyzer.create_scope(stat.get_range(), LuaScopeKind::FuncStat);
             stats::analyze_local_func_stat(analyzer, stat);
         }
+        LuaAst::LuaGlobalStat(stat) => {
+            if stat.get_closure().is_some() {
+                analyzer.create_scope(stat.get_range(), LuaScopeKind::FuncStat);
+            } else {
+                analyzer.create_scope(stat.get_range(), LuaScopeKind::LocalOrAssignStat);
+            }
+            stats::analyze_global_stat(analyzer, stat);
+        }
         LuaAst::LuaRepeatStat(stat) => {
             analyzer.create_scope(stat.get_range(), LuaScopeKind::Repeat);
         }
@@ -102,6 +110,9 @@ fn walk_node_enter(analyzer: &mut DeclAnalyzer, node: LuaAst) {
         LuaAst::LuaDocTagAlias(doc_tag) => {
             docs::analyze_doc_tag_alias(analyzer, doc_tag);
         }
+        LuaAst::LuaTypeAliasStat(stat) => {
+            docs::analyze_type_alias_stat(analyzer, stat);
+        }
         LuaAst::LuaDocTagNamespace(doc_tag) => {
             docs::analyze_doc_tag_namespace(analyzer, doc_tag);
         }
@@ -132,7 +143,9 @@ fn is_scope_owner(node: &LuaAst) -> bool {
         | LuaSyntaxKind::LocalStat
         | LuaSyntaxKind::FuncStat
         | LuaSyntaxKind::LocalFuncStat
-        | LuaSyntaxKind::AssignStat => true,
+        | LuaSyntaxKind::AssignStat
+        | LuaSyntaxKind::CompoundAssignStat
+        | LuaSyntaxKind::GlobalStat => true,
         _ => false,
     }
 }
@@ -1,7 +1,7 @@
 use emmylua_parser::{
     LuaAssignStat, LuaAstNode, LuaAstToken, LuaExpr, LuaForRangeStat, LuaForStat, LuaFuncStat,
-    LuaIndexExpr, LuaIndexKey, LuaLocalFuncStat, LuaLocalStat, LuaSyntaxId, LuaSyntaxKind,
-    LuaVarExpr,
+    LuaGlobalStat, LuaIndexExpr, LuaIndexKey, LuaLocalFuncStat, LuaLocalStat, LuaSyntaxId,
+    LuaSyntaxKind, LuaVarExpr,
 };
 
 use crate::{
@@ -59,6 +59,11 @@ pub fn analyze_local_stat(analyzer: &mut DeclAnalyzer, stat: LuaLocalStat) -> Op
 }
 
 pub fn analyze_assign_stat(analyzer: &mut DeclAnalyzer, stat: LuaAssignStat) -> Option<()> {
+    // `a += 1` neither declares `a` nor defines a member, the var is only a reference
+    if stat.is_compound_assign() {
+        return Some(());
+    }
+
     let (vars, value_exprs) = stat.get_var_and_expr_list();
     for (idx, var) in vars.iter().enumerate() {
         let value_expr_id = if let Some(expr) = value_exprs.get(idx) {
@@ -87,6 +92,7 @@ pub fn analyze_assign_stat(analyzer: &mut DeclAnalyzer, stat: LuaAssignStat) ->
                         range,
                         LuaDeclExtra::Global {
                             kind: LuaSyntaxKind::NameExpr.into(),
+                            attrib: None,
                         },
                         value_expr_id,
                     );
@@ -156,6 +162,7 @@ fn analyze_maybe_global_index_expr(
                     range,
                     LuaDeclExtra::Global {
                         kind: LuaSyntaxKind::IndexExpr.into(),
+                        attrib: None,
                     },
                     value_expr_id,
                 );
@@ -232,6 +239,7 @@ pub fn analyze_func_stat(analyzer: &mut DeclAnalyzer, stat: LuaFuncStat) -> Opti
                     range,
                     LuaDeclExtra::Global {
                         kind: LuaSyntaxKind::NameExpr.into(),
+                        attrib: None,
                     },
                     None,
                 );
@@ -318,3 +326,45 @@ pub fn analyze_local_func_stat(analyzer: &mut DeclAnalyzer, stat: LuaLocalFuncSt
 
     Some(())
 }
+
+/// `global x`, `global<const> x = 1` and `global function f() end` declare globals that are
+/// visible to the end of the enclosing block, Lua 5.5
+pub fn analyze_global_stat(analyzer: &mut DeclAnalyzer, stat: LuaGlobalStat) -> Option<()> {
+    let stat_attrib = stat.get_attrib();
+    let value_expr_list = stat.get_value_exprs().collect::<Vec<_>>();
+    let file_id = analyzer.get_file_id();
+    for (index, global_name) in stat.get_global_name_list().enumerate() {
+        let Some(name_token) = global_name.get_name_token() else {
+            continue;
+        };
+        let attrib = match global_name.get_attrib().or(stat_attrib.clone()) {
+            Some(attrib) if attrib.is_const() => Some(LocalAttribute::Const),
+            Some(attrib) if attrib.is_close() => Some(LocalAttribute::Close),
+            _ => None,
+        };
+        let expr_id = value_expr_list.get(index).map(|expr| expr.get_syntax_id());
+        let decl = LuaDecl::new(
+            name_token.get_name_text(),
+            file_id,
+            name_token.get_range(),
+            LuaDeclExtra::Global {
+                kind: global_name.syntax().kind(),
+                attrib,
+            },
+            expr_id,
+        );
+        let decl_id = analyzer.add_decl(decl);
+
+        if let Some(closure) = stat.get_closure() {
+            let closure_owner_id =
+                LuaSemanticDeclId::Signature(LuaSignatureId::from_closure(file_id, &closure));
+            analyzer.db.get_property_index_mut().add_owner_map(
+                LuaSemanticDeclId::LuaDecl(decl_id),
+                closure_owner_id,
+                file_id,
+            );
+        }
+    }
+
+    Some(())
+}
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use rowan::{TextRange, TextSize};
 
-use crate::GenericTplId;
+use crate::{GenericParam, GenericTplId};
 
 #[derive(Debug, Clone)]
 pub struct FileGenericIndex {
@@ -23,7 +23,7 @@ impl FileGenericIndex {
     pub fn add_generic_scope(
         &mut self,
         ranges: Vec<TextRange>,
-        params: HashMap<String, usize>,
+        params: Vec<GenericParam>,
         is_func: bool,
     ) {
         let params_id = self.generic_params.len();
@@ -93,16 +93,20 @@ impl FileGenericIndex {
         false
     }
 
-    pub fn find_generic(&self, position: TextSize, name: &str) -> Option<GenericTplId> {
+    pub fn find_generic(
+        &self,
+        position: TextSize,
+        name: &str,
+    ) -> Option<(GenericTplId, &GenericParam)> {
         let params_ids = self.find_generic_params(position)?;
 
         for params_id in params_ids.iter().rev() {
             if let Some(params) = self.generic_params.get(*params_id) {
-                if let Some(id) = params.params.get(name) {
+                if let Some((id, param)) = params.params.get(name) {
                     if params.is_func {
-                        return Some(GenericTplId::Func(*id as u32));
+                        return Some((GenericTplId::Func(*id as u32), param));
                     } else {
-                        return Some(GenericTplId::Type(*id as u32));
+                        return Some((GenericTplId::Type(*id as u32), param));
                     }
                 }
             }
@@ -178,12 +182,17 @@ impl GenericEffectId {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GenericParams {
-    params: HashMap<String, usize>,
+    params: HashMap<String, (usize, GenericParam)>,
     is_func: bool,
 }
 
 impl GenericParams {
-    pub fn new(params: HashMap<String, usize>, is_func: bool) -> Self {
+    pub fn new(params: Vec<GenericParam>, is_func: bool) -> Self {
+        let params = params
+            .into_iter()
+            .enumerate()
+            .map(|(idx, param)| (param.name.clone(), (idx, param)))
+            .collect();
         Self { params, is_func }
     }
 }
@@ -14,8 +14,8 @@ use crate::{
         AnalyzeError, LuaAliasCallType, LuaFunctionType, LuaGenericType, LuaIndexAccessKey,
         LuaIntersectionType, LuaObjectType, LuaStringTplType, LuaTupleType, LuaType, LuaUnionType,
     },
-    DiagnosticCode, GenericTpl, InFiled, LuaAliasCallKind, LuaMultiLineUnion, LuaTypeDeclId,
-    TypeOps, VariadicType,
+    DiagnosticCode, GenericTpl, InFiled, LuaAliasCallKind, LuaMultiLineUnion, LuaTypeCache,
+    LuaTypeDeclId, TypeOps, VariadicType,
 };
 
 use super::{preprocess_description, DocAnalyzer};
@@ -142,10 +142,12 @@ fn infer_buildin_or_ref_type(
             LuaType::Table
         }
         _ => {
-            if let Some(tpl_id) = analyzer.generic_index.find_generic(position, name) {
-                return LuaType::TplRef(Arc::new(GenericTpl::new(
+            if let Some((tpl_id, param)) = analyzer.generic_index.find_generic(position, name) {
+                return LuaType::TplRef(Arc::new(GenericTpl::new_with_bound(
                     tpl_id,
                     SmolStr::new(name).into(),
+                    param.constraint.clone(),
+                    param.default.clone(),
                 )));
             }
 
@@ -229,7 +231,14 @@ fn infer_generic_type(analyzer: &mut DocAnalyzer, generic_type: &LuaDocGenericTy
                 }
             }
 
-            return LuaType::Generic(LuaGenericType::new(id, generic_params).into());
+            let typ = LuaType::Generic(LuaGenericType::new(id, generic_params).into());
+            // keep the explicit type arguments around for the constraint check
+            let in_filed_syntax_id = InFiled::new(analyzer.file_id, generic_type.get_syntax_id());
+            analyzer.db.get_type_index_mut().bind_type(
+                in_filed_syntax_id.into(),
+                LuaTypeCache::DocType(typ.clone()),
+            );
+            return typ;
         }
     }
 
@@ -419,11 +428,14 @@ fn infer_unary_type(analyzer: &mut DocAnalyzer, unary_type: &LuaDocUnaryType) ->
 
 fn infer_func_type(analyzer: &mut DocAnalyzer, func: &LuaDocFuncType) -> LuaType {
     let mut params_result = Vec::new();
-    for param in func.get_params() {
+    for (idx, param) in func.get_params().enumerate() {
         let name = if let Some(param) = param.get_name_token() {
             param.get_name_text().to_string()
         } else if param.is_dots() {
             "...".to_string()
+        } else if param.get_type().is_some() {
+            // luau allows unnamed params, `(number, string) -> ()`
+            format!("arg{}", idx + 1)
         } else {
             continue;
         };
@@ -489,7 +501,7 @@ fn infer_func_type(analyzer: &mut DocAnalyzer, func: &LuaDocFuncType) -> LuaType
 }
 
 fn get_colon_define(analyzer: &mut DocAnalyzer) -> Option<bool> {
-    let owner = analyzer.comment.get_owner()?;
+    let owner = analyzer.comment.as_ref()?.get_owner()?;
     match owner {
         LuaAst::LuaFuncStat(func_stat) => {
             let func_name = func_stat.get_func_name()?;
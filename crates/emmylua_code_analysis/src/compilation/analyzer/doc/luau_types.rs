@@ -0,0 +1,226 @@
+use emmylua_parser::{
+    LuaAst, LuaAstNode, LuaAstToken, LuaCastExpr, LuaClosureExpr, LuaDocFuncType,
+    LuaDocGenericDeclList, LuaDocType, LuaLocalName, LuaNameToken, LuaSyntaxNode, LuaTypeAliasStat,
+};
+
+use crate::{
+    compilation::analyzer::bind_type::bind_type,
+    db_index::{LuaDeclId, LuaDocParamInfo, LuaDocReturnInfo, LuaSignatureId, LuaType},
+    InFiled, LuaTypeCache, SignatureReturnStatus,
+};
+
+use super::{infer_type::infer_type, type_def_tags::get_generic_params, DocAnalyzer};
+
+// luau writes types inline instead of in doc comments, they are lowered the same way
+pub fn analyze_luau_types(analyzer: &mut DocAnalyzer) {
+    let root = analyzer.root.clone();
+    // generic scopes must exist before any type is inferred
+    for node in root.descendants() {
+        match LuaAst::cast(node) {
+            Some(LuaAst::LuaClosureExpr(closure)) => {
+                analyze_closure_generic(analyzer, closure);
+            }
+            Some(LuaAst::LuaTypeAliasStat(stat)) => {
+                analyze_type_alias_generic(analyzer, stat);
+            }
+            Some(LuaAst::LuaDocFuncType(func_type)) => {
+                analyze_func_type_generic(analyzer, func_type);
+            }
+            _ => {}
+        }
+    }
+
+    for node in root.descendants() {
+        match LuaAst::cast(node) {
+            Some(LuaAst::LuaTypeAliasStat(stat)) => {
+                analyze_type_alias(analyzer, stat);
+            }
+            Some(LuaAst::LuaClosureExpr(closure)) => {
+                analyze_closure_types(analyzer, closure);
+            }
+            Some(LuaAst::LuaLocalName(local_name)) => {
+                analyze_local_name_type(analyzer, local_name);
+            }
+            Some(LuaAst::LuaForStat(for_stat)) => {
+                analyze_loop_var_types(analyzer, for_stat.syntax());
+            }
+            Some(LuaAst::LuaForRangeStat(for_range_stat)) => {
+                analyze_loop_var_types(analyzer, for_range_stat.syntax());
+            }
+            Some(LuaAst::LuaCastExpr(cast_expr)) => {
+                analyze_cast_expr(analyzer, cast_expr);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn analyze_closure_generic(analyzer: &mut DocAnalyzer, closure: LuaClosureExpr) -> Option<()> {
+    let params = get_generic_params(analyzer, closure.get_generic_decl_list()?);
+    analyzer
+        .generic_index
+        .add_generic_scope(vec![closure.get_range()], params.clone(), true);
+
+    let signature_id = LuaSignatureId::from_closure(analyzer.file_id, &closure);
+    let signature = analyzer
+        .db
+        .get_signature_index_mut()
+        .get_or_create(signature_id);
+    signature.generic_params = params;
+    Some(())
+}
+
+fn analyze_type_alias_generic(analyzer: &mut DocAnalyzer, stat: LuaTypeAliasStat) -> Option<()> {
+    let generic_decl_list = stat.get_generic_decl_list()?;
+    let name = stat.get_name_token()?.get_name_text().to_string();
+    let alias_decl_id = analyzer
+        .db
+        .get_type_index()
+        .find_type_decl(analyzer.file_id, &name)?
+        .get_id();
+
+    let params = get_generic_params(analyzer, generic_decl_list);
+    analyzer
+        .db
+        .get_type_index_mut()
+        .add_generic_params(alias_decl_id, params.clone());
+    analyzer
+        .generic_index
+        .add_generic_scope(vec![stat.get_range()], params, false);
+    Some(())
+}
+
+fn analyze_func_type_generic(analyzer: &mut DocAnalyzer, func_type: LuaDocFuncType) -> Option<()> {
+    let generic_decl_list = func_type.child::<LuaDocGenericDeclList>()?;
+    let params = get_generic_params(analyzer, generic_decl_list);
+    analyzer
+        .generic_index
+        .add_generic_scope(vec![func_type.get_range()], params, true);
+    Some(())
+}
+
+fn analyze_type_alias(analyzer: &mut DocAnalyzer, stat: LuaTypeAliasStat) -> Option<()> {
+    let name = stat.get_name_token()?.get_name_text().to_string();
+    let alias_decl_id = {
+        let alias_decl = analyzer
+            .db
+            .get_type_index()
+            .find_type_decl(analyzer.file_id, &name)?;
+        if !alias_decl.is_alias() {
+            return None;
+        }
+
+        alias_decl.get_id()
+    };
+
+    let origin_type = infer_type(analyzer, stat.get_type()?);
+    analyzer
+        .db
+        .get_type_index_mut()
+        .get_type_decl_mut(&alias_decl_id)?
+        .add_alias_origin(origin_type);
+    Some(())
+}
+
+fn analyze_closure_types(analyzer: &mut DocAnalyzer, closure: LuaClosureExpr) -> Option<()> {
+    let signature_id = LuaSignatureId::from_closure(analyzer.file_id, &closure);
+    for param in closure.get_params_list()?.get_params() {
+        let Some(doc_type) = param.get_type() else {
+            continue;
+        };
+        let name = if let Some(name_token) = param.get_name_token() {
+            name_token.get_name_text().to_string()
+        } else if param.is_dots() {
+            "...".to_string()
+        } else {
+            continue;
+        };
+
+        let type_ref = infer_type(analyzer, doc_type);
+        let signature = analyzer
+            .db
+            .get_signature_index_mut()
+            .get_or_create(signature_id);
+        let Some(idx) = signature.find_param_idx(&name) else {
+            continue;
+        };
+        signature.param_docs.insert(
+            idx,
+            LuaDocParamInfo {
+                name,
+                nullable: type_ref.is_nullable(),
+                type_ref,
+                description: None,
+            },
+        );
+    }
+
+    let return_type_list = closure.get_return_type_list()?;
+    let mut return_docs = Vec::new();
+    for return_type in return_type_list.get_return_type_list() {
+        let (name_token, doc_type) = return_type.get_name_and_type();
+        let type_ref = match doc_type {
+            Some(doc_type) => infer_type(analyzer, doc_type),
+            None => LuaType::Unknown,
+        };
+        return_docs.push(LuaDocReturnInfo {
+            name: name_token.map(|it| it.get_name_text().to_string()),
+            type_ref,
+            description: None,
+        });
+    }
+
+    let signature = analyzer
+        .db
+        .get_signature_index_mut()
+        .get_or_create(signature_id);
+    signature.return_docs = return_docs;
+    signature.resolve_return = SignatureReturnStatus::DocResolve;
+    Some(())
+}
+
+fn analyze_local_name_type(analyzer: &mut DocAnalyzer, local_name: LuaLocalName) -> Option<()> {
+    let doc_type = local_name.get_type()?;
+    let name_token = local_name.get_name_token()?;
+    bind_decl_type(analyzer, name_token, doc_type);
+    Some(())
+}
+
+// `for i: number = 1, 10` and `for k: string, v: number in pairs(t)`
+fn analyze_loop_var_types(analyzer: &mut DocAnalyzer, loop_stat: &LuaSyntaxNode) -> Option<()> {
+    let mut last_name_token = None;
+    for child in loop_stat.children_with_tokens() {
+        if let Some(name_token) = child.as_token().cloned().and_then(LuaNameToken::cast) {
+            last_name_token = Some(name_token);
+        } else if let Some(doc_type) = child.into_node().and_then(LuaDocType::cast) {
+            bind_decl_type(analyzer, last_name_token.take()?, doc_type);
+        }
+    }
+
+    Some(())
+}
+
+fn bind_decl_type(analyzer: &mut DocAnalyzer, name_token: LuaNameToken, doc_type: LuaDocType) {
+    let type_ref = infer_type(analyzer, doc_type);
+    if type_ref.is_unknown() {
+        return;
+    }
+
+    let decl_id = LuaDeclId::new(analyzer.file_id, name_token.get_position());
+    analyzer
+        .db
+        .get_type_index_mut()
+        .bind_type(decl_id.into(), LuaTypeCache::DocType(type_ref));
+}
+
+// `expr :: type` works like `---@as type`
+fn analyze_cast_expr(analyzer: &mut DocAnalyzer, cast_expr: LuaCastExpr) -> Option<()> {
+    let type_ref = infer_type(analyzer, cast_expr.get_type()?);
+    let in_filed_syntax_id = InFiled::new(analyzer.file_id, cast_expr.get_syntax_id());
+    bind_type(
+        analyzer.db,
+        in_filed_syntax_id.into(),
+        LuaTypeCache::DocType(type_ref),
+    );
+    Some(())
+}
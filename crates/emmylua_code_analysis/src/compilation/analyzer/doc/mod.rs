@@ -2,6 +2,7 @@ mod diagnostic_tags;
 mod field_or_operator_def_tags;
 mod file_generic_index;
 mod infer_type;
+mod luau_types;
 mod property_tags;
 mod tags;
 mod type_def_tags;
@@ -11,7 +12,7 @@ use super::AnalyzeContext;
 use crate::{
     db_index::{DbIndex, LuaTypeDeclId},
     profile::Profile,
-    FileId,
+    EmmyrcLuaVersion, FileId,
 };
 use emmylua_parser::{LuaAstNode, LuaComment, LuaDocDescriptionOwner, LuaSyntaxNode};
 use file_generic_index::FileGenericIndex;
@@ -21,6 +22,8 @@ pub(crate) fn analyze(db: &mut DbIndex, context: &mut AnalyzeContext) {
     let _p = Profile::cond_new("doc analyze", context.tree_list.len() > 1);
     let tree_list = context.tree_list.clone();
     for in_filed_tree in tree_list.iter() {
+        let is_luau =
+            db.get_file_emmyrc(&in_filed_tree.file_id).runtime.version == EmmyrcLuaVersion::Luau;
         let root = &in_filed_tree.value;
         let mut generic_index = FileGenericIndex::new();
         for comment in root.descendants::<LuaComment>() {
@@ -28,17 +31,29 @@ pub(crate) fn analyze(db: &mut DbIndex, context: &mut AnalyzeContext) {
                 db,
                 in_filed_tree.file_id,
                 &mut generic_index,
-                comment,
+                Some(comment),
                 root.syntax().clone(),
                 context,
             );
             analyze_comment(&mut analyzer);
         }
+
+        if is_luau {
+            let mut analyzer = DocAnalyzer::new(
+                db,
+                in_filed_tree.file_id,
+                &mut generic_index,
+                None,
+                root.syntax().clone(),
+                context,
+            );
+            luau_types::analyze_luau_types(&mut analyzer);
+        }
     }
 }
 
 fn analyze_comment(analyzer: &mut DocAnalyzer) -> Option<()> {
-    let comment = analyzer.comment.clone();
+    let comment = analyzer.comment.clone()?;
     for tag in comment.get_doc_tags() {
         tags::analyze_tag(analyzer, tag);
     }
@@ -61,7 +76,7 @@ pub struct DocAnalyzer<'a> {
     db: &'a mut DbIndex,
     generic_index: &'a mut FileGenericIndex,
     current_type_id: Option<LuaTypeDeclId>,
-    comment: LuaComment,
+    comment: Option<LuaComment>,
     root: LuaSyntaxNode,
     is_meta: bool,
     context: &'a mut AnalyzeContext,
@@ -72,7 +87,7 @@ impl<'a> DocAnalyzer<'a> {
         db: &'a mut DbIndex,
         file_id: FileId,
         generic_index: &'a mut FileGenericIndex,
-        comment: LuaComment,
+        comment: Option<LuaComment>,
         root: LuaSyntaxNode,
         context: &'a mut AnalyzeContext,
     ) -> DocAnalyzer<'a> {
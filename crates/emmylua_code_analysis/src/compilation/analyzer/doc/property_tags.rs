@@ -5,8 +5,8 @@ use super::{
     DocAnalyzer,
 };
 use emmylua_parser::{
-    LuaDocDescriptionOwner, LuaDocTagDeprecated, LuaDocTagNodiscard, LuaDocTagSource,
-    LuaDocTagVersion, LuaDocTagVisibility,
+    LuaDocDescriptionOwner, LuaDocTagDeprecated, LuaDocTagLanguage, LuaDocTagNodiscard,
+    LuaDocTagSource, LuaDocTagVersion, LuaDocTagVisibility,
 };
 
 pub fn analyze_visibility(
@@ -110,3 +110,22 @@ pub fn analyze_async(analyzer: &mut DocAnalyzer) -> Option<()> {
 
     Some(())
 }
+
+// a language without a parameter applies to the statement, it is looked up from the syntax tree
+pub fn analyze_language(analyzer: &mut DocAnalyzer, language: LuaDocTagLanguage) -> Option<()> {
+    let param_name = language.get_param_name_token()?;
+    let language_name = language.get_language_token()?;
+    let closure = find_owner_closure(analyzer)?;
+    let signature_id = LuaSignatureId::from_closure(analyzer.file_id, &closure);
+    let signature = analyzer
+        .db
+        .get_signature_index_mut()
+        .get_or_create(signature_id);
+
+    signature.param_languages.insert(
+        param_name.get_name_text().to_string(),
+        language_name.get_name_text().to_lowercase(),
+    );
+
+    Some(())
+}
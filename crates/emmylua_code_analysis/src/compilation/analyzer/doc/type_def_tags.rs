@@ -1,5 +1,3 @@
-use std::collections::HashMap;
-
 use emmylua_parser::{
     LuaAssignStat, LuaAst, LuaAstNode, LuaAstToken, LuaCommentOwner, LuaDocDescription,
     LuaDocDescriptionOwner, LuaDocGenericDeclList, LuaDocTagAlias, LuaDocTagClass, LuaDocTagEnum,
@@ -11,7 +9,7 @@ use rowan::TextRange;
 use crate::{
     compilation::analyzer::bind_type::bind_type,
     db_index::{LuaDeclId, LuaMemberId, LuaSemanticDeclId, LuaSignatureId, LuaType},
-    LuaTypeCache, LuaTypeDeclId,
+    GenericParam, LuaTypeCache, LuaTypeDeclId,
 };
 
 use super::{
@@ -31,19 +29,12 @@ pub fn analyze_class(analyzer: &mut DocAnalyzer, tag: LuaDocTagClass) -> Option<
     analyzer.current_type_id = Some(class_decl_id.clone());
     if let Some(generic_params) = tag.get_generic_decl() {
         let params = get_generic_params(analyzer, generic_params);
-        let mut params_index = HashMap::new();
-        let mut count = 0;
-        for (name, _) in params.iter() {
-            params_index.insert(name.clone(), count);
-            count += 1;
-        }
-
         analyzer
             .db
             .get_type_index_mut()
-            .add_generic_params(class_decl_id.clone(), params);
+            .add_generic_params(class_decl_id.clone(), params.clone());
 
-        add_generic_index(analyzer, params_index);
+        add_generic_index(analyzer, params);
     }
 
     if let Some(supers) = tag.get_supers() {
@@ -74,8 +65,11 @@ fn add_description_for_type_decl(
 ) {
     let mut description_text = String::new();
 
-    let comment = analyzer.comment.clone();
-    if let Some(description) = comment.get_description() {
+    if let Some(description) = analyzer
+        .comment
+        .as_ref()
+        .and_then(|comment| comment.get_description())
+    {
         let description = preprocess_description(&description.get_description_text());
         if !description.is_empty() {
             description_text.push_str(&description);
@@ -156,21 +150,14 @@ pub fn analyze_alias(analyzer: &mut DocAnalyzer, tag: LuaDocTagAlias) -> Option<
 
     if let Some(generic_params) = tag.get_generic_decl_list() {
         let params = get_generic_params(analyzer, generic_params);
-        let mut params_index = HashMap::new();
-        let mut count = 0;
-        for (name, _) in params.iter() {
-            params_index.insert(name.clone(), count);
-            count += 1;
-        }
-
         analyzer
             .db
             .get_type_index_mut()
-            .add_generic_params(alias_decl_id.clone(), params);
-        let range = analyzer.comment.get_range();
+            .add_generic_params(alias_decl_id.clone(), params.clone());
+        let range = analyzer.comment.as_ref()?.get_range();
         analyzer
             .generic_index
-            .add_generic_scope(vec![range], params_index, false);
+            .add_generic_scope(vec![range], params, false);
     }
 
     let origin_type = infer_type(analyzer, tag.get_type()?);
@@ -188,10 +175,10 @@ pub fn analyze_alias(analyzer: &mut DocAnalyzer, tag: LuaDocTagAlias) -> Option<
     Some(())
 }
 
-fn get_generic_params(
+pub fn get_generic_params(
     analyzer: &mut DocAnalyzer,
     params: LuaDocGenericDeclList,
-) -> Vec<(String, Option<LuaType>)> {
+) -> Vec<GenericParam> {
     let mut params_result = Vec::new();
     for param in params.get_generic_decl() {
         let name = if let Some(param) = param.get_name_token() {
@@ -200,23 +187,32 @@ fn get_generic_params(
             continue;
         };
 
-        let type_ref = if let Some(type_ref) = param.get_type() {
-            Some(infer_type(analyzer, type_ref))
-        } else {
-            None
-        };
-
-        params_result.push((name, type_ref));
+        let constraint = param
+            .get_type()
+            .map(|type_ref| infer_type(analyzer, type_ref));
+        let default = param
+            .get_default_type()
+            .map(|type_ref| infer_type(analyzer, type_ref));
+
+        params_result.push(GenericParam {
+            name,
+            constraint,
+            default,
+            variance: param.get_variance(),
+        });
     }
 
     params_result
 }
 
-fn add_generic_index(analyzer: &mut DocAnalyzer, params_index: HashMap<String, usize>) {
+fn add_generic_index(analyzer: &mut DocAnalyzer, params: Vec<GenericParam>) {
+    let Some(comment) = analyzer.comment.clone() else {
+        return;
+    };
     let mut ranges = Vec::new();
-    let range = analyzer.comment.get_range();
+    let range = comment.get_range();
     ranges.push(range);
-    if let Some(comment_owner) = analyzer.comment.get_owner() {
+    if let Some(comment_owner) = comment.get_owner() {
         let range = comment_owner.get_range();
         ranges.push(range);
         match comment_owner {
@@ -236,7 +232,7 @@ fn add_generic_index(analyzer: &mut DocAnalyzer, params_index: HashMap<String, u
 
     analyzer
         .generic_index
-        .add_generic_scope(ranges, params_index, false);
+        .add_generic_scope(ranges, params, false);
 }
 
 fn get_local_stat_reference_ranges(
@@ -326,38 +322,21 @@ fn get_global_reference_ranges(
 }
 
 pub fn analyze_func_generic(analyzer: &mut DocAnalyzer, tag: LuaDocTagGeneric) -> Option<()> {
-    let comment_owner = analyzer.comment.get_owner()?;
-    let mut params_result = HashMap::new();
-    let mut param_info = Vec::new();
-    if let Some(params_list) = tag.get_generic_decl_list() {
-        let mut count = 0;
-        for param in params_list.get_generic_decl() {
-            let name = if let Some(param) = param.get_name_token() {
-                param.get_name_text().to_string()
-            } else {
-                continue;
-            };
-
-            let type_ref = if let Some(type_ref) = param.get_type() {
-                Some(infer_type(analyzer, type_ref))
-            } else {
-                None
-            };
-
-            params_result.insert(name.clone(), count);
-            param_info.push((name, type_ref));
-            count += 1;
-        }
-    }
+    let comment_owner = analyzer.comment.as_ref()?.get_owner()?;
+    let param_info = if let Some(params_list) = tag.get_generic_decl_list() {
+        get_generic_params(analyzer, params_list)
+    } else {
+        Vec::new()
+    };
 
     let mut ranges = Vec::new();
-    let range = analyzer.comment.get_range();
+    let range = analyzer.comment.as_ref()?.get_range();
     ranges.push(range);
     let range = comment_owner.get_range();
     ranges.push(range);
     analyzer
         .generic_index
-        .add_generic_scope(ranges, params_result, true);
+        .add_generic_scope(ranges, param_info.clone(), true);
 
     let closure = find_owner_closure(analyzer)?;
     let signature_id = LuaSignatureId::from_closure(analyzer.file_id, &closure);
@@ -371,7 +350,7 @@ pub fn analyze_func_generic(analyzer: &mut DocAnalyzer, tag: LuaDocTagGeneric) -
 }
 
 fn bind_def_type(analyzer: &mut DocAnalyzer, type_def: LuaType) -> Option<()> {
-    let owner = analyzer.comment.get_owner()?;
+    let owner = analyzer.comment.as_ref()?.get_owner()?;
     match owner {
         LuaAst::LuaLocalStat(local_stat) => {
             let local_name = local_stat.child::<LuaLocalName>()?;
@@ -0,0 +1,24 @@
+use emmylua_parser::LuaAstToken;
+
+use crate::{
+    db_index::DbIndex,
+    profile::Profile,
+    semantic::{infer_embedded_regions, LuaInferCache},
+};
+
+use super::AnalyzeContext;
+
+// the language of a string can depend on the resolved signature of the call it is passed to, so
+// this runs after everything else is resolved
+pub fn analyze(db: &mut DbIndex, context: &mut AnalyzeContext) {
+    let _p = Profile::cond_new("embedded analyze", context.tree_list.len() > 1);
+    for in_filed_tree in &context.tree_list {
+        let file_id = in_filed_tree.file_id;
+        let mut cache = LuaInferCache::new(file_id, Default::default());
+        let regions = infer_embedded_regions(db, &mut cache, &in_filed_tree.value)
+            .into_iter()
+            .map(|region| (region.token.get_range(), region.language))
+            .collect();
+        db.get_embedded_index_mut().add_regions(file_id, regions);
+    }
+}
@@ -1,9 +1,9 @@
 use std::collections::HashMap;
 
 use emmylua_parser::{
-    LuaAst, LuaAstNode, LuaAstToken, LuaBlock, LuaBreakStat, LuaChunk, LuaDocTagCast, LuaGotoStat,
-    LuaIndexExpr, LuaLabelStat, LuaLoopStat, LuaNameExpr, LuaStat, LuaSyntaxKind, LuaTokenKind,
-    PathTrait,
+    BinaryOperator, LuaAst, LuaAstNode, LuaAstToken, LuaBinaryExpr, LuaBlock, LuaBreakStat,
+    LuaChunk, LuaDocTagCast, LuaExpr, LuaGotoStat, LuaIndexExpr, LuaIndexKey, LuaLabelStat,
+    LuaLoopStat, LuaNameExpr, LuaStat, LuaSyntaxKind, PathTrait,
 };
 use rowan::{TextRange, TextSize, WalkEvent};
 use smol_str::SmolStr;
@@ -21,6 +21,9 @@ pub struct LuaFlowTreeBuilder {
     flow_nodes: HashMap<LuaFlowId, FlowNode>,
     var_flow_ref: HashMap<LuaVarRefId, Vec<(LuaVarRefNode, LuaFlowId)>>,
     root_flow_id: LuaFlowId,
+    // assigned access paths and the end of their assignment
+    assign_paths: Vec<(SmolStr, TextSize, LuaFlowId)>,
+    call_ends: Vec<(TextSize, LuaFlowId)>,
 }
 
 #[allow(unused)]
@@ -33,6 +36,8 @@ impl LuaFlowTreeBuilder {
             flow_nodes: HashMap::new(),
             var_flow_ref: HashMap::new(),
             root_flow_id: current_flow_id,
+            assign_paths: Vec::new(),
+            call_ends: Vec::new(),
         };
 
         builder.flow_nodes.insert(
@@ -122,6 +127,38 @@ impl LuaFlowTreeBuilder {
     ) -> Option<&Vec<(LuaVarRefNode, LuaFlowId)>> {
         self.var_flow_ref.get(var_ref_id)
     }
+
+    pub fn add_assign_path(&mut self, path: SmolStr, position: TextSize) {
+        self.assign_paths
+            .push((path, position, self.current_flow_id));
+    }
+
+    pub fn add_call_end(&mut self, position: TextSize) {
+        self.call_ends.push((position, self.current_flow_id));
+    }
+
+    /// positions after which the narrowing of the member path `path` is no longer valid
+    pub fn get_path_invalid_positions(
+        &self,
+        path: &str,
+        include_call: bool,
+    ) -> Vec<(TextSize, LuaFlowId)> {
+        let mut positions = self
+            .assign_paths
+            .iter()
+            .filter(|(prefix, _, _)| {
+                path.len() > prefix.len()
+                    && path.starts_with(prefix.as_str())
+                    && path[prefix.len()..].starts_with('.')
+            })
+            .map(|(_, position, flow_id)| (*position, *flow_id))
+            .collect::<Vec<_>>();
+        if include_call {
+            positions.extend(self.call_ends.iter().copied());
+        }
+
+        positions
+    }
 }
 
 pub fn build_flow_tree(db: &mut DbIndex, file_id: FileId, root: LuaChunk) -> LuaFlowTreeBuilder {
@@ -155,6 +192,27 @@ pub fn build_flow_tree(db: &mut DbIndex, file_id: FileId, root: LuaChunk) -> Lua
                 LuaAst::LuaBreakStat(break_stat) => {
                     build_break_flow(db, &mut flow_tree, file_id, break_stat);
                 }
+                LuaAst::LuaAssignStat(assign_stat) => {
+                    let position = assign_stat.get_range().end();
+                    for var in assign_stat.get_var_and_expr_list().0 {
+                        if let Some(path) = var.get_access_path() {
+                            flow_tree.add_assign_path(SmolStr::new(&path), position);
+                        }
+                    }
+                }
+                LuaAst::LuaLocalStat(local_stat) => {
+                    let position = local_stat.get_range().end();
+                    for local_name in local_stat.get_local_name_list() {
+                        if let Some(name) = local_name.get_name_token() {
+                            flow_tree.add_assign_path(SmolStr::new(name.get_name_text()), position);
+                        }
+                    }
+                }
+                LuaAst::LuaCallExpr(call_expr)
+                    if !call_expr.is_assert() && !call_expr.is_type() =>
+                {
+                    flow_tree.add_call_end(call_expr.get_range().end());
+                }
                 _ => {}
             },
             WalkEvent::Leave(node) => match node {
@@ -182,15 +240,15 @@ fn build_name_expr_flow(
     match &parent {
         LuaAst::LuaIndexExpr(index_expr) => {
             let parent = index_expr.get_parent::<LuaAst>()?;
-            if parent.syntax().kind() != LuaSyntaxKind::CallExpr.into() {
+            if parent.syntax().kind() != LuaSyntaxKind::CallExpr.into()
+                && !is_discriminant_compare(index_expr)
+            {
                 return None;
             }
         }
         LuaAst::LuaCallExpr(_) | LuaAst::LuaFuncStat(_) => return None,
         LuaAst::LuaAssignStat(assign_stat) => {
-            let eq_pos = assign_stat
-                .token_by_kind(LuaTokenKind::TkAssign)?
-                .get_position();
+            let eq_pos = assign_stat.get_assign_op()?.get_position();
             let decl_id = LuaDeclId::new(file_id, name_expr.get_position());
             if db.get_decl_index().get_decl(&decl_id).is_some() {
                 return None;
@@ -247,15 +305,15 @@ fn build_index_expr_flow(
     match parent {
         LuaAst::LuaIndexExpr(index_expr) => {
             let parent = index_expr.get_parent::<LuaAst>()?;
-            if parent.syntax().kind() != LuaSyntaxKind::CallExpr.into() {
+            if parent.syntax().kind() != LuaSyntaxKind::CallExpr.into()
+                && !is_discriminant_compare(&index_expr)
+            {
                 return None;
             }
         }
         LuaAst::LuaCallExpr(_) | LuaAst::LuaFuncStat(_) => return None,
         LuaAst::LuaAssignStat(assign_stat) => {
-            let eq_pos = assign_stat
-                .token_by_kind(LuaTokenKind::TkAssign)?
-                .get_position();
+            let eq_pos = assign_stat.get_assign_op()?.get_position();
 
             let decl_id = LuaDeclId::new(file_id, index_expr.get_position());
             if db.get_decl_index().get_decl(&decl_id).is_some() {
@@ -269,6 +327,10 @@ fn build_index_expr_flow(
         _ => {}
     }
 
+    if !is_constant_access_path(db, file_id, &index_expr) {
+        return None;
+    }
+
     let ref_id = LuaVarRefId::Name(SmolStr::new(&index_expr.get_access_path()?));
     if is_assign {
         builder.add_flow_node(ref_id, LuaVarRefNode::AssignRef(index_expr.into()));
@@ -279,6 +341,66 @@ fn build_index_expr_flow(
     Some(())
 }
 
+/// `x.kind == "a"` or `x.kind ~= "a"`, which narrows `x` itself
+fn is_discriminant_compare(index_expr: &LuaIndexExpr) -> bool {
+    let Some(binary_expr) = index_expr.get_parent::<LuaBinaryExpr>() else {
+        return false;
+    };
+    let Some(op) = binary_expr.get_op_token() else {
+        return false;
+    };
+    if !matches!(op.get_op(), BinaryOperator::OpEq | BinaryOperator::OpNe) {
+        return false;
+    }
+
+    let Some((left, right)) = binary_expr.get_exprs() else {
+        return false;
+    };
+    let other = if left.get_position() == index_expr.get_position() {
+        right
+    } else {
+        left
+    };
+
+    matches!(other, LuaExpr::LiteralExpr(_))
+        && matches!(
+            index_expr.get_index_key(),
+            Some(LuaIndexKey::Name(_) | LuaIndexKey::String(_))
+        )
+}
+
+/// only paths like `a.b.c`, `a[1]`, `a["b"]` or `a[k]` with a constant local `k` can be narrowed
+fn is_constant_access_path(db: &DbIndex, file_id: FileId, index_expr: &LuaIndexExpr) -> bool {
+    let mut current = index_expr.clone();
+    loop {
+        match current.get_index_key() {
+            Some(LuaIndexKey::Name(_) | LuaIndexKey::String(_) | LuaIndexKey::Integer(_)) => {}
+            Some(LuaIndexKey::Expr(LuaExpr::NameExpr(name_expr)))
+                if is_constant_local(db, file_id, &name_expr) => {}
+            _ => return false,
+        }
+
+        match current.get_prefix_expr() {
+            Some(LuaExpr::IndexExpr(prefix)) => current = prefix,
+            Some(LuaExpr::NameExpr(_)) => return true,
+            _ => return false,
+        }
+    }
+}
+
+/// a local that is never assigned after its declaration
+fn is_constant_local(db: &DbIndex, file_id: FileId, name_expr: &LuaNameExpr) -> bool {
+    let Some(file_reference) = db.get_reference_index().get_local_reference(&file_id) else {
+        return false;
+    };
+    let Some(decl_id) = file_reference.get_decl_id(&name_expr.get_range()) else {
+        return false;
+    };
+    file_reference
+        .get_decl_references(&decl_id)
+        .is_some_and(|references| references.iter().all(|reference| !reference.is_write))
+}
+
 fn build_cast_flow(
     db: &DbIndex,
     builder: &mut LuaFlowTreeBuilder,
@@ -75,7 +75,18 @@ fn analyze_flow(
         }
     }
 
-    for (_, var_trace) in var_trace_map {
+    let call_invalidates_field = db
+        .get_file_emmyrc(&file_id)
+        .strict
+        .call_invalidates_field_narrowing;
+    for (var_ref_id, mut var_trace) in var_trace_map {
+        if let LuaVarRefId::Name(path) = &var_ref_id {
+            if path.contains('.') {
+                let positions = flow_tree.get_path_invalid_positions(path, call_invalidates_field);
+                var_trace.invalidate_after(&positions);
+            }
+        }
+
         db.get_flow_index_mut()
             .add_flow_chain(file_id, var_trace.finish());
     }
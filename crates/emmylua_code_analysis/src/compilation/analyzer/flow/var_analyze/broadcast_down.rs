@@ -56,7 +56,7 @@ fn is_stat_change_flow(stat: LuaStat) -> Option<bool> {
         }
         LuaStat::ReturnStat(_) => Some(true),
         LuaStat::DoStat(do_stat) => Some(is_block_has_return(do_stat.get_block()).unwrap_or(false)),
-        LuaStat::BreakStat(_) => Some(true),
+        LuaStat::BreakStat(_) | LuaStat::ContinueStat(_) => Some(true),
         _ => Some(false),
     }
 }
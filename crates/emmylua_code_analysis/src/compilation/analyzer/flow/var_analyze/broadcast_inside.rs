@@ -43,7 +43,7 @@ fn analyze_block_inside_condition(
                     return Some(());
                 }
             }
-            LuaStat::ReturnStat(_) | LuaStat::BreakStat(_) => {
+            LuaStat::ReturnStat(_) | LuaStat::BreakStat(_) | LuaStat::ContinueStat(_) => {
                 let ne_type_assert = trace_info.type_assertion.get_negation()?;
                 let ne_trace_info = trace_info.with_type_assertion(ne_type_assert);
                 broadcast_outside_block(db, var_trace, ne_trace_info, origin_block.clone());
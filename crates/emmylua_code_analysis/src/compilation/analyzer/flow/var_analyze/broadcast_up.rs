@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use emmylua_parser::{
     BinaryOperator, LuaAst, LuaAstNode, LuaBinaryExpr, LuaCallArgList, LuaCallExpr,
-    LuaCallExprStat, LuaExpr, LuaLiteralToken, UnaryOperator,
+    LuaCallExprStat, LuaExpr, LuaIndexExpr, LuaIndexKey, LuaLiteralToken, UnaryOperator,
 };
 use smol_str::SmolStr;
 
@@ -182,6 +182,11 @@ pub fn broadcast_up(
                 return None;
             }
 
+            // only `obj:isXXX()` narrows `obj`, `M.isXXX(v)` must not narrow `M`
+            if !index_expr.get_index_token()?.is_colon() {
+                return broadcast_up_field_compare(db, var_trace, index_expr);
+            }
+
             let call_expr = index_expr.get_parent::<LuaCallExpr>()?;
             let param_idx = -1;
 
@@ -379,6 +384,58 @@ fn broadcast_up_call_arg_list(
     Some(())
 }
 
+/// `x.kind == "a"` narrows a discriminated union `x`
+fn broadcast_up_field_compare(
+    db: &mut DbIndex,
+    var_trace: &mut VarTrace,
+    index_expr: LuaIndexExpr,
+) -> Option<()> {
+    let key = match index_expr.get_index_key()? {
+        LuaIndexKey::Name(name) => SmolStr::new(name.get_name_text()),
+        LuaIndexKey::String(string) => SmolStr::new(string.get_value()),
+        _ => return None,
+    };
+
+    let binary_expr = index_expr.get_parent::<LuaBinaryExpr>()?;
+    let is_eq = match binary_expr.get_op_token()?.get_op() {
+        BinaryOperator::OpEq => true,
+        BinaryOperator::OpNe => false,
+        _ => return None,
+    };
+
+    let (left, right) = binary_expr.get_exprs()?;
+    let other = if left.get_position() == index_expr.get_position() {
+        right
+    } else {
+        left
+    };
+    let LuaExpr::LiteralExpr(literal) = other else {
+        return None;
+    };
+
+    let value = match literal.get_literal()? {
+        LuaLiteralToken::String(s) => LuaType::StringConst(SmolStr::new(s.get_value()).into()),
+        LuaLiteralToken::Number(i) if i.is_int() => LuaType::IntegerConst(i.get_int_value()),
+        LuaLiteralToken::Bool(b) => LuaType::BooleanConst(b.is_true()),
+        _ => return None,
+    };
+
+    let type_assert = if is_eq {
+        TypeAssertion::NarrowField(key, value)
+    } else {
+        TypeAssertion::RemoveField(key, value)
+    };
+
+    broadcast_up(
+        db,
+        var_trace,
+        VarTraceInfo::new(type_assert, LuaAst::cast(binary_expr.syntax().clone())?).into(),
+        binary_expr.get_parent::<LuaAst>()?,
+    );
+
+    Some(())
+}
+
 fn broadcast_up_type_assert(
     db: &mut DbIndex,
     var_trace: &mut VarTrace,
@@ -69,6 +69,25 @@ pub fn analyze_ref_assign(
     }
 
     let (var_exprs, value_exprs) = assign_stat.get_var_and_expr_list();
+    if let Some(op) = assign_stat.get_compound_op() {
+        let value_expr = value_exprs.first()?;
+        let type_assert = TypeAssertion::CompoundReassign {
+            op,
+            id: value_expr.get_syntax_id(),
+        };
+        broadcast_down_after_node(
+            db,
+            var_trace,
+            Arc::new(VarTraceInfo::new(
+                type_assert,
+                LuaAst::cast(value_expr.syntax().clone())?,
+            )),
+            LuaAst::LuaAssignStat(assign_stat),
+            true,
+        );
+        return Some(());
+    }
+
     let var_index = var_exprs
         .iter()
         .position(|it| it.get_position() == var_expr.get_position())?;
@@ -1,6 +1,6 @@
 use std::{collections::HashMap, sync::Arc, vec};
 
-use rowan::TextRange;
+use rowan::{TextRange, TextSize};
 
 use crate::{
     compilation::analyzer::flow::build_flow_tree::LuaFlowTreeBuilder, LuaFlowChain,
@@ -142,6 +142,21 @@ impl<'a> VarTrace<'a> {
         !self.unresolve_traces.is_empty()
     }
 
+    /// stop the narrowing at the given positions, e.g. after a prefix of a member path is assigned
+    pub fn invalidate_after(&mut self, positions: &[(TextSize, LuaFlowId)]) {
+        for assertion in &mut self.assertions {
+            for (position, invalid_flow_id) in positions {
+                // an assignment in a nested `if` or loop may run, so it ends the narrowing too
+                if assertion.allow_flow_id.contains(invalid_flow_id)
+                    && assertion.range.start() < *position
+                    && *position < assertion.range.end()
+                {
+                    assertion.range = TextRange::new(assertion.range.start(), *position);
+                }
+            }
+        }
+    }
+
     pub fn finish(self) -> LuaFlowChain {
         let mut asserts = self.assertions;
         asserts.sort_by(|a, b| a.range.start().cmp(&b.range.start()));
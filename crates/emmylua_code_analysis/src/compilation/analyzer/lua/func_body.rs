@@ -65,7 +65,7 @@ fn analyze_block_returns(block: LuaBlock, returns: &mut Vec<LuaReturnPoint>) ->
                     _ => {}
                 }
             }
-            LuaStat::BreakStat(_) => {
+            LuaStat::BreakStat(_) | LuaStat::ContinueStat(_) => {
                 return Some(ChangeFlow::Break);
             }
             LuaStat::ReturnStat(return_stat) => {
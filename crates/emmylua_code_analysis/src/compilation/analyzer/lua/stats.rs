@@ -1,5 +1,5 @@
 use emmylua_parser::{
-    BinaryOperator, LuaAssignStat, LuaAstNode, LuaExpr, LuaFuncStat, LuaIndexExpr,
+    BinaryOperator, LuaAssignStat, LuaAstNode, LuaExpr, LuaFuncStat, LuaGlobalStat, LuaIndexExpr,
     LuaLocalFuncStat, LuaLocalStat, LuaTableField, LuaVarExpr, PathTrait,
 };
 
@@ -250,6 +250,10 @@ fn set_index_expr_owner(analyzer: &mut LuaAnalyzer, var_expr: LuaVarExpr) -> Opt
 
 // assign stat is toooooooooo complex
 pub fn analyze_assign_stat(analyzer: &mut LuaAnalyzer, assign_stat: LuaAssignStat) -> Option<()> {
+    if assign_stat.is_compound_assign() {
+        return Some(());
+    }
+
     let (var_list, expr_list) = assign_stat.get_var_and_expr_list();
     let expr_count = expr_list.len();
     let var_count = var_list.len();
@@ -437,6 +441,51 @@ pub fn analyze_local_func_stat(
     Some(())
 }
 
+pub fn analyze_global_stat(analyzer: &mut LuaAnalyzer, global_stat: LuaGlobalStat) -> Option<()> {
+    let expr_list: Vec<_> = global_stat.get_value_exprs().collect();
+    for (i, global_name) in global_stat.get_global_name_list().enumerate() {
+        let decl_id = LuaDeclId::new(analyzer.file_id, global_name.get_position());
+        // `global x` only declares the name, its type comes from the assignments
+        let Some(expr) = expr_list.get(i) else {
+            analyzer
+                .db
+                .get_type_index_mut()
+                .bind_type(decl_id.into(), LuaTypeCache::InferType(LuaType::Unknown));
+            continue;
+        };
+
+        match analyzer.infer_expr(expr) {
+            Ok(mut expr_type) => {
+                if let LuaType::Variadic(multi) = expr_type {
+                    expr_type = multi.get_type(0)?.clone();
+                }
+                bind_type(
+                    analyzer.db,
+                    decl_id.into(),
+                    LuaTypeCache::InferType(expr_type),
+                );
+            }
+            Err(InferFailReason::None) => {
+                analyzer
+                    .db
+                    .get_type_index_mut()
+                    .bind_type(decl_id.into(), LuaTypeCache::InferType(LuaType::Nil));
+            }
+            Err(reason) => {
+                let unresolve = UnResolveDecl {
+                    file_id: analyzer.file_id,
+                    decl_id,
+                    expr: expr.clone(),
+                    ret_idx: 0,
+                };
+                analyzer.context.add_unresolve(unresolve.into(), reason);
+            }
+        }
+    }
+
+    Some(())
+}
+
 pub fn analyze_table_field(analyzer: &mut LuaAnalyzer, field: LuaTableField) -> Option<()> {
     let _ = field.get_field_key()?;
     let value_expr = field.get_value_expr()?;
@@ -1,26 +1,26 @@
 mod bind_type;
 mod decl;
 mod doc;
+mod embedded;
 mod flow;
 mod infer_manager;
 mod lua;
 mod unresolve;
 
-use std::{collections::HashMap, sync::Arc};
+use std::collections::HashMap;
 
-use crate::{
-    db_index::DbIndex, profile::Profile, Emmyrc, InFiled, InferFailReason, LuaType, WorkspaceId,
-};
+use crate::{db_index::DbIndex, profile::Profile, InFiled, InferFailReason, LuaType, WorkspaceId};
 use emmylua_parser::{LuaChunk, LuaSyntaxId};
 use infer_manager::InferCacheManager;
 use unresolve::UnResolve;
 
-pub fn analyze(db: &mut DbIndex, need_analyzed_files: Vec<InFiled<LuaChunk>>, config: Arc<Emmyrc>) {
+/// Each file is analyzed with the config of its workspace folder, `DbIndex::get_file_emmyrc`
+pub fn analyze(db: &mut DbIndex, need_analyzed_files: Vec<InFiled<LuaChunk>>) {
     if need_analyzed_files.is_empty() {
         return;
     }
 
-    let contexts = module_analyze(db, need_analyzed_files, config);
+    let contexts = module_analyze(db, need_analyzed_files);
 
     for (workspace_id, mut context) in contexts {
         let profile_log = format!("analyze workspace {}", workspace_id);
@@ -30,13 +30,13 @@ pub fn analyze(db: &mut DbIndex, need_analyzed_files: Vec<InFiled<LuaChunk>>, co
         flow::analyze(db, &mut context);
         lua::analyze(db, &mut context);
         unresolve::analyze(db, &mut context);
+        embedded::analyze(db, &mut context);
     }
 }
 
 fn module_analyze(
     db: &mut DbIndex,
     need_analyzed_files: Vec<InFiled<LuaChunk>>,
-    config: Arc<Emmyrc>,
 ) -> Vec<(WorkspaceId, AnalyzeContext)> {
     if need_analyzed_files.len() == 1 {
         let in_filed_tree = need_analyzed_files[0].clone();
@@ -54,7 +54,7 @@ fn module_analyze(
                 .get_module_index_mut()
                 .add_module_by_path(file_id, path_str);
             let workspace_id = workspace_id.unwrap_or(WorkspaceId::MAIN);
-            let mut context = AnalyzeContext::new(config);
+            let mut context = AnalyzeContext::new();
             context.add_tree_chunk(in_filed_tree);
             return vec![(workspace_id, context)];
         }
@@ -88,7 +88,7 @@ fn module_analyze(
 
     let mut contexts = Vec::new();
     for (workspace_id, tree_list) in file_tree_map {
-        let mut context = AnalyzeContext::new(config.clone());
+        let mut context = AnalyzeContext::new();
         context.tree_list = tree_list;
         contexts.push((workspace_id, context));
     }
@@ -98,18 +98,15 @@ fn module_analyze(
 #[derive(Debug)]
 pub struct AnalyzeContext {
     tree_list: Vec<InFiled<LuaChunk>>,
-    #[allow(unused)]
-    config: Arc<Emmyrc>,
     cast_flow: HashMap<InFiled<LuaSyntaxId>, LuaType>,
     unresolves: Vec<(UnResolve, InferFailReason)>,
     infer_manager: InferCacheManager,
 }
 
 impl AnalyzeContext {
-    pub fn new(emmyrc: Arc<Emmyrc>) -> Self {
+    pub fn new() -> Self {
         Self {
             tree_list: Vec::new(),
-            config: emmyrc,
             cast_flow: HashMap::new(),
             unresolves: Vec::new(),
             infer_manager: InferCacheManager::new(),
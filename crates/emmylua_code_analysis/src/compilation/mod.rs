@@ -1,7 +1,10 @@
 mod analyzer;
 mod test;
 
-use std::sync::Arc;
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use crate::{
     db_index::DbIndex, semantic::SemanticModel, Emmyrc, FileId, InFiled, LuaIndex, LuaInferCache,
@@ -10,17 +13,13 @@ use crate::{
 #[derive(Debug)]
 pub struct LuaCompilation {
     db: DbIndex,
-    emmyrc: Arc<Emmyrc>,
 }
 
 impl LuaCompilation {
     pub fn new(emmyrc: Arc<Emmyrc>) -> Self {
-        let mut compilation = Self {
-            db: DbIndex::new(),
-            emmyrc: emmyrc.clone(),
-        };
+        let mut compilation = Self { db: DbIndex::new() };
 
-        compilation.db.update_config(emmyrc.clone());
+        compilation.db.update_config(emmyrc);
         compilation
     }
 
@@ -31,7 +30,7 @@ impl LuaCompilation {
             file_id,
             &self.db,
             cache,
-            self.emmyrc.clone(),
+            self.db.get_file_emmyrc(&file_id),
             tree.get_chunk_node(),
         ))
     }
@@ -52,7 +51,7 @@ impl LuaCompilation {
             });
         }
 
-        analyzer::analyze(&mut self.db, need_analyzed_files, self.emmyrc.clone());
+        analyzer::analyze(&mut self.db, need_analyzed_files);
     }
 
     pub fn remove_index(&mut self, file_ids: Vec<FileId>) {
@@ -72,7 +71,14 @@ impl LuaCompilation {
     }
 
     pub fn update_config(&mut self, config: Arc<Emmyrc>) {
-        self.emmyrc = config.clone();
         self.db.update_config(config);
     }
+
+    pub fn update_workspace_config(&mut self, root: PathBuf, config: Arc<Emmyrc>) {
+        self.db.update_workspace_config(root, config);
+    }
+
+    pub fn remove_workspace_config(&mut self, root: &Path) {
+        self.db.remove_workspace_config(root);
+    }
 }
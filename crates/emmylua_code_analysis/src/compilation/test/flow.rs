@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod test {
 
+    use std::sync::Arc;
+
     use crate::{DiagnosticCode, LuaType, VirtualWorkspace};
 
     #[test]
@@ -782,4 +784,530 @@ end
             "#
         ));
     }
+
+    #[test]
+    fn test_type_guard_across_modules() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def_file(
+            "guards.lua",
+            r#"
+            local M = {}
+
+            ---@param v any
+            ---@return TypeGuard<string>
+            function M.isString(v)
+                return type(v) == "string"
+            end
+
+            ---@return boolean
+            ---@return_cast n integer
+            function M.isInteger(n)
+                return math.type(n) == "integer"
+            end
+
+            return M
+            "#,
+        );
+
+        ws.def(
+            r#"
+            local guards = require("guards")
+
+            local a ---@type string | boolean
+            if guards.isString(a) then
+                b = a
+            end
+
+            local c ---@type integer | string
+            if guards.isInteger(c) then
+                d = c
+            else
+                e = c
+            end
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("b"), ws.ty("string"));
+        assert_eq!(ws.expr_ty("d"), ws.ty("integer"));
+        assert_eq!(ws.expr_ty("e"), ws.ty("string"));
+    }
+
+    #[test]
+    fn test_method_return_cast_across_modules() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def_file(
+            "node.lua",
+            r#"
+            ---@class Node
+            local Node = {}
+
+            ---@class Leaf: Node
+
+            ---@return Node
+            function Node.new()
+                return setmetatable({}, { __index = Node })
+            end
+
+            ---@return boolean
+            ---@return_cast self Leaf
+            function Node:isLeaf()
+                return false
+            end
+
+            return Node
+            "#,
+        );
+
+        ws.def(
+            r#"
+            local Node = require("node")
+
+            ---@param node Node
+            function f(node)
+                if node:isLeaf() then
+                    a = node
+                end
+            end
+
+            local n = Node.new()
+            if not n:isLeaf() then
+                return
+            end
+            b = n
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), ws.ty("Leaf"));
+        assert_eq!(ws.expr_ty("b"), ws.ty("Leaf"));
+    }
+
+    #[test]
+    fn test_type_guard_not_and_or() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+            ---@param v any
+            ---@return TypeGuard<string>
+            local function isString(v)
+                return type(v) == "string"
+            end
+
+            local a ---@type string | integer
+            if not isString(a) then
+                b = a
+            else
+                c = a
+            end
+
+            local x ---@type string | integer | nil
+            if x and isString(x) then
+                d = x
+            end
+
+            local y ---@type string | integer
+            if isString(y) or false then
+                e = y
+            end
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("b"), ws.ty("integer"));
+        assert_eq!(ws.expr_ty("c"), ws.ty("string"));
+        assert_eq!(ws.expr_ty("d"), ws.ty("string"));
+        assert_eq!(ws.expr_ty("e"), ws.ty("string"));
+    }
+
+    #[test]
+    fn test_type_guard_early_return() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def_file(
+            "guards.lua",
+            r#"
+            local M = {}
+
+            ---@param v any
+            ---@return TypeGuard<string>
+            function M.isString(v)
+                return type(v) == "string"
+            end
+
+            return M
+            "#,
+        );
+
+        ws.def(
+            r#"
+            local guards = require("guards")
+
+            ---@param v string | integer
+            function f(v)
+                if not guards.isString(v) then
+                    return
+                end
+
+                a = v
+            end
+
+            ---@param v string | integer
+            function g(v)
+                if guards.isString(v) then
+                    return
+                end
+
+                b = v
+            end
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), ws.ty("string"));
+        assert_eq!(ws.expr_ty("b"), ws.ty("integer"));
+    }
+
+    #[test]
+    fn test_type_guard_does_not_narrow_module() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def_file(
+            "guards.lua",
+            r#"
+            local M = {}
+
+            ---@param v any
+            ---@param w any
+            ---@return TypeGuard<string>
+            function M.isString(v, w)
+                return type(v) == "string"
+            end
+
+            return M
+            "#,
+        );
+
+        ws.def(
+            r#"
+            local M = require("guards")
+
+            local a ---@type string | integer
+            if not M.isString(a) then
+                error("not a string")
+            end
+            b = a
+
+            local c ---@type string | integer
+            local d ---@type string | integer
+            if M.isString(c, d) then
+                e = c
+                f = d
+            end
+
+            local g ---@type string | integer
+            local h ---@type string | integer
+            if not (M.isString(g) and M.isString(h)) then
+                return
+            end
+            i = g
+            j = h
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("b"), ws.ty("string"));
+        assert_eq!(ws.expr_ty("e"), ws.ty("string"));
+        assert_eq!(ws.expr_ty("f"), ws.ty("string | integer"));
+        assert_eq!(ws.expr_ty("i"), ws.ty("string"));
+        assert_eq!(ws.expr_ty("j"), ws.ty("string"));
+    }
+
+    #[test]
+    fn test_member_path_narrow() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+            ---@class Conn
+            ---@field close fun(self: Conn)
+
+            ---@class Client
+            ---@field conn Conn?
+            ---@field inner { conn: Conn? }
+            ---@field list (Conn?)[]
+
+            ---@param c Client
+            function f(c)
+                if c.conn then
+                    a = c.conn
+                end
+
+                if c.inner.conn then
+                    b = c.inner.conn
+                end
+
+                if c.list[1] then
+                    d = c.list[1]
+                end
+
+                if not c["conn"] then
+                    return
+                end
+                e = c.conn
+            end
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), ws.ty("Conn"));
+        assert_eq!(ws.expr_ty("b"), ws.ty("Conn"));
+        assert_eq!(ws.expr_ty("d"), ws.ty("Conn"));
+        assert_eq!(ws.expr_ty("e"), ws.ty("Conn"));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::NeedCheckNil,
+            r#"
+            ---@class Conn2
+            local Conn2 = {}
+
+            function Conn2:close() end
+
+            ---@class Client2
+            ---@field conn Conn2?
+            local Client2 = {}
+
+            function Client2:stop()
+                if self.conn then
+                    self.conn:close()
+                end
+
+                if not self.conn then
+                    return
+                end
+                self.conn:close()
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_member_path_narrow_invalidate() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+            ---@class Conn
+
+            ---@class Client
+            ---@field conn Conn?
+            ---@field inner { conn: Conn? }
+
+            ---@param c Client
+            ---@param other Client
+            function f(c, other)
+                if c.inner.conn then
+                    c.inner = {}
+                    a = c.inner.conn
+                end
+
+                if c.conn then
+                    c.conn = nil
+                    b = c.conn
+                end
+
+                if other.conn then
+                    print()
+                    d = other.conn
+                end
+            end
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), ws.ty("Conn?"));
+        assert_eq!(ws.expr_ty("b"), ws.ty("Conn?"));
+        // calls keep the narrowing unless `strict.callInvalidatesFieldNarrowing` is set
+        assert_eq!(ws.expr_ty("d"), ws.ty("Conn"));
+    }
+
+    #[test]
+    fn test_member_path_narrow_invalidate_nested() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+            ---@class Conn
+
+            ---@class Client
+            ---@field inner { conn: Conn? }
+
+            ---@param c Client
+            ---@param other Client
+            ---@param flag boolean
+            function f(c, other, flag)
+                if c.inner.conn then
+                    if flag then
+                        c.inner = other.inner
+                    end
+                    a = c.inner.conn
+                end
+
+                if c.inner.conn then
+                    for _ = 1, 2 do
+                        c.inner = other.inner
+                    end
+                    b = c.inner.conn
+                end
+
+                if c.inner.conn then
+                    if flag then
+                        local inner = c.inner
+                    end
+                    d = c.inner.conn
+                end
+            end
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), ws.ty("Conn?"));
+        assert_eq!(ws.expr_ty("b"), ws.ty("Conn?"));
+        assert_eq!(ws.expr_ty("d"), ws.ty("Conn"));
+    }
+
+    #[test]
+    fn test_member_path_narrow_array() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+            ---@class Conn
+
+            ---@param list (Conn?)[]
+            ---@param k integer
+            ---@param i integer
+            function f(list, k, i)
+                if list[1] then
+                    a = list[1]
+                    list[1] = nil
+                    b = list[1]
+                end
+
+                if list[k] then
+                    d = list[k]
+                end
+
+                -- `i` is reassigned, so `list[i]` is never narrowed
+                if list[i] then
+                    e = list[i]
+                end
+                i = i + 1
+            end
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), ws.ty("Conn"));
+        assert_eq!(ws.expr_ty("b"), ws.ty("nil"));
+        assert_eq!(ws.expr_ty("d"), ws.ty("Conn"));
+        assert!(ws.expr_ty("e").is_nullable());
+    }
+
+    #[test]
+    fn test_member_path_narrow_invalidate_by_call() {
+        let mut ws = VirtualWorkspace::new();
+        let mut emmyrc = ws.analysis.emmyrc.as_ref().clone();
+        emmyrc.strict.call_invalidates_field_narrowing = true;
+        ws.analysis.update_config(Arc::new(emmyrc));
+
+        ws.def(
+            r#"
+            ---@class Conn
+
+            ---@class Client
+            ---@field conn Conn?
+
+            ---@param c Client
+            function f(c)
+                if c.conn then
+                    a = c.conn
+                    print(c.conn)
+                    b = c.conn
+                end
+            end
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), ws.ty("Conn"));
+        assert_eq!(ws.expr_ty("b"), ws.ty("Conn?"));
+    }
+
+    #[test]
+    fn test_discriminated_union_narrow() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+            ---@alias Msg {kind: "a", x: integer} | {kind: "b", y: string} | {kind: 1, z: boolean}
+
+            ---@param msg Msg
+            function f(msg)
+                if msg.kind == "a" then
+                    a = msg.x
+                elseif msg.kind == "b" then
+                    b = msg.y
+                else
+                    c = msg.z
+                end
+
+                if msg.kind ~= "a" and msg.kind ~= 1 then
+                    d = msg.y
+                end
+            end
+
+            ---@param v string | Msg
+            function g(v)
+                if type(v) == "table" and v["kind"] == 1 then
+                    e = v.z
+                end
+            end
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), ws.ty("integer"));
+        assert_eq!(ws.expr_ty("b"), ws.ty("string"));
+        assert_eq!(ws.expr_ty("c"), ws.ty("boolean"));
+        assert_eq!(ws.expr_ty("d"), ws.ty("string"));
+        assert_eq!(ws.expr_ty("e"), ws.ty("boolean"));
+    }
+
+    #[test]
+    fn test_discriminated_class_union_narrow() {
+        let mut ws = VirtualWorkspace::new();
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndefinedField,
+            r#"
+            ---@class Circle
+            ---@field shape "circle"
+            ---@field radius number
+
+            ---@class Rect
+            ---@field shape "rect"
+            ---@field width number
+
+            ---@class Shapes
+            ---@field current Circle | Rect
+
+            ---@param s Circle | Rect
+            ---@param shapes Shapes
+            function area(s, shapes)
+                if s.shape == "circle" then
+                    local r = s.radius
+                else
+                    local w = s.width
+                end
+
+                if shapes.current.shape == "rect" then
+                    local w = shapes.current.width
+                end
+            end
+            "#
+        ));
+    }
 }
@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use crate::{DiagnosticCode, EmmyrcLuaVersion, LuaType, VirtualWorkspace};
+
+    fn luau_workspace() -> VirtualWorkspace {
+        let mut ws = VirtualWorkspace::new();
+        let mut emmyrc = ws.analysis.emmyrc.as_ref().clone();
+        emmyrc.runtime.version = EmmyrcLuaVersion::Luau;
+        ws.analysis.update_config(Arc::new(emmyrc));
+        ws
+    }
+
+    #[test]
+    fn test_luau_local_annotation() {
+        let mut ws = luau_workspace();
+        ws.def(
+            r#"
+            local a: number? = nil
+            b = a
+            "#,
+        );
+
+        let ty = ws.expr_ty("b");
+        assert_eq!(ws.humanize_type(ty), "number?");
+    }
+
+    #[test]
+    fn test_luau_type_alias() {
+        let mut ws = luau_workspace();
+        ws.def(
+            r#"
+            export type Point = { x: number, y: number }
+            type Callback<T> = (value: T) -> ()
+
+            local p: Point = { x = 1, y = 2 }
+            px = p.x
+            "#,
+        );
+
+        let ty = ws.expr_ty("px");
+        assert_eq!(ty, LuaType::Number);
+        let point = ws.ty("Point");
+        let shape = ws.ty("{ x: number, y: number }");
+        assert!(ws.check_type(&point, &shape));
+    }
+
+    #[test]
+    fn test_luau_function_annotation() {
+        let mut ws = luau_workspace();
+        ws.def(
+            r#"
+            local function add(a: number, b: number): number
+                return a + b
+            end
+
+            local function pair(): (string, boolean)
+                return "", true
+            end
+
+            r1 = add(1, 2)
+            r2, r3 = pair()
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("r1"), LuaType::Number);
+        assert_eq!(ws.expr_ty("r2"), LuaType::String);
+        assert_eq!(ws.expr_ty("r3"), LuaType::Boolean);
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ParamTypeNotMatch,
+            r#"
+            local function sub(a: number, b: number): number
+                return a - b
+            end
+
+            sub("a", 2)
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_luau_generic_function() {
+        let mut ws = luau_workspace();
+        ws.def(
+            r#"
+            local function identity<T>(value: T): T
+                return value
+            end
+
+            local s: string = "hello"
+            r = identity(s)
+            "#,
+        );
+
+        let ty = ws.expr_ty("r");
+        assert_eq!(ws.humanize_type(ty), "string");
+    }
+
+    #[test]
+    fn test_luau_expressions() {
+        let mut ws = luau_workspace();
+        ws.def(
+            r#"
+            local value = 1
+            r1 = `value is {value}`
+            r2 = if value > 0 then "positive" else 0
+            r3 = (value :: any) :: string
+            value += 1
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("r1"), LuaType::String);
+        let ty = ws.expr_ty("r2");
+        assert_eq!(ws.humanize_type(ty), r#"("positive"|0)"#);
+        assert_eq!(ws.expr_ty("r3"), LuaType::String);
+    }
+}
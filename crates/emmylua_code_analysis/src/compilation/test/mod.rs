@@ -9,6 +9,7 @@ mod flow;
 mod for_range_var_infer_test;
 mod infer_str_tpl_test;
 mod inherit_type;
+mod luau_test;
 mod mathlib_test;
 mod member_infer_test;
 mod metatable_test;
@@ -20,7 +21,10 @@ mod overload_test;
 mod pcall_test;
 mod return_unwrap_test;
 mod static_cal_cmp;
+mod structural_search_test;
 mod syntax_error_test;
+mod syntax_extension_test;
 mod tuple_test;
 mod type_check_test;
 mod unpack_test;
+mod workspace_config_test;
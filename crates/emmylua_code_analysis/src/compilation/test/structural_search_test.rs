@@ -0,0 +1,228 @@
+#[cfg(test)]
+mod test {
+    use emmylua_parser::LuaTextEdit;
+
+    use crate::{
+        Emmyrc, EmmyrcLuaVersion, StructuralSearch, StructuralSearchError, VirtualWorkspace,
+    };
+
+    fn replace(ws: &mut VirtualWorkspace, code: &str, search: &StructuralSearch) -> String {
+        let file_id = ws.def(code);
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        LuaTextEdit::apply(code, &search.replace(&semantic_model))
+    }
+
+    #[test]
+    fn test_swap_arguments() {
+        let mut ws = VirtualWorkspace::new();
+        let search = StructuralSearch::new("foo.bar($x, $y)", &Emmyrc::default())
+            .unwrap()
+            .with_replacement("foo:baz($y, $x)")
+            .unwrap();
+        let result = replace(
+            &mut ws,
+            r#"
+            foo.bar(1, "a")
+            local v = foo.bar( t[1] , -- comment
+                g(2))
+            foo.bar(1)
+            other.bar(1, 2)
+            "#,
+            &search,
+        );
+        assert_eq!(
+            result,
+            r#"
+            foo:baz("a", 1)
+            local v = foo.bar( t[1] , -- comment
+                g(2))
+            foo.bar(1)
+            other.bar(1, 2)
+            "#
+        );
+    }
+
+    #[test]
+    fn test_keep_comments() {
+        let mut ws = VirtualWorkspace::new();
+        let search = StructuralSearch::new("foo.bar($x, $y)", &Emmyrc::default())
+            .unwrap()
+            .with_replacement("foo:baz($y, $x)")
+            .unwrap();
+        let file_id = ws.def(
+            r#"
+            foo.bar(f(--[[keep]] 1), 2)
+            foo.bar(1, --[[drop]] 2)
+            "#,
+        );
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        let matches = search.find_matches(&semantic_model);
+        assert_eq!(matches.len(), 2);
+        assert!(!matches[0].drops_comments);
+        assert!(matches[1].drops_comments);
+
+        let code = semantic_model.get_document().get_text().to_string();
+        assert_eq!(
+            LuaTextEdit::apply(&code, &search.replace(&semantic_model)),
+            r#"
+            foo:baz(2, f(--[[keep]] 1))
+            foo.bar(1, --[[drop]] 2)
+            "#
+        );
+    }
+
+    #[test]
+    fn test_workspace_language_level() {
+        assert!(StructuralSearch::new("$a // $b", &Emmyrc::default()).is_ok());
+
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.runtime.version = EmmyrcLuaVersion::Lua51;
+        assert!(matches!(
+            StructuralSearch::new("$a // $b", &emmyrc),
+            Err(StructuralSearchError::InvalidPattern(_))
+        ));
+    }
+
+    #[test]
+    fn test_variadic() {
+        let mut ws = VirtualWorkspace::new();
+        let search = StructuralSearch::new("log($level, $args...)", &Emmyrc::default())
+            .unwrap()
+            .with_replacement("logger:log($level, $args...)")
+            .unwrap();
+        let result = replace(
+            &mut ws,
+            r#"
+            log("info")
+            log("warn", a, b.c)
+            "#,
+            &search,
+        );
+        assert_eq!(
+            result,
+            r#"
+            logger:log("info")
+            logger:log("warn", a, b.c)
+            "#
+        );
+
+        let search = StructuralSearch::new("if $c then $body... end", &Emmyrc::default())
+            .unwrap()
+            .with_replacement("if not ($c) then return end\n$body...")
+            .unwrap();
+        let file_id = ws.def(
+            r#"
+            if ok then
+                print(1)
+                print(2)
+            end
+            "#,
+        );
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        let matches = search.find_matches(&semantic_model);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].bindings["c"], "ok");
+        assert_eq!(
+            matches[0].bindings["body"],
+            "print(1)\n                print(2)"
+        );
+    }
+
+    #[test]
+    fn test_repeated_placeholder() {
+        let mut ws = VirtualWorkspace::new();
+        let search = StructuralSearch::new("$a = $a or $b", &Emmyrc::default())
+            .unwrap()
+            .with_replacement("$a = $a or $b -- default")
+            .unwrap();
+        let result = replace(
+            &mut ws,
+            r#"
+            x = x or 1
+            t.y = t.y or {}
+            x = y or 1
+            "#,
+            &search,
+        );
+        assert_eq!(
+            result,
+            r#"
+            x = x or 1 -- default
+            t.y = t.y or {} -- default
+            x = y or 1
+            "#
+        );
+    }
+
+    #[test]
+    fn test_outermost_match() {
+        let mut ws = VirtualWorkspace::new();
+        let search = StructuralSearch::new("f($x)", &Emmyrc::default())
+            .unwrap()
+            .with_replacement("g($x)")
+            .unwrap();
+        let result = replace(&mut ws, "local a = f(f(1))", &search);
+        assert_eq!(result, "local a = g(f(1))");
+    }
+
+    #[test]
+    fn test_type_constraint() {
+        let mut ws = VirtualWorkspace::new();
+        let search = StructuralSearch::new("#$s", &Emmyrc::default())
+            .unwrap()
+            .with_constraint("$s", "string")
+            .unwrap()
+            .with_replacement("$s:len()")
+            .unwrap();
+        let result = replace(
+            &mut ws,
+            r#"
+            ---@class Buffer
+
+            ---@type string
+            local name
+            ---@type integer[]
+            local list
+            ---@type Buffer
+            local buffer
+            local a, b, c = #name, #list, #buffer
+            "#,
+            &search,
+        );
+        assert!(result.contains("local a, b, c = name:len(), #list, #buffer"));
+
+        let search = StructuralSearch::new("#$s", &Emmyrc::default())
+            .unwrap()
+            .with_constraint("s", "Buffer")
+            .unwrap();
+        let file_id = ws.def(
+            r#"
+            ---@type Buffer
+            local buffer
+            local n = #buffer
+            "#,
+        );
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        assert_eq!(search.find_matches(&semantic_model).len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        assert!(matches!(
+            StructuralSearch::new("foo((", &Emmyrc::default()),
+            Err(StructuralSearchError::InvalidPattern(_))
+        ));
+        assert!(matches!(
+            StructuralSearch::new("f($x)", &Emmyrc::default())
+                .unwrap()
+                .with_replacement("g($y)"),
+            Err(StructuralSearchError::InvalidReplacement(_))
+        ));
+        assert!(matches!(
+            StructuralSearch::new("f($x...)", &Emmyrc::default())
+                .unwrap()
+                .with_constraint("x", "string"),
+            Err(StructuralSearchError::InvalidConstraint(_))
+        ));
+    }
+}
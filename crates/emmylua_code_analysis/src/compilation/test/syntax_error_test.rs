@@ -1,5 +1,8 @@
 #[cfg(test)]
 mod tests {
+    use lsp_types::NumberOrString;
+    use tokio_util::sync::CancellationToken;
+
     use crate::{DiagnosticCode, VirtualWorkspace};
 
     #[test]
@@ -13,4 +16,56 @@ mod tests {
             "#
         ));
     }
+
+    #[test]
+    fn test_unclosed_block_related_information() {
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def(
+            r#"
+            local function f()
+                return 1
+            "#,
+        );
+        let diagnostics = ws
+            .analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .unwrap();
+        let code = Some(NumberOrString::String(
+            DiagnosticCode::SyntaxError.get_name().to_string(),
+        ));
+        let diagnostic = diagnostics
+            .into_iter()
+            .find(|diagnostic| diagnostic.code == code)
+            .unwrap();
+        let related = diagnostic.related_information.unwrap();
+        assert_eq!(related.len(), 1);
+        assert_eq!(related[0].location.range.start.line, 1);
+        assert_eq!(related[0].location.range.start.character, 18);
+    }
+
+    #[test]
+    fn test_parse_error_code_in_data() {
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def(
+            r#"
+            if a
+                print(a)
+            end
+            "#,
+        );
+        let diagnostics = ws
+            .analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .unwrap();
+        let code = Some(NumberOrString::String(
+            DiagnosticCode::SyntaxError.get_name().to_string(),
+        ));
+        let diagnostic = diagnostics
+            .into_iter()
+            .find(|diagnostic| diagnostic.code == code)
+            .unwrap();
+        let data = diagnostic.data.unwrap();
+        assert_eq!(data["parseErrorCode"], "missing-then");
+        assert_eq!(data["expected"], serde_json::json!(["TkThen"]));
+    }
 }
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use crate::{DiagnosticCode, EmmyrcSyntaxExtension, LuaType, VirtualWorkspace};
+
+    fn extension_workspace() -> VirtualWorkspace {
+        let mut ws = VirtualWorkspace::new();
+        let mut emmyrc = ws.analysis.emmyrc.as_ref().clone();
+        emmyrc.runtime.syntax_extensions = vec![
+            EmmyrcSyntaxExtension::CompoundAssign,
+            EmmyrcSyntaxExtension::Continue,
+            EmmyrcSyntaxExtension::NotEqual,
+        ];
+        ws.analysis.update_config(Arc::new(emmyrc));
+        ws
+    }
+
+    #[test]
+    fn test_compound_assign() {
+        let mut ws = extension_workspace();
+        ws.def(
+            r#"
+            ---@type integer
+            local count = 0
+            count += 1
+            a = count
+
+            ---@type string
+            local text = ""
+            text ..= 1
+            b = text
+
+            ---@type integer
+            local ratio = 10
+            ratio /= 4
+            c = ratio
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), LuaType::Integer);
+        assert_eq!(ws.expr_ty("b"), LuaType::String);
+        assert_eq!(ws.expr_ty("c"), LuaType::Number);
+    }
+
+    #[test]
+    fn test_continue_narrow() {
+        let mut ws = extension_workspace();
+        ws.def(
+            r#"
+            ---@type string?
+            local v
+            while true do
+                if not v then
+                    continue
+                end
+                a = v
+            end
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), LuaType::String);
+    }
+
+    #[test]
+    fn test_not_equal() {
+        let mut ws = extension_workspace();
+        assert!(ws.check_code_for(
+            DiagnosticCode::SyntaxError,
+            r#"
+            local a = 1
+            if a != 2 then
+            end
+            "#,
+        ));
+    }
+}
@@ -0,0 +1,282 @@
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use emmylua_parser::{LuaAstNode, LuaExpr, LuaIndexExpr};
+    use rowan::NodeOrToken;
+
+    use crate::{
+        DiagnosticCode, Emmyrc, EmmyrcLuaVersion, FileId, LuaSemanticDeclId, LuaType,
+        SemanticDeclLevel, VirtualWorkspace,
+    };
+
+    #[test]
+    fn test_workspace_folder_runtime_version() {
+        let mut ws = VirtualWorkspace::new();
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.runtime.version = EmmyrcLuaVersion::Lua55;
+        let root = ws.virtual_url_generator.new_path("lua55");
+        ws.analysis.update_workspace_config(root, Arc::new(emmyrc));
+
+        let code = r#"
+            global x = 1
+        "#;
+        let file_id = ws.def_file("lua55/a.lua", code);
+        let tree = ws
+            .analysis
+            .compilation
+            .get_db()
+            .get_vfs()
+            .get_syntax_tree(&file_id)
+            .unwrap();
+        assert!(tree.get_errors().is_empty());
+        assert_eq!(
+            ws.analysis.get_file_emmyrc(file_id).runtime.version,
+            EmmyrcLuaVersion::Lua55
+        );
+
+        let file_id = ws.def_file("lua54/a.lua", code);
+        let tree = ws
+            .analysis
+            .compilation
+            .get_db()
+            .get_vfs()
+            .get_syntax_tree(&file_id)
+            .unwrap();
+        assert!(!tree.get_errors().is_empty());
+    }
+
+    #[test]
+    fn test_workspace_folder_diagnostics() {
+        let mut ws = VirtualWorkspace::new();
+        let mut emmyrc = Emmyrc::default();
+        emmyrc
+            .diagnostics
+            .disable
+            .push(DiagnosticCode::UndefinedGlobal);
+        let root = ws.virtual_url_generator.new_path("quiet");
+        ws.analysis.update_workspace_config(root, Arc::new(emmyrc));
+
+        let code = r#"
+            print(undefined_name)
+        "#;
+        let file_id = ws.def_file("quiet/a.lua", code);
+        assert!(!has_diagnostic(
+            &ws,
+            file_id,
+            DiagnosticCode::UndefinedGlobal
+        ));
+        let file_id = ws.def_file("loud/a.lua", code);
+        assert!(has_diagnostic(
+            &ws,
+            file_id,
+            DiagnosticCode::UndefinedGlobal
+        ));
+    }
+
+    #[test]
+    fn test_workspace_folder_require_pattern() {
+        let mut ws = VirtualWorkspace::new();
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.runtime.require_pattern = vec!["src/?.lua".to_string()];
+        let root = ws.virtual_url_generator.new_path("pkg");
+        ws.analysis.add_main_workspace(root.clone());
+        ws.analysis.update_workspace_config(root, Arc::new(emmyrc));
+
+        let file_id = ws.def_file("pkg/src/util.lua", "return {}");
+        let module_info = ws
+            .analysis
+            .compilation
+            .get_db()
+            .get_module_index()
+            .get_module(file_id)
+            .unwrap();
+        assert_eq!(module_info.full_module_name, "util");
+    }
+
+    #[test]
+    fn test_nested_directory_config() {
+        let mut ws = VirtualWorkspace::new();
+        let mut emmyrc = Emmyrc::default();
+        emmyrc
+            .diagnostics
+            .disable
+            .push(DiagnosticCode::UndefinedGlobal);
+        let tests_dir = ws.virtual_url_generator.new_path("tests");
+        ws.analysis
+            .update_workspace_config(tests_dir.clone(), Arc::new(emmyrc));
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.diagnostics.globals.push("describe".to_string());
+        let strict_dir = ws.virtual_url_generator.new_path("tests/strict");
+        ws.analysis
+            .update_workspace_config(strict_dir, Arc::new(emmyrc));
+
+        let file_id = ws.def_file("tests/a.lua", "return undefined_name");
+        assert!(!has_diagnostic(
+            &ws,
+            file_id,
+            DiagnosticCode::UndefinedGlobal
+        ));
+        let file_id = ws.def_file("tests/strict/a.lua", "return undefined_name");
+        assert!(has_diagnostic(
+            &ws,
+            file_id,
+            DiagnosticCode::UndefinedGlobal
+        ));
+        let file_id = ws.def_file("tests/strict/b.lua", "return describe");
+        assert!(!has_diagnostic(
+            &ws,
+            file_id,
+            DiagnosticCode::UndefinedGlobal
+        ));
+
+        // dropping the outer config also drops the nested one
+        ws.analysis.remove_workspace_config(&tests_dir);
+        let file_id = ws.def_file("tests/strict/c.lua", "return describe");
+        assert!(has_diagnostic(
+            &ws,
+            file_id,
+            DiagnosticCode::UndefinedGlobal
+        ));
+    }
+
+    #[test]
+    fn test_remove_workspace() {
+        let mut ws = VirtualWorkspace::new();
+        let root = ws.virtual_url_generator.new_path("removed");
+        ws.analysis.add_main_workspace(root.clone());
+        ws.analysis
+            .update_workspace_config(root.clone(), Arc::new(Emmyrc::default()));
+        let removed_id = ws.def_file("removed/a.lua", "return 1");
+        let kept_id = ws.def_file("kept/a.lua", "return 1");
+
+        let removed_uris = ws.analysis.remove_workspace(&root);
+        assert_eq!(removed_uris.len(), 1);
+        assert!(ws.analysis.get_uri(removed_id).is_none());
+        assert!(ws.analysis.get_uri(kept_id).is_some());
+    }
+
+    #[test]
+    fn test_remove_workspace_libraries() {
+        let mut ws = VirtualWorkspace::new();
+        let first = ws.virtual_url_generator.new_path("first");
+        let second = ws.virtual_url_generator.new_path("second");
+        let only_first = ws.virtual_url_generator.new_path("only_first");
+        let shared = ws.virtual_url_generator.new_path("shared");
+        for root in [&first, &second] {
+            ws.analysis.add_main_workspace(root.clone());
+            ws.analysis
+                .update_workspace_config(root.clone(), Arc::new(Emmyrc::default()));
+        }
+        ws.analysis
+            .add_folder_library(first.clone(), only_first.clone());
+        ws.analysis
+            .add_folder_library(first.clone(), shared.clone());
+        ws.analysis
+            .add_folder_library(second.clone(), shared.clone());
+        let only_first_id = ws.def_file("only_first/lib.lua", "return 1");
+        let shared_id = ws.def_file("shared/lib.lua", "return 1");
+
+        ws.analysis.remove_workspace(&first);
+        assert!(ws.analysis.get_uri(only_first_id).is_none());
+        assert!(ws.analysis.get_uri(shared_id).is_some());
+
+        ws.analysis.remove_workspace(&second);
+        assert!(ws.analysis.get_uri(shared_id).is_none());
+    }
+
+    #[test]
+    fn test_workspace_folder_inference() {
+        let mut ws = VirtualWorkspace::new();
+        for (folder, version, array_index) in [
+            ("lua51", EmmyrcLuaVersion::Lua51, true),
+            ("lua54", EmmyrcLuaVersion::Lua54, false),
+        ] {
+            let mut emmyrc = Emmyrc::default();
+            emmyrc.runtime.version = version;
+            emmyrc.strict.array_index = array_index;
+            let root = ws.virtual_url_generator.new_path(folder);
+            ws.analysis.update_workspace_config(root, Arc::new(emmyrc));
+        }
+        ws.def_file(
+            "lib/m.lua",
+            r#"
+            ---@meta
+            ---@class M
+            M = {}
+
+            ---@version 5.1
+            ---@return integer
+            function M.f() end
+
+            ---@version 5.4
+            ---@return string
+            function M.f() end
+            "#,
+        );
+        let code = r#"
+            ---@type string[]
+            local list = {}
+            return M.f, list[1]
+        "#;
+        let lua51_id = ws.def_file("lua51/a.lua", code);
+        let lua54_id = ws.def_file("lua54/a.lua", code);
+
+        // the `@version` definition of the folder's runtime version
+        let lua51_decl = index_expr_decl(&ws, lua51_id, 0);
+        let lua54_decl = index_expr_decl(&ws, lua54_id, 0);
+        assert!(lua51_decl.is_some());
+        assert_ne!(lua51_decl, lua54_decl);
+
+        // `strict.arrayIndex` of the folder
+        let lua51_type = index_expr_type(&ws, lua51_id, 1);
+        assert_eq!(ws.humanize_type(lua51_type), "string?");
+        assert_eq!(index_expr_type(&ws, lua54_id, 1), LuaType::String);
+    }
+
+    fn nth_index_expr(ws: &VirtualWorkspace, file_id: FileId, n: usize) -> LuaIndexExpr {
+        let tree = ws
+            .analysis
+            .compilation
+            .get_db()
+            .get_vfs()
+            .get_syntax_tree(&file_id)
+            .unwrap();
+        tree.get_chunk_node()
+            .descendants::<LuaIndexExpr>()
+            .nth(n)
+            .unwrap()
+    }
+
+    fn index_expr_decl(
+        ws: &VirtualWorkspace,
+        file_id: FileId,
+        n: usize,
+    ) -> Option<LuaSemanticDeclId> {
+        let index_expr = nth_index_expr(ws, file_id, n);
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        semantic_model.find_decl(
+            NodeOrToken::Node(index_expr.syntax().clone()),
+            SemanticDeclLevel::NoTrace,
+        )
+    }
+
+    fn index_expr_type(ws: &VirtualWorkspace, file_id: FileId, n: usize) -> LuaType {
+        let index_expr = nth_index_expr(ws, file_id, n);
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        semantic_model
+            .infer_expr(LuaExpr::IndexExpr(index_expr))
+            .unwrap()
+    }
+
+    fn has_diagnostic(ws: &VirtualWorkspace, file_id: crate::FileId, code: DiagnosticCode) -> bool {
+        let code = Some(lsp_types::NumberOrString::String(
+            code.get_name().to_string(),
+        ));
+        ws.analysis
+            .diagnose_file(file_id, Default::default())
+            .unwrap_or_default()
+            .iter()
+            .any(|diagnostic| diagnostic.code == code)
+    }
+}
@@ -7,35 +7,7 @@ use crate::read_file_with_encoding;
 use super::{flatten_config::FlattenConfigObject, Emmyrc};
 
 pub fn load_configs(config_files: Vec<PathBuf>, partial_emmyrcs: Option<Vec<Value>>) -> Emmyrc {
-    let mut config_jsons = Vec::new();
-
-    for config_file in config_files {
-        log::info!("Loading config file: {:?}", config_file);
-        let config_json_str = match read_file_with_encoding(&config_file, "utf-8") {
-            Some(json_str) => json_str,
-            None => {
-                log::error!(
-                    "Failed to read config file: {:?}, error: File not found or unreadable",
-                    config_file
-                );
-                continue;
-            }
-        };
-
-        let config_json: Value = match serde_json::from_str(&config_json_str) {
-            Ok(json) => json,
-            Err(e) => {
-                log::error!(
-                    "Failed to parse config file: {:?}, error: {:?}",
-                    &config_file,
-                    e
-                );
-                continue;
-            }
-        };
-
-        config_jsons.push(config_json);
-    }
+    let mut config_jsons: Vec<Value> = config_files.iter().filter_map(read_config_json).collect();
 
     if let Some(partial_emmyrcs) = partial_emmyrcs {
         for partial_emmyrc in partial_emmyrcs {
@@ -86,6 +58,56 @@ pub fn load_configs(config_files: Vec<PathBuf>, partial_emmyrcs: Option<Vec<Valu
     }
 }
 
+/// Load the config files of a sub directory on top of `base`, with the same merge as `load_configs`
+pub fn load_config_overrides(base: &Emmyrc, config_files: Vec<PathBuf>) -> Emmyrc {
+    let mut merge_config = match serde_json::to_value(base) {
+        Ok(value) => value,
+        Err(err) => {
+            log::error!("Failed to serialize config, error: {:?}", err);
+            return base.clone();
+        }
+    };
+
+    for config_json in config_files.iter().filter_map(read_config_json) {
+        let flatten_config = FlattenConfigObject::parse(config_json);
+        merge_values(&mut merge_config, flatten_config.to_emmyrc());
+    }
+
+    match serde_json::from_value(merge_config) {
+        Ok(config) => config,
+        Err(err) => {
+            log::error!("Failed to parse config, error: {:?}", err);
+            base.clone()
+        }
+    }
+}
+
+fn read_config_json(config_file: &PathBuf) -> Option<Value> {
+    log::info!("Loading config file: {:?}", config_file);
+    let config_json_str = match read_file_with_encoding(config_file, "utf-8") {
+        Some(json_str) => json_str,
+        None => {
+            log::error!(
+                "Failed to read config file: {:?}, error: File not found or unreadable",
+                config_file
+            );
+            return None;
+        }
+    };
+
+    match serde_json::from_str(&config_json_str) {
+        Ok(json) => Some(json),
+        Err(e) => {
+            log::error!(
+                "Failed to parse config file: {:?}, error: {:?}",
+                &config_file,
+                e
+            );
+            None
+        }
+    }
+}
+
 fn merge_values(base: &mut Value, overlay: Value) {
     match (base, overlay) {
         (Value::Object(base_map), Value::Object(overlay_map)) => {
@@ -113,3 +135,37 @@ fn merge_values(base: &mut Value, overlay: Value) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DiagnosticCode, EmmyrcLuaVersion};
+
+    #[test]
+    fn test_load_config_overrides() {
+        let dir = std::env::temp_dir().join(format!("emmylua_overrides_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("create temp dir");
+        let config_file = dir.join(".emmyrc.json");
+        std::fs::write(
+            &config_file,
+            r#"{
+                "runtime": { "version": "Lua5.1" },
+                "diagnostics.disable": ["undefined-global"]
+            }"#,
+        )
+        .expect("write config file");
+
+        let mut base = Emmyrc::default();
+        base.diagnostics.disable.push(DiagnosticCode::Unused);
+        base.diagnostics.globals.push("vim".to_string());
+        let emmyrc = load_config_overrides(&base, vec![config_file]);
+        std::fs::remove_dir_all(&dir).expect("remove temp dir");
+
+        assert_eq!(emmyrc.runtime.version, EmmyrcLuaVersion::Lua51);
+        assert_eq!(
+            emmyrc.diagnostics.disable,
+            vec![DiagnosticCode::Unused, DiagnosticCode::UndefinedGlobal]
+        );
+        assert_eq!(emmyrc.diagnostics.globals, vec!["vim".to_string()]);
+    }
+}
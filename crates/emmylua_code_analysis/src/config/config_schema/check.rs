@@ -0,0 +1,332 @@
+use std::collections::HashMap;
+
+use emmylua_parser::LineIndex;
+use lsp_types::{Diagnostic, DiagnosticSeverity, Position, Range};
+use rowan::TextRange;
+use schemars::schema::InstanceType;
+
+use crate::{
+    config::flatten_config::{flatten_object, is_luals_key, translate_luals_entry},
+    DiagnosticCode,
+};
+
+use super::{
+    json_tree::{parse_json, JsonKind, JsonMember, JsonNode},
+    schema::{instance_type_name, root_schema, SchemaNode},
+};
+
+/// Check the text of a `.emmyrc.json`/`.luarc.json` against the config schema. Unknown keys and
+/// diagnostic codes are warnings since loading ignores them, the other errors make the whole
+/// config fall back to the default.
+pub fn check_config_json(text: &str) -> Vec<Diagnostic> {
+    let tree = parse_json(text);
+    let mut checker = ConfigChecker {
+        text,
+        line_index: LineIndex::parse(text),
+        diagnostics: Vec::new(),
+    };
+    for error in &tree.errors {
+        checker.add(
+            error.range,
+            DiagnosticSeverity::ERROR,
+            t!("Invalid json: %{message}", message = error.message).to_string(),
+        );
+    }
+
+    if let Some(root) = &tree.root {
+        if matches!(root.kind, JsonKind::Object(_)) {
+            checker.check_value(root, root_schema(), &mut Vec::new());
+        } else {
+            checker.add(
+                root.range,
+                DiagnosticSeverity::ERROR,
+                t!("The config must be a json object").to_string(),
+            );
+        }
+    }
+
+    checker.diagnostics
+}
+
+struct ConfigChecker<'a> {
+    text: &'a str,
+    line_index: LineIndex,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl ConfigChecker<'_> {
+    fn add(&mut self, range: TextRange, severity: DiagnosticSeverity, message: String) {
+        let start = self.position(range.start());
+        let end = self.position(range.end());
+        self.diagnostics.push(Diagnostic {
+            range: Range { start, end },
+            severity: Some(severity),
+            source: Some("EmmyLua".into()),
+            message,
+            ..Default::default()
+        });
+    }
+
+    fn position(&self, offset: rowan::TextSize) -> Position {
+        let (line, character) = self
+            .line_index
+            .get_line_col(offset, self.text)
+            .unwrap_or((0, 0));
+        Position {
+            line: line as u32,
+            character: character as u32,
+        }
+    }
+
+    fn check_value(&mut self, node: &JsonNode, schema: SchemaNode, path: &mut Vec<String>) {
+        let instance_types = schema.instance_types();
+        if !instance_types.is_empty() && !is_instance_of(node, &instance_types) {
+            let expected = instance_types
+                .iter()
+                .filter(|instance_type| **instance_type != InstanceType::Null)
+                .map(instance_type_name)
+                .collect::<Vec<_>>()
+                .join(" | ");
+            self.add(
+                node.range,
+                DiagnosticSeverity::ERROR,
+                t!(
+                    "Expected %{expected}, found %{found}",
+                    expected = expected,
+                    found = node.kind_name()
+                )
+                .to_string(),
+            );
+            return;
+        }
+
+        match &node.kind {
+            JsonKind::Object(members) => {
+                for member in members {
+                    self.check_member(member, schema, path);
+                }
+            }
+            JsonKind::Array(items) => {
+                if let Some(item_schema) = schema.items() {
+                    for item in items {
+                        self.check_value(item, item_schema, path);
+                    }
+                }
+            }
+            JsonKind::String(value) => self.check_enum_value(node.range, value, schema),
+            _ => {}
+        }
+    }
+
+    // a key may be dotted, `"diagnostics.disable": []` is the same as a nested object
+    fn check_member(&mut self, member: &JsonMember, schema: SchemaNode, path: &mut Vec<String>) {
+        let depth = path.len();
+        let mut current = schema;
+        let segments: Vec<&str> = member.key.split('.').collect();
+        for (i, segment) in segments.iter().copied().enumerate() {
+            if is_severity_map(path) {
+                self.check_diagnostic_code(member.key_range, segment);
+            }
+
+            match current.property(segment) {
+                Some(next) => {
+                    path.push(segment.to_string());
+                    current = next;
+                }
+                None => {
+                    let luals_key = path
+                        .iter()
+                        .map(String::as_str)
+                        .chain(segments[i..].iter().copied())
+                        .collect::<Vec<_>>()
+                        .join(".");
+                    if is_luals_key(&luals_key) {
+                        if let Some(value) = &member.value {
+                            self.check_luals_setting(&luals_key, member.key_range, value);
+                        }
+                        path.truncate(depth);
+                        return;
+                    }
+
+                    let candidates = current.properties();
+                    let message = match find_similar(segment, candidates.iter().map(|(k, _)| *k)) {
+                        Some(suggestion) => t!(
+                            "Unknown config key '%{key}', did you mean '%{suggestion}'?",
+                            key = segment,
+                            suggestion = suggestion
+                        ),
+                        None => t!("Unknown config key '%{key}'", key = segment),
+                    };
+                    self.add(
+                        member.key_range,
+                        DiagnosticSeverity::WARNING,
+                        message.to_string(),
+                    );
+                    path.truncate(depth);
+                    return;
+                }
+            }
+        }
+
+        if let Some(value) = &member.value {
+            // lua-language-server spellings of emmyrc settings, such as `"callSnippet": "Both"`
+            let is_luals_value = !matches!(value.kind, JsonKind::Object(_))
+                && self.check_luals_setting(&path.join("."), member.key_range, value);
+            if !is_luals_value {
+                self.check_value(value, current, path);
+            }
+        }
+        path.truncate(depth);
+    }
+
+    // report the parts of a lua-language-server setting that are not translated to emmyrc, returns
+    // whether `key` is such a setting
+    fn check_luals_setting(&mut self, key: &str, key_range: TextRange, node: &JsonNode) -> bool {
+        let Ok(value) = serde_json::from_str::<serde_json::Value>(&self.text[node.range]) else {
+            return is_luals_key(key);
+        };
+        let mut entries = HashMap::new();
+        flatten_object(key, &value, &mut entries);
+        let mut entries: Vec<_> = entries.into_iter().collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut is_luals_setting = false;
+        for (entry_key, entry_value) in entries {
+            let untranslated = match translate_luals_entry(&entry_key, &entry_value) {
+                Some(translation) => translation.untranslated,
+                None if is_luals_key(&entry_key) => vec![entry_value],
+                None => continue,
+            };
+            is_luals_setting = true;
+            for value in untranslated {
+                self.add(
+                    key_range,
+                    DiagnosticSeverity::WARNING,
+                    t!(
+                        "lua-language-server setting '%{key}' = %{value} has no equivalent and is ignored",
+                        key = entry_key,
+                        value = value
+                    )
+                    .to_string(),
+                );
+            }
+        }
+        is_luals_setting
+    }
+
+    fn check_enum_value(&mut self, range: TextRange, value: &str, schema: SchemaNode) {
+        if schema.definition == Some("DiagnosticCode") {
+            self.check_diagnostic_code(range, value);
+            return;
+        }
+
+        let values = schema.enum_values();
+        if values.is_empty() || values.iter().any(|item| item.value == value) {
+            return;
+        }
+
+        let expected = values
+            .iter()
+            .map(|item| format!("'{}'", item.value))
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.add(
+            range,
+            DiagnosticSeverity::ERROR,
+            t!(
+                "Invalid value '%{value}', expected one of %{expected}",
+                value = value,
+                expected = expected
+            )
+            .to_string(),
+        );
+    }
+
+    fn check_diagnostic_code(&mut self, range: TextRange, code: &str) {
+        if is_known_diagnostic_code(code) {
+            return;
+        }
+
+        let codes = diagnostic_code_names();
+        let message = match find_similar(code, codes.iter().map(String::as_str)) {
+            Some(suggestion) => t!(
+                "Unknown diagnostic code '%{code}', did you mean '%{suggestion}'?",
+                code = code,
+                suggestion = suggestion
+            ),
+            None => t!("Unknown diagnostic code '%{code}'", code = code),
+        };
+        self.add(range, DiagnosticSeverity::WARNING, message.to_string());
+    }
+}
+
+fn is_instance_of(node: &JsonNode, instance_types: &[InstanceType]) -> bool {
+    instance_types
+        .iter()
+        .any(|instance_type| match (&node.kind, instance_type) {
+            (JsonKind::Object(_), InstanceType::Object) => true,
+            (JsonKind::Array(_), InstanceType::Array) => true,
+            (JsonKind::String(_), InstanceType::String) => true,
+            (JsonKind::Number { .. }, InstanceType::Number) => true,
+            (JsonKind::Number { is_integer }, InstanceType::Integer) => *is_integer,
+            (JsonKind::Bool, InstanceType::Boolean) => true,
+            (JsonKind::Null, InstanceType::Null) => true,
+            _ => false,
+        })
+}
+
+// `diagnostics.severity` maps diagnostic codes to a severity
+fn is_severity_map(path: &[String]) -> bool {
+    path.len() == 2 && path[0] == "diagnostics" && path[1] == "severity"
+}
+
+// unknown names deserialize to `DiagnosticCode::None` and are dropped silently
+fn is_known_diagnostic_code(code: &str) -> bool {
+    !matches!(
+        serde_json::from_value::<DiagnosticCode>(serde_json::Value::String(code.to_string())),
+        Ok(DiagnosticCode::None) | Err(_)
+    )
+}
+
+pub(super) fn diagnostic_code_names() -> Vec<String> {
+    diagnostic_code_schema()
+        .map(|schema| {
+            schema
+                .enum_values()
+                .into_iter()
+                .map(|item| item.value)
+                .filter(|code| is_known_diagnostic_code(code))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+pub(super) fn diagnostic_code_schema() -> Option<SchemaNode> {
+    root_schema()
+        .property("diagnostics")?
+        .property("disable")?
+        .items()
+}
+
+fn find_similar<'a>(name: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    let max_distance = (name.chars().count() / 3).max(1);
+    candidates
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b_chars.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b_chars.len() + 1];
+        for (j, b_char) in b_chars.iter().enumerate() {
+            let cost = if a_char == *b_char { 0 } else { 1 };
+            current[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(current[j] + 1);
+        }
+        prev = current;
+    }
+    prev[b_chars.len()]
+}
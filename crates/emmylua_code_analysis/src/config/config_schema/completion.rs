@@ -0,0 +1,287 @@
+use rowan::{TextRange, TextSize};
+use schemars::schema::InstanceType;
+
+use super::{
+    check::{diagnostic_code_names, diagnostic_code_schema},
+    json_tree::{parse_json, JsonKind, JsonNode},
+    schema::{root_schema, SchemaNode},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigCompletionKind {
+    Key,
+    Value,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConfigCompletionItem {
+    pub label: String,
+    pub kind: ConfigCompletionKind,
+    pub description: Option<String>,
+    /// the text replacing `range`
+    pub new_text: String,
+    pub range: TextRange,
+}
+
+/// Complete config keys and enum values, such as diagnostic codes, at `offset`
+pub fn complete_config_json(text: &str, offset: TextSize) -> Vec<ConfigCompletionItem> {
+    let tree = parse_json(text);
+    let Some(root) = &tree.root else {
+        return Vec::new();
+    };
+    let Some(context) = find_cursor_context(text, root, root_schema(), offset) else {
+        return Vec::new();
+    };
+
+    match context {
+        CursorContext::Key {
+            schema,
+            range,
+            quoted,
+            existing,
+        } => complete_keys(schema, range, quoted, &existing),
+        CursorContext::Value {
+            schema,
+            range,
+            quoted,
+        } => complete_values(schema, range, quoted),
+    }
+}
+
+enum CursorContext {
+    Key {
+        schema: SchemaNode,
+        range: TextRange,
+        quoted: bool,
+        existing: Vec<String>,
+    },
+    Value {
+        schema: SchemaNode,
+        range: TextRange,
+        quoted: bool,
+    },
+}
+
+fn find_cursor_context(
+    text: &str,
+    node: &JsonNode,
+    schema: SchemaNode,
+    offset: TextSize,
+) -> Option<CursorContext> {
+    match &node.kind {
+        JsonKind::Object(members) => {
+            for (i, member) in members.iter().enumerate() {
+                if is_inside_string(text, member.key_range, offset) {
+                    return Some(CursorContext::Key {
+                        schema,
+                        range: string_content_range(text, member.key_range),
+                        quoted: true,
+                        existing: members.iter().map(|m| m.key.clone()).collect(),
+                    });
+                }
+
+                match &member.value {
+                    Some(value) if contains(value.range, offset) => {
+                        let value_schema = resolve_key(schema, &member.key)?;
+                        return find_cursor_context(text, value, value_schema, offset);
+                    }
+                    // `"key": |` with the value not written yet
+                    None if offset > member.key_range.end()
+                        && members
+                            .get(i + 1)
+                            .is_none_or(|next| offset < next.key_range.start()) =>
+                    {
+                        return Some(CursorContext::Value {
+                            schema: resolve_key(schema, &member.key)?,
+                            range: TextRange::empty(offset),
+                            quoted: false,
+                        });
+                    }
+                    _ => {}
+                }
+            }
+
+            if is_inside_brackets(text, node.range, offset) {
+                return Some(CursorContext::Key {
+                    schema,
+                    range: TextRange::empty(offset),
+                    quoted: false,
+                    existing: members.iter().map(|m| m.key.clone()).collect(),
+                });
+            }
+            None
+        }
+        JsonKind::Array(items) => {
+            let item_schema = schema.items()?;
+            for item in items {
+                if contains(item.range, offset) {
+                    return find_cursor_context(text, item, item_schema, offset);
+                }
+            }
+
+            if is_inside_brackets(text, node.range, offset) {
+                return Some(CursorContext::Value {
+                    schema: item_schema,
+                    range: TextRange::empty(offset),
+                    quoted: false,
+                });
+            }
+            None
+        }
+        JsonKind::String(_) => {
+            if !is_inside_string(text, node.range, offset) {
+                return None;
+            }
+            Some(CursorContext::Value {
+                schema,
+                range: string_content_range(text, node.range),
+                quoted: true,
+            })
+        }
+        _ => Some(CursorContext::Value {
+            schema,
+            range: node.range,
+            quoted: false,
+        }),
+    }
+}
+
+fn complete_keys(
+    schema: SchemaNode,
+    range: TextRange,
+    quoted: bool,
+    existing: &[String],
+) -> Vec<ConfigCompletionItem> {
+    let mut items = Vec::new();
+    for (key, property) in schema.properties() {
+        if existing.iter().any(|name| name == key) && !quoted {
+            continue;
+        }
+        items.push(ConfigCompletionItem {
+            label: key.to_string(),
+            kind: ConfigCompletionKind::Key,
+            description: property.description.map(str::to_string),
+            new_text: if quoted {
+                key.to_string()
+            } else {
+                format!("\"{}\": ", key)
+            },
+            range,
+        });
+    }
+
+    // the keys of `diagnostics.severity` are diagnostic codes
+    if !schema.has_properties() {
+        if let Some(code_schema) = diagnostic_code_schema() {
+            let codes = diagnostic_code_names();
+            for code in code_schema.enum_values() {
+                if !codes.contains(&code.value) || existing.contains(&code.value) {
+                    continue;
+                }
+                items.push(ConfigCompletionItem {
+                    new_text: if quoted {
+                        code.value.clone()
+                    } else {
+                        format!("\"{}\": ", code.value)
+                    },
+                    label: code.value,
+                    kind: ConfigCompletionKind::Key,
+                    description: code.description.map(str::to_string),
+                    range,
+                });
+            }
+        }
+    }
+
+    items
+}
+
+fn complete_values(
+    schema: SchemaNode,
+    range: TextRange,
+    quoted: bool,
+) -> Vec<ConfigCompletionItem> {
+    let mut items = Vec::new();
+    let codes = if schema.definition == Some("DiagnosticCode") {
+        Some(diagnostic_code_names())
+    } else {
+        None
+    };
+    for value in schema.enum_values() {
+        if codes
+            .as_ref()
+            .is_some_and(|codes| !codes.contains(&value.value))
+        {
+            continue;
+        }
+        items.push(ConfigCompletionItem {
+            new_text: if quoted {
+                value.value.clone()
+            } else {
+                format!("\"{}\"", value.value)
+            },
+            label: value.value,
+            kind: ConfigCompletionKind::Value,
+            description: value.description.map(str::to_string),
+            range,
+        });
+    }
+
+    if !quoted && schema.instance_types().contains(&InstanceType::Boolean) {
+        for value in ["true", "false"] {
+            items.push(ConfigCompletionItem {
+                label: value.to_string(),
+                kind: ConfigCompletionKind::Value,
+                description: None,
+                new_text: value.to_string(),
+                range,
+            });
+        }
+    }
+
+    items
+}
+
+pub(super) fn resolve_key(schema: SchemaNode, key: &str) -> Option<SchemaNode> {
+    let mut current = schema;
+    for segment in key.split('.') {
+        current = current.property(segment)?;
+    }
+    Some(current)
+}
+
+fn contains(range: TextRange, offset: TextSize) -> bool {
+    range.start() <= offset && offset <= range.end()
+}
+
+fn is_terminated_string(text: &str, range: TextRange) -> bool {
+    let content = &text[range];
+    content.len() >= 2 && content.ends_with('"')
+}
+
+// between the quotes, or after the opening quote of an unterminated string
+pub(super) fn is_inside_string(text: &str, range: TextRange, offset: TextSize) -> bool {
+    if offset <= range.start() || offset > range.end() {
+        return false;
+    }
+    offset < range.end() || !is_terminated_string(text, range)
+}
+
+fn string_content_range(text: &str, range: TextRange) -> TextRange {
+    let start = range.start() + TextSize::new(1);
+    if is_terminated_string(text, range) {
+        TextRange::new(start, range.end() - TextSize::new(1))
+    } else {
+        TextRange::new(start, range.end())
+    }
+}
+
+// after the opening bracket and before the closing one, if the object or array is closed
+fn is_inside_brackets(text: &str, range: TextRange, offset: TextSize) -> bool {
+    if offset <= range.start() || offset > range.end() {
+        return false;
+    }
+    let content = &text[range];
+    let is_closed = content.len() >= 2 && (content.ends_with('}') || content.ends_with(']'));
+    offset < range.end() || !is_closed
+}
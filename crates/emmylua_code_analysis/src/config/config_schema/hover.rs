@@ -0,0 +1,68 @@
+use rowan::TextSize;
+
+use super::{
+    completion::{is_inside_string, resolve_key},
+    json_tree::{parse_json, JsonKind, JsonNode},
+    schema::{root_schema, SchemaNode},
+};
+
+/// Markdown describing the config key or enum value, such as a diagnostic code, at `offset`
+pub fn hover_config_json(text: &str, offset: TextSize) -> Option<String> {
+    let tree = parse_json(text);
+    hover_node(text, tree.root.as_ref()?, root_schema(), offset)
+}
+
+fn hover_node(text: &str, node: &JsonNode, schema: SchemaNode, offset: TextSize) -> Option<String> {
+    match &node.kind {
+        JsonKind::Object(members) => {
+            for member in members {
+                if member.key_range.contains(offset) {
+                    let property = resolve_key(schema, &member.key)?;
+                    return Some(describe_key(&member.key, property));
+                }
+                if let Some(value) = &member.value {
+                    if value.range.contains(offset) {
+                        let property = resolve_key(schema, &member.key)?;
+                        return hover_node(text, value, property, offset);
+                    }
+                }
+            }
+            None
+        }
+        JsonKind::Array(items) => {
+            let item_schema = schema.items()?;
+            let item = items.iter().find(|item| item.range.contains(offset))?;
+            hover_node(text, item, item_schema, offset)
+        }
+        JsonKind::String(value) => {
+            if !is_inside_string(text, node.range, offset) {
+                return None;
+            }
+            let item = schema
+                .enum_values()
+                .into_iter()
+                .find(|item| item.value == *value)?;
+            let mut markdown = format!("`\"{}\"`", item.value);
+            if let Some(description) = item.description {
+                markdown.push_str("\n\n");
+                markdown.push_str(description);
+            }
+            Some(markdown)
+        }
+        _ => None,
+    }
+}
+
+fn describe_key(key: &str, property: SchemaNode) -> String {
+    let mut markdown = format!("`{}`", key);
+    if let Some(description) = property.description {
+        markdown.push_str("\n\n");
+        markdown.push_str(description);
+    }
+    if let Some(default) = property.default {
+        if let Ok(default) = serde_json::to_string(default) {
+            markdown.push_str(&format!("\n\nDefault: `{}`", default));
+        }
+    }
+    markdown
+}
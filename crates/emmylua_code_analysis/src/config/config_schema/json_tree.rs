@@ -0,0 +1,389 @@
+use rowan::{TextRange, TextSize};
+
+/// A json value with its text range. The parser keeps going after an error, so an unfinished
+/// config being edited still produces a tree for completion.
+#[derive(Debug, Clone)]
+pub struct JsonNode {
+    pub range: TextRange,
+    pub kind: JsonKind,
+}
+
+#[derive(Debug, Clone)]
+pub enum JsonKind {
+    Object(Vec<JsonMember>),
+    Array(Vec<JsonNode>),
+    String(String),
+    Number { is_integer: bool },
+    Bool,
+    Null,
+}
+
+#[derive(Debug, Clone)]
+pub struct JsonMember {
+    pub key: String,
+    pub key_range: TextRange,
+    pub value: Option<JsonNode>,
+}
+
+#[derive(Debug, Clone)]
+pub struct JsonParseError {
+    pub range: TextRange,
+    pub message: String,
+}
+
+#[derive(Debug)]
+pub struct JsonTree {
+    pub root: Option<JsonNode>,
+    pub errors: Vec<JsonParseError>,
+}
+
+impl JsonNode {
+    pub fn kind_name(&self) -> &'static str {
+        match &self.kind {
+            JsonKind::Object(_) => "object",
+            JsonKind::Array(_) => "array",
+            JsonKind::String(_) => "string",
+            JsonKind::Number { .. } => "number",
+            JsonKind::Bool => "boolean",
+            JsonKind::Null => "null",
+        }
+    }
+}
+
+pub fn parse_json(text: &str) -> JsonTree {
+    let mut parser = JsonParser {
+        text,
+        pos: 0,
+        errors: Vec::new(),
+    };
+    parser.skip_whitespace();
+    let root = if parser.is_eof() {
+        parser.error_at_pos("Expected a json value");
+        None
+    } else {
+        parser.parse_value()
+    };
+    parser.skip_whitespace();
+    if !parser.is_eof() {
+        let start = parser.pos;
+        parser.error(
+            start,
+            text.len(),
+            "Unexpected content after the end of the config",
+        );
+    }
+
+    JsonTree {
+        root,
+        errors: parser.errors,
+    }
+}
+
+struct JsonParser<'a> {
+    text: &'a str,
+    pos: usize,
+    errors: Vec<JsonParseError>,
+}
+
+impl JsonParser<'_> {
+    fn is_eof(&self) -> bool {
+        self.pos >= self.text.len()
+    }
+
+    fn current(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) {
+        if let Some(ch) = self.current() {
+            self.pos += ch.len_utf8();
+        }
+    }
+
+    fn range(&self, start: usize) -> TextRange {
+        TextRange::new(TextSize::new(start as u32), TextSize::new(self.pos as u32))
+    }
+
+    fn error(&mut self, start: usize, end: usize, message: &str) {
+        self.errors.push(JsonParseError {
+            range: TextRange::new(TextSize::new(start as u32), TextSize::new(end as u32)),
+            message: message.to_string(),
+        });
+    }
+
+    fn error_at_pos(&mut self, message: &str) {
+        let end = self.pos + self.current().map(|ch| ch.len_utf8()).unwrap_or(0);
+        self.error(self.pos, end, message);
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(ch) = self.current() {
+            if ch.is_whitespace() {
+                self.bump();
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn parse_value(&mut self) -> Option<JsonNode> {
+        match self.current()? {
+            '{' => Some(self.parse_object()),
+            '[' => Some(self.parse_array()),
+            '"' => {
+                let start = self.pos;
+                let value = self.parse_string();
+                Some(JsonNode {
+                    range: self.range(start),
+                    kind: JsonKind::String(value),
+                })
+            }
+            '-' | '0'..='9' => Some(self.parse_number()),
+            't' | 'f' | 'n' => self.parse_literal(),
+            _ => {
+                self.error_at_pos("Expected a json value");
+                None
+            }
+        }
+    }
+
+    fn parse_object(&mut self) -> JsonNode {
+        let start = self.pos;
+        self.bump();
+        let mut members = Vec::new();
+        let mut after_comma = false;
+        loop {
+            self.skip_whitespace();
+            match self.current() {
+                None => {
+                    self.error_at_pos("Expected '}'");
+                    break;
+                }
+                Some('}') => {
+                    if after_comma {
+                        self.error_at_pos("Trailing comma is not allowed");
+                    }
+                    self.bump();
+                    break;
+                }
+                Some('"') => {
+                    let key_start = self.pos;
+                    let key = self.parse_string();
+                    let key_range = self.range(key_start);
+                    self.skip_whitespace();
+                    let value = if self.current() == Some(':') {
+                        self.bump();
+                        self.skip_whitespace();
+                        self.parse_value()
+                    } else {
+                        self.error_at_pos("Expected ':'");
+                        None
+                    };
+                    members.push(JsonMember {
+                        key,
+                        key_range,
+                        value,
+                    });
+                }
+                Some(_) => {
+                    self.error_at_pos("Expected a property name");
+                    self.recover(&[',', '}', '"']);
+                    if self.current() != Some(',') {
+                        continue;
+                    }
+                }
+            }
+
+            self.skip_whitespace();
+            match self.current() {
+                Some(',') => {
+                    self.bump();
+                    after_comma = true;
+                }
+                Some('}') | None => after_comma = false,
+                Some('"') => {
+                    self.error_at_pos("Expected ',' or '}'");
+                    after_comma = false;
+                }
+                Some(_) => {
+                    self.error_at_pos("Expected ',' or '}'");
+                    self.recover(&[',', '}', '"']);
+                    if self.current() == Some(',') {
+                        self.bump();
+                    }
+                    after_comma = false;
+                }
+            }
+        }
+
+        JsonNode {
+            range: self.range(start),
+            kind: JsonKind::Object(members),
+        }
+    }
+
+    fn parse_array(&mut self) -> JsonNode {
+        let start = self.pos;
+        self.bump();
+        let mut items = Vec::new();
+        let mut after_comma = false;
+        loop {
+            self.skip_whitespace();
+            match self.current() {
+                None => {
+                    self.error_at_pos("Expected ']'");
+                    break;
+                }
+                Some(']') => {
+                    if after_comma {
+                        self.error_at_pos("Trailing comma is not allowed");
+                    }
+                    self.bump();
+                    break;
+                }
+                Some(_) => match self.parse_value() {
+                    Some(item) => items.push(item),
+                    None => self.recover(&[',', ']']),
+                },
+            }
+
+            self.skip_whitespace();
+            match self.current() {
+                Some(',') => {
+                    self.bump();
+                    after_comma = true;
+                }
+                Some(']') | None => after_comma = false,
+                Some(_) => {
+                    self.error_at_pos("Expected ',' or ']'");
+                    self.recover(&[',', ']']);
+                    if self.current() == Some(',') {
+                        self.bump();
+                    }
+                    after_comma = false;
+                }
+            }
+        }
+
+        JsonNode {
+            range: self.range(start),
+            kind: JsonKind::Array(items),
+        }
+    }
+
+    // a string ends at its closing quote or, when unterminated, at the end of the line
+    fn parse_string(&mut self) -> String {
+        let start = self.pos;
+        self.bump();
+        let mut value = String::new();
+        loop {
+            match self.current() {
+                None | Some('\n') | Some('\r') => {
+                    let end = self.pos;
+                    self.error(start, end, "Unterminated string");
+                    break;
+                }
+                Some('"') => {
+                    self.bump();
+                    break;
+                }
+                Some('\\') => {
+                    self.bump();
+                    match self.current() {
+                        Some('n') => value.push('\n'),
+                        Some('t') => value.push('\t'),
+                        Some('r') => value.push('\r'),
+                        Some('b') => value.push('\u{8}'),
+                        Some('f') => value.push('\u{c}'),
+                        Some('u') => {
+                            let hex = self.text.get(self.pos + 1..self.pos + 5).unwrap_or("");
+                            match u32::from_str_radix(hex, 16).ok().and_then(char::from_u32) {
+                                Some(ch) => {
+                                    value.push(ch);
+                                    self.pos += 4;
+                                }
+                                None => self.error_at_pos("Invalid unicode escape"),
+                            }
+                        }
+                        Some(ch @ ('"' | '\\' | '/')) => value.push(ch),
+                        _ => self.error_at_pos("Invalid escape sequence"),
+                    }
+                    self.bump();
+                }
+                Some(ch) => {
+                    value.push(ch);
+                    self.bump();
+                }
+            }
+        }
+
+        value
+    }
+
+    fn parse_number(&mut self) -> JsonNode {
+        let start = self.pos;
+        let mut is_integer = true;
+        if self.current() == Some('-') {
+            self.bump();
+        }
+        let digits_start = self.pos;
+        self.skip_digits();
+        if self.pos == digits_start {
+            self.error_at_pos("Invalid number");
+        }
+        if self.current() == Some('.') {
+            is_integer = false;
+            self.bump();
+            self.skip_digits();
+        }
+        if matches!(self.current(), Some('e' | 'E')) {
+            is_integer = false;
+            self.bump();
+            if matches!(self.current(), Some('+' | '-')) {
+                self.bump();
+            }
+            self.skip_digits();
+        }
+
+        JsonNode {
+            range: self.range(start),
+            kind: JsonKind::Number { is_integer },
+        }
+    }
+
+    fn skip_digits(&mut self) {
+        while matches!(self.current(), Some('0'..='9')) {
+            self.bump();
+        }
+    }
+
+    fn parse_literal(&mut self) -> Option<JsonNode> {
+        let start = self.pos;
+        let rest = &self.text[self.pos..];
+        let (len, kind) = if rest.starts_with("true") {
+            (4, JsonKind::Bool)
+        } else if rest.starts_with("false") {
+            (5, JsonKind::Bool)
+        } else if rest.starts_with("null") {
+            (4, JsonKind::Null)
+        } else {
+            self.error_at_pos("Expected a json value");
+            return None;
+        };
+        self.pos += len;
+        Some(JsonNode {
+            range: self.range(start),
+            kind,
+        })
+    }
+
+    // skip to one of `stops` so that parsing can go on after an error
+    fn recover(&mut self, stops: &[char]) {
+        while let Some(ch) = self.current() {
+            if stops.contains(&ch) {
+                break;
+            }
+            self.bump();
+        }
+    }
+}
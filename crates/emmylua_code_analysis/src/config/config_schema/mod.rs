@@ -0,0 +1,10 @@
+mod check;
+mod completion;
+mod hover;
+mod json_tree;
+mod schema;
+mod test;
+
+pub use check::check_config_json;
+pub use completion::{complete_config_json, ConfigCompletionItem, ConfigCompletionKind};
+pub use hover::hover_config_json;
@@ -0,0 +1,191 @@
+use std::sync::LazyLock;
+
+use schemars::schema::{InstanceType, RootSchema, Schema, SchemaObject, SingleOrVec};
+use serde_json::Value;
+
+use crate::Emmyrc;
+
+static EMMYRC_SCHEMA: LazyLock<RootSchema> = LazyLock::new(|| schemars::schema_for!(Emmyrc));
+
+/// A schema with its `$ref` and single `allOf` wrappers resolved, it keeps the description of the
+/// outermost wrapper, which is where schemars puts the field docs
+#[derive(Debug, Clone, Copy)]
+pub struct SchemaNode {
+    pub object: &'static SchemaObject,
+    pub description: Option<&'static str>,
+    pub default: Option<&'static Value>,
+    pub definition: Option<&'static str>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SchemaEnumValue {
+    pub value: String,
+    pub description: Option<&'static str>,
+}
+
+pub fn root_schema() -> SchemaNode {
+    let object = &EMMYRC_SCHEMA.schema;
+    SchemaNode {
+        object,
+        description: None,
+        default: None,
+        definition: None,
+    }
+}
+
+fn resolve(schema: &'static Schema) -> Option<SchemaNode> {
+    let Schema::Object(object) = schema else {
+        return None;
+    };
+    let metadata = object.metadata.as_deref();
+    let mut node = SchemaNode {
+        object,
+        description: metadata.and_then(|m| m.description.as_deref()),
+        default: metadata.and_then(|m| m.default.as_ref()),
+        definition: None,
+    };
+
+    loop {
+        let object = node.object;
+        if let Some(reference) = &object.reference {
+            let name = reference.trim_start_matches("#/definitions/");
+            let Some(Schema::Object(target)) = EMMYRC_SCHEMA.definitions.get(name) else {
+                return None;
+            };
+            node.object = target;
+            node.definition = Some(name);
+            if node.description.is_none() {
+                node.description = target
+                    .metadata
+                    .as_deref()
+                    .and_then(|m| m.description.as_deref());
+            }
+            continue;
+        }
+
+        // `allOf: [ref]` for a documented field and `anyOf: [ref, null]` for an optional one
+        let inner = object.subschemas.as_deref().and_then(|subschemas| {
+            if let Some([single]) = subschemas.all_of.as_deref() {
+                return Some(single);
+            }
+            subschemas
+                .any_of
+                .as_deref()?
+                .iter()
+                .find(|schema| !is_null_schema(schema))
+        });
+        match inner {
+            Some(Schema::Object(inner)) => node.object = inner,
+            _ => return Some(node),
+        }
+    }
+}
+
+fn is_null_schema(schema: &Schema) -> bool {
+    match schema {
+        Schema::Object(object) => matches!(
+            &object.instance_type,
+            Some(SingleOrVec::Single(instance_type)) if **instance_type == InstanceType::Null
+        ),
+        Schema::Bool(_) => false,
+    }
+}
+
+impl SchemaNode {
+    pub fn property(&self, key: &str) -> Option<SchemaNode> {
+        let object = self.object.object.as_deref()?;
+        if let Some(property) = object.properties.get(key) {
+            return resolve(property);
+        }
+
+        resolve(object.additional_properties.as_deref()?)
+    }
+
+    pub fn properties(&self) -> Vec<(&'static str, SchemaNode)> {
+        let Some(object) = self.object.object.as_deref() else {
+            return Vec::new();
+        };
+        object
+            .properties
+            .iter()
+            .filter_map(|(key, schema)| Some((key.as_str(), resolve(schema)?)))
+            .collect()
+    }
+
+    pub fn has_properties(&self) -> bool {
+        self.object
+            .object
+            .as_deref()
+            .is_some_and(|object| !object.properties.is_empty())
+    }
+
+    pub fn items(&self) -> Option<SchemaNode> {
+        match self.object.array.as_deref()?.items.as_ref()? {
+            SingleOrVec::Single(schema) => resolve(schema),
+            SingleOrVec::Vec(_) => None,
+        }
+    }
+
+    pub fn enum_values(&self) -> Vec<SchemaEnumValue> {
+        if let Some(values) = &self.object.enum_values {
+            return values
+                .iter()
+                .filter_map(|value| {
+                    Some(SchemaEnumValue {
+                        value: value.as_str()?.to_string(),
+                        description: None,
+                    })
+                })
+                .collect();
+        }
+
+        let Some(one_of) = self
+            .object
+            .subschemas
+            .as_deref()
+            .and_then(|subschemas| subschemas.one_of.as_deref())
+        else {
+            return Vec::new();
+        };
+        one_of
+            .iter()
+            .filter_map(|schema| {
+                let variant = resolve(schema)?;
+                let values = variant.object.enum_values.as_deref()?;
+                let [value] = values else {
+                    return None;
+                };
+                Some(SchemaEnumValue {
+                    value: value.as_str()?.to_string(),
+                    description: variant
+                        .object
+                        .metadata
+                        .as_deref()
+                        .and_then(|m| m.description.as_deref()),
+                })
+            })
+            .collect()
+    }
+
+    pub fn instance_types(&self) -> Vec<InstanceType> {
+        match &self.object.instance_type {
+            Some(SingleOrVec::Single(instance_type)) => vec![**instance_type],
+            Some(SingleOrVec::Vec(instance_types)) => instance_types.clone(),
+            None if self.has_properties() => vec![InstanceType::Object],
+            None if !self.enum_values().is_empty() => vec![InstanceType::String],
+            None => Vec::new(),
+        }
+    }
+}
+
+pub fn instance_type_name(instance_type: &InstanceType) -> &'static str {
+    match instance_type {
+        InstanceType::Null => "null",
+        InstanceType::Boolean => "boolean",
+        InstanceType::Object => "object",
+        InstanceType::Array => "array",
+        InstanceType::Number => "number",
+        InstanceType::String => "string",
+        InstanceType::Integer => "integer",
+    }
+}
@@ -0,0 +1,199 @@
+#[cfg(test)]
+mod tests {
+    use lsp_types::DiagnosticSeverity;
+    use rowan::TextSize;
+
+    use crate::config::config_schema::{
+        check_config_json, complete_config_json, hover_config_json, ConfigCompletionKind,
+    };
+
+    fn messages(text: &str) -> Vec<String> {
+        check_config_json(text)
+            .into_iter()
+            .map(|diagnostic| diagnostic.message)
+            .collect()
+    }
+
+    // the offset of `|` in `text`, and the text without it
+    fn split_cursor(text: &str) -> (String, TextSize) {
+        let offset = text.find('|').expect("cursor marker");
+        (text.replace('|', ""), TextSize::new(offset as u32))
+    }
+
+    #[test]
+    fn test_valid_config() {
+        let text = r#"{
+            "$schema": "https://raw.githubusercontent.com/EmmyLuaLs/emmylua-analyzer-rust/refs/heads/main/crates/emmylua_code_analysis/resources/schema.json",
+            "runtime": { "version": "Lua5.4", "requirePattern": ["?.lua"] },
+            "diagnostics": {
+                "disable": ["undefined-global"],
+                "severity": { "unused": "hint" },
+                "diagnosticInterval": 300
+            },
+            "workspace.library": ["/usr/share/lua"]
+        }"#;
+        assert_eq!(messages(text), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_unknown_key() {
+        let text = r#"{ "diagnostics": { "disabel": [] }, "foo": 1 }"#;
+        assert_eq!(
+            messages(text),
+            vec![
+                "Unknown config key 'disabel', did you mean 'disable'?".to_string(),
+                "Unknown config key 'foo'".to_string(),
+            ]
+        );
+        let diagnostics = check_config_json(text);
+        assert_eq!(diagnostics[0].severity, Some(DiagnosticSeverity::WARNING));
+        assert_eq!(diagnostics[0].range.start.character, 19);
+    }
+
+    #[test]
+    fn test_unknown_diagnostic_code() {
+        let text = r#"{
+            "diagnostics": {
+                "disable": ["undefined-globl", "no-such-check"],
+                "severity": { "unsued": "error" }
+            }
+        }"#;
+        assert_eq!(
+            messages(text),
+            vec![
+                "Unknown diagnostic code 'undefined-globl', did you mean 'undefined-global'?"
+                    .to_string(),
+                "Unknown diagnostic code 'no-such-check'".to_string(),
+                "Unknown diagnostic code 'unsued', did you mean 'unused'?".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_type_mismatch() {
+        let text = r#"{
+            "diagnostics": { "enable": "yes", "diagnosticInterval": 1.5 },
+            "runtime": { "version": "Lua6.0" }
+        }"#;
+        let diagnostics = check_config_json(text);
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.message.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "Expected boolean, found string",
+                "Expected integer, found number",
+                "Invalid value 'Lua6.0', expected one of 'Lua5.1', 'LuaJIT', 'Luau', 'Lua5.2', 'Lua5.3', 'Lua5.4', 'Lua5.5', 'LuaLatest'",
+            ]
+        );
+        assert!(diagnostics
+            .iter()
+            .all(|diagnostic| diagnostic.severity == Some(DiagnosticSeverity::ERROR)));
+    }
+
+    #[test]
+    fn test_invalid_json() {
+        let text = "{\n  \"runtime\": {\n    \"version\": \"Lua5.4\",\n  }\n}";
+        let diagnostics = check_config_json(text);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "Invalid json: Trailing comma is not allowed"
+        );
+        assert_eq!(diagnostics[0].range.start.line, 3);
+    }
+
+    #[test]
+    fn test_complete_keys() {
+        let (text, offset) = split_cursor(r#"{ "diagnostics": { "enable": true, | } }"#);
+        let items = complete_config_json(&text, offset);
+        let disable = items.iter().find(|item| item.label == "disable").unwrap();
+        assert_eq!(disable.kind, ConfigCompletionKind::Key);
+        assert_eq!(disable.new_text, "\"disable\": ");
+        assert_eq!(
+            disable.description.as_deref(),
+            Some("A list of diagnostic codes that are disabled.")
+        );
+        assert!(!items.iter().any(|item| item.label == "enable"));
+
+        let (text, offset) = split_cursor("{\n  \"runt|\n}");
+        let items = complete_config_json(&text, offset);
+        let runtime = items.iter().find(|item| item.label == "runtime").unwrap();
+        assert_eq!(runtime.new_text, "runtime");
+        assert_eq!(&text[runtime.range], "runt");
+    }
+
+    #[test]
+    fn test_complete_diagnostic_codes() {
+        let (text, offset) = split_cursor(r#"{ "diagnostics": { "disable": ["undefined-|"] } }"#);
+        let items = complete_config_json(&text, offset);
+        let code = items
+            .iter()
+            .find(|item| item.label == "undefined-global")
+            .unwrap();
+        assert_eq!(code.kind, ConfigCompletionKind::Value);
+        assert_eq!(code.new_text, "undefined-global");
+        assert_eq!(&text[code.range], "undefined-");
+        assert!(!items.iter().any(|item| item.label == "none"));
+
+        let (text, offset) = split_cursor(r#"{ "diagnostics": { "severity": { | } } }"#);
+        let items = complete_config_json(&text, offset);
+        let code = items.iter().find(|item| item.label == "unused").unwrap();
+        assert_eq!(code.new_text, "\"unused\": ");
+    }
+
+    #[test]
+    fn test_complete_values() {
+        let (text, offset) = split_cursor(r#"{ "runtime": { "version": | } }"#);
+        let items = complete_config_json(&text, offset);
+        assert!(items
+            .iter()
+            .any(|item| item.label == "Lua5.4" && item.new_text == "\"Lua5.4\""));
+
+        let (text, offset) = split_cursor(r#"{ "diagnostics": { "enable": | } }"#);
+        let labels: Vec<String> = complete_config_json(&text, offset)
+            .into_iter()
+            .map(|item| item.label)
+            .collect();
+        assert_eq!(labels, vec!["true".to_string(), "false".to_string()]);
+    }
+
+    #[test]
+    fn test_hover() {
+        let (text, offset) = split_cursor(r#"{ "diagnostics": { "glob|als": [] } }"#);
+        assert_eq!(
+            hover_config_json(&text, offset).unwrap(),
+            "`globals`\n\nA list of global variables.\n\nDefault: `[]`"
+        );
+
+        let (text, offset) =
+            split_cursor(r#"{ "diagnostics": { "disable": ["unde|fined-global"] } }"#);
+        assert_eq!(
+            hover_config_json(&text, offset).unwrap(),
+            "`\"undefined-global\"`\n\nUndefined global"
+        );
+    }
+
+    #[test]
+    fn test_luals_settings() {
+        let text = r#"{
+            "runtime.path": ["?.lua"],
+            "runtime.nonstandardSymbol": ["+=", "||"],
+            "completion": { "callSnippet": "Both" },
+            "diagnostics": {
+                "neededFileStatus": { "lowercase-global": "Any" },
+                "severity": { "unused": "Warning!" }
+            },
+            "hint.await": true
+        }"#;
+        assert_eq!(
+            messages(text),
+            vec![
+                "lua-language-server setting 'runtime.nonstandardSymbol' = \"||\" has no equivalent and is ignored".to_string(),
+                "lua-language-server setting 'diagnostics.neededFileStatus.lowercase-global' = \"Any\" has no equivalent and is ignored".to_string(),
+                "lua-language-server setting 'hint.await' = true has no equivalent and is ignored".to_string(),
+            ]
+        );
+    }
+}
@@ -22,7 +22,7 @@ pub use hover::EmmyrcHover;
 pub use inlayhint::EmmyrcInlayHint;
 pub use references::EmmyrcReference;
 pub use resource::EmmyrcResource;
-pub use runtime::{EmmyrcLuaVersion, EmmyrcRuntime};
+pub use runtime::{EmmyrcLuaVersion, EmmyrcRuntime, EmmyrcSyntaxExtension};
 pub use semantictoken::EmmyrcSemanticToken;
 pub use signature::EmmyrcSignature;
 pub use strict::EmmyrcStrict;
@@ -1,4 +1,4 @@
-use emmylua_parser::LuaVersionNumber;
+use emmylua_parser::{LuaSyntaxExtension, LuaVersionNumber};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -20,6 +20,9 @@ pub struct EmmyrcRuntime {
     #[serde(default)]
     /// Require pattern. eg. "?.lua", "?/init.lua"
     pub require_pattern: Vec<String>,
+    #[serde(default)]
+    /// Syntax extensions accepted on top of the Lua version. eg: "compoundAssign", "continue", "notEqual"
+    pub syntax_extensions: Vec<EmmyrcSyntaxExtension>,
 }
 
 impl Default for EmmyrcRuntime {
@@ -30,6 +33,7 @@ impl Default for EmmyrcRuntime {
             framework_versions: Default::default(),
             extensions: Default::default(),
             require_pattern: Default::default(),
+            syntax_extensions: Default::default(),
         }
     }
 }
@@ -42,6 +46,9 @@ pub enum EmmyrcLuaVersion {
     /// LuaJIT
     #[serde(rename = "LuaJIT")]
     LuaJIT,
+    /// Luau
+    #[serde(rename = "Luau")]
+    Luau,
     /// Lua 5.2
     #[serde(rename = "Lua5.2", alias = "Lua 5.2")]
     Lua52,
@@ -70,6 +77,7 @@ impl EmmyrcLuaVersion {
         match self {
             EmmyrcLuaVersion::Lua51 => LuaVersionNumber::new(5, 1, 0),
             EmmyrcLuaVersion::LuaJIT => LuaVersionNumber::LUA_JIT,
+            EmmyrcLuaVersion::Luau => LuaVersionNumber::new(5, 1, 0),
             EmmyrcLuaVersion::Lua52 => LuaVersionNumber::new(5, 2, 0),
             EmmyrcLuaVersion::Lua53 => LuaVersionNumber::new(5, 3, 0),
             EmmyrcLuaVersion::Lua54 => LuaVersionNumber::new(5, 4, 0),
@@ -79,6 +87,27 @@ impl EmmyrcLuaVersion {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum EmmyrcSyntaxExtension {
+    /// `a += 1`, `a -= 1`, `a ..= "x"` ...
+    CompoundAssign,
+    /// `continue` inside loops
+    Continue,
+    /// `a != b`
+    NotEqual,
+}
+
+impl EmmyrcSyntaxExtension {
+    pub fn to_syntax_extension(&self) -> LuaSyntaxExtension {
+        match self {
+            EmmyrcSyntaxExtension::CompoundAssign => LuaSyntaxExtension::CompoundAssign,
+            EmmyrcSyntaxExtension::Continue => LuaSyntaxExtension::Continue,
+            EmmyrcSyntaxExtension::NotEqual => LuaSyntaxExtension::NotEqual,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -98,4 +127,20 @@ mod tests {
         let runtime: EmmyrcRuntime = serde_json::from_str(json2).unwrap();
         assert_eq!(runtime.version, EmmyrcLuaVersion::Lua51);
     }
+
+    #[test]
+    fn test_emmyrc_syntax_extensions() {
+        let json = r#"{
+            "syntaxExtensions": ["compoundAssign", "continue", "notEqual"]
+        }"#;
+        let runtime: EmmyrcRuntime = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            runtime.syntax_extensions,
+            vec![
+                EmmyrcSyntaxExtension::CompoundAssign,
+                EmmyrcSyntaxExtension::Continue,
+                EmmyrcSyntaxExtension::NotEqual,
+            ]
+        );
+    }
 }
@@ -24,6 +24,12 @@ pub struct EmmyrcStrict {
     /// meta define overrides file define
     #[serde(default = "default_true")]
     pub meta_override_file_define: bool,
+    /// Whether a function call invalidates the narrowed type of table fields such as `self.x`.
+    #[serde(default)]
+    pub call_invalidates_field_narrowing: bool,
+    /// Whether a `number` is rejected where an `integer` is expected.
+    #[serde(default = "default_true")]
+    pub number_to_integer: bool,
 }
 
 impl Default for EmmyrcStrict {
@@ -33,6 +39,8 @@ impl Default for EmmyrcStrict {
             type_call: false,
             array_index: true,
             meta_override_file_define: true,
+            call_invalidates_field_narrowing: false,
+            number_to_integer: true,
         }
     }
 }
@@ -0,0 +1,311 @@
+use serde_json::Value;
+
+use crate::DiagnosticCode;
+
+/// The emmyrc settings a lua-language-server setting maps onto
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LuaLsTranslation {
+    /// flattened emmyrc keys and their values
+    pub entries: Vec<(String, Value)>,
+    /// the values, or array items, without an emmyrc equivalent
+    pub untranslated: Vec<Value>,
+}
+
+impl LuaLsTranslation {
+    fn entry(key: &str, value: Value) -> Self {
+        Self {
+            entries: vec![(key.to_string(), value)],
+            untranslated: Vec::new(),
+        }
+    }
+
+    fn untranslated(value: &Value) -> Self {
+        Self {
+            entries: Vec::new(),
+            untranslated: vec![value.clone()],
+        }
+    }
+}
+
+// lua-language-server settings without any emmyrc equivalent
+const UNSUPPORTED_KEYS: &[&str] = &[
+    "diagnostics.ignoredFiles",
+    "diagnostics.libraryFiles",
+    "diagnostics.unusedLocalExclude",
+    "diagnostics.workspaceDelay",
+    "diagnostics.workspaceEvent",
+    "diagnostics.workspaceRate",
+    "runtime.builtin",
+    "runtime.fileEncoding",
+    "runtime.meta",
+    "runtime.pathStrict",
+    "runtime.plugin",
+    "runtime.pluginArgs",
+    "runtime.unicodeName",
+    "workspace.ignoreSubmodules",
+    "workspace.maxPreload",
+    "workspace.useGitIgnore",
+    "workspace.userThirdParty",
+    "hint.await",
+    "hint.awaitPropagate",
+    "hint.semicolon",
+    "type",
+];
+
+/// Translate one flattened lua-language-server setting, `None` if `key` is not one of them
+pub fn translate_luals_entry(key: &str, value: &Value) -> Option<LuaLsTranslation> {
+    if let Some(code) = key.strip_prefix("diagnostics.neededFileStatus.") {
+        return Some(translate_file_status(&[code], value));
+    }
+    if let Some(group) = key.strip_prefix("diagnostics.groupFileStatus.") {
+        return Some(translate_file_status(group_codes(group), value));
+    }
+    if let Some(group) = key.strip_prefix("diagnostics.groupSeverity.") {
+        return Some(translate_severity(group_codes(group), value));
+    }
+    // lua-language-server spells severities as `Warning` or `Warning!`
+    if let Some(code) = key.strip_prefix("diagnostics.severity.") {
+        let severity = value.as_str()?;
+        if severity.chars().next()?.is_lowercase() {
+            return None;
+        }
+        return Some(translate_severity(&[code], value));
+    }
+    if let Some(name) = key.strip_prefix("runtime.special.") {
+        return Some(match value.as_str() {
+            Some("require") => LuaLsTranslation::entry(
+                "runtime.requireLikeFunction",
+                Value::Array(vec![Value::String(name.to_string())]),
+            ),
+            _ => LuaLsTranslation::untranslated(value),
+        });
+    }
+
+    let translation = match key {
+        "runtime.path" => LuaLsTranslation::entry("runtime.requirePattern", value.clone()),
+        "runtime.nonstandardSymbol" => translate_nonstandard_symbols(value),
+        // the third party library prompt does not exist, so only disabling it is a match
+        "workspace.checkThirdParty" => match value {
+            Value::Bool(false) => LuaLsTranslation::default(),
+            Value::String(mode) if mode == "Disable" => LuaLsTranslation::default(),
+            _ => LuaLsTranslation::untranslated(value),
+        },
+        // allowing the cast is the lenient side of the strict flag
+        "type.castNumberToInteger" => match value {
+            Value::Bool(cast) => {
+                LuaLsTranslation::entry("strict.numberToInteger", Value::Bool(!cast))
+            }
+            _ => LuaLsTranslation::untranslated(value),
+        },
+        "completion.callSnippet" => match value.as_str()? {
+            "Disable" => LuaLsTranslation::entry("completion.callSnippet", Value::Bool(false)),
+            "Both" | "Replace" => {
+                LuaLsTranslation::entry("completion.callSnippet", Value::Bool(true))
+            }
+            _ => LuaLsTranslation::untranslated(value),
+        },
+        "hint.paramName" => match value.as_str() {
+            Some("All") | Some("Literal") => {
+                LuaLsTranslation::entry("hint.paramHint", Value::Bool(true))
+            }
+            Some("Disable") => LuaLsTranslation::entry("hint.paramHint", Value::Bool(false)),
+            _ => LuaLsTranslation::untranslated(value),
+        },
+        // `paramHint` also covers argument names, so only enabling it is a match
+        "hint.paramType" => match value {
+            Value::Bool(true) => LuaLsTranslation::entry("hint.paramHint", Value::Bool(true)),
+            _ => LuaLsTranslation::untranslated(value),
+        },
+        "hint.setType" => match value {
+            Value::Bool(_) => LuaLsTranslation::entry("hint.localHint", value.clone()),
+            _ => LuaLsTranslation::untranslated(value),
+        },
+        "hint.arrayIndex" => match value.as_str() {
+            Some("Enable") | Some("Auto") => {
+                LuaLsTranslation::entry("hint.indexHint", Value::Bool(true))
+            }
+            Some("Disable") => LuaLsTranslation::entry("hint.indexHint", Value::Bool(false)),
+            _ => LuaLsTranslation::untranslated(value),
+        },
+        _ if is_unsupported_key(key) => LuaLsTranslation::untranslated(value),
+        _ => return None,
+    };
+    Some(translation)
+}
+
+/// Whether `key` is a lua-language-server setting, or nested under one
+pub fn is_luals_key(key: &str) -> bool {
+    const PREFIXES: &[&str] = &[
+        "diagnostics.neededFileStatus",
+        "diagnostics.groupFileStatus",
+        "diagnostics.groupSeverity",
+        "runtime.special",
+        "runtime.path",
+        "runtime.nonstandardSymbol",
+        "workspace.checkThirdParty",
+        "hint.paramName",
+        "hint.paramType",
+        "hint.setType",
+        "hint.arrayIndex",
+    ];
+    PREFIXES
+        .iter()
+        .chain(UNSUPPORTED_KEYS)
+        .any(|prefix| key == *prefix || is_nested_key(key, prefix))
+}
+
+fn is_unsupported_key(key: &str) -> bool {
+    UNSUPPORTED_KEYS
+        .iter()
+        .any(|prefix| key == *prefix || is_nested_key(key, prefix))
+}
+
+fn is_nested_key(key: &str, prefix: &str) -> bool {
+    key.strip_prefix(prefix)
+        .is_some_and(|rest| rest.starts_with('.'))
+}
+
+// `Any` and `Opened` both enable the diagnostic, every file is checked either way
+fn translate_file_status(codes: &[&str], value: &Value) -> LuaLsTranslation {
+    let key = match value.as_str().map(|status| status.trim_end_matches('!')) {
+        Some("Any") | Some("Opened") => "diagnostics.enables",
+        Some("None") => "diagnostics.disable",
+        _ => return LuaLsTranslation::untranslated(value),
+    };
+    let codes = translate_codes(codes);
+    if codes.is_empty() {
+        return LuaLsTranslation::untranslated(value);
+    }
+    LuaLsTranslation::entry(key, Value::Array(codes))
+}
+
+fn translate_severity(codes: &[&str], value: &Value) -> LuaLsTranslation {
+    let severity = match value
+        .as_str()
+        .map(|severity| severity.trim_end_matches('!'))
+    {
+        Some("Error") => "error",
+        Some("Warning") => "warning",
+        Some("Information") => "information",
+        Some("Hint") => "hint",
+        // keep the default severity
+        Some("Fallback") => return LuaLsTranslation::default(),
+        _ => return LuaLsTranslation::untranslated(value),
+    };
+    let codes = translate_codes(codes);
+    if codes.is_empty() {
+        return LuaLsTranslation::untranslated(value);
+    }
+    LuaLsTranslation {
+        entries: codes
+            .into_iter()
+            .filter_map(|code| {
+                let code = code.as_str()?;
+                Some((
+                    format!("diagnostics.severity.{}", code),
+                    Value::String(severity.to_string()),
+                ))
+            })
+            .collect(),
+        untranslated: Vec::new(),
+    }
+}
+
+fn translate_nonstandard_symbols(value: &Value) -> LuaLsTranslation {
+    let Value::Array(symbols) = value else {
+        return LuaLsTranslation::untranslated(value);
+    };
+    let mut extensions = Vec::new();
+    let mut untranslated = Vec::new();
+    for symbol in symbols {
+        let extension = match symbol.as_str() {
+            Some("+=" | "-=" | "*=" | "/=" | "%=" | "^=" | "//=" | "|=" | "&=" | "<<=" | ">>=") => {
+                "compoundAssign"
+            }
+            Some("continue") => "continue",
+            Some("!=") => "notEqual",
+            _ => {
+                untranslated.push(symbol.clone());
+                continue;
+            }
+        };
+        let extension = Value::String(extension.to_string());
+        if !extensions.contains(&extension) {
+            extensions.push(extension);
+        }
+    }
+
+    LuaLsTranslation {
+        entries: if extensions.is_empty() {
+            Vec::new()
+        } else {
+            vec![(
+                "runtime.syntaxExtensions".to_string(),
+                Value::Array(extensions),
+            )]
+        },
+        untranslated,
+    }
+}
+
+// lua-language-server diagnostic names to the known emmylua codes, unknown names are dropped
+fn translate_codes(codes: &[&str]) -> Vec<Value> {
+    let mut result = Vec::new();
+    for code in codes {
+        let code = match *code {
+            "unused-local" | "unused-function" | "unused-vararg" | "unused-label" => "unused",
+            "param-type-mismatch" => "param-type-not-match",
+            "cast-local-type" => "assign-type-mismatch",
+            "undefined-doc-name" => "type-not-found",
+            "invisible" => "access-invisible",
+            "codestyle-check" => "code-style-check",
+            code => code,
+        };
+        let known = !matches!(
+            serde_json::from_value::<DiagnosticCode>(Value::String(code.to_string())),
+            Ok(DiagnosticCode::None) | Err(_)
+        );
+        let code = Value::String(code.to_string());
+        if known && !result.contains(&code) {
+            result.push(code);
+        }
+    }
+    result
+}
+
+// the lua-language-server diagnostic groups, by the names of their emmylua counterparts
+fn group_codes(group: &str) -> &'static [&'static str] {
+    match group {
+        "await" => &["await-in-sync"],
+        "codestyle" => &["code-style-check", "name-style-check"],
+        "duplicate" => &["duplicate-index", "duplicate-set-field"],
+        "global" => &["undefined-global"],
+        "luadoc" => &[
+            "circle-doc-class",
+            "duplicate-doc-field",
+            "type-not-found",
+            "undefined-doc-param",
+            "incomplete-signature-doc",
+            "missing-global-doc",
+        ],
+        "redefined" => &["redefined-local"],
+        "strict" => &["deprecated", "discard-returns", "access-invisible"],
+        "type-check" => &[
+            "assign-type-mismatch",
+            "inject-field",
+            "param-type-not-match",
+            "return-type-mismatch",
+        ],
+        "unbalanced" => &[
+            "missing-fields",
+            "missing-parameter",
+            "missing-return",
+            "missing-return-value",
+            "redundant-parameter",
+            "redundant-return-value",
+            "unbalanced-assignments",
+        ],
+        "unused" => &["unreachable-code", "unused"],
+        _ => &[],
+    }
+}
@@ -1,9 +1,12 @@
+mod luals_translate;
 mod test;
 
 use std::collections::HashMap;
 
 use serde_json::Value;
 
+pub use luals_translate::{is_luals_key, translate_luals_entry};
+
 #[derive(Debug, Clone)]
 pub struct FlattenConfigObject {
     config: HashMap<String, Value>,
@@ -13,6 +16,7 @@ impl FlattenConfigObject {
     pub fn parse(luals_json: Value) -> Self {
         let mut config = HashMap::new();
         flatten_object("", &luals_json, &mut config);
+        translate_luals_config(&mut config);
         Self { config }
     }
 
@@ -21,7 +25,7 @@ impl FlattenConfigObject {
     }
 }
 
-fn flatten_object(prefix: &str, val: &Value, config: &mut HashMap<String, Value>) {
+pub(super) fn flatten_object(prefix: &str, val: &Value, config: &mut HashMap<String, Value>) {
     match val {
         Value::Object(map) => {
             for (k, v) in map.iter() {
@@ -39,6 +43,44 @@ fn flatten_object(prefix: &str, val: &Value, config: &mut HashMap<String, Value>
     }
 }
 
+// replace the lua-language-server settings with their emmyrc counterparts, explicit emmyrc
+// settings win over translated ones
+fn translate_luals_config(config: &mut HashMap<String, Value>) {
+    let mut keys: Vec<String> = config.keys().cloned().collect();
+    keys.sort();
+    for key in keys {
+        let Some(value) = config.get(&key) else {
+            continue;
+        };
+        let Some(translation) = translate_luals_entry(&key, value) else {
+            continue;
+        };
+        config.remove(&key);
+        for value in translation.untranslated {
+            log::warn!(
+                "lua-language-server setting {}: {} has no equivalent, ignored",
+                key,
+                value
+            );
+        }
+        for (key, value) in translation.entries {
+            match (config.get_mut(&key), value) {
+                (Some(Value::Array(items)), Value::Array(values)) => {
+                    for value in values {
+                        if !items.contains(&value) {
+                            items.push(value);
+                        }
+                    }
+                }
+                (Some(_), _) => {}
+                (None, value) => {
+                    config.insert(key, value);
+                }
+            }
+        }
+    }
+}
+
 fn to_emmyrc_json(config: &FlattenConfigObject) -> Value {
     let mut emmyrc = Value::Object(Default::default());
     for (k, v) in &config.config {
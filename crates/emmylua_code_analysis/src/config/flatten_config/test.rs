@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod test {
-    use crate::config::flatten_config::FlattenConfigObject;
+    use crate::{
+        config::flatten_config::FlattenConfigObject, DiagnosticCode, Emmyrc, EmmyrcSyntaxExtension,
+    };
 
     #[test]
     fn test_parse() {
@@ -33,4 +35,55 @@ mod test {
 }"#;
         assert_eq!(json_str, expected);
     }
+
+    #[test]
+    fn test_translate_luals() {
+        let luals_json = serde_json::json!({
+            "diagnostics": {
+                "disable": ["undefined-field"],
+                "neededFileStatus": {
+                    "unused-local": "None!",
+                    "incomplete-signature-doc": "Any"
+                },
+                "groupSeverity": { "redefined": "Error", "unused": "Fallback" },
+                "severity": { "undefined-global": "Warning!" }
+            },
+            "runtime.path": ["?.lua", "?/init.lua"],
+            "runtime.special": { "include": "require", "myError": "error" },
+            "runtime.nonstandardSymbol": ["+=", "-=", "continue", "||"],
+            "workspace.checkThirdParty": false,
+            "type.castNumberToInteger": true,
+            "completion.callSnippet": "Replace",
+            "hint": { "paramName": "Disable", "setType": false, "arrayIndex": "Auto", "await": true }
+        });
+        let config = FlattenConfigObject::parse(luals_json);
+        let emmyrc: Emmyrc = serde_json::from_value(config.to_emmyrc()).unwrap();
+
+        assert_eq!(
+            emmyrc.diagnostics.disable,
+            vec![DiagnosticCode::UndefinedField, DiagnosticCode::Unused]
+        );
+        assert_eq!(
+            emmyrc.diagnostics.enables,
+            vec![DiagnosticCode::IncompleteSignatureDoc]
+        );
+        assert_eq!(
+            serde_json::to_value(&emmyrc.diagnostics.severity).unwrap(),
+            serde_json::json!({ "redefined-local": "error", "undefined-global": "warning" })
+        );
+        assert_eq!(emmyrc.runtime.require_pattern, vec!["?.lua", "?/init.lua"]);
+        assert_eq!(emmyrc.runtime.require_like_function, vec!["include"]);
+        assert_eq!(
+            emmyrc.runtime.syntax_extensions,
+            vec![
+                EmmyrcSyntaxExtension::CompoundAssign,
+                EmmyrcSyntaxExtension::Continue
+            ]
+        );
+        assert!(!emmyrc.strict.number_to_integer);
+        assert!(emmyrc.completion.call_snippet);
+        assert!(!emmyrc.hint.param_hint);
+        assert!(!emmyrc.hint.local_hint);
+        assert!(emmyrc.hint.index_hint);
+    }
 }
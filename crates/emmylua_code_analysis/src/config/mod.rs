@@ -1,4 +1,5 @@
 mod config_loader;
+mod config_schema;
 mod configs;
 mod flatten_config;
 
@@ -7,15 +8,19 @@ use std::{
     path::{Path, PathBuf},
 };
 
-pub use config_loader::load_configs;
+pub use config_loader::{load_config_overrides, load_configs};
+pub use config_schema::{
+    check_config_json, complete_config_json, hover_config_json, ConfigCompletionItem,
+    ConfigCompletionKind,
+};
 pub use configs::EmmyrcFilenameConvention;
-pub use configs::EmmyrcLuaVersion;
 use configs::{EmmyrcCodeAction, EmmyrcDocumentColor};
 use configs::{
     EmmyrcCodeLen, EmmyrcCompletion, EmmyrcDiagnostic, EmmyrcHover, EmmyrcInlayHint,
     EmmyrcReference, EmmyrcResource, EmmyrcRuntime, EmmyrcSemanticToken, EmmyrcSignature,
     EmmyrcStrict, EmmyrcWorkspace,
 };
+pub use configs::{EmmyrcLuaVersion, EmmyrcSyntaxExtension};
 use emmylua_parser::{LuaLanguageLevel, ParserConfig, SpecialFunction};
 use regex::Regex;
 use rowan::NodeCache;
@@ -59,27 +64,35 @@ pub struct Emmyrc {
 }
 
 impl Emmyrc {
-    pub fn get_parse_config<'cache>(
-        &self,
-        node_cache: &'cache mut NodeCache,
-    ) -> ParserConfig<'cache> {
-        let level = &self.runtime.version;
-
-        let lua_language_level = match level {
+    pub fn get_language_level(&self) -> LuaLanguageLevel {
+        match &self.runtime.version {
             EmmyrcLuaVersion::Lua51 => LuaLanguageLevel::Lua51,
             EmmyrcLuaVersion::Lua52 => LuaLanguageLevel::Lua52,
             EmmyrcLuaVersion::Lua53 => LuaLanguageLevel::Lua53,
             EmmyrcLuaVersion::Lua54 => LuaLanguageLevel::Lua54,
             EmmyrcLuaVersion::LuaJIT => LuaLanguageLevel::LuaJIT,
+            EmmyrcLuaVersion::Luau => LuaLanguageLevel::Luau,
             EmmyrcLuaVersion::LuaLatest => LuaLanguageLevel::Lua54,
             EmmyrcLuaVersion::Lua55 => LuaLanguageLevel::Lua55,
-        };
+        }
+    }
 
+    pub fn get_parse_config<'cache>(
+        &self,
+        node_cache: &'cache mut NodeCache,
+    ) -> ParserConfig<'cache> {
         let mut special_like = HashMap::new();
         for name in self.runtime.require_like_function.iter() {
             special_like.insert(name.clone(), SpecialFunction::Require);
         }
-        ParserConfig::new(lua_language_level, Some(node_cache), special_like)
+        let extensions = self
+            .runtime
+            .syntax_extensions
+            .iter()
+            .map(|extension| extension.to_syntax_extension())
+            .collect();
+        ParserConfig::new(self.get_language_level(), Some(node_cache), special_like)
+            .with_extensions(extensions)
     }
 
     pub fn pre_process_emmyrc(&mut self, workspace_root: &Path) {
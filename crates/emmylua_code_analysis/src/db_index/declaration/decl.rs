@@ -28,6 +28,8 @@ pub enum LuaDeclExtra {
     },
     Global {
         kind: LuaKind,
+        /// `global<const> x`, Lua 5.5
+        attrib: Option<LocalAttribute>,
     },
 }
 
@@ -0,0 +1,43 @@
+use std::collections::HashMap;
+
+use rowan::TextRange;
+
+use crate::FileId;
+
+use super::LuaIndex;
+
+/// The string literals of each file that hold code of another language, with the language
+#[derive(Debug, Default)]
+pub struct LuaEmbeddedIndex {
+    regions: HashMap<FileId, Vec<(TextRange, String)>>,
+}
+
+impl LuaEmbeddedIndex {
+    pub fn new() -> Self {
+        Self {
+            regions: HashMap::new(),
+        }
+    }
+
+    pub fn add_regions(&mut self, file_id: FileId, regions: Vec<(TextRange, String)>) {
+        if regions.is_empty() {
+            self.regions.remove(&file_id);
+        } else {
+            self.regions.insert(file_id, regions);
+        }
+    }
+
+    pub fn get_regions(&self, file_id: &FileId) -> Option<&Vec<(TextRange, String)>> {
+        self.regions.get(file_id)
+    }
+}
+
+impl LuaIndex for LuaEmbeddedIndex {
+    fn remove(&mut self, file_id: FileId) {
+        self.regions.remove(&file_id);
+    }
+
+    fn clear(&mut self) {
+        self.regions.clear();
+    }
+}
@@ -1,7 +1,13 @@
 use std::{ops::Deref, sync::Arc};
 
-use crate::{infer_expr, DbIndex, InferFailReason, LuaInferCache, LuaType, TypeOps};
-use emmylua_parser::{LuaAstNode, LuaCallExpr, LuaExpr, LuaSyntaxId, LuaSyntaxNode};
+use crate::{
+    find_members, infer_binary_op_type, infer_expr, DbIndex, InferFailReason, LuaInferCache,
+    LuaMemberKey, LuaType, LuaUnionType, TypeOps,
+};
+use emmylua_parser::{
+    BinaryOperator, LuaAstNode, LuaCallExpr, LuaExpr, LuaSyntaxId, LuaSyntaxNode,
+};
+use smol_str::SmolStr;
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub enum TypeAssertion {
@@ -11,11 +17,17 @@ pub enum TypeAssertion {
     Add(LuaType),
     Remove(LuaType),
     Reassign { id: LuaSyntaxId, idx: i32 },
+    // `a += b`, `id` is the right hand expr
+    CompoundReassign { op: BinaryOperator, id: LuaSyntaxId },
     Force(LuaType),
     And(Arc<Vec<TypeAssertion>>),
     Or(Arc<Vec<TypeAssertion>>),
     Call { id: LuaSyntaxId, param_idx: i32 },
     NeCall { id: LuaSyntaxId, param_idx: i32 },
+    // `x.kind == "a"`, keep the union members whose field may equal the literal
+    NarrowField(SmolStr, LuaType),
+    // `x.kind ~= "a"`, remove the union members whose field is exactly the literal
+    RemoveField(SmolStr, LuaType),
 }
 
 #[allow(unused)]
@@ -44,6 +56,12 @@ impl TypeAssertion {
                 id: *id,
                 param_idx: *param_idx,
             }),
+            TypeAssertion::NarrowField(key, value) => {
+                Some(TypeAssertion::RemoveField(key.clone(), value.clone()))
+            }
+            TypeAssertion::RemoveField(key, value) => {
+                Some(TypeAssertion::NarrowField(key.clone(), value.clone()))
+            }
             _ => None,
         }
     }
@@ -74,6 +92,12 @@ impl TypeAssertion {
                 };
                 Ok(TypeOps::Narrow.apply(db, &source, &expr_type))
             }
+            TypeAssertion::CompoundReassign { op, id } => {
+                let expr = LuaExpr::cast(id.to_node_from_root(root).ok_or(InferFailReason::None)?)
+                    .ok_or(InferFailReason::None)?;
+                let expr_type = infer_expr(db, cache, expr)?;
+                infer_binary_op_type(db, source, expr_type, *op)
+            }
             TypeAssertion::And(a) => {
                 let mut result = source;
                 for assertion in a.iter() {
@@ -126,6 +150,12 @@ impl TypeAssertion {
                     Err(e) => Err(e),
                 }
             }
+            TypeAssertion::NarrowField(key, value) => {
+                Ok(narrow_by_field(db, source, key, value, true))
+            }
+            TypeAssertion::RemoveField(key, value) => {
+                Ok(narrow_by_field(db, source, key, value, false))
+            }
             _ => Ok(source),
         }
     }
@@ -183,6 +213,13 @@ fn call_assertion(
         return Err(InferFailReason::None);
     };
 
+    // map the argument index to the parameter index, `-1` means `self`
+    let param_idx = match (call_expr.is_colon_call(), signature.is_colon_define) {
+        (true, false) => param_idx + 1,
+        (false, true) => param_idx - 1,
+        _ => param_idx,
+    };
+
     let return_type = signature.get_return_type();
     // donot change the condition
     match return_type {
@@ -207,7 +244,124 @@ fn call_assertion(
 
             Ok(typeassert.clone())
         }
-        LuaType::TypeGuard(inner) => Ok(TypeAssertion::Force(inner.deref().clone())),
+        // a type guard narrows its first parameter, or `self` for a method without parameters
+        LuaType::TypeGuard(inner) => {
+            let guard_idx = if signature.params.is_empty() && signature.is_colon_define {
+                -1
+            } else {
+                0
+            };
+            if param_idx != guard_idx {
+                return Err(InferFailReason::None);
+            }
+
+            Ok(TypeAssertion::Force(inner.deref().clone()))
+        }
         _ => return Err(InferFailReason::None),
     }
 }
+
+/// discriminated union narrowing, `is_eq` is false for `~=`
+fn narrow_by_field(
+    db: &DbIndex,
+    source: LuaType,
+    key: &SmolStr,
+    value: &LuaType,
+    is_eq: bool,
+) -> LuaType {
+    let union_types = match expand_alias(db, &source) {
+        LuaType::Union(union) => union.get_types().to_vec(),
+        _ => return source,
+    };
+
+    let member_key = LuaMemberKey::Name(key.clone());
+    let mut result = Vec::new();
+    for typ in &union_types {
+        let field_type = find_members(db, typ).and_then(|members| {
+            members
+                .into_iter()
+                .find(|member| member.key == member_key)
+                .map(|member| member.typ)
+        });
+        let keep = match field_type {
+            Some(field_type) => {
+                let literals = get_literal_values(&field_type);
+                if is_eq {
+                    // a field that is not a literal may hold any value
+                    literals.is_empty() || literals.iter().any(|it| literal_eq(it, value))
+                } else {
+                    !(literals.len() == 1 && literal_eq(&literals[0], value))
+                }
+            }
+            // a member without the field can never be equal to the literal
+            None => !is_eq || matches!(typ, LuaType::Table | LuaType::Any | LuaType::Unknown),
+        };
+
+        if keep {
+            result.push(typ.clone());
+        }
+    }
+
+    match result.len() {
+        0 => source,
+        1 => result.remove(0),
+        n if n == union_types.len() => source,
+        _ => LuaType::Union(LuaUnionType::new(result).into()),
+    }
+}
+
+fn expand_alias(db: &DbIndex, typ: &LuaType) -> LuaType {
+    if let LuaType::Ref(type_decl_id) = typ {
+        if let Some(type_decl) = db.get_type_index().get_type_decl(type_decl_id) {
+            if type_decl.is_alias() {
+                if let Some(origin) = type_decl.get_alias_origin(db, None) {
+                    return origin;
+                }
+            }
+        }
+    }
+
+    typ.clone()
+}
+
+/// the literal values of a field type, empty if the field may hold any other value
+fn get_literal_values(typ: &LuaType) -> Vec<LuaType> {
+    match typ {
+        LuaType::StringConst(_)
+        | LuaType::DocStringConst(_)
+        | LuaType::IntegerConst(_)
+        | LuaType::DocIntegerConst(_)
+        | LuaType::BooleanConst(_)
+        | LuaType::DocBooleanConst(_) => vec![typ.clone()],
+        LuaType::Union(union) => {
+            let mut values = Vec::new();
+            for typ in union.get_types() {
+                let literals = get_literal_values(typ);
+                if literals.is_empty() {
+                    return Vec::new();
+                }
+                values.extend(literals);
+            }
+            values
+        }
+        _ => Vec::new(),
+    }
+}
+
+fn literal_eq(a: &LuaType, b: &LuaType) -> bool {
+    match (a, b) {
+        (
+            LuaType::StringConst(a) | LuaType::DocStringConst(a),
+            LuaType::StringConst(b) | LuaType::DocStringConst(b),
+        ) => a == b,
+        (
+            LuaType::IntegerConst(a) | LuaType::DocIntegerConst(a),
+            LuaType::IntegerConst(b) | LuaType::DocIntegerConst(b),
+        ) => a == b,
+        (
+            LuaType::BooleanConst(a) | LuaType::DocBooleanConst(a),
+            LuaType::BooleanConst(b) | LuaType::DocBooleanConst(b),
+        ) => a == b,
+        _ => false,
+    }
+}
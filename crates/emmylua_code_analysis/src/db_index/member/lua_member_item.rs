@@ -1,4 +1,4 @@
-use crate::{DbIndex, InferFailReason, LuaSemanticDeclId, LuaType, TypeOps};
+use crate::{DbIndex, FileId, InferFailReason, LuaSemanticDeclId, LuaType, TypeOps};
 
 use super::LuaMemberId;
 
@@ -13,8 +13,13 @@ impl LuaMemberIndexItem {
         resolve_member_type(db, &self)
     }
 
-    pub fn resolve_semantic_decl(&self, db: &DbIndex) -> Option<LuaSemanticDeclId> {
-        resolve_member_semantic_id(db, &self)
+    /// `file_id` is the file of the access, its runtime version picks among `@version` definitions
+    pub fn resolve_semantic_decl(
+        &self,
+        db: &DbIndex,
+        file_id: FileId,
+    ) -> Option<LuaSemanticDeclId> {
+        resolve_member_semantic_id(db, &self, file_id)
     }
 
     #[allow(unused)]
@@ -195,6 +200,7 @@ enum MemberTypeResolveState {
 fn resolve_member_semantic_id(
     db: &DbIndex,
     member_item: &LuaMemberIndexItem,
+    file_id: FileId,
 ) -> Option<LuaSemanticDeclId> {
     match member_item {
         LuaMemberIndexItem::One(member_id) => Some(LuaSemanticDeclId::Member(*member_id)),
@@ -223,7 +229,7 @@ fn resolve_member_semantic_id(
                         if feature.is_meta_decl() {
                             let semantic_id = LuaSemanticDeclId::Member(member.get_id());
                             last_valid_member = semantic_id.clone();
-                            if check_member_version(db, semantic_id.clone()) {
+                            if check_member_version(db, semantic_id.clone(), file_id) {
                                 return Some(semantic_id);
                             }
                         }
@@ -263,13 +269,17 @@ enum MemberSemanticDeclResolveState {
     FileDecl,
 }
 
-fn check_member_version(db: &DbIndex, semantic_id: LuaSemanticDeclId) -> bool {
+fn check_member_version(db: &DbIndex, semantic_id: LuaSemanticDeclId, file_id: FileId) -> bool {
     let Some(property) = db.get_property_index().get_property(&semantic_id) else {
         return true;
     };
 
     if let Some(version) = &property.version_conds {
-        let version_number = db.get_emmyrc().runtime.version.to_lua_version_number();
+        let version_number = db
+            .get_file_emmyrc(&file_id)
+            .runtime
+            .version
+            .to_lua_version_number();
         return version.iter().any(|cond| cond.check(&version_number));
     }
 
@@ -1,6 +1,7 @@
 mod declaration;
 mod dependency;
 mod diagnostic;
+mod embedded;
 mod flow;
 mod global;
 mod member;
@@ -14,12 +15,16 @@ mod signature;
 mod traits;
 mod r#type;
 
-use std::sync::Arc;
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use crate::{Emmyrc, FileId, Vfs};
 pub use declaration::*;
 use dependency::LuaDependencyIndex;
 pub use diagnostic::{AnalyzeError, DiagnosticAction, DiagnosticActionKind, DiagnosticIndex};
+pub use embedded::LuaEmbeddedIndex;
 pub use flow::*;
 pub use global::GlobalId;
 use global::LuaGlobalIndex;
@@ -50,6 +55,7 @@ pub struct DbIndex {
     file_dependencies_index: LuaDependencyIndex,
     metatable_index: LuaMetatableIndex,
     global_index: LuaGlobalIndex,
+    embedded_index: LuaEmbeddedIndex,
     emmyrc: Arc<Emmyrc>,
 }
 
@@ -71,6 +77,7 @@ impl DbIndex {
             file_dependencies_index: LuaDependencyIndex::new(),
             metatable_index: LuaMetatableIndex::new(),
             global_index: LuaGlobalIndex::new(),
+            embedded_index: LuaEmbeddedIndex::new(),
             emmyrc: Arc::new(Emmyrc::default()),
         }
     }
@@ -193,6 +200,14 @@ impl DbIndex {
         &mut self.global_index
     }
 
+    pub fn get_embedded_index(&self) -> &LuaEmbeddedIndex {
+        &self.embedded_index
+    }
+
+    pub fn get_embedded_index_mut(&mut self) -> &mut LuaEmbeddedIndex {
+        &mut self.embedded_index
+    }
+
     pub fn update_config(&mut self, config: Arc<Emmyrc>) {
         self.vfs.update_config(config.clone());
         self.modules_index.update_config(config.clone());
@@ -202,6 +217,25 @@ impl DbIndex {
     pub fn get_emmyrc(&self) -> &Emmyrc {
         &self.emmyrc
     }
+
+    /// Use `config` for the files under `root`, a workspace folder or one of its sub directories
+    pub fn update_workspace_config(&mut self, root: PathBuf, config: Arc<Emmyrc>) {
+        self.modules_index.update_workspace_config(&root, &config);
+        self.vfs.update_folder_config(root, config);
+    }
+
+    pub fn remove_workspace_config(&mut self, root: &Path) {
+        self.modules_index.remove_workspace_config(root);
+        self.vfs.remove_folder_config(root);
+    }
+
+    /// The config of the innermost directory with its own config containing the file, or the main config
+    pub fn get_file_emmyrc(&self, file_id: &FileId) -> Arc<Emmyrc> {
+        match self.vfs.get_folder_config(file_id) {
+            Some((_, emmyrc)) => emmyrc.clone(),
+            None => self.emmyrc.clone(),
+        }
+    }
 }
 
 impl LuaIndex for DbIndex {
@@ -219,6 +253,7 @@ impl LuaIndex for DbIndex {
         self.file_dependencies_index.remove(file_id);
         self.metatable_index.remove(file_id);
         self.global_index.remove(file_id);
+        self.embedded_index.remove(file_id);
     }
 
     fn clear(&mut self) {
@@ -235,5 +270,6 @@ impl LuaIndex for DbIndex {
         self.file_dependencies_index.clear();
         self.metatable_index.clear();
         self.global_index.clear();
+        self.embedded_index.clear();
     }
 }
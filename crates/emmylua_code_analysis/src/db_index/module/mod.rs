@@ -29,6 +29,8 @@ pub struct LuaModuleIndex {
     id_counter: u32,
     fuzzy_search: bool,
     module_replace_vec: Vec<(Regex, String)>,
+    // require patterns of workspace folders with their own config
+    workspace_module_patterns: HashMap<PathBuf, Vec<Regex>>,
 }
 
 impl LuaModuleIndex {
@@ -43,6 +45,7 @@ impl LuaModuleIndex {
             id_counter: 1,
             fuzzy_search: false,
             module_replace_vec: Vec::new(),
+            workspace_module_patterns: HashMap::new(),
         };
 
         let root_node = ModuleNode::default();
@@ -53,24 +56,7 @@ impl LuaModuleIndex {
 
     // patterns like "?.lua" and "?/init.lua"
     pub fn set_module_extract_patterns(&mut self, patterns: Vec<String>) {
-        let mut patterns = patterns;
-        patterns.sort_by(|a, b| b.len().cmp(&a.len()));
-        patterns.dedup();
-        self.module_patterns.clear();
-        for item in patterns {
-            let regex_str = format!(
-                "^{}$",
-                regex::escape(&item.replace('\\', "/")).replace("\\?", "(.*)")
-            );
-            match Regex::new(&regex_str) {
-                Ok(re) => self.module_patterns.push(re),
-                Err(e) => {
-                    error!("Invalid module pattern: {}, error: {}", item, e);
-                    return;
-                }
-            };
-        }
-
+        self.module_patterns = compile_module_patterns(patterns);
         info!("update module pattern: {:?}", self.module_patterns);
     }
 
@@ -311,7 +297,11 @@ impl LuaModuleIndex {
         for workspace in &self.workspaces {
             if let Ok(relative_path) = path.strip_prefix(&workspace.root) {
                 let relative_path_str = relative_path.to_str().unwrap_or("");
-                let module_path = self.match_pattern(relative_path_str);
+                let patterns = self
+                    .workspace_module_patterns
+                    .get(&workspace.root)
+                    .unwrap_or(&self.module_patterns);
+                let module_path = match_pattern(patterns, relative_path_str);
                 if let Some(module_path) = module_path {
                     if matched_module_path.is_none() {
                         matched_module_path = Some((module_path, workspace.id));
@@ -339,62 +329,30 @@ impl LuaModuleIndex {
         module_path.to_string()
     }
 
-    fn match_pattern(&self, path: &str) -> Option<String> {
-        for pattern in &self.module_patterns {
-            if let Some(captures) = pattern.captures(path) {
-                if let Some(matched) = captures.get(1) {
-                    return Some(matched.as_str().to_string());
-                }
-            }
-        }
-
-        None
-    }
-
     pub fn add_workspace_root(&mut self, root: PathBuf, workspace_id: WorkspaceId) {
         if !self.workspaces.iter().any(|w| w.root == root) {
             self.workspaces.push(Workspace::new(root, workspace_id));
         }
     }
 
-    #[allow(unused)]
     pub fn remove_workspace_root(&mut self, root: &Path) {
         self.workspaces.retain(|r| r.root != root);
     }
 
-    pub fn update_config(&mut self, config: Arc<Emmyrc>) {
-        let mut extension_names = Vec::new();
-
-        for extension in &config.runtime.extensions {
-            if extension.starts_with(".") {
-                extension_names.push(extension[1..].to_string());
-            } else if extension.starts_with("*.") {
-                extension_names.push(extension[2..].to_string());
-            } else {
-                extension_names.push(extension.clone());
-            }
-        }
-
-        if !extension_names.contains(&"lua".to_string()) {
-            extension_names.push("lua".to_string());
-        }
-
-        let mut patterns = Vec::new();
-        for extension in &extension_names {
-            patterns.push(format!("?.{}", extension));
-        }
+    /// The require patterns of `config` apply to the files under `root`
+    pub fn update_workspace_config(&mut self, root: &Path, config: &Emmyrc) {
+        let patterns = compile_module_patterns(get_module_extract_patterns(config));
+        self.workspace_module_patterns
+            .insert(root.to_path_buf(), patterns);
+    }
 
-        let require_pattern = config.runtime.require_pattern.clone();
-        if require_pattern.is_empty() {
-            // add default require pattern
-            for extension in &extension_names {
-                patterns.push(format!("?/init.{}", extension));
-            }
-        } else {
-            patterns.extend(require_pattern);
-        }
+    pub fn remove_workspace_config(&mut self, root: &Path) {
+        self.workspace_module_patterns
+            .retain(|folder, _| !folder.starts_with(root));
+    }
 
-        self.set_module_extract_patterns(patterns);
+    pub fn update_config(&mut self, config: Arc<Emmyrc>) {
+        self.set_module_extract_patterns(get_module_extract_patterns(&config));
         self.set_module_replace_patterns(
             config
                 .workspace
@@ -487,6 +445,73 @@ impl LuaModuleIndex {
     }
 }
 
+fn get_module_extract_patterns(config: &Emmyrc) -> Vec<String> {
+    let mut extension_names = Vec::new();
+    for extension in &config.runtime.extensions {
+        if extension.starts_with(".") {
+            extension_names.push(extension[1..].to_string());
+        } else if extension.starts_with("*.") {
+            extension_names.push(extension[2..].to_string());
+        } else {
+            extension_names.push(extension.clone());
+        }
+    }
+
+    if !extension_names.contains(&"lua".to_string()) {
+        extension_names.push("lua".to_string());
+    }
+
+    let mut patterns = Vec::new();
+    for extension in &extension_names {
+        patterns.push(format!("?.{}", extension));
+    }
+
+    let require_pattern = config.runtime.require_pattern.clone();
+    if require_pattern.is_empty() {
+        // add default require pattern
+        for extension in &extension_names {
+            patterns.push(format!("?/init.{}", extension));
+        }
+    } else {
+        patterns.extend(require_pattern);
+    }
+
+    patterns
+}
+
+fn compile_module_patterns(mut patterns: Vec<String>) -> Vec<Regex> {
+    patterns.sort_by(|a, b| b.len().cmp(&a.len()));
+    patterns.dedup();
+    let mut regexes = Vec::new();
+    for item in patterns {
+        let regex_str = format!(
+            "^{}$",
+            regex::escape(&item.replace('\\', "/")).replace("\\?", "(.*)")
+        );
+        match Regex::new(&regex_str) {
+            Ok(re) => regexes.push(re),
+            Err(e) => {
+                error!("Invalid module pattern: {}, error: {}", item, e);
+                break;
+            }
+        };
+    }
+
+    regexes
+}
+
+fn match_pattern(patterns: &[Regex], path: &str) -> Option<String> {
+    for pattern in patterns {
+        if let Some(captures) = pattern.captures(path) {
+            if let Some(matched) = captures.get(1) {
+                return Some(matched.as_str().to_string());
+            }
+        }
+    }
+
+    None
+}
+
 impl LuaIndex for LuaModuleIndex {
     fn remove(&mut self, file_id: FileId) {
         let (mut parent_id, mut child_id) =
@@ -6,15 +6,15 @@ use std::{collections::HashMap, sync::Arc};
 use emmylua_parser::{LuaAstNode, LuaClosureExpr, LuaDocFuncType};
 use rowan::TextSize;
 
-use crate::VariadicType;
 use crate::{
     db_index::{LuaFunctionType, LuaType},
     FileId,
 };
+use crate::{GenericParam, VariadicType};
 
 #[derive(Debug)]
 pub struct LuaSignature {
-    pub generic_params: Vec<(String, Option<LuaType>)>,
+    pub generic_params: Vec<GenericParam>,
     pub overloads: Vec<Arc<LuaFunctionType>>,
     pub param_docs: HashMap<usize, LuaDocParamInfo>,
     pub params: Vec<String>,
@@ -23,6 +23,8 @@ pub struct LuaSignature {
     pub is_colon_define: bool,
     pub is_async: bool,
     pub nodiscard: Option<LuaNoDiscard>,
+    /// `---@language sql query` marks the strings passed as `query` as SQL
+    pub param_languages: HashMap<String, String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -43,6 +45,7 @@ impl LuaSignature {
             is_colon_define: false,
             is_async: false,
             nodiscard: None,
+            param_languages: HashMap::new(),
         }
     }
 
@@ -0,0 +1,27 @@
+use emmylua_parser::VarianceKind;
+
+use super::LuaType;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenericParam {
+    pub name: String,
+    pub constraint: Option<LuaType>,
+    pub default: Option<LuaType>,
+    pub variance: VarianceKind,
+}
+
+impl GenericParam {
+    pub fn new(name: String, constraint: Option<LuaType>) -> Self {
+        Self {
+            name,
+            constraint,
+            default: None,
+            variance: VarianceKind::None,
+        }
+    }
+
+    /// the type used when the template can not be inferred or is not given
+    pub fn get_fallback_type(&self) -> Option<&LuaType> {
+        self.default.as_ref().or(self.constraint.as_ref())
+    }
+}
@@ -112,7 +112,7 @@ fn humanize_def_type(db: &DbIndex, id: &LuaTypeDeclId, level: RenderLevel) -> St
 
     let generic_names = generic
         .iter()
-        .map(|it| it.0.clone())
+        .map(|it| it.name.clone())
         .collect::<Vec<_>>()
         .join(", ");
     format!("{}<{}>", full_name, generic_names)
@@ -681,7 +681,7 @@ fn humanize_signature_type(
     let generics = signature
         .generic_params
         .iter()
-        .map(|(name, _)| name.to_string())
+        .map(|param| param.name.clone())
         .collect::<Vec<_>>()
         .join(", ");
 
@@ -1015,11 +1015,40 @@ impl GenericTplId {
 pub struct GenericTpl {
     tpl_id: GenericTplId,
     name: ArcIntern<SmolStr>,
+    constraint: Option<LuaType>,
+    default: Option<LuaType>,
 }
 
 impl GenericTpl {
     pub fn new(tpl_id: GenericTplId, name: ArcIntern<SmolStr>) -> Self {
-        Self { tpl_id, name }
+        Self {
+            tpl_id,
+            name,
+            constraint: None,
+            default: None,
+        }
+    }
+
+    pub fn new_with_bound(
+        tpl_id: GenericTplId,
+        name: ArcIntern<SmolStr>,
+        constraint: Option<LuaType>,
+        default: Option<LuaType>,
+    ) -> Self {
+        Self {
+            tpl_id,
+            name,
+            constraint,
+            default,
+        }
+    }
+
+    pub fn get_constraint(&self) -> Option<&LuaType> {
+        self.constraint.as_ref()
+    }
+
+    pub fn get_default(&self) -> Option<&LuaType> {
+        self.default.as_ref()
     }
 
     pub fn get_tpl_id(&self) -> GenericTplId {
@@ -0,0 +1,66 @@
+use emmylua_parser::{LuaAstToken, LuaEmbeddedText, LuaStringToken};
+use rowan::{TextRange, TextSize};
+
+use crate::{DiagnosticCode, SemanticModel};
+
+use super::{Checker, DiagnosticContext};
+
+pub struct EmbeddedSyntaxErrorChecker;
+
+impl Checker for EmbeddedSyntaxErrorChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::EmbeddedSyntaxError];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        for region in semantic_model.get_embedded_regions() {
+            match region.language.as_str() {
+                "lua" => check_lua(context, semantic_model, &region.token),
+                "json" => {
+                    check_json(context, &region.token);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn check_lua(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    token: &LuaStringToken,
+) {
+    let embedded_chunk = semantic_model.parse_embedded_lua(token);
+    for error in embedded_chunk.get_errors() {
+        context.add_diagnostic(
+            DiagnosticCode::EmbeddedSyntaxError,
+            error.range,
+            t!("Embedded Lua: %{message}", message = error.message).to_string(),
+            None,
+        );
+    }
+}
+
+fn check_json(context: &mut DiagnosticContext, token: &LuaStringToken) -> Option<()> {
+    let text = LuaEmbeddedText::new(token);
+    let error = serde_json::from_str::<serde_json::Value>(text.get_text()).err()?;
+    // serde_json reports the line and column at which it stopped
+    let offset = text
+        .get_text()
+        .split_inclusive('\n')
+        .take(error.line().saturating_sub(1))
+        .map(str::len)
+        .sum::<usize>()
+        + error.column().saturating_sub(1);
+    let offset = offset.min(text.get_text().len());
+    let start = text.to_source_offset(offset);
+    let range = TextRange::new(start, start + TextSize::from(1))
+        .intersect(token.get_range())
+        .filter(|range| !range.is_empty())
+        .unwrap_or_else(|| token.get_range());
+    context.add_diagnostic(
+        DiagnosticCode::EmbeddedSyntaxError,
+        range,
+        t!("Embedded JSON: %{message}", message = error.to_string()).to_string(),
+        None,
+    );
+    Some(())
+}
@@ -0,0 +1,168 @@
+use emmylua_parser::{LuaAstNode, LuaCallExpr, LuaDocGenericType, LuaExpr};
+use rowan::TextRange;
+
+use crate::{
+    humanize_type, DiagnosticCode, GenericParam, GenericTplId, InFiled, LuaFunctionType, LuaType,
+    RenderLevel, SemanticModel, SubstitutorValue,
+};
+
+use super::{Checker, DiagnosticContext};
+
+pub struct GenericConstraintMismatchChecker;
+
+impl Checker for GenericConstraintMismatchChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::GenericConstraintMismatch];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let root = semantic_model.get_root().clone();
+        for generic_type in root.descendants::<LuaDocGenericType>() {
+            check_doc_generic_type(context, semantic_model, generic_type);
+        }
+
+        for call_expr in root.descendants::<LuaCallExpr>() {
+            check_call_expr(context, semantic_model, call_expr);
+        }
+    }
+}
+
+/// check explicit type arguments, `---@type Box<string>`
+fn check_doc_generic_type(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    generic_type: LuaDocGenericType,
+) -> Option<()> {
+    let db = semantic_model.get_db();
+    let in_filed_syntax_id = InFiled::new(context.get_file_id(), generic_type.get_syntax_id());
+    let type_cache = db
+        .get_type_index()
+        .get_type_cache(&in_filed_syntax_id.into())?;
+    let LuaType::Generic(generic) = type_cache.as_type() else {
+        return None;
+    };
+
+    let generic_params = db
+        .get_type_index()
+        .get_generic_params(&generic.get_base_type_id())?;
+    let arg_docs = generic_type
+        .get_generic_types()?
+        .get_types()
+        .collect::<Vec<_>>();
+    for (i, arg_type) in generic.get_params().iter().enumerate() {
+        let Some(param) = generic_params.get(i) else {
+            break;
+        };
+
+        let Some(arg_doc) = arg_docs.get(i) else {
+            break;
+        };
+
+        check_constraint(
+            context,
+            semantic_model,
+            param,
+            arg_type,
+            arg_doc.get_range(),
+        );
+    }
+
+    Some(())
+}
+
+/// check the inferred type arguments of a generic function call
+fn check_call_expr(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    call_expr: LuaCallExpr,
+) -> Option<()> {
+    let prefix_type = semantic_model
+        .infer_expr(call_expr.get_prefix_expr()?)
+        .ok()?;
+    let LuaType::Signature(signature_id) = prefix_type else {
+        return None;
+    };
+
+    let signature = semantic_model
+        .get_db()
+        .get_signature_index()
+        .get(&signature_id)?;
+    if !signature
+        .generic_params
+        .iter()
+        .any(|param| param.constraint.is_some())
+    {
+        return None;
+    }
+
+    let func = signature.to_doc_func_type();
+    let substitutor = semantic_model.infer_call_generic_substitutor(call_expr.clone(), &func)?;
+    let args = call_expr.get_args_list()?.get_args().collect::<Vec<_>>();
+    for (i, param) in signature.generic_params.iter().enumerate() {
+        let tpl_id = GenericTplId::Func(i as u32);
+        let Some(SubstitutorValue::Type(arg_type)) = substitutor.get(tpl_id) else {
+            continue;
+        };
+
+        let range =
+            find_tpl_arg_range(&call_expr, &func, &args, tpl_id).unwrap_or(call_expr.get_range());
+        check_constraint(context, semantic_model, param, arg_type, range);
+    }
+
+    Some(())
+}
+
+fn find_tpl_arg_range(
+    call_expr: &LuaCallExpr,
+    func: &LuaFunctionType,
+    args: &[LuaExpr],
+    tpl_id: GenericTplId,
+) -> Option<TextRange> {
+    let param_idx = func.get_params().iter().position(|(_, typ)| match typ {
+        Some(LuaType::TplRef(tpl)) => tpl.get_tpl_id() == tpl_id,
+        _ => false,
+    })?;
+
+    let arg_idx = match (func.is_colon_define(), call_expr.is_colon_call()) {
+        (true, false) => param_idx + 1,
+        (false, true) => param_idx.checked_sub(1)?,
+        _ => param_idx,
+    };
+
+    args.get(arg_idx).map(|arg| arg.get_range())
+}
+
+fn check_constraint(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    param: &GenericParam,
+    arg_type: &LuaType,
+    range: TextRange,
+) -> Option<()> {
+    let constraint = param.constraint.as_ref()?;
+    if constraint.contain_tpl() || arg_type.contain_tpl() {
+        return None;
+    }
+
+    if arg_type.is_any() || arg_type.is_unknown() {
+        return None;
+    }
+
+    if semantic_model.type_check(constraint, arg_type).is_ok() {
+        return None;
+    }
+
+    let db = semantic_model.get_db();
+    context.add_diagnostic(
+        DiagnosticCode::GenericConstraintMismatch,
+        range,
+        t!(
+            "type `%{found}` does not satisfy the constraint `%{constraint}` of generic parameter `%{name}`",
+            found = humanize_type(db, arg_type, RenderLevel::Simple),
+            constraint = humanize_type(db, constraint, RenderLevel::Simple),
+            name = param.name
+        )
+        .to_string(),
+        None,
+    );
+
+    Some(())
+}
@@ -21,7 +21,7 @@ impl Checker for LocalConstReassignChecker {
         };
         for (decl_id, decl) in decl_tree.get_decls() {
             match &decl.extra {
-                LuaDeclExtra::Local { attrib, .. } => {
+                LuaDeclExtra::Local { attrib, .. } | LuaDeclExtra::Global { attrib, .. } => {
                     if let Some(attrib) = attrib {
                         if matches!(attrib, LocalAttribute::Const | LocalAttribute::IterConst) {
                             check_local_const_reassign(context, semantic_model, decl_id, &attrib);
@@ -0,0 +1,80 @@
+use emmylua_parser::{
+    LuaAstNode, LuaAstToken, LuaCallExpr, LuaPattern, LuaPatternError, LuaPatternErrorKind,
+    LuaStringToken,
+};
+
+use crate::{DiagnosticCode, LuaStringLibFunc, SemanticModel};
+
+use super::{Checker, DiagnosticContext};
+
+pub struct MalformedPatternChecker;
+
+impl Checker for MalformedPatternChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::MalformedPattern];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let root = semantic_model.get_root().clone();
+        for call_expr in root.descendants::<LuaCallExpr>() {
+            check_pattern_call(context, semantic_model, call_expr);
+        }
+    }
+}
+
+fn check_pattern_call(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    call_expr: LuaCallExpr,
+) -> Option<()> {
+    let string_call = semantic_model.get_string_lib_call(&call_expr)?;
+    if !string_call.func.takes_pattern() || string_call.is_plain_find() {
+        return Some(());
+    }
+    let pattern_token = string_call.get_string_arg(1)?;
+    let pattern = LuaPattern::parse(&pattern_token.get_value());
+    if let Some(error) = &pattern.error {
+        add_pattern_diagnostic(context, &pattern_token, error);
+        return Some(());
+    }
+
+    if string_call.func == LuaStringLibFunc::GSub {
+        let replacement_token = string_call.get_string_arg(2)?;
+        let level = semantic_model.get_emmyrc().get_language_level();
+        if let Some(error) = pattern.check_replacement(&replacement_token.get_value(), level) {
+            add_pattern_diagnostic(context, &replacement_token, &error);
+        }
+    }
+    Some(())
+}
+
+fn add_pattern_diagnostic(
+    context: &mut DiagnosticContext,
+    token: &LuaStringToken,
+    error: &LuaPatternError,
+) {
+    let message = match error.kind {
+        LuaPatternErrorKind::EndsWithPercent => t!("Malformed pattern: ends with '%'"),
+        LuaPatternErrorKind::MissingBracket => t!("Malformed pattern: missing ']'"),
+        LuaPatternErrorKind::MissingBalanceArgs => {
+            t!("Malformed pattern: missing arguments to '%b'")
+        }
+        LuaPatternErrorKind::MissingFrontierSet => t!("Malformed pattern: missing '[' after '%f'"),
+        LuaPatternErrorKind::InvalidCaptureIndex => t!("Malformed pattern: invalid capture index"),
+        LuaPatternErrorKind::InvalidCapture => t!("Malformed pattern: invalid pattern capture"),
+        LuaPatternErrorKind::UnfinishedCapture => t!("Malformed pattern: unfinished capture"),
+        LuaPatternErrorKind::TooManyCaptures => t!("Malformed pattern: too many captures"),
+        LuaPatternErrorKind::InvalidReplacement => {
+            t!("Invalid use of '%' in replacement string")
+        }
+    };
+    // escapes in the literal hide where the error is, the whole string is reported then
+    let range = token
+        .get_value_range(error.range.clone())
+        .filter(|range| !range.is_empty())
+        .unwrap_or_else(|| token.get_range());
+    context.add_diagnostic(
+        DiagnosticCode::MalformedPattern,
+        range,
+        message.to_string(),
+        None,
+    );
+}
@@ -0,0 +1,357 @@
+use emmylua_parser::{
+    BinaryOperator, LuaAstNode, LuaBlock, LuaExpr, LuaIfStat, LuaIndexKey, LuaStat, LuaTableExpr,
+};
+use itertools::Itertools;
+use rowan::TextRange;
+
+use crate::{DiagnosticCode, LuaMemberKey, LuaMemberOwner, LuaType, SemanticModel};
+
+use super::{Checker, DiagnosticContext};
+
+pub struct MissingCasesChecker;
+
+impl Checker for MissingCasesChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::MissingCases];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let root = semantic_model.get_root().clone();
+        for if_stat in root.descendants::<LuaIfStat>() {
+            check_if_stat(context, semantic_model, if_stat);
+        }
+
+        for table_expr in root.descendants::<LuaTableExpr>() {
+            check_dispatch_table(context, semantic_model, table_expr);
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum CaseValue {
+    Integer(i64),
+    String(String),
+    Boolean(bool),
+}
+
+#[derive(Debug)]
+struct Case {
+    value: CaseValue,
+    label: String,
+}
+
+/// check `if x == A then ... elseif x == B then ... end` chains
+fn check_if_stat(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    if_stat: LuaIfStat,
+) -> Option<()> {
+    let mut conditions = vec![if_stat.get_condition_expr()?];
+    for clause in if_stat.get_else_if_clause_list() {
+        conditions.push(clause.get_condition_expr()?);
+    }
+
+    let mut subject: Option<LuaExpr> = None;
+    let mut case_exprs = Vec::new();
+    for condition in &conditions {
+        collect_case_exprs(condition.clone(), &mut subject, &mut case_exprs)?;
+    }
+
+    // a single comparison is an ordinary check, not a dispatch
+    if case_exprs.len() < 2 {
+        return None;
+    }
+
+    let else_clause = if_stat.get_else_clause();
+    let unreachable_else = match &else_clause {
+        Some(else_clause) => {
+            if !is_unreachable_block(else_clause.get_block()) {
+                return None;
+            }
+            true
+        }
+        None => false,
+    };
+
+    let subject = subject?;
+    let subject_type = semantic_model.infer_expr(subject.clone()).ok()?;
+    let enum_prefix = case_exprs.iter().find_map(|expr| match expr {
+        LuaExpr::IndexExpr(index_expr) => index_expr
+            .get_prefix_expr()
+            .map(|prefix| prefix.syntax().text().to_string()),
+        _ => None,
+    });
+    let all_cases = collect_cases(semantic_model, &subject_type, enum_prefix.as_deref())?;
+
+    let mut covered = Vec::new();
+    for case_expr in case_exprs {
+        let case_type = semantic_model.infer_expr(case_expr).ok()?;
+        covered.extend(get_case_values(semantic_model, &case_type)?);
+    }
+
+    let missing = all_cases
+        .into_iter()
+        .filter(|case| !covered.contains(&case.value))
+        .collect::<Vec<_>>();
+    if missing.is_empty() {
+        return Some(());
+    }
+
+    let missing_labels = missing.iter().map(|case| case.label.clone()).collect_vec();
+    let (range, message) = if unreachable_else {
+        (
+            else_clause?.get_range(),
+            t!(
+                "Unreachable branch can be reached, missing cases: %{cases}",
+                cases = missing_labels.iter().map(|s| format!("`{}`", s)).join(", ")
+            )
+            .to_string(),
+        )
+    } else {
+        (
+            TextRange::new(if_stat.get_position(), conditions[0].get_range().end()),
+            t!(
+                "Missing cases: %{cases}",
+                cases = missing_labels.iter().map(|s| format!("`{}`", s)).join(", ")
+            )
+            .to_string(),
+        )
+    };
+
+    context.add_diagnostic(
+        DiagnosticCode::MissingCases,
+        range,
+        message,
+        Some(serde_json::json!({
+            "subject": subject.syntax().text().to_string(),
+            "cases": missing_labels,
+        })),
+    );
+
+    Some(())
+}
+
+/// check table literals declared as `table<K, V>` where `K` is an enum or a literal union
+fn check_dispatch_table(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    table_expr: LuaTableExpr,
+) -> Option<()> {
+    let table_type = semantic_model.infer_table_should_be(table_expr.clone())?;
+    let LuaType::TableGeneric(params) = &table_type else {
+        return None;
+    };
+    let key_type = params.first()?;
+
+    let mut covered = Vec::new();
+    let mut enum_prefix = None;
+    for field in table_expr.get_fields() {
+        match field.get_field_key()? {
+            LuaIndexKey::Name(name) => {
+                covered.push(CaseValue::String(name.get_name_text().to_string()))
+            }
+            LuaIndexKey::String(string) => covered.push(CaseValue::String(string.get_value())),
+            LuaIndexKey::Integer(number) => {
+                covered.push(CaseValue::Integer(number.get_int_value()))
+            }
+            LuaIndexKey::Expr(expr) => {
+                if let LuaExpr::IndexExpr(index_expr) = &expr {
+                    enum_prefix = index_expr
+                        .get_prefix_expr()
+                        .map(|prefix| prefix.syntax().text().to_string());
+                }
+                let key_type = semantic_model.infer_expr(expr).ok()?;
+                covered.extend(get_case_values(semantic_model, &key_type)?);
+            }
+            LuaIndexKey::Idx(_) => return None,
+        }
+    }
+
+    let missing_labels = collect_cases(semantic_model, key_type, enum_prefix.as_deref())?
+        .into_iter()
+        .filter(|case| !covered.contains(&case.value))
+        .map(|case| format!("`{}`", case.label))
+        .collect_vec();
+    if missing_labels.is_empty() {
+        return Some(());
+    }
+
+    context.add_diagnostic(
+        DiagnosticCode::MissingCases,
+        table_expr.get_range(),
+        t!("Missing cases: %{cases}", cases = missing_labels.join(", ")).to_string(),
+        None,
+    );
+
+    Some(())
+}
+
+/// split `x == A or x == B` into the subject `x` and the compared values
+fn collect_case_exprs(
+    condition: LuaExpr,
+    subject: &mut Option<LuaExpr>,
+    case_exprs: &mut Vec<LuaExpr>,
+) -> Option<()> {
+    match condition {
+        LuaExpr::ParenExpr(paren_expr) => {
+            collect_case_exprs(paren_expr.get_expr()?, subject, case_exprs)
+        }
+        LuaExpr::BinaryExpr(binary_expr) => {
+            let op = binary_expr.get_op_token()?.get_op();
+            let (left, right) = binary_expr.get_exprs()?;
+            match op {
+                BinaryOperator::OpOr => {
+                    collect_case_exprs(left, subject, case_exprs)?;
+                    collect_case_exprs(right, subject, case_exprs)
+                }
+                BinaryOperator::OpEq => {
+                    let (var_expr, case_expr) = if is_case_expr(&right) {
+                        (left, right)
+                    } else if is_case_expr(&left) {
+                        (right, left)
+                    } else {
+                        return None;
+                    };
+
+                    if !matches!(var_expr, LuaExpr::NameExpr(_) | LuaExpr::IndexExpr(_)) {
+                        return None;
+                    }
+
+                    match subject {
+                        Some(subject) => {
+                            if subject.syntax().text() != var_expr.syntax().text() {
+                                return None;
+                            }
+                        }
+                        None => *subject = Some(var_expr),
+                    }
+
+                    case_exprs.push(case_expr);
+                    Some(())
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn is_case_expr(expr: &LuaExpr) -> bool {
+    match expr {
+        LuaExpr::LiteralExpr(_) => true,
+        LuaExpr::IndexExpr(index_expr) => {
+            matches!(index_expr.get_prefix_expr(), Some(LuaExpr::NameExpr(_)))
+        }
+        _ => false,
+    }
+}
+
+/// an `else` branch that raises an error is expected to be unreachable
+fn is_unreachable_block(block: Option<LuaBlock>) -> bool {
+    let Some(block) = block else {
+        return false;
+    };
+
+    // `error` may follow some logging, e.g. `log(x) error("unreachable")`
+    block.get_stats().any(|stat| match stat {
+        LuaStat::CallExprStat(call_expr_stat) => call_expr_stat
+            .get_call_expr()
+            .is_some_and(|call_expr| call_expr.is_error()),
+        _ => false,
+    })
+}
+
+fn collect_cases(
+    semantic_model: &SemanticModel,
+    typ: &LuaType,
+    enum_prefix: Option<&str>,
+) -> Option<Vec<Case>> {
+    let mut cases = Vec::new();
+    match typ {
+        LuaType::Ref(type_decl_id) => {
+            let db = semantic_model.get_db();
+            let type_decl = db.get_type_index().get_type_decl(type_decl_id)?;
+            if !type_decl.is_enum() {
+                return None;
+            }
+
+            let prefix = enum_prefix.unwrap_or(type_decl.get_name());
+            let members = db
+                .get_member_index()
+                .get_sorted_members(&LuaMemberOwner::Type(type_decl_id.clone()))?;
+            for member in members {
+                if type_decl.is_enum_key() {
+                    let (value, label) = match member.get_key() {
+                        LuaMemberKey::Name(name) => {
+                            (CaseValue::String(name.to_string()), format!("\"{}\"", name))
+                        }
+                        LuaMemberKey::Integer(i) => (CaseValue::Integer(*i), i.to_string()),
+                        _ => continue,
+                    };
+                    cases.push(Case { value, label });
+                    continue;
+                }
+
+                let member_type = db
+                    .get_type_index()
+                    .get_type_cache(&member.get_id().into())?
+                    .as_type()
+                    .clone();
+                let value = get_case_values(semantic_model, &member_type)?
+                    .into_iter()
+                    .next()?;
+                let label = match member.get_key() {
+                    LuaMemberKey::Name(name) => format!("{}.{}", prefix, name),
+                    LuaMemberKey::Integer(i) => format!("{}[{}]", prefix, i),
+                    _ => continue,
+                };
+                cases.push(Case { value, label });
+            }
+        }
+        LuaType::Union(union_type) => {
+            for typ in union_type.get_types() {
+                if typ.is_nil() {
+                    continue;
+                }
+                for case in collect_cases(semantic_model, typ, enum_prefix)? {
+                    if !cases.iter().any(|it: &Case| it.value == case.value) {
+                        cases.push(case);
+                    }
+                }
+            }
+        }
+        LuaType::Boolean => {
+            for b in [true, false] {
+                cases.push(Case {
+                    value: CaseValue::Boolean(b),
+                    label: b.to_string(),
+                });
+            }
+        }
+        _ => {
+            let value = get_case_values(semantic_model, typ)?.into_iter().next()?;
+            let label = match &value {
+                CaseValue::Integer(i) => i.to_string(),
+                CaseValue::String(s) => format!("\"{}\"", s),
+                CaseValue::Boolean(b) => b.to_string(),
+            };
+            cases.push(Case { value, label });
+        }
+    }
+
+    Some(cases)
+}
+
+fn get_case_values(semantic_model: &SemanticModel, typ: &LuaType) -> Option<Vec<CaseValue>> {
+    let value = match typ {
+        LuaType::IntegerConst(i) | LuaType::DocIntegerConst(i) => CaseValue::Integer(*i),
+        LuaType::StringConst(s) | LuaType::DocStringConst(s) => CaseValue::String(s.to_string()),
+        LuaType::BooleanConst(b) | LuaType::DocBooleanConst(b) => CaseValue::Boolean(*b),
+        LuaType::Ref(_) => {
+            // comparing with an enum value as a whole covers every member
+            return collect_cases(semantic_model, typ, None)
+                .map(|cases| cases.into_iter().map(|case| case.value).collect());
+        }
+        _ => return None,
+    };
+
+    Some(vec![value])
+}
@@ -14,15 +14,21 @@ mod duplicate_field;
 mod duplicate_index;
 mod duplicate_require;
 mod duplicate_type;
+mod embedded_syntax_error;
+mod generic_constraint_mismatch;
 mod incomplete_signature_doc;
 mod local_const_reassign;
+mod malformed_pattern;
+mod missing_cases;
 mod missing_fields;
 mod need_check_nil;
 mod param_type_check;
 mod redefined_local;
 mod return_type_mismatch;
+mod string_format_mismatch;
 mod syntax_error;
 mod unbalanced_assignments;
+mod undeclared_global;
 mod undefined_doc_param;
 mod undefined_global;
 mod unnecessary_assert;
@@ -32,7 +38,10 @@ mod unused;
 use emmylua_parser::{
     LuaAstNode, LuaClosureExpr, LuaComment, LuaReturnStat, LuaStat, LuaSyntaxKind,
 };
-use lsp_types::{Diagnostic, DiagnosticSeverity, DiagnosticTag, NumberOrString};
+use lsp_types::{
+    Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity, DiagnosticTag, Location,
+    NumberOrString,
+};
 use rowan::TextRange;
 use std::sync::Arc;
 
@@ -91,6 +100,15 @@ pub fn check_file(context: &mut DiagnosticContext, semantic_model: &SemanticMode
     run_check::<check_param_count::CheckParamCountChecker>(context, semantic_model);
     run_check::<duplicate_field::DuplicateFieldChecker>(context, semantic_model);
     run_check::<duplicate_index::DuplicateIndexChecker>(context, semantic_model);
+    run_check::<generic_constraint_mismatch::GenericConstraintMismatchChecker>(
+        context,
+        semantic_model,
+    );
+    run_check::<missing_cases::MissingCasesChecker>(context, semantic_model);
+    run_check::<malformed_pattern::MalformedPatternChecker>(context, semantic_model);
+    run_check::<string_format_mismatch::StringFormatMismatchChecker>(context, semantic_model);
+    run_check::<embedded_syntax_error::EmbeddedSyntaxErrorChecker>(context, semantic_model);
+    run_check::<undeclared_global::UndeclaredGlobalChecker>(context, semantic_model);
 
     run_check::<code_style::non_literal_expressions_in_assert::NonLiteralExpressionsInAssertChecker>(
         context,
@@ -130,6 +148,18 @@ impl<'a> DiagnosticContext<'a> {
         range: TextRange,
         message: String,
         data: Option<serde_json::Value>,
+    ) {
+        self.add_diagnostic_with_related(code, range, message, Vec::new(), data);
+    }
+
+    /// `related` are other ranges of the same file, e.g. the `function` an `end` is missing for
+    pub fn add_diagnostic_with_related(
+        &mut self,
+        code: DiagnosticCode,
+        range: TextRange,
+        message: String,
+        related: Vec<(TextRange, String)>,
+        data: Option<serde_json::Value>,
     ) {
         if !self.is_checker_enable_by_code(&code) {
             return;
@@ -139,6 +169,7 @@ impl<'a> DiagnosticContext<'a> {
             return;
         }
 
+        let related_information = self.translate_related(related);
         let diagnostic = Diagnostic {
             message,
             range: self.translate_range(range).unwrap_or(lsp_types::Range {
@@ -155,6 +186,7 @@ impl<'a> DiagnosticContext<'a> {
             code: Some(NumberOrString::String(code.get_name().to_string())),
             source: Some("EmmyLua".into()),
             tags: self.get_tags(code),
+            related_information,
             data,
             ..Default::default()
         };
@@ -203,6 +235,30 @@ impl<'a> DiagnosticContext<'a> {
         })
     }
 
+    fn translate_related(
+        &self,
+        related: Vec<(TextRange, String)>,
+    ) -> Option<Vec<DiagnosticRelatedInformation>> {
+        if related.is_empty() {
+            return None;
+        }
+
+        let uri = self.db.get_vfs().get_uri(&self.file_id)?;
+        let related_information = related
+            .into_iter()
+            .filter_map(|(range, message)| {
+                Some(DiagnosticRelatedInformation {
+                    location: Location {
+                        uri: uri.clone(),
+                        range: self.translate_range(range)?,
+                    },
+                    message,
+                })
+            })
+            .collect();
+        Some(related_information)
+    }
+
     pub fn get_diagnostics(self) -> Vec<Diagnostic> {
         self.diagnostics
     }
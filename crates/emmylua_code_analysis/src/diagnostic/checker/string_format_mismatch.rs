@@ -0,0 +1,171 @@
+use emmylua_parser::{
+    LuaAstNode, LuaAstToken, LuaCallExpr, LuaExpr, LuaFormatSpec, LuaFormatSpecKind,
+    LuaFormatString, LuaLanguageLevel, LuaLiteralToken, LuaStringToken,
+};
+
+use crate::{DiagnosticCode, LuaStringLibFunc, LuaType, SemanticModel};
+
+use super::{humanize_lint_type, Checker, DiagnosticContext};
+
+pub struct StringFormatMismatchChecker;
+
+impl Checker for StringFormatMismatchChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::StringFormatMismatch];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let root = semantic_model.get_root().clone();
+        for call_expr in root.descendants::<LuaCallExpr>() {
+            check_format_call(context, semantic_model, call_expr);
+        }
+    }
+}
+
+fn check_format_call(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    call_expr: LuaCallExpr,
+) -> Option<()> {
+    let string_call = semantic_model.get_string_lib_call(&call_expr)?;
+    if string_call.func != LuaStringLibFunc::Format {
+        return Some(());
+    }
+    let format_token = string_call.get_string_arg(0)?;
+    let format_text = format_token.get_value();
+    let level = semantic_model.get_emmyrc().get_language_level();
+    let format = LuaFormatString::parse(&format_text, level);
+    if let Some(error) = format.error {
+        let spec = &format_text[error.clone()];
+        context.add_diagnostic(
+            DiagnosticCode::StringFormatMismatch,
+            spec_range(&format_token, error),
+            t!("Invalid conversion '%{spec}' to 'format'", spec = spec).to_string(),
+            None,
+        );
+        return Some(());
+    }
+
+    // a call or `...` as the last argument gives any number of values
+    let mut values = &string_call.args[1..];
+    let variadic = match values.last() {
+        Some(LuaExpr::CallExpr(_)) => true,
+        Some(LuaExpr::LiteralExpr(literal)) => {
+            matches!(literal.get_literal(), Some(LuaLiteralToken::Dots(_)))
+        }
+        _ => false,
+    };
+    if variadic {
+        values = &values[..values.len() - 1];
+    }
+
+    for (idx, spec) in format.specs.iter().enumerate() {
+        let spec_text = &format_text[spec.range.clone()];
+        let Some(value) = values.get(idx) else {
+            if !variadic {
+                context.add_diagnostic(
+                    DiagnosticCode::StringFormatMismatch,
+                    spec_range(&format_token, spec.range.clone()),
+                    t!("Missing argument for '%{spec}'", spec = spec_text).to_string(),
+                    None,
+                );
+            }
+            continue;
+        };
+        let Ok(typ) = semantic_model.infer_expr(value.clone()) else {
+            continue;
+        };
+        if !is_spec_compatible(spec, &typ, level) {
+            context.add_diagnostic(
+                DiagnosticCode::StringFormatMismatch,
+                value.get_range(),
+                t!(
+                    "'%{spec}' expects %{expected}, got '%{found}'",
+                    spec = spec_text,
+                    expected = expected_type_name(spec.kind),
+                    found = humanize_lint_type(semantic_model.get_db(), &typ)
+                )
+                .to_string(),
+                None,
+            );
+        }
+    }
+
+    if !variadic {
+        for value in values.iter().skip(format.specs.len()) {
+            context.add_diagnostic(
+                DiagnosticCode::StringFormatMismatch,
+                value.get_range(),
+                t!("Argument is not used by the format string").to_string(),
+                None,
+            );
+        }
+    }
+    Some(())
+}
+
+fn spec_range(token: &LuaStringToken, range: std::ops::Range<usize>) -> rowan::TextRange {
+    token
+        .get_value_range(range)
+        .unwrap_or_else(|| token.get_range())
+}
+
+fn expected_type_name(kind: LuaFormatSpecKind) -> &'static str {
+    match kind {
+        LuaFormatSpecKind::Integer => "integer",
+        LuaFormatSpecKind::Float => "number",
+        LuaFormatSpecKind::Quoted => "string|number|boolean|nil",
+        LuaFormatSpecKind::String | LuaFormatSpecKind::Pointer => "any",
+    }
+}
+
+// only types that certainly fail at runtime are reported, strings convert to numbers
+fn is_spec_compatible(spec: &LuaFormatSpec, typ: &LuaType, level: LuaLanguageLevel) -> bool {
+    match spec.kind {
+        LuaFormatSpecKind::String | LuaFormatSpecKind::Pointer => true,
+        // before Lua 5.3 the integer conversions truncate a float
+        LuaFormatSpecKind::Integer => {
+            is_number_compatible(typ, level.is_version_at_least(LuaLanguageLevel::Lua53))
+        }
+        LuaFormatSpecKind::Float => is_number_compatible(typ, false),
+        LuaFormatSpecKind::Quoted => is_quoted_compatible(typ),
+    }
+}
+
+fn is_number_compatible(typ: &LuaType, integer: bool) -> bool {
+    match typ {
+        LuaType::Union(union) => union
+            .get_types()
+            .iter()
+            .any(|typ| is_number_compatible(typ, integer)),
+        LuaType::FloatConst(value) => !integer || value.fract() == 0.0,
+        LuaType::StringConst(value) | LuaType::DocStringConst(value) => {
+            is_numeric_string(value, integer)
+        }
+        LuaType::Nil => false,
+        typ => !(typ.is_boolean() || typ.is_table() || typ.is_function() || typ.is_thread()),
+    }
+}
+
+fn is_quoted_compatible(typ: &LuaType) -> bool {
+    match typ {
+        LuaType::Union(union) => union.get_types().iter().any(is_quoted_compatible),
+        typ => !(typ.is_table() || typ.is_function() || typ.is_thread() || typ.is_userdata()),
+    }
+}
+
+fn is_numeric_string(value: &str, integer: bool) -> bool {
+    let value = value.trim();
+    if let Some(hex) = value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+    {
+        return !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit());
+    }
+    // `inf` and `nan` are not numerals in Lua
+    if !value.bytes().any(|b| b.is_ascii_digit()) {
+        return false;
+    }
+    match value.parse::<f64>() {
+        Ok(number) => !integer || number.fract() == 0.0,
+        Err(_) => false,
+    }
+}
@@ -20,7 +20,27 @@ impl Checker for SyntaxErrorChecker {
                     LuaParseErrorKind::DocError => DiagnosticCode::DocSyntaxError,
                 };
 
-                context.add_diagnostic(code, parse_error.range, parse_error.message, None);
+                let related = parse_error
+                    .secondary
+                    .into_iter()
+                    .map(|label| (label.range, label.message))
+                    .collect();
+                // the parse error code is stable, clients and `emmylua_check` can match on it
+                let data = serde_json::json!({
+                    "parseErrorCode": parse_error.code.get_name(),
+                    "expected": parse_error
+                        .expected
+                        .iter()
+                        .map(|kind| kind.to_string())
+                        .collect::<Vec<_>>(),
+                });
+                context.add_diagnostic_with_related(
+                    code,
+                    parse_error.range,
+                    parse_error.message,
+                    related,
+                    Some(data),
+                );
             }
         }
 
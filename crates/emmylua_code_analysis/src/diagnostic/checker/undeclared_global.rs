@@ -0,0 +1,95 @@
+use emmylua_parser::{LuaAstNode, LuaBlock, LuaGlobalStat, LuaNameExpr, LuaStat, LuaSyntaxKind};
+
+use crate::{DiagnosticCode, LuaDeclExtra, SemanticModel};
+
+use super::{Checker, DiagnosticContext};
+
+/// Lua 5.5 semantics, once a block declares `global x` every free name after it must be declared,
+/// `global *` makes all free names globals again
+pub struct UndeclaredGlobalChecker;
+
+impl Checker for UndeclaredGlobalChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::UndeclaredGlobal];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let root = semantic_model.get_root().clone();
+        if root.descendants::<LuaGlobalStat>().next().is_none() {
+            return;
+        }
+
+        for name_expr in root.descendants::<LuaNameExpr>() {
+            check_name_expr(context, semantic_model, name_expr);
+        }
+    }
+}
+
+fn check_name_expr(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    name_expr: LuaNameExpr,
+) -> Option<()> {
+    let name_text = name_expr.get_name_text()?;
+    if matches!(name_text.as_str(), "_ENV" | "self") {
+        return Some(());
+    }
+
+    let file_id = semantic_model.get_file_id();
+    let decl_tree = semantic_model
+        .get_db()
+        .get_decl_index()
+        .get_decl_tree(&file_id)?;
+    let position = name_expr.get_position();
+    if let Some(decl) = decl_tree.find_local_decl(&name_text, position) {
+        let declared = match &decl.extra {
+            LuaDeclExtra::Global { kind, .. } => *kind == LuaSyntaxKind::GlobalName.into(),
+            _ => true,
+        };
+        if declared {
+            return Some(());
+        }
+    }
+
+    if !is_in_strict_scope(&name_expr) {
+        return Some(());
+    }
+
+    context.add_diagnostic(
+        DiagnosticCode::UndeclaredGlobal,
+        name_expr.get_range(),
+        t!(
+            "Global variable '%{name}' is not declared",
+            name = name_text
+        )
+        .to_string(),
+        None,
+    );
+
+    Some(())
+}
+
+// a named `global` declaration is visible at the name and no `global *` is
+fn is_in_strict_scope(name_expr: &LuaNameExpr) -> bool {
+    let position = name_expr.get_position();
+    let mut has_named_decl = false;
+    for block in name_expr.ancestors::<LuaBlock>() {
+        for stat in block.get_stats() {
+            let LuaStat::GlobalStat(global_stat) = stat else {
+                continue;
+            };
+            // `global function f` declares `f` before its body
+            let visible_from = match global_stat.get_closure() {
+                Some(closure) => closure.get_position(),
+                None => global_stat.get_range().end(),
+            };
+            if visible_from > position {
+                break;
+            }
+            if global_stat.is_all() {
+                return false;
+            }
+            has_named_decl = true;
+        }
+    }
+
+    has_named_decl
+}
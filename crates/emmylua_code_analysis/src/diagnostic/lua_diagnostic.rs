@@ -1,4 +1,8 @@
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 pub use super::checker::DiagnosticContext;
 use super::{checker::check_file, lua_diagnostic_config::LuaDiagnosticConfig};
@@ -10,6 +14,8 @@ use tokio_util::sync::CancellationToken;
 pub struct LuaDiagnostic {
     enable: bool,
     config: Arc<LuaDiagnosticConfig>,
+    // workspace folders and sub directories with their own diagnostics settings
+    folder_configs: HashMap<PathBuf, (bool, Arc<LuaDiagnosticConfig>)>,
 }
 
 impl LuaDiagnostic {
@@ -17,6 +23,7 @@ impl LuaDiagnostic {
         Self {
             enable: true,
             config: Arc::new(LuaDiagnosticConfig::default()),
+            folder_configs: HashMap::new(),
         }
     }
 
@@ -25,13 +32,35 @@ impl LuaDiagnostic {
         self.config = LuaDiagnosticConfig::new(&emmyrc).into();
     }
 
+    pub fn update_workspace_config(&mut self, root: PathBuf, emmyrc: &Emmyrc) {
+        self.folder_configs.insert(
+            root,
+            (
+                emmyrc.diagnostics.enable,
+                LuaDiagnosticConfig::new(emmyrc).into(),
+            ),
+        );
+    }
+
+    pub fn remove_workspace_config(&mut self, root: &Path) {
+        self.folder_configs
+            .retain(|folder, _| !folder.starts_with(root));
+    }
+
     pub fn diagnose_file(
         &self,
         compilation: &LuaCompilation,
         file_id: FileId,
         cancel_token: CancellationToken,
     ) -> Option<Vec<Diagnostic>> {
-        if !self.enable {
+        let db = compilation.get_db();
+        let (enable, config) = db
+            .get_vfs()
+            .get_folder_config(&file_id)
+            .and_then(|(root, _)| self.folder_configs.get(root))
+            .map(|(enable, config)| (*enable, config.clone()))
+            .unwrap_or((self.enable, self.config.clone()));
+        if !enable {
             return None;
         }
 
@@ -39,9 +68,8 @@ impl LuaDiagnostic {
             return None;
         }
 
-        let db = compilation.get_db();
         let mut semantic_model = compilation.get_semantic_model(file_id)?;
-        let mut context = DiagnosticContext::new(file_id, db, self.config.clone());
+        let mut context = DiagnosticContext::new(file_id, db, config);
 
         check_file(&mut context, &mut semantic_model);
 
@@ -96,6 +96,18 @@ pub enum DiagnosticCode {
     DuplicateSetField,
     /// duplicate-index
     DuplicateIndex,
+    /// generic-constraint-mismatch
+    GenericConstraintMismatch,
+    /// missing-cases
+    MissingCases,
+    /// malformed-pattern
+    MalformedPattern,
+    /// string-format-mismatch
+    StringFormatMismatch,
+    /// embedded-syntax-error
+    EmbeddedSyntaxError,
+    /// undeclared-global
+    UndeclaredGlobal,
 
     #[serde(other)]
     None,
@@ -123,6 +135,9 @@ pub fn get_default_severity(code: DiagnosticCode) -> DiagnosticSeverity {
         DiagnosticCode::DuplicateType => DiagnosticSeverity::WARNING,
         DiagnosticCode::AnnotationUsageError => DiagnosticSeverity::ERROR,
         DiagnosticCode::RedefinedLocal => DiagnosticSeverity::HINT,
+        DiagnosticCode::MalformedPattern => DiagnosticSeverity::ERROR,
+        DiagnosticCode::EmbeddedSyntaxError => DiagnosticSeverity::ERROR,
+        DiagnosticCode::UndeclaredGlobal => DiagnosticSeverity::ERROR,
         _ => DiagnosticSeverity::WARNING,
     }
 }
@@ -136,6 +151,7 @@ pub fn is_code_default_enable(code: &DiagnosticCode) -> bool {
         DiagnosticCode::CodeStyleCheck => false,
         DiagnosticCode::IncompleteSignatureDoc => false,
         DiagnosticCode::MissingGlobalDoc => false,
+        DiagnosticCode::MissingCases => false,
 
         // ... handle other variants
 
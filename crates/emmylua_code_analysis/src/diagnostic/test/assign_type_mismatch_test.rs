@@ -858,4 +858,22 @@ return t
         "#
         ));
     }
+
+    #[test]
+    fn test_number_to_integer() {
+        let mut ws = VirtualWorkspace::new();
+        let code = r#"
+            ---@type number
+            local n = 1.5
+
+            ---@type integer
+            local i = n
+        "#;
+        assert!(!ws.check_code_for(DiagnosticCode::AssignTypeMismatch, code));
+
+        let mut emmyrc = crate::Emmyrc::default();
+        emmyrc.strict.number_to_integer = false;
+        ws.analysis.update_config(emmyrc.into());
+        assert!(ws.check_code_for(DiagnosticCode::AssignTypeMismatch, code));
+    }
 }
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod test {
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_embedded_lua() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::EmbeddedSyntaxError,
+            r#"
+            local f = load("return 1 + 2")
+            local g = load([[
+                local a = 1
+                return a
+            ]])
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::EmbeddedSyntaxError,
+            r#"
+            local f = load("return 1 +")
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::EmbeddedSyntaxError,
+            r#"
+            local f = load("local a = \n(")
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_embedded_json() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::EmbeddedSyntaxError,
+            r#"
+            ---@language json
+            local config = [[{ "a": [1, 2], "b": null }]]
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::EmbeddedSyntaxError,
+            r#"
+            ---@language json
+            local config = [[{ "a": [1, 2 }]]
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::EmbeddedSyntaxError,
+            r#"
+            ---@language json
+            ---@param text string
+            local function decode(text) end
+
+            ---@language json text
+            ---@param text string
+            local function parse(text) end
+
+            parse("{ 'a': 1 }")
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_embedded_param_language() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::EmbeddedSyntaxError,
+            r#"
+            ---@class Db
+            local Db = {}
+
+            ---@language json payload
+            ---@param payload string
+            function Db:send(payload) end
+
+            Db:send("[1,")
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::EmbeddedSyntaxError,
+            r#"
+            ---@class Db2
+            local Db2 = {}
+
+            ---@language json payload
+            ---@param payload string
+            function Db2:send(payload) end
+
+            Db2:send("[1]")
+            Db2.send(Db2, "[2]")
+            "#
+        ));
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod test {
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_func_generic_constraint() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class Animal
+            ---@class Dog: Animal
+
+            ---@generic T: Animal
+            ---@param a T
+            ---@return T
+            function feed(a)
+                return a
+            end
+            "#,
+        );
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::GenericConstraintMismatch,
+            r#"
+            local dog ---@type Dog
+            feed(dog)
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::GenericConstraintMismatch,
+            r#"
+            feed("hello")
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_explicit_type_argument_constraint() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class Animal
+            ---@class Dog: Animal
+
+            ---@class Kennel<T: Animal>
+            "#,
+        );
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::GenericConstraintMismatch,
+            r#"
+            local a ---@type Kennel<Dog>
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::GenericConstraintMismatch,
+            r#"
+            local b ---@type Kennel<string>
+            "#
+        ));
+    }
+}
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod test {
+    use crate::{DiagnosticCode, Emmyrc, EmmyrcLuaVersion, VirtualWorkspace};
+
+    #[test]
+    fn test_malformed_pattern() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::MalformedPattern,
+            r#"
+            local s = "key = value"
+            local k, v = string.match(s, "^(%w+)%s*=%s*(%w+)$")
+            for w in s:gmatch("[%a_][%w_]*") do end
+            local r = s:gsub("(%w+)", "<%1>")
+            local i = string.find(s, "[", 1, true)
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::MalformedPattern,
+            r#"
+            local i = string.find("a", "[a")
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::MalformedPattern,
+            r#"
+            local s = "a"
+            local m = s:match("100%")
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::MalformedPattern,
+            r#"
+            local m = string.match("(a)", "%b(")
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::MalformedPattern,
+            r#"
+            local r = string.gsub("a", "(a)", "%2")
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_replacement_escapes_by_version() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        let code = r#"
+            local r = string.gsub("a.b", "%.", "%-")
+        "#;
+        assert!(!ws.check_code_for(DiagnosticCode::MalformedPattern, code));
+
+        // Lua 5.1 and LuaJIT read `%-` as `-`
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.runtime.version = EmmyrcLuaVersion::Lua51;
+        ws.analysis.update_config(emmyrc.into());
+        assert!(ws.check_code_for(DiagnosticCode::MalformedPattern, code));
+    }
+
+    #[test]
+    fn test_shadowed_string_lib() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::MalformedPattern,
+            r#"
+            local string = { find = function(s, p) end }
+            string.find("a", "[a")
+            "#
+        ));
+    }
+}
@@ -0,0 +1,175 @@
+#[cfg(test)]
+mod test {
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_enum_if_chain() {
+        let mut ws = VirtualWorkspace::new();
+        ws.enable_check(DiagnosticCode::MissingCases);
+        ws.def(
+            r#"
+            ---@enum Color
+            Color = {
+                Red = 1,
+                Green = 2,
+                Blue = 3,
+            }
+            "#,
+        );
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::MissingCases,
+            r#"
+            ---@param c Color
+            local function f(c)
+                if c == Color.Red then
+                elseif c == Color.Green then
+                end
+            end
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::MissingCases,
+            r#"
+            ---@param c Color
+            local function f(c)
+                if c == Color.Red then
+                elseif c == Color.Green or c == Color.Blue then
+                end
+            end
+            "#
+        ));
+
+        // an ordinary `else` handles the remaining cases
+        assert!(ws.check_code_for(
+            DiagnosticCode::MissingCases,
+            r#"
+            ---@param c Color
+            local function f(c)
+                if c == Color.Red then
+                elseif c == Color.Green then
+                else
+                    print(c)
+                end
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_unreachable_else() {
+        let mut ws = VirtualWorkspace::new();
+        ws.enable_check(DiagnosticCode::MissingCases);
+        assert!(!ws.check_code_for(
+            DiagnosticCode::MissingCases,
+            r#"
+            ---@param mode 'r'|'w'|'a'
+            local function f(mode)
+                if mode == 'r' then
+                elseif mode == 'w' then
+                else
+                    error("unreachable")
+                end
+            end
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::MissingCases,
+            r#"
+            ---@param mode 'r'|'w'|'a'
+            local function f(mode)
+                if mode == 'r' then
+                elseif mode == 'w' then
+                elseif mode == 'a' then
+                else
+                    error("unreachable")
+                end
+            end
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::MissingCases,
+            r#"
+            ---@param mode 'r'|'w'|'a'
+            local function f(mode)
+                if mode == 'r' then
+                elseif mode == 'w' then
+                else
+                    print(mode)
+                    error("unreachable: " .. mode)
+                end
+            end
+            "#
+        ));
+
+        // an `else` branch that does not raise is a fallback, not unreachable
+        assert!(ws.check_code_for(
+            DiagnosticCode::MissingCases,
+            r#"
+            ---@param mode 'r'|'w'|'a'
+            local function f(mode)
+                if mode == 'r' then
+                elseif mode == 'w' then
+                else
+                    print(mode)
+                end
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_single_comparison() {
+        let mut ws = VirtualWorkspace::new();
+        ws.enable_check(DiagnosticCode::MissingCases);
+        assert!(ws.check_code_for(
+            DiagnosticCode::MissingCases,
+            r#"
+            ---@param mode 'r'|'w'|'a'
+            local function f(mode)
+                if mode == 'r' then
+                end
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_dispatch_table() {
+        let mut ws = VirtualWorkspace::new();
+        ws.enable_check(DiagnosticCode::MissingCases);
+        ws.def(
+            r#"
+            ---@enum Op
+            Op = {
+                Add = 1,
+                Sub = 2,
+            }
+            "#,
+        );
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::MissingCases,
+            r#"
+            ---@type table<Op, fun(a: number, b: number): number>
+            local handlers = {
+                [Op.Add] = function(a, b) return a + b end,
+            }
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::MissingCases,
+            r#"
+            ---@type table<Op, fun(a: number, b: number): number>
+            local handlers = {
+                [Op.Add] = function(a, b) return a + b end,
+                [Op.Sub] = function(a, b) return a - b end,
+            }
+            "#
+        ));
+    }
+}
@@ -7,8 +7,12 @@ mod disable_line_test;
 mod duplicate_field_test;
 mod duplicate_index_test;
 mod duplicate_require_test;
+mod embedded_syntax_error_test;
+mod generic_constraint_mismatch_test;
 mod incomplete_signature_doc_test;
 mod inject_field_test;
+mod malformed_pattern_test;
+mod missing_cases_test;
 mod missing_fields_test;
 mod missing_parameter_test;
 mod need_check_nil_test;
@@ -16,8 +20,10 @@ mod param_type_check_test;
 mod redefined_local_test;
 mod redundant_parameter_test;
 mod return_type_mismatch_test;
+mod string_format_mismatch_test;
 mod syntax_error_test;
 mod unbalanced_assignments_test;
+mod undeclared_global_test;
 mod undefined_doc_param_test;
 mod undefined_field_test;
 mod undefined_global_test;
@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod test {
+    use crate::{DiagnosticCode, Emmyrc, EmmyrcLuaVersion, VirtualWorkspace};
+
+    fn workspace_for(version: EmmyrcLuaVersion) -> VirtualWorkspace {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.runtime.version = version;
+        ws.analysis.update_config(emmyrc.into());
+        ws
+    }
+
+    #[test]
+    fn test_format_arg_count() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::StringFormatMismatch,
+            r#"
+            local a = string.format("%d%% %5.2f %s %q", 1, 2.5, {}, "x")
+            local b = ("%s=%s"):format("k", "v")
+            local function f(...)
+                return string.format("%s %s %s", 1, ...)
+            end
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::StringFormatMismatch,
+            r#"
+            local a = string.format("%s %s", 1)
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::StringFormatMismatch,
+            r#"
+            local a = string.format("%s", 1, 2)
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::StringFormatMismatch,
+            r#"
+            local a = string.format("%y", 1)
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_format_arg_type() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::StringFormatMismatch,
+            r#"
+            ---@param n integer?
+            local function f(n)
+                return string.format("%d %x %d %.1f", n, 255, "10", "1.5")
+            end
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::StringFormatMismatch,
+            r#"
+            local a = string.format("%d", "x")
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::StringFormatMismatch,
+            r#"
+            local a = string.format("%d", 1.5)
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::StringFormatMismatch,
+            r#"
+            local a = ("%f"):format(true)
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::StringFormatMismatch,
+            r#"
+            local a = string.format("%q", {})
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_format_by_version() {
+        let mut ws = workspace_for(EmmyrcLuaVersion::Lua51);
+        // Lua 5.1 truncates a float for `%d`
+        assert!(ws.check_code_for(
+            DiagnosticCode::StringFormatMismatch,
+            r#"
+            local a = string.format("%d %d", 1.5, "2.5")
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::StringFormatMismatch,
+            r#"
+            local a = string.format("%a", 1.5)
+            "#
+        ));
+
+        let mut ws = workspace_for(EmmyrcLuaVersion::Lua53);
+        assert!(ws.check_code_for(
+            DiagnosticCode::StringFormatMismatch,
+            r#"
+            local a = string.format("%a", 1.5)
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::StringFormatMismatch,
+            r#"
+            local a = string.format("%p", {})
+            "#
+        ));
+
+        let mut ws = workspace_for(EmmyrcLuaVersion::Lua54);
+        assert!(ws.check_code_for(
+            DiagnosticCode::StringFormatMismatch,
+            r#"
+            local a = string.format("%F %p", 1.5, {})
+            "#
+        ));
+    }
+}
@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod test {
+    use crate::{DiagnosticCode, Emmyrc, EmmyrcLuaVersion, VirtualWorkspace};
+
+    fn lua55_workspace() -> VirtualWorkspace {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.runtime.version = EmmyrcLuaVersion::Lua55;
+        ws.analysis.update_config(emmyrc.into());
+        ws
+    }
+
+    #[test]
+    fn test_declared_globals() {
+        let mut ws = lua55_workspace();
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndeclaredGlobal,
+            r#"
+            global print, x
+            local y = 1
+            x = y
+            print(x)
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndeclaredGlobal,
+            r#"
+            global print
+            print(z)
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndeclaredGlobal,
+            r#"
+            global x
+            w = 1
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_global_scope() {
+        let mut ws = lua55_workspace();
+
+        // without a declaration every free name is a global
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndeclaredGlobal,
+            r#"
+            print(a)
+            "#
+        ));
+
+        // the declaration only applies after it and inside its block
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndeclaredGlobal,
+            r#"
+            print(a)
+            do
+                global b
+                b = 1
+            end
+            print(a)
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndeclaredGlobal,
+            r#"
+            global function f()
+                f()
+                return g
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_global_all() {
+        let mut ws = lua55_workspace();
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndeclaredGlobal,
+            r#"
+            global x
+            global *
+            print(y)
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_global_const_reassign() {
+        let mut ws = lua55_workspace();
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::LocalConstReassign,
+            r#"
+            global<const> VERSION = 1
+            VERSION = 2
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::LocalConstReassign,
+            r#"
+            global VERSION = 1
+            VERSION = 2
+            "#
+        ));
+    }
+}
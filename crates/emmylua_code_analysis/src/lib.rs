@@ -13,6 +13,7 @@ mod locale;
 mod profile;
 mod resources;
 mod semantic;
+mod structural_search;
 mod test_lib;
 mod vfs;
 
@@ -26,7 +27,12 @@ use lsp_types::Uri;
 pub use profile::Profile;
 use resources::load_resource_std;
 pub use semantic::*;
-use std::{collections::HashSet, path::PathBuf, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+pub use structural_search::*;
 pub use test_lib::VirtualWorkspace;
 use tokio_util::sync::CancellationToken;
 pub use vfs::*;
@@ -46,6 +52,8 @@ pub struct EmmyLuaAnalysis {
     pub diagnostic: LuaDiagnostic,
     pub emmyrc: Arc<Emmyrc>,
     lib_workspace_counter: u32,
+    // the library roots of workspace folders with their own config, unloaded with the folder
+    folder_libraries: HashMap<PathBuf, Vec<PathBuf>>,
 }
 
 impl EmmyLuaAnalysis {
@@ -56,6 +64,7 @@ impl EmmyLuaAnalysis {
             diagnostic: LuaDiagnostic::new(),
             emmyrc,
             lib_workspace_counter: 2,
+            folder_libraries: HashMap::new(),
         }
     }
 
@@ -107,6 +116,16 @@ impl EmmyLuaAnalysis {
             .add_workspace_root(root, id);
     }
 
+    /// A library of the config of the workspace folder `folder`, it is removed with the folder
+    pub fn add_folder_library(&mut self, folder: PathBuf, root: PathBuf) {
+        let libraries = self.folder_libraries.entry(folder).or_default();
+        if libraries.contains(&root) {
+            return;
+        }
+        libraries.push(root.clone());
+        self.add_library_workspace(root);
+    }
+
     pub fn update_file_by_uri(&mut self, uri: &Uri, text: Option<String>) -> Option<FileId> {
         let is_removed = text.is_none();
         let file_id = self
@@ -183,6 +202,88 @@ impl EmmyLuaAnalysis {
         self.emmyrc.clone()
     }
 
+    /// Give `root` its own config, a workspace folder or a sub directory with a nested config file.
+    /// Must be called before its files are loaded
+    pub fn update_workspace_config(&mut self, root: PathBuf, config: Arc<Emmyrc>) {
+        self.diagnostic
+            .update_workspace_config(root.clone(), &config);
+        self.compilation.update_workspace_config(root, config);
+    }
+
+    /// Unload a workspace folder: its config, module root, files and libraries, returns the uris of
+    /// the removed files
+    pub fn remove_workspace(&mut self, root: &Path) -> Vec<Uri> {
+        self.remove_workspace_config(root);
+        let mut removed = self.remove_workspace_root(root);
+
+        // its libraries, unless the main config or another folder still uses them
+        let libraries = self.folder_libraries.remove(root).unwrap_or_default();
+        for library in libraries {
+            let in_use = self
+                .emmyrc
+                .workspace
+                .library
+                .iter()
+                .any(|lib| Path::new(lib) == library)
+                || self
+                    .folder_libraries
+                    .values()
+                    .any(|libraries| libraries.contains(&library));
+            if !in_use {
+                removed.extend(self.remove_workspace_root(&library));
+            }
+        }
+
+        removed
+    }
+
+    // the module root and the files under it
+    fn remove_workspace_root(&mut self, root: &Path) -> Vec<Uri> {
+        self.compilation
+            .get_db_mut()
+            .get_module_index_mut()
+            .remove_workspace_root(root);
+
+        let file_ids = self.compilation.get_db().get_vfs().get_file_ids_under(root);
+        let mut removed = Vec::new();
+        for file_id in file_ids {
+            let Some(uri) = self.get_uri(file_id) else {
+                continue;
+            };
+            if self.remove_file_by_uri(&uri).is_some() {
+                removed.push(uri);
+            }
+        }
+
+        removed
+    }
+
+    /// Drop the configs of `root` and its sub directories, their files fall back to the outer config
+    pub fn remove_workspace_config(&mut self, root: &Path) {
+        self.diagnostic.remove_workspace_config(root);
+        self.compilation.remove_workspace_config(root);
+    }
+
+    /// Parse and index the files under `root` again with their current content, after its config changed
+    pub fn reload_files_under(&mut self, root: &Path) -> Vec<FileId> {
+        let vfs = self.compilation.get_db().get_vfs();
+        let files = vfs
+            .get_file_ids_under(root)
+            .into_iter()
+            .filter_map(|file_id| {
+                let uri = vfs.get_uri(&file_id)?;
+                let text = vfs.get_file_content(&file_id)?.clone();
+                Some((uri, Some(text)))
+            })
+            .collect();
+        self.update_files_by_uri(files)
+    }
+
+    /// The config that applies to the file, the one of its workspace folder if any
+    pub fn get_file_emmyrc(&self, file_id: FileId) -> Arc<Emmyrc> {
+        self.compilation.get_db().get_file_emmyrc(&file_id)
+    }
+
     pub fn diagnose_file(
         &self,
         file_id: FileId,
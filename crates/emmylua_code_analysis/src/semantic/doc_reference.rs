@@ -0,0 +1,109 @@
+use emmylua_parser::{parse_description_items, LuaDescriptionItemKind};
+
+use crate::{DbIndex, LuaSemanticDeclId, LuaType, LuaTypeDeclId, LuaTypeOwner};
+
+use super::find_members;
+
+/// Resolve `Class`, `Class.member`, `Class:method`, `Class#member` or `global.member` of a
+/// description or `@see`
+pub fn resolve_doc_reference(db: &DbIndex, path: &str) -> Option<LuaSemanticDeclId> {
+    let parts = path.split(['.', ':', '#']).collect::<Vec<_>>();
+    let (mut typ, rest) = match find_type_prefix(db, &parts) {
+        Some((type_decl_id, len)) => {
+            if len == parts.len() {
+                return Some(LuaSemanticDeclId::TypeDecl(type_decl_id));
+            }
+            (LuaType::Ref(type_decl_id), &parts[len..])
+        }
+        None => {
+            let decl_id = db
+                .get_global_index()
+                .resolve_global_decl_id(db, parts.first()?)?;
+            if parts.len() == 1 {
+                return Some(LuaSemanticDeclId::LuaDecl(decl_id));
+            }
+            let typ = db
+                .get_type_index()
+                .get_type_cache(&LuaTypeOwner::Decl(decl_id))?
+                .as_type()
+                .clone();
+            (typ, &parts[1..])
+        }
+    };
+
+    for (idx, name) in rest.iter().enumerate() {
+        let member = find_members(db, &typ)?
+            .into_iter()
+            .find(|member| member.key.get_name() == Some(name))?;
+        if idx + 1 == rest.len() {
+            return member.property_owner_id;
+        }
+        typ = member.typ;
+    }
+
+    None
+}
+
+// the longest leading parts that name a type, `a.b.C.method` may be a method of class `a.b.C`
+fn find_type_prefix(db: &DbIndex, parts: &[&str]) -> Option<(LuaTypeDeclId, usize)> {
+    let type_index = db.get_type_index();
+    (1..=parts.len()).rev().find_map(|len| {
+        let type_decl_id = LuaTypeDeclId::new(&parts[..len].join("."));
+        type_index
+            .get_type_decl(&type_decl_id)
+            .map(|_| (type_decl_id, len))
+    })
+}
+
+/// Render a description as Markdown, `[Class.member]` and `@see` references become links to what
+/// `get_link` gives for their target and fenced code without a language is Lua. `see_label` is
+/// written before the content of an inline `@see`.
+pub fn render_doc_description(
+    db: &DbIndex,
+    text: &str,
+    see_label: &str,
+    get_link: impl Fn(LuaSemanticDeclId) -> Option<String>,
+) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut last = 0;
+    for item in parse_description_items(text) {
+        result.push_str(&text[last..item.range.start]);
+        let item_text = &text[item.range.clone()];
+        match &item.kind {
+            LuaDescriptionItemKind::Reference(path) => {
+                match resolve_doc_reference(db, path).and_then(&get_link) {
+                    Some(link) => result.push_str(&format!("[`{}`]({})", path, link)),
+                    None => result.push_str(item_text),
+                }
+            }
+            LuaDescriptionItemKind::See(path) => {
+                result.push_str(see_label);
+                result.push(' ');
+                result.push_str(&render_doc_see(db, path, &get_link));
+            }
+            LuaDescriptionItemKind::CodeBlock(None) => {
+                result.push_str(&item_text.replacen("```", "```lua", 1));
+            }
+            LuaDescriptionItemKind::CodeSpan | LuaDescriptionItemKind::CodeBlock(_) => {
+                result.push_str(item_text);
+            }
+        }
+        last = item.range.end;
+    }
+    result.push_str(&text[last..]);
+    result
+}
+
+/// Render the content of a `@see` tag as Markdown, the reference before any text is a link
+pub fn render_doc_see(
+    db: &DbIndex,
+    see: &str,
+    get_link: impl Fn(LuaSemanticDeclId) -> Option<String>,
+) -> String {
+    let (path, rest) = see.split_once(' ').unwrap_or((see, ""));
+    match resolve_doc_reference(db, path).and_then(get_link) {
+        Some(link) if rest.is_empty() => format!("[`{}`]({})", path, link),
+        Some(link) => format!("[`{}`]({}) {}", path, link, rest),
+        None => see.to_string(),
+    }
+}
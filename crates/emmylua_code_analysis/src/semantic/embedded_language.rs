@@ -0,0 +1,115 @@
+use emmylua_parser::{
+    LuaAst, LuaAstNode, LuaAstToken, LuaCallExpr, LuaChunk, LuaCommentOwner, LuaDocTag, LuaExpr,
+    LuaStat, LuaStringToken, LuaSyntaxKind,
+};
+
+use crate::{DbIndex, LuaType};
+
+use super::{infer_expr, LuaInferCache};
+
+/// A string literal that holds code of another language
+#[derive(Debug, Clone)]
+pub struct LuaEmbeddedRegion {
+    /// lowercase language id, such as `lua`, `sql` or `json`
+    pub language: String,
+    pub token: LuaStringToken,
+}
+
+pub fn infer_embedded_regions(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    root: &LuaChunk,
+) -> Vec<LuaEmbeddedRegion> {
+    root.syntax()
+        .descendants_with_tokens()
+        .filter_map(|element| element.into_token().and_then(LuaStringToken::cast))
+        .filter_map(|token| {
+            let language = infer_embedded_language(db, cache, &token)?;
+            Some(LuaEmbeddedRegion { language, token })
+        })
+        .collect()
+}
+
+pub fn infer_embedded_language(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    token: &LuaStringToken,
+) -> Option<String> {
+    if let Some(language) = infer_arg_language(db, cache, token) {
+        return Some(language);
+    }
+
+    find_statement_language(token)
+}
+
+// the string is passed to `load` or to a parameter marked with `---@language`
+fn infer_arg_language(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    token: &LuaStringToken,
+) -> Option<String> {
+    let literal = token.syntax().parent()?;
+    let args_list = literal.parent()?;
+    if args_list.kind() != LuaSyntaxKind::CallArgList.into() {
+        return None;
+    }
+    let call_expr = args_list.parent().and_then(LuaCallExpr::cast)?;
+    let arg_idx = call_expr
+        .get_args_list()?
+        .get_args()
+        .position(|arg| arg.syntax() == &literal)?;
+
+    let prefix_expr = call_expr.get_prefix_expr()?;
+    let LuaType::Signature(signature_id) = infer_expr(db, cache, prefix_expr.clone()).ok()? else {
+        return None;
+    };
+    if let LuaExpr::NameExpr(name_expr) = &prefix_expr {
+        if arg_idx == 0
+            && matches!(name_expr.get_name_text()?.as_str(), "load" | "loadstring")
+            && db.get_module_index().is_std(&signature_id.get_file_id())
+        {
+            return Some("lua".to_string());
+        }
+    }
+
+    let signature = db.get_signature_index().get(&signature_id)?;
+    if signature.param_languages.is_empty() {
+        return None;
+    }
+    let param_idx = match (call_expr.is_colon_call(), signature.is_colon_define) {
+        (true, false) => arg_idx + 1,
+        (false, true) => arg_idx.checked_sub(1)?,
+        _ => arg_idx,
+    };
+    let param_name = signature.params.get(param_idx)?;
+    signature.param_languages.get(param_name).cloned()
+}
+
+// `---@language sql` above a statement applies to the strings in it, but not in nested functions
+fn find_statement_language(token: &LuaStringToken) -> Option<String> {
+    for node in token.syntax().parent_ancestors() {
+        let Some(ast) = LuaAst::cast(node) else {
+            continue;
+        };
+        let comments = match ast {
+            LuaAst::LuaClosureExpr(_) => return None,
+            LuaAst::LuaTableField(field) => field.get_comments(),
+            ast => match LuaStat::cast(ast.syntax().clone()) {
+                Some(stat) => stat.get_comments(),
+                None => continue,
+            },
+        };
+        for comment in comments {
+            for tag in comment.get_doc_tags() {
+                if let LuaDocTag::Language(language) = tag {
+                    if language.get_param_name_token().is_none() {
+                        let name = language.get_language_token()?;
+                        return Some(name.get_name_text().to_lowercase());
+                    }
+                }
+            }
+        }
+    }
+
+    None
+}
@@ -5,7 +5,7 @@ use emmylua_parser::{LuaAstNode, LuaCallExpr, LuaExpr};
 use crate::{
     db_index::{DbIndex, LuaType},
     semantic::{infer::InferFailReason, infer_expr, LuaInferCache},
-    LuaFunctionType,
+    GenericTpl, LuaFunctionType, VariadicType,
 };
 
 use super::{
@@ -20,13 +20,30 @@ pub fn instantiate_func_generic(
     func: &LuaFunctionType,
     call_expr: LuaCallExpr,
 ) -> Result<LuaFunctionType, InferFailReason> {
+    let mut substitutor = infer_func_generic_substitutor(db, cache, func, &call_expr)?;
+    fill_unresolved_tpl(func, &mut substitutor);
+
+    if let LuaType::DocFunction(f) = instantiate_doc_function(db, func, &substitutor) {
+        Ok(f.deref().clone())
+    } else {
+        Ok(func.clone())
+    }
+}
+
+/// match the call arguments against the function params, without any fallback
+pub fn infer_func_generic_substitutor(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    func: &LuaFunctionType,
+    call_expr: &LuaCallExpr,
+) -> Result<TypeSubstitutor, InferFailReason> {
     let origin_params = func.get_params();
     let func_param_types: Vec<_> = origin_params
         .iter()
         .map(|(_, t)| t.clone().unwrap_or(LuaType::Unknown))
         .collect();
 
-    let mut arg_types = collect_arg_types(db, cache, &call_expr)?;
+    let mut arg_types = collect_arg_types(db, cache, call_expr)?;
 
     let colon_call = call_expr.is_colon_call();
     let colon_define = func.is_colon_define();
@@ -53,14 +70,10 @@ pub fn instantiate_func_generic(
     )?;
 
     if func.contain_self() {
-        infer_self_type(db, cache, &call_expr, &mut substitutor)?;
+        infer_self_type(db, cache, call_expr, &mut substitutor)?;
     }
 
-    if let LuaType::DocFunction(f) = instantiate_doc_function(db, func, &substitutor) {
-        Ok(f.deref().clone())
-    } else {
-        Ok(func.clone())
-    }
+    Ok(substitutor)
 }
 
 fn collect_arg_types(
@@ -97,3 +110,84 @@ fn infer_self_type(
 
     Ok(())
 }
+
+/// templates that can not be inferred from the arguments fall back to their
+/// declared default, or else to their constraint
+fn fill_unresolved_tpl(func: &LuaFunctionType, substitutor: &mut TypeSubstitutor) {
+    let mut tpls = Vec::new();
+    for (_, param_type) in func.get_params() {
+        if let Some(param_type) = param_type {
+            collect_tpl_refs(param_type, &mut tpls);
+        }
+    }
+    collect_tpl_refs(func.get_ret(), &mut tpls);
+
+    for tpl in tpls {
+        let tpl_id = tpl.get_tpl_id();
+        if !tpl_id.is_func() || substitutor.get(tpl_id).is_some() {
+            continue;
+        }
+
+        if let Some(fallback) = tpl.get_default().or(tpl.get_constraint()) {
+            substitutor.insert_type(tpl_id, fallback.clone());
+        }
+    }
+}
+
+fn collect_tpl_refs<'a>(typ: &'a LuaType, tpls: &mut Vec<&'a GenericTpl>) {
+    match typ {
+        LuaType::TplRef(tpl) => tpls.push(tpl),
+        LuaType::Array(base) => collect_tpl_refs(base, tpls),
+        LuaType::Tuple(tuple) => {
+            for typ in tuple.get_types() {
+                collect_tpl_refs(typ, tpls);
+            }
+        }
+        LuaType::DocFunction(func) => {
+            for (_, param_type) in func.get_params() {
+                if let Some(param_type) = param_type {
+                    collect_tpl_refs(param_type, tpls);
+                }
+            }
+            collect_tpl_refs(func.get_ret(), tpls);
+        }
+        LuaType::Object(object) => {
+            for typ in object.get_fields().values() {
+                collect_tpl_refs(typ, tpls);
+            }
+            for (key, value) in object.get_index_access() {
+                collect_tpl_refs(key, tpls);
+                collect_tpl_refs(value, tpls);
+            }
+        }
+        LuaType::Union(union) => {
+            for typ in union.get_types() {
+                collect_tpl_refs(typ, tpls);
+            }
+        }
+        LuaType::Intersection(intersection) => {
+            for typ in intersection.get_types() {
+                collect_tpl_refs(typ, tpls);
+            }
+        }
+        LuaType::Generic(generic) => {
+            for typ in generic.get_params() {
+                collect_tpl_refs(typ, tpls);
+            }
+        }
+        LuaType::TableGeneric(params) => {
+            for typ in params.iter() {
+                collect_tpl_refs(typ, tpls);
+            }
+        }
+        // variadic templates are expanded from the arguments, never from a fallback
+        LuaType::Variadic(variadic) => {
+            if let VariadicType::Multi(types) = variadic.deref() {
+                for typ in types {
+                    collect_tpl_refs(typ, tpls);
+                }
+            }
+        }
+        _ => {}
+    }
+}
@@ -269,6 +269,13 @@ fn instantiate_tpl_ref(_: &DbIndex, tpl: &GenericTpl, substitutor: &TypeSubstitu
         }
     }
 
+    // missing class type arguments use the declared default, `Map<K, V = any>`
+    if tpl.get_tpl_id().is_type() {
+        if let Some(default) = tpl.get_default() {
+            return default.clone();
+        }
+    }
+
     LuaType::TplRef(tpl.clone().into())
 }
 
@@ -5,8 +5,8 @@ mod test;
 mod tpl_pattern;
 mod type_substitutor;
 
-pub use instantiate_func_generic::instantiate_func_generic;
+pub use instantiate_func_generic::{infer_func_generic_substitutor, instantiate_func_generic};
 pub use instantiate_type_generic::instantiate_doc_function;
 pub use instantiate_type_generic::instantiate_type_generic;
 pub use tpl_pattern::tpl_pattern_match_args;
-pub use type_substitutor::TypeSubstitutor;
+pub use type_substitutor::{SubstitutorValue, TypeSubstitutor};
@@ -93,4 +93,53 @@ mod test {
         assert_eq!(b, expected_b);
         assert_eq!(c, expected_c);
     }
+
+    #[test]
+    fn test_generic_fallback_to_constraint() {
+        let mut ws = crate::VirtualWorkspace::new();
+        ws.def(
+            r#"
+        ---@class Animal
+
+        ---@generic T: Animal
+        ---@param a? T
+        ---@return T
+        function make(a)
+        end
+
+        ---@generic T: Animal = string
+        ---@param a? T
+        ---@return T
+        function make_default(a)
+        end
+
+        r1 = make()
+        r2 = make_default()
+        "#,
+        );
+
+        assert_eq!(ws.expr_ty("r1"), ws.ty("Animal"));
+        assert_eq!(ws.expr_ty("r2"), ws.ty("string"));
+    }
+
+    #[test]
+    fn test_class_default_type_argument() {
+        let mut ws = crate::VirtualWorkspace::new();
+        ws.def(
+            r#"
+        ---@class Pair<K, V = integer>
+        ---@field key K
+        ---@field value V
+
+        ---@type Pair<string>
+        local p
+
+        a = p.key
+        b = p.value
+        "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), ws.ty("string"));
+        assert_eq!(ws.expr_ty("b"), ws.ty("integer"));
+    }
 }
@@ -26,7 +26,19 @@ pub fn infer_binary_expr(
         if let Some(ty) = special_or_rule(db, &left_type, &right_type, left, right) {
             return Ok(ty);
         }
-    } else if !matches!(op, BinaryOperator::OpAnd | BinaryOperator::OpOr) {
+    }
+
+    infer_binary_op_type(db, left_type, right_type, op)
+}
+
+// also used for `a += b`, where there is no binary expr to infer
+pub fn infer_binary_op_type(
+    db: &DbIndex,
+    left_type: LuaType,
+    right_type: LuaType,
+    op: BinaryOperator,
+) -> InferResult {
+    if !matches!(op, BinaryOperator::OpAnd | BinaryOperator::OpOr) {
         if let Some(ty) = infer_union_binary_expr(db, op, &left_type, &right_type) {
             return Ok(ty);
         }
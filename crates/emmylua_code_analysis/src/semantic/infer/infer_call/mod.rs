@@ -592,6 +592,7 @@ fn is_last_call_expr(call_expr: &LuaCallExpr) -> bool {
     while let Some(parent) = &opt_parent {
         match parent.kind().into() {
             LuaSyntaxKind::AssignStat
+            | LuaSyntaxKind::CompoundAssignStat
             | LuaSyntaxKind::LocalStat
             | LuaSyntaxKind::ReturnStat
             | LuaSyntaxKind::TableArrayExpr
@@ -72,10 +72,6 @@ fn infer_member_type_pass_flow(
 ) -> InferResult {
     let mut allow_reassign = true;
     match &prefix_type {
-        // TODO: flow analysis should not generate corresponding `flow_chain` if the prefix type is an array
-        LuaType::Array(_) => {
-            return Ok(member_type.clone());
-        }
         LuaType::Ref(decl_id) => {
             if let Some(members) = db
                 .get_member_index()
@@ -105,18 +101,23 @@ fn infer_member_type_pass_flow(
         .get_flow_chain(cache.get_file_id(), var_ref_id);
     if let Some(flow_chain) = flow_chain {
         let root = index_expr.get_root();
+        let origin_type = member_type.clone();
         for type_assert in flow_chain.get_type_asserts(index_expr.get_position(), flow_id) {
-            let new_type = type_assert
-                .tighten_type(db, cache, &root, member_type.clone())
-                .unwrap_or(LuaType::Unknown);
             if type_assert.is_reassign() && !allow_reassign {
-                // 允许仅去除 nil
-                if member_type.is_nullable() && !new_type.is_nullable() {
+                // 重新赋值会使之前的收窄失效, 但仅允许去除声明类型中的 nil
+                let new_type = type_assert
+                    .tighten_type(db, cache, &root, origin_type.clone())
+                    .unwrap_or(LuaType::Unknown);
+                if origin_type.is_nullable() && !new_type.is_nullable() {
                     member_type = new_type;
+                } else {
+                    member_type = origin_type.clone();
                 }
                 continue;
             }
-            member_type = new_type;
+            member_type = type_assert
+                .tighten_type(db, cache, &root, member_type.clone())
+                .unwrap_or(LuaType::Unknown);
         }
     }
 
@@ -167,7 +168,7 @@ fn infer_array_member(
     index_expr: LuaIndexMemberExpr,
 ) -> Result<LuaType, InferFailReason> {
     let key = index_expr.get_index_key().ok_or(InferFailReason::None)?;
-    let expression_type = if db.get_emmyrc().strict.array_index {
+    let expression_type = if db.get_file_emmyrc(&cache.get_file_id()).strict.array_index {
         TypeOps::Union.apply(db, array_type, &LuaType::Nil)
     } else {
         array_type.clone()
@@ -788,7 +789,7 @@ fn infer_member_by_index_array(
     index_expr: LuaIndexMemberExpr,
 ) -> InferResult {
     let member_key = index_expr.get_index_key().ok_or(InferFailReason::None)?;
-    let expression_type = if db.get_emmyrc().strict.array_index {
+    let expression_type = if db.get_file_emmyrc(&cache.get_file_id()).strict.array_index {
         TypeOps::Union.apply(db, base, &LuaType::Nil)
     } else {
         base.clone()
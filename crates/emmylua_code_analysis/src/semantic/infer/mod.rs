@@ -10,16 +10,17 @@ mod test;
 use std::ops::Deref;
 
 use emmylua_parser::{
-    LuaAst, LuaAstNode, LuaClosureExpr, LuaExpr, LuaLiteralExpr, LuaLiteralToken, LuaTableExpr,
-    LuaVarExpr,
+    LuaAst, LuaAstNode, LuaClosureExpr, LuaExpr, LuaIfExpr, LuaLiteralExpr, LuaLiteralToken,
+    LuaTableExpr, LuaVarExpr,
 };
 use infer_binary::infer_binary_expr;
+pub use infer_binary::infer_binary_op_type;
 use infer_call::infer_call_expr;
 pub use infer_call::infer_call_expr_func;
 pub use infer_fail_reason::InferFailReason;
 use infer_index::infer_index_expr;
 use infer_name::infer_name_expr;
-pub use infer_name::{find_self_decl_or_member_id, infer_param};
+pub use infer_name::{find_self_decl_or_member_id, infer_global_type, infer_param};
 use infer_table::infer_table_expr;
 pub use infer_table::{infer_table_field_value_should_be, infer_table_should_be};
 use infer_unary::infer_unary_expr;
@@ -29,7 +30,7 @@ use smol_str::SmolStr;
 
 use crate::{
     db_index::{DbIndex, LuaOperator, LuaOperatorMetaMethod, LuaSignatureId, LuaType},
-    InFiled, VariadicType,
+    InFiled, TypeOps, VariadicType,
 };
 
 use super::{member::find_members, CacheEntry, CacheKey, LuaInferCache};
@@ -77,6 +78,14 @@ pub fn infer_expr(db: &DbIndex, cache: &mut LuaInferCache, expr: LuaExpr) -> Inf
         ),
         LuaExpr::NameExpr(name_expr) => infer_name_expr(db, cache, name_expr),
         LuaExpr::IndexExpr(index_expr) => infer_index_expr(db, cache, index_expr),
+        // the cast type is bound to the expr syntax id, only reach here when it is missing
+        LuaExpr::CastExpr(cast_expr) => infer_expr(
+            db,
+            cache,
+            cast_expr.get_expr().ok_or(InferFailReason::None)?,
+        ),
+        LuaExpr::IfExpr(if_expr) => infer_if_expr(db, cache, if_expr),
+        LuaExpr::InterpStringExpr(_) => Ok(LuaType::String),
     };
 
     match &result_type {
@@ -101,6 +110,16 @@ pub fn infer_expr(db: &DbIndex, cache: &mut LuaInferCache, expr: LuaExpr) -> Inf
     result_type
 }
 
+fn infer_if_expr(db: &DbIndex, cache: &mut LuaInferCache, if_expr: LuaIfExpr) -> InferResult {
+    let mut result_type = LuaType::Unknown;
+    for branch_expr in if_expr.get_branch_exprs() {
+        let branch_type = infer_expr(db, cache, branch_expr)?;
+        result_type = TypeOps::Union.apply(db, &result_type, &branch_type);
+    }
+
+    Ok(result_type)
+}
+
 fn infer_literal_expr(db: &DbIndex, config: &LuaInferCache, expr: LuaLiteralExpr) -> InferResult {
     match expr.get_literal().ok_or(InferFailReason::None)? {
         LuaLiteralToken::Nil(_) => Ok(LuaType::Nil),
@@ -1,10 +1,13 @@
 mod cache;
+mod doc_reference;
+mod embedded_language;
 mod generic;
 mod infer;
 mod member;
 mod overload_resolve;
 mod reference;
 mod semantic_info;
+mod string_lib_call;
 mod type_check;
 mod visibility;
 
@@ -14,23 +17,24 @@ use std::{collections::HashSet, sync::Arc};
 
 pub use cache::{CacheEntry, CacheKey, CacheOptions, LuaAnalysisPhase, LuaInferCache};
 use emmylua_parser::{
-    LuaCallExpr, LuaChunk, LuaExpr, LuaIndexKey, LuaParseError, LuaSyntaxNode, LuaSyntaxToken,
-    LuaTableExpr,
+    LuaAstNode, LuaAstToken, LuaCallExpr, LuaChunk, LuaEmbeddedChunk, LuaExpr, LuaIndexKey,
+    LuaParseError, LuaStringToken, LuaSyntaxNode, LuaSyntaxToken, LuaTableExpr,
 };
 use infer::{infer_left_value_type_from_right_value, infer_multi_value_adjusted_expression_types};
 pub use infer::{infer_table_field_value_should_be, infer_table_should_be};
 use lsp_types::Uri;
-use member::find_members;
+pub(crate) use member::find_members;
 pub use member::get_member_map;
 pub use member::LuaMemberInfo;
 use reference::is_reference_to;
-use rowan::{NodeOrToken, TextRange};
+use rowan::{NodeCache, NodeOrToken, TextRange};
 pub(crate) use semantic_info::infer_node_semantic_decl;
 pub use semantic_info::SemanticInfo;
 use semantic_info::{
     infer_node_semantic_info, infer_token_semantic_decl, infer_token_semantic_info,
 };
 pub(crate) use type_check::check_type_compact;
+use type_check::check_type_compact_in_file;
 use type_check::is_sub_type_of;
 use visibility::check_visibility;
 
@@ -40,12 +44,18 @@ use crate::{
     FileId,
 };
 use crate::{LuaFunctionType, LuaMemberKey, LuaTypeOwner};
+pub use doc_reference::{render_doc_description, render_doc_see, resolve_doc_reference};
+use embedded_language::infer_embedded_language;
+pub(crate) use embedded_language::infer_embedded_regions;
+pub use embedded_language::LuaEmbeddedRegion;
 pub use generic::*;
 pub use infer::infer_param;
 pub use infer::InferFailReason;
-pub(crate) use infer::{infer_call_expr_func, infer_expr};
+pub(crate) use infer::{infer_binary_op_type, infer_call_expr_func, infer_expr, infer_global_type};
 use overload_resolve::resolve_signature;
 pub use semantic_info::SemanticDeclLevel;
+use string_lib_call::infer_string_lib_call;
+pub use string_lib_call::{LuaStringLibCall, LuaStringLibFunc};
 pub use type_check::{TypeCheckFailReason, TypeCheckResult};
 
 #[derive(Debug)]
@@ -123,7 +133,7 @@ impl<'a> SemanticModel<'a> {
     }
 
     pub fn type_check(&self, source: &LuaType, compact_type: &LuaType) -> TypeCheckResult {
-        check_type_compact(self.db, source, compact_type)
+        check_type_compact_in_file(self.db, self.file_id, source, compact_type)
     }
 
     pub fn infer_call_expr_func(
@@ -149,6 +159,21 @@ impl<'a> SemanticModel<'a> {
         .ok()
     }
 
+    /// infer the generic arguments of `func` from the arguments of `call_expr`
+    pub fn infer_call_generic_substitutor(
+        &self,
+        call_expr: LuaCallExpr,
+        func: &LuaFunctionType,
+    ) -> Option<TypeSubstitutor> {
+        infer_func_generic_substitutor(
+            self.db,
+            &mut self.infer_cache.borrow_mut(),
+            func,
+            &call_expr,
+        )
+        .ok()
+    }
+
     /// 获取赋值时所有右值类型或调用时所有参数类型或返回时所有返回值类型
     pub fn infer_multi_value_adjusted_expression_types(
         &self,
@@ -163,6 +188,40 @@ impl<'a> SemanticModel<'a> {
         )
     }
 
+    /// `string.find`, `match`, `gmatch`, `gsub` or `format` of the standard library
+    pub fn get_string_lib_call(&self, call_expr: &LuaCallExpr) -> Option<LuaStringLibCall> {
+        infer_string_lib_call(self.db, &mut self.infer_cache.borrow_mut(), call_expr)
+    }
+
+    /// The strings of the file that hold code of another language, found when the file was
+    /// analyzed
+    pub fn get_embedded_regions(&self) -> Vec<LuaEmbeddedRegion> {
+        let Some(regions) = self.db.get_embedded_index().get_regions(&self.file_id) else {
+            return Vec::new();
+        };
+        regions
+            .iter()
+            .filter_map(|(range, language)| {
+                let token = self.root.syntax().covering_element(*range).into_token()?;
+                Some(LuaEmbeddedRegion {
+                    language: language.clone(),
+                    token: LuaStringToken::cast(token)?,
+                })
+            })
+            .collect()
+    }
+
+    /// The language of the code in a string, from `---@language` or a call of `load`
+    pub fn get_embedded_language(&self, token: &LuaStringToken) -> Option<String> {
+        infer_embedded_language(self.db, &mut self.infer_cache.borrow_mut(), token)
+    }
+
+    /// Parse Lua code in a string with the parser settings of the workspace, for its syntax only
+    pub fn parse_embedded_lua(&self, token: &LuaStringToken) -> LuaEmbeddedChunk {
+        let mut node_cache = NodeCache::default();
+        LuaEmbeddedChunk::parse(token, self.emmyrc.get_parse_config(&mut node_cache))
+    }
+
     /// 从右值推断左值已绑定的类型
     pub fn infer_left_value_type_from_right_value(&self, expr: LuaExpr) -> Option<LuaType> {
         infer_left_value_type_from_right_value(self.db, &mut self.infer_cache.borrow_mut(), expr)
@@ -7,14 +7,14 @@ use crate::{
     TypeOps,
 };
 use emmylua_parser::{
-    LuaAstNode, LuaAstToken, LuaDocNameType, LuaDocTag, LuaExpr, LuaLocalName, LuaSyntaxKind,
-    LuaSyntaxNode, LuaSyntaxToken, LuaTableField,
+    LuaAstNode, LuaAstToken, LuaDocNameType, LuaDocTag, LuaExpr, LuaGlobalName, LuaLocalName,
+    LuaSyntaxKind, LuaSyntaxNode, LuaSyntaxToken, LuaTableField,
 };
 use infer_expr_semantic_decl::infer_expr_semantic_decl;
 pub use semantic_decl_level::SemanticDeclLevel;
 use semantic_guard::SemanticDeclGuard;
 
-use super::{infer_expr, LuaInferCache};
+use super::{infer_expr, infer_global_type, LuaInferCache};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct SemanticInfo {
@@ -41,6 +41,14 @@ pub fn infer_token_semantic_info(
                 semantic_decl: Some(LuaSemanticDeclId::LuaDecl(decl_id)),
             })
         }
+        LuaSyntaxKind::GlobalName => {
+            let file_id = cache.get_file_id();
+            let decl_id = LuaDeclId::new(file_id, token.text_range().start());
+            Some(SemanticInfo {
+                typ: infer_global_type(db, token.text()).unwrap_or(LuaType::Unknown),
+                semantic_decl: Some(LuaSemanticDeclId::LuaDecl(decl_id)),
+            })
+        }
         LuaSyntaxKind::ParamName => {
             let file_id = cache.get_file_id();
             let decl_id = LuaDeclId::new(file_id, token.text_range().start());
@@ -163,6 +171,7 @@ pub fn infer_token_semantic_decl(
         LuaSyntaxKind::ForStat
         | LuaSyntaxKind::ForRangeStat
         | LuaSyntaxKind::LocalName
+        | LuaSyntaxKind::GlobalName
         | LuaSyntaxKind::ParamName => {
             let file_id = cache.get_file_id();
             let decl_id = LuaDeclId::new(file_id, token.text_range().start());
@@ -220,6 +229,11 @@ pub fn infer_node_semantic_decl(
             let name_token = local_name.get_name_token()?;
             infer_token_semantic_decl(db, cache, name_token.syntax().clone(), level)
         }
+        global_name if LuaGlobalName::can_cast(global_name.kind().into()) => {
+            let global_name = LuaGlobalName::cast(global_name)?;
+            let name_token = global_name.get_name_token()?;
+            infer_token_semantic_decl(db, cache, name_token.syntax().clone(), level)
+        }
         _ => None,
     }
 }
@@ -0,0 +1,103 @@
+use emmylua_parser::{LuaCallExpr, LuaExpr, LuaLiteralToken, LuaStringToken};
+
+use crate::{DbIndex, LuaType};
+
+use super::{infer_expr, LuaInferCache};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LuaStringLibFunc {
+    Find,
+    Match,
+    GMatch,
+    GSub,
+    Format,
+}
+
+impl LuaStringLibFunc {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "find" => Some(LuaStringLibFunc::Find),
+            "match" => Some(LuaStringLibFunc::Match),
+            "gmatch" => Some(LuaStringLibFunc::GMatch),
+            "gsub" => Some(LuaStringLibFunc::GSub),
+            "format" => Some(LuaStringLibFunc::Format),
+            _ => None,
+        }
+    }
+
+    pub fn takes_pattern(&self) -> bool {
+        !matches!(self, LuaStringLibFunc::Format)
+    }
+}
+
+/// A call of a `string` library function that takes a Lua pattern or a format string
+#[derive(Debug, Clone)]
+pub struct LuaStringLibCall {
+    pub func: LuaStringLibFunc,
+    /// the arguments as for `string.find(s, pattern)`, `s:find(pattern)` gets `s` as the first
+    pub args: Vec<LuaExpr>,
+}
+
+impl LuaStringLibCall {
+    /// The pattern argument of `find`, `match`, `gmatch` and `gsub`
+    pub fn get_pattern_arg(&self) -> Option<&LuaExpr> {
+        if self.func.takes_pattern() {
+            self.args.get(1)
+        } else {
+            None
+        }
+    }
+
+    /// The string literal passed as argument `idx`
+    pub fn get_string_arg(&self, idx: usize) -> Option<LuaStringToken> {
+        get_string_literal(self.args.get(idx)?)
+    }
+
+    /// `string.find(s, pattern, init, true)` matches plain text
+    pub fn is_plain_find(&self) -> bool {
+        self.func == LuaStringLibFunc::Find
+            && matches!(
+                self.args.get(3),
+                Some(LuaExpr::LiteralExpr(literal))
+                    if matches!(literal.get_literal(), Some(LuaLiteralToken::Bool(b)) if b.is_true())
+            )
+    }
+}
+
+pub fn infer_string_lib_call(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    call_expr: &LuaCallExpr,
+) -> Option<LuaStringLibCall> {
+    let LuaExpr::IndexExpr(index_expr) = call_expr.get_prefix_expr()? else {
+        return None;
+    };
+    let func = LuaStringLibFunc::from_name(index_expr.get_name_token()?.get_name_text())?;
+    let LuaType::Signature(signature_id) =
+        infer_expr(db, cache, LuaExpr::IndexExpr(index_expr.clone())).ok()?
+    else {
+        return None;
+    };
+    if !db.get_module_index().is_std(&signature_id.get_file_id()) {
+        return None;
+    }
+
+    let mut args = Vec::new();
+    if call_expr.is_colon_call() {
+        args.push(index_expr.get_prefix_expr()?);
+    }
+    args.extend(call_expr.get_args_list()?.get_args());
+    Some(LuaStringLibCall { func, args })
+}
+
+// `("%d"):format(1)` has its literal in parentheses
+fn get_string_literal(expr: &LuaExpr) -> Option<LuaStringToken> {
+    match expr {
+        LuaExpr::LiteralExpr(literal) => match literal.get_literal()? {
+            LuaLiteralToken::String(token) => Some(token),
+            _ => None,
+        },
+        LuaExpr::ParenExpr(paren) => get_string_literal(&paren.get_expr()?),
+        _ => None,
+    }
+}
@@ -1,7 +1,9 @@
+use emmylua_parser::VarianceKind;
+
 use crate::{DbIndex, LuaGenericType, LuaType, TypeSubstitutor};
 
 use super::{
-    check_general_type_compact, type_check_fail_reason::TypeCheckFailReason,
+    check_general_type_compact, is_sub_type_of, type_check_fail_reason::TypeCheckFailReason,
     type_check_guard::TypeCheckGuard, TypeCheckResult,
 };
 
@@ -69,15 +71,59 @@ fn check_generic_type_compact_generic(
         return Err(TypeCheckFailReason::TypeNotMatch);
     }
 
+    let generic_params = db.get_type_index().get_generic_params(&source_base_id);
     for i in 0..source_params.len() {
         let source_param = &source_params[i];
         let compact_param = &compact_params[i];
-        if check_general_type_compact(db, source_param, compact_param, check_guard.next_level()?)
-            .is_err()
-        {
+        let variance = generic_params
+            .and_then(|params| params.get(i))
+            .map(|param| param.variance)
+            .unwrap_or(VarianceKind::None);
+        check_generic_param_variance(db, source_param, compact_param, variance, check_guard)?;
+    }
+
+    Ok(())
+}
+
+fn check_generic_param_variance(
+    db: &DbIndex,
+    source_param: &LuaType,
+    compact_param: &LuaType,
+    variance: VarianceKind,
+    check_guard: TypeCheckGuard,
+) -> TypeCheckResult {
+    let covariant = || check_strict_param(db, source_param, compact_param, check_guard);
+    let contravariant = || check_strict_param(db, compact_param, source_param, check_guard);
+
+    let result = match variance {
+        // unannotated parameters keep the lenient covariant check
+        VarianceKind::None => {
+            check_general_type_compact(db, source_param, compact_param, check_guard.next_level()?)
+        }
+        VarianceKind::Out => covariant(),
+        VarianceKind::In => contravariant(),
+        VarianceKind::InOut => covariant().and_then(|_| contravariant()),
+    };
+
+    result.map_err(|_| TypeCheckFailReason::TypeNotMatch)
+}
+
+/// like `check_general_type_compact`, but a superclass is not implicitly accepted as its subclass
+fn check_strict_param(
+    db: &DbIndex,
+    source_param: &LuaType,
+    compact_param: &LuaType,
+    check_guard: TypeCheckGuard,
+) -> TypeCheckResult {
+    if let (
+        LuaType::Ref(source_id) | LuaType::Def(source_id),
+        LuaType::Ref(compact_id) | LuaType::Def(compact_id),
+    ) = (source_param, compact_param)
+    {
+        if source_id != compact_id && is_sub_type_of(db, source_id, compact_id) {
             return Err(TypeCheckFailReason::TypeNotMatch);
         }
     }
 
-    Ok(())
+    check_general_type_compact(db, source_param, compact_param, check_guard.next_level()?)
 }
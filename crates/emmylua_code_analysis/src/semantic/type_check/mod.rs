@@ -16,7 +16,10 @@ use simple_type::check_simple_type_compact;
 pub use type_check_fail_reason::TypeCheckFailReason;
 use type_check_guard::TypeCheckGuard;
 
-use crate::db_index::{DbIndex, LuaType};
+use crate::{
+    db_index::{DbIndex, LuaType},
+    FileId,
+};
 pub use sub_type::is_sub_type_of;
 pub type TypeCheckResult = Result<(), TypeCheckFailReason>;
 
@@ -25,7 +28,17 @@ pub fn check_type_compact(
     source: &LuaType,
     compact_type: &LuaType,
 ) -> TypeCheckResult {
-    check_general_type_compact(db, source, compact_type, TypeCheckGuard::new())
+    check_general_type_compact(db, source, compact_type, TypeCheckGuard::new(None))
+}
+
+/// Like `check_type_compact`, with the config of the workspace folder of `file_id`
+pub fn check_type_compact_in_file(
+    db: &DbIndex,
+    file_id: FileId,
+    source: &LuaType,
+    compact_type: &LuaType,
+) -> TypeCheckResult {
+    check_general_type_compact(db, source, compact_type, TypeCheckGuard::new(Some(file_id)))
 }
 
 fn check_general_type_compact(
@@ -89,6 +89,9 @@ pub fn check_simple_type_compact(
             LuaType::Integer | LuaType::IntegerConst(_) | LuaType::DocIntegerConst(_) => {
                 return Ok(());
             }
+            LuaType::Number if !number_to_integer_strict(db, check_guard) => {
+                return Ok(());
+            }
             LuaType::Ref(_) => {
                 if check_base_type_for_ref_compact(db, source, compact_type, check_guard).is_ok() {
                     return Ok(());
@@ -323,3 +326,10 @@ fn check_variadic_type_compact(
 
     Ok(())
 }
+
+fn number_to_integer_strict(db: &DbIndex, check_guard: TypeCheckGuard) -> bool {
+    match check_guard.get_file_id() {
+        Some(file_id) => db.get_file_emmyrc(&file_id).strict.number_to_integer,
+        None => db.get_emmyrc().strict.number_to_integer,
+    }
+}
@@ -150,4 +150,39 @@ mod test {
         let ty2 = ws.expr_ty("(\"hello\"):match(\".*\")");
         assert!(ws.check_type(&ty, &ty2));
     }
+
+    #[test]
+    fn test_generic_variance() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class Base
+            ---@class Sub: Base
+
+            ---@class List<T>
+            ---@class ReadOnlyList<out T>
+            ---@class Consumer<in T>
+            ---@class Cell<in out T>
+            "#,
+        );
+
+        let list_base = ws.ty("List<Base>");
+        let list_sub = ws.ty("List<Sub>");
+        assert!(ws.check_type(&list_base, &list_sub));
+
+        let read_only_base = ws.ty("ReadOnlyList<Base>");
+        let read_only_sub = ws.ty("ReadOnlyList<Sub>");
+        assert!(ws.check_type(&read_only_base, &read_only_sub));
+        assert!(!ws.check_type(&read_only_sub, &read_only_base));
+
+        let consumer_base = ws.ty("Consumer<Base>");
+        let consumer_sub = ws.ty("Consumer<Sub>");
+        assert!(ws.check_type(&consumer_sub, &consumer_base));
+        assert!(!ws.check_type(&consumer_base, &consumer_sub));
+
+        let cell_base = ws.ty("Cell<Base>");
+        let cell_sub = ws.ty("Cell<Sub>");
+        assert!(ws.check_type(&cell_base, &cell_base));
+        assert!(!ws.check_type(&cell_base, &cell_sub));
+    }
 }
@@ -0,0 +1,247 @@
+use std::collections::HashMap;
+
+use emmylua_parser::{
+    LuaAstNode, LuaExpr, LuaKind, LuaStat, LuaSyntaxElement, LuaSyntaxKind, LuaSyntaxNode,
+    LuaTokenKind,
+};
+use rowan::{NodeOrToken, TextRange};
+
+use super::pattern::{single_placeholder_name, variadic_placeholder_name};
+
+/// What a placeholder stands for in one match
+#[derive(Debug, Clone)]
+pub struct MatchBinding {
+    /// `None` for a variadic placeholder that matched nothing
+    pub range: Option<TextRange>,
+    /// the expression bound to a single placeholder, used for type constraints
+    pub node: Option<LuaSyntaxNode>,
+    // tokens without trivia, a placeholder used twice must match the same code
+    key: String,
+}
+
+pub type MatchBindings = HashMap<String, MatchBinding>;
+
+pub fn match_node(pattern: &LuaSyntaxNode, target: &LuaSyntaxNode) -> Option<MatchBindings> {
+    let mut bindings = MatchBindings::new();
+    let pattern_root = LuaSyntaxElement::Node(pattern.clone());
+    let target = LuaSyntaxElement::Node(target.clone());
+    match_element(&pattern_root, &target, &mut bindings).then_some(bindings)
+}
+
+/// `$x` as the whole pattern, matches any expression
+pub fn is_placeholder_expr(node: &LuaSyntaxNode) -> bool {
+    node.kind() == LuaSyntaxKind::NameExpr.into()
+        && single_placeholder_name(&node.text().to_string()).is_some()
+}
+
+pub fn is_same_kind(a: LuaKind, b: LuaKind) -> bool {
+    normalize_kind(a) == normalize_kind(b)
+}
+
+fn match_element(
+    pattern: &LuaSyntaxElement,
+    target: &LuaSyntaxElement,
+    bindings: &mut MatchBindings,
+) -> bool {
+    match (pattern, target) {
+        (NodeOrToken::Node(pattern), _) if is_placeholder_expr(pattern) => {
+            let text = pattern.text().to_string();
+            let (Some(name), NodeOrToken::Node(target)) = (single_placeholder_name(&text), target)
+            else {
+                return false;
+            };
+            LuaExpr::can_cast(target.kind().into())
+                && bind(
+                    bindings,
+                    name,
+                    MatchBinding {
+                        range: Some(target.text_range()),
+                        node: Some(target.clone()),
+                        key: element_key(&[NodeOrToken::Node(target.clone())]),
+                    },
+                )
+        }
+        (NodeOrToken::Node(pattern), NodeOrToken::Node(target)) => {
+            match_children(pattern, target, bindings)
+        }
+        (NodeOrToken::Token(pattern), NodeOrToken::Token(target)) => {
+            if pattern.kind() == LuaTokenKind::TkName.into() {
+                if let Some(name) = single_placeholder_name(pattern.text()) {
+                    return target.kind() == LuaTokenKind::TkName.into()
+                        && bind(
+                            bindings,
+                            name,
+                            MatchBinding {
+                                range: Some(target.text_range()),
+                                node: None,
+                                key: target.text().to_string(),
+                            },
+                        );
+                }
+            }
+            pattern.kind() == target.kind() && pattern.text() == target.text()
+        }
+        _ => false,
+    }
+}
+
+fn match_children(
+    pattern: &LuaSyntaxNode,
+    target: &LuaSyntaxNode,
+    bindings: &mut MatchBindings,
+) -> bool {
+    if normalize_kind(pattern.kind()) != normalize_kind(target.kind()) {
+        return false;
+    }
+    let pattern_children = significant_children(pattern);
+    let target_children = significant_children(target);
+    match_sequence(&pattern_children, &target_children, bindings)
+}
+
+fn match_sequence(
+    pattern: &[LuaSyntaxElement],
+    target: &[LuaSyntaxElement],
+    bindings: &mut MatchBindings,
+) -> bool {
+    let Some(first) = pattern.first() else {
+        return target.is_empty();
+    };
+
+    if let Some(name) = variadic_name(first) {
+        for count in 0..=target.len() {
+            let mut candidate = bindings.clone();
+            let binding = MatchBinding {
+                range: element_range(&target[..count]),
+                node: None,
+                key: element_key(&target[..count]),
+            };
+            if !bind(&mut candidate, &name, binding) {
+                continue;
+            }
+            // `$rest..., x` matching `x` leaves the separator out
+            let rest = match pattern.get(1) {
+                Some(next) if count == 0 && is_separator(next) => &pattern[2..],
+                _ => &pattern[1..],
+            };
+            if match_sequence(rest, &target[count..], &mut candidate) {
+                *bindings = candidate;
+                return true;
+            }
+        }
+        return false;
+    }
+
+    // `x, $rest...` matching `x` leaves the separator out
+    if is_separator(first) {
+        if let Some(name) = pattern.get(1).and_then(variadic_name) {
+            let mut candidate = bindings.clone();
+            let binding = MatchBinding {
+                range: None,
+                node: None,
+                key: String::new(),
+            };
+            if bind(&mut candidate, &name, binding)
+                && match_sequence(&pattern[2..], target, &mut candidate)
+            {
+                *bindings = candidate;
+                return true;
+            }
+        }
+    }
+
+    let Some(target_first) = target.first() else {
+        return false;
+    };
+    match_element(first, target_first, bindings)
+        && match_sequence(&pattern[1..], &target[1..], bindings)
+}
+
+fn bind(bindings: &mut MatchBindings, name: &str, binding: MatchBinding) -> bool {
+    match bindings.get(name) {
+        Some(bound) => bound.key == binding.key,
+        None => {
+            bindings.insert(name.to_string(), binding);
+            true
+        }
+    }
+}
+
+fn variadic_name(element: &LuaSyntaxElement) -> Option<String> {
+    let node = element.as_node()?;
+    // a block holding only `__ssrv_body()` is matched statement by statement, the range check
+    // keeps large nodes from being turned into text
+    if node.kind() == LuaSyntaxKind::Block.into() || usize::from(node.text_range().len()) > 128 {
+        return None;
+    }
+    let text = node.text().to_string();
+    variadic_placeholder_name(text.trim()).map(|name| name.to_string())
+}
+
+fn is_separator(element: &LuaSyntaxElement) -> bool {
+    matches!(
+        element.kind(),
+        LuaKind::Token(LuaTokenKind::TkComma | LuaTokenKind::TkSemicolon)
+    )
+}
+
+fn significant_children(node: &LuaSyntaxNode) -> Vec<LuaSyntaxElement> {
+    let is_stat = LuaStat::can_cast(node.kind().into());
+    node.children_with_tokens()
+        .filter(|element| match element.kind() {
+            LuaKind::Token(LuaTokenKind::TkWhitespace | LuaTokenKind::TkEndOfLine) => false,
+            LuaKind::Syntax(LuaSyntaxKind::Comment) => false,
+            // `f();` is the same statement as `f()`
+            LuaKind::Token(LuaTokenKind::TkSemicolon) => !is_stat,
+            _ => true,
+        })
+        .collect()
+}
+
+// the parser gives calls of some well known functions their own kind
+fn normalize_kind(kind: LuaKind) -> LuaKind {
+    match kind {
+        LuaKind::Syntax(
+            LuaSyntaxKind::AssertCallExpr
+            | LuaSyntaxKind::ErrorCallExpr
+            | LuaSyntaxKind::RequireCallExpr
+            | LuaSyntaxKind::TypeCallExpr
+            | LuaSyntaxKind::SetmetatableCallExpr,
+        ) => LuaKind::Syntax(LuaSyntaxKind::CallExpr),
+        kind => kind,
+    }
+}
+
+fn element_range(elements: &[LuaSyntaxElement]) -> Option<TextRange> {
+    let first = elements.first()?;
+    let last = elements.last()?;
+    Some(TextRange::new(
+        first.text_range().start(),
+        last.text_range().end(),
+    ))
+}
+
+fn element_key(elements: &[LuaSyntaxElement]) -> String {
+    let mut tokens = Vec::new();
+    for element in elements {
+        match element {
+            NodeOrToken::Node(node) => {
+                for token in node
+                    .descendants_with_tokens()
+                    .filter_map(|e| e.into_token())
+                {
+                    if !matches!(
+                        token.kind().into(),
+                        LuaTokenKind::TkWhitespace | LuaTokenKind::TkEndOfLine
+                    ) && !token
+                        .parent_ancestors()
+                        .any(|parent| parent.kind() == LuaSyntaxKind::Comment.into())
+                    {
+                        tokens.push(token.text().to_string());
+                    }
+                }
+            }
+            NodeOrToken::Token(token) => tokens.push(token.text().to_string()),
+        }
+    }
+    tokens.join(" ")
+}
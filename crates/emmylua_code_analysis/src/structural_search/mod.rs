@@ -0,0 +1,286 @@
+mod matcher;
+mod pattern;
+
+use std::{collections::HashMap, fmt};
+
+use emmylua_parser::{LuaAstNode, LuaExpr, LuaSyntaxKind, LuaTextEdit};
+use matcher::{match_node, MatchBindings};
+use pattern::{scan_placeholders, PlaceholderKind, SearchPattern};
+use rowan::TextRange;
+
+use crate::{DbIndex, Emmyrc, FileId, LuaType, SemanticModel, TypeOps};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StructuralSearchError {
+    InvalidPattern(String),
+    InvalidReplacement(String),
+    InvalidConstraint(String),
+}
+
+impl fmt::Display for StructuralSearchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StructuralSearchError::InvalidPattern(message) => {
+                write!(f, "invalid pattern: {}", message)
+            }
+            StructuralSearchError::InvalidReplacement(message) => {
+                write!(f, "invalid replacement: {}", message)
+            }
+            StructuralSearchError::InvalidConstraint(message) => {
+                write!(f, "invalid constraint: {}", message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for StructuralSearchError {}
+
+/// One place where the pattern matched
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StructuralMatch {
+    pub range: TextRange,
+    /// source text of every placeholder, empty for a variadic placeholder that matched nothing
+    pub bindings: HashMap<String, String>,
+    /// a comment of the match is outside every placeholder, the replacement would drop it
+    pub drops_comments: bool,
+}
+
+/// Structural search and replace over the Lua syntax tree
+///
+/// The pattern is one expression or statement in which `$x` stands for any expression or name
+/// and `$x...` for any number of arguments, parameters, table fields or statements. A
+/// placeholder used twice must match the same code both times, trivia and comments are ignored.
+/// For example `foo.bar($a, $b)` with the replacement `foo:baz($b, $a)`. A match is not replaced
+/// when that would drop a comment.
+#[derive(Debug, Clone)]
+pub struct StructuralSearch {
+    pattern: SearchPattern,
+    replacement: Option<String>,
+    /// placeholder and the type its inferred type must be assignable to
+    constraints: Vec<(String, String)>,
+}
+
+impl StructuralSearch {
+    /// The pattern is parsed with the parser settings of the workspace
+    pub fn new(pattern: &str, emmyrc: &Emmyrc) -> Result<Self, StructuralSearchError> {
+        Ok(Self {
+            pattern: SearchPattern::parse(pattern, emmyrc)?,
+            replacement: None,
+            constraints: Vec::new(),
+        })
+    }
+
+    pub fn with_replacement(mut self, replacement: &str) -> Result<Self, StructuralSearchError> {
+        for placeholder in scan_placeholders(replacement) {
+            match self.pattern.placeholders.get(&placeholder.name) {
+                Some(kind) if *kind == placeholder.kind => {}
+                Some(_) => {
+                    return Err(StructuralSearchError::InvalidReplacement(format!(
+                        "`${}` is used differently in the pattern",
+                        placeholder.name
+                    )))
+                }
+                None => {
+                    return Err(StructuralSearchError::InvalidReplacement(format!(
+                        "`${}` is not in the pattern",
+                        placeholder.name
+                    )))
+                }
+            }
+        }
+        self.replacement = Some(replacement.to_string());
+        Ok(self)
+    }
+
+    /// Only match when the type inferred for `$placeholder` is assignable to `type_text`, which
+    /// is a builtin type, a class or alias name, or a `|` separated union of them
+    pub fn with_constraint(
+        mut self,
+        placeholder: &str,
+        type_text: &str,
+    ) -> Result<Self, StructuralSearchError> {
+        let placeholder = placeholder.trim_start_matches('$');
+        if self.pattern.placeholders.get(placeholder) != Some(&PlaceholderKind::Single) {
+            return Err(StructuralSearchError::InvalidConstraint(format!(
+                "`${}` is not a single placeholder of the pattern",
+                placeholder
+            )));
+        }
+        self.constraints
+            .push((placeholder.to_string(), type_text.trim().to_string()));
+        Ok(self)
+    }
+
+    pub fn has_replacement(&self) -> bool {
+        self.replacement.is_some()
+    }
+
+    /// Matches in source order, a match inside another match is not reported
+    pub fn find_matches(&self, semantic_model: &SemanticModel) -> Vec<StructuralMatch> {
+        let mut constraints = Vec::new();
+        for (placeholder, type_text) in &self.constraints {
+            let Some(typ) = resolve_constraint_type(
+                semantic_model.get_db(),
+                semantic_model.get_file_id(),
+                type_text,
+            ) else {
+                return Vec::new();
+            };
+            constraints.push((placeholder, typ));
+        }
+
+        let root = semantic_model.get_root().syntax();
+        let source = root.text().to_string();
+        let pattern = self.pattern.get_root();
+        let any_expr = matcher::is_placeholder_expr(&pattern);
+        let mut matches = Vec::new();
+        let mut last_end = 0.into();
+        for node in root.descendants() {
+            if node.text_range().start() < last_end {
+                continue;
+            }
+            let candidate = if any_expr {
+                LuaExpr::can_cast(node.kind().into())
+            } else {
+                matcher::is_same_kind(pattern.kind(), node.kind())
+            };
+            if !candidate {
+                continue;
+            }
+            let Some(bindings) = match_node(&pattern, &node) else {
+                continue;
+            };
+            if !check_constraints(semantic_model, &bindings, &constraints) {
+                continue;
+            }
+
+            last_end = node.text_range().end();
+            let drops_comments = node
+                .descendants()
+                .filter(|child| child.kind() == LuaSyntaxKind::Comment.into())
+                .any(|comment| {
+                    !bindings.values().any(|binding| {
+                        binding
+                            .range
+                            .is_some_and(|range| range.contains_range(comment.text_range()))
+                    })
+                });
+            let bindings = bindings
+                .into_iter()
+                .map(|(name, binding)| {
+                    let text = binding
+                        .range
+                        .map(|range| source[range].to_string())
+                        .unwrap_or_default();
+                    (name, text)
+                })
+                .collect();
+            matches.push(StructuralMatch {
+                range: node.text_range(),
+                bindings,
+                drops_comments,
+            });
+        }
+        matches
+    }
+
+    /// The replacement text of a match, `None` without a replacement
+    pub fn get_replacement(&self, structural_match: &StructuralMatch) -> Option<String> {
+        let replacement = self.replacement.as_ref()?;
+        let mut result = String::new();
+        let mut last = 0;
+        for placeholder in scan_placeholders(replacement) {
+            let mut before = &replacement[last..placeholder.start];
+            let text = structural_match
+                .bindings
+                .get(&placeholder.name)
+                .map(String::as_str)
+                .unwrap_or_default();
+            last = placeholder.end;
+            // `f(a, $rest...)` and `f($rest..., a)` do not keep the separator for an empty list
+            if text.is_empty() && placeholder.kind == PlaceholderKind::Variadic {
+                let trimmed = before.trim_end();
+                if let Some(stripped) = trimmed.strip_suffix(',') {
+                    before = stripped;
+                } else if trimmed.is_empty() && result.trim_end().ends_with(',') {
+                    result.truncate(result.trim_end().len() - 1);
+                } else {
+                    let rest = replacement[last..].trim_start();
+                    if let Some(stripped) = rest.strip_prefix(',') {
+                        last = replacement.len() - stripped.trim_start().len();
+                    }
+                }
+            }
+            result.push_str(before);
+            result.push_str(text);
+        }
+        result.push_str(&replacement[last..]);
+        Some(result)
+    }
+
+    /// Minimal text edits replacing every match that keeps its comments, empty without a
+    /// replacement
+    pub fn replace(&self, semantic_model: &SemanticModel) -> Vec<LuaTextEdit> {
+        let source = semantic_model.get_root().syntax().text().to_string();
+        self.find_matches(semantic_model)
+            .iter()
+            .filter(|structural_match| !structural_match.drops_comments)
+            .filter_map(|structural_match| {
+                let new_text = self.get_replacement(structural_match)?;
+                let range = structural_match.range;
+                let edit = LuaTextEdit::minimal_replace(range, &source[range], &new_text);
+                (!edit.range.is_empty() || !edit.new_text.is_empty()).then_some(edit)
+            })
+            .collect()
+    }
+}
+
+fn check_constraints(
+    semantic_model: &SemanticModel,
+    bindings: &MatchBindings,
+    constraints: &[(&String, LuaType)],
+) -> bool {
+    for (placeholder, typ) in constraints {
+        let Some(expr) = bindings
+            .get(*placeholder)
+            .and_then(|binding| binding.node.clone())
+            .and_then(LuaExpr::cast)
+        else {
+            return false;
+        };
+        let Ok(expr_type) = semantic_model.infer_expr(expr) else {
+            return false;
+        };
+        if expr_type.is_unknown() || semantic_model.type_check(typ, &expr_type).is_err() {
+            return false;
+        }
+    }
+    true
+}
+
+fn resolve_constraint_type(db: &DbIndex, file_id: FileId, type_text: &str) -> Option<LuaType> {
+    let mut result: Option<LuaType> = None;
+    for name in type_text.split('|').map(str::trim) {
+        let typ = match name {
+            "nil" => LuaType::Nil,
+            "any" => LuaType::Any,
+            "boolean" => LuaType::Boolean,
+            "string" => LuaType::String,
+            "integer" => LuaType::Integer,
+            "number" => LuaType::Number,
+            "table" => LuaType::Table,
+            "function" => LuaType::Function,
+            "userdata" => LuaType::Userdata,
+            "thread" => LuaType::Thread,
+            _ => {
+                let decl = db.get_type_index().find_type_decl(file_id, name)?;
+                LuaType::Ref(decl.get_id())
+            }
+        };
+        result = Some(match result {
+            Some(result) => TypeOps::Union.apply(db, &result, &typ),
+            None => typ,
+        });
+    }
+    result
+}
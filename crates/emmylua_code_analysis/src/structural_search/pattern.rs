@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+
+use emmylua_parser::{LuaAstNode, LuaExpr, LuaParser, LuaReturnStat, LuaSyntaxNode};
+use rowan::{GreenNode, NodeCache};
+
+use crate::Emmyrc;
+
+use super::StructuralSearchError;
+
+const PLACEHOLDER_PREFIX: &str = "__ssr_";
+const VARIADIC_PREFIX: &str = "__ssrv_";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaceholderKind {
+    /// `$x`, one expression or name
+    Single,
+    /// `$x...`, zero or more list items or statements
+    Variadic,
+}
+
+/// A placeholder found in a pattern or replacement
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Placeholder {
+    pub name: String,
+    pub kind: PlaceholderKind,
+    /// byte range of `$x` or `$x...` in the source text
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Finds `$name` and `$name...` in `text`
+pub fn scan_placeholders(text: &str) -> Vec<Placeholder> {
+    let bytes = text.as_bytes();
+    let mut placeholders = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'$' {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        let mut end = i + 1;
+        while end < bytes.len() && (bytes[end].is_ascii_alphanumeric() || bytes[end] == b'_') {
+            end += 1;
+        }
+        if end == start + 1 || bytes[start + 1].is_ascii_digit() {
+            i = end;
+            continue;
+        }
+
+        let name = text[start + 1..end].to_string();
+        let kind = if text[end..].starts_with("...") {
+            end += 3;
+            PlaceholderKind::Variadic
+        } else {
+            PlaceholderKind::Single
+        };
+        placeholders.push(Placeholder {
+            name,
+            kind,
+            start,
+            end,
+        });
+        i = end;
+    }
+    placeholders
+}
+
+/// The parsed pattern, placeholders are replaced by reserved names so the parser accepts it
+#[derive(Debug, Clone)]
+pub struct SearchPattern {
+    // the green node keeps the search `Send`
+    green: GreenNode,
+    pub placeholders: HashMap<String, PlaceholderKind>,
+}
+
+impl SearchPattern {
+    pub fn parse(pattern: &str, emmyrc: &Emmyrc) -> Result<Self, StructuralSearchError> {
+        let found = scan_placeholders(pattern);
+        let mut placeholders = HashMap::new();
+        for placeholder in &found {
+            if let Some(kind) = placeholders.insert(placeholder.name.clone(), placeholder.kind) {
+                if kind != placeholder.kind {
+                    return Err(StructuralSearchError::InvalidPattern(format!(
+                        "`${}` is used both as a single and a variadic placeholder",
+                        placeholder.name
+                    )));
+                }
+            }
+        }
+
+        // a variadic placeholder in statement position is written as a call, `__ssrv_body()`
+        for variadic_as_call in [false, true] {
+            let encoded = encode(pattern, &found, variadic_as_call);
+            if let Some(root) =
+                parse_expr(&encoded, emmyrc).or_else(|| parse_stat(&encoded, emmyrc))
+            {
+                return Ok(Self {
+                    green: root.green().into_owned(),
+                    placeholders,
+                });
+            }
+        }
+
+        Err(StructuralSearchError::InvalidPattern(
+            "the pattern must be a single expression or statement".to_string(),
+        ))
+    }
+
+    pub fn get_root(&self) -> LuaSyntaxNode {
+        LuaSyntaxNode::new_root(self.green.clone())
+    }
+}
+
+fn encode(pattern: &str, placeholders: &[Placeholder], variadic_as_call: bool) -> String {
+    let mut result = String::new();
+    let mut last = 0;
+    for placeholder in placeholders {
+        result.push_str(&pattern[last..placeholder.start]);
+        match placeholder.kind {
+            PlaceholderKind::Single => {
+                result.push_str(PLACEHOLDER_PREFIX);
+                result.push_str(&placeholder.name);
+            }
+            PlaceholderKind::Variadic => {
+                result.push_str(VARIADIC_PREFIX);
+                result.push_str(&placeholder.name);
+                if variadic_as_call {
+                    result.push_str("()");
+                }
+            }
+        }
+        last = placeholder.end;
+    }
+    result.push_str(&pattern[last..]);
+    result
+}
+
+/// `__ssr_x` gives `x`
+pub fn single_placeholder_name(text: &str) -> Option<&str> {
+    text.strip_prefix(PLACEHOLDER_PREFIX)
+}
+
+/// `__ssrv_x` or `__ssrv_x()` gives `x`
+pub fn variadic_placeholder_name(text: &str) -> Option<&str> {
+    let text = text.strip_prefix(VARIADIC_PREFIX)?;
+    let name = text.strip_suffix("()").unwrap_or(text);
+    name.bytes()
+        .all(|b| b.is_ascii_alphanumeric() || b == b'_')
+        .then_some(name)
+}
+
+fn parse_expr(text: &str, emmyrc: &Emmyrc) -> Option<LuaSyntaxNode> {
+    let source = format!("return {}", text);
+    let mut node_cache = NodeCache::default();
+    let tree = LuaParser::parse(&source, emmyrc.get_parse_config(&mut node_cache));
+    if !tree.get_errors().is_empty() {
+        return None;
+    }
+    let return_stat = tree
+        .get_chunk_node()
+        .descendants::<LuaReturnStat>()
+        .next()?;
+    let mut exprs = return_stat.get_expr_list();
+    let expr: LuaExpr = exprs.next()?;
+    if exprs.next().is_some() || expr.syntax().text() != text.trim() {
+        return None;
+    }
+    Some(expr.syntax().clone_subtree())
+}
+
+fn parse_stat(text: &str, emmyrc: &Emmyrc) -> Option<LuaSyntaxNode> {
+    let mut node_cache = NodeCache::default();
+    let tree = LuaParser::parse(text, emmyrc.get_parse_config(&mut node_cache));
+    if !tree.get_errors().is_empty() {
+        return None;
+    }
+    let block = tree.get_chunk_node().get_block()?;
+    let mut stats = block.get_stats();
+    let stat = stats.next()?;
+    if stats.next().is_some() {
+        return None;
+    }
+    Some(stat.syntax().clone_subtree())
+}
@@ -12,7 +12,7 @@ pub use loader::{load_workspace_files, read_file_with_encoding, LuaFileInfo};
 use lsp_types::Uri;
 use rowan::NodeCache;
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 pub use virtual_url::VirtualUrlGenerator;
 
@@ -26,6 +26,8 @@ pub struct Vfs {
     line_index_map: HashMap<FileId, LineIndex>,
     tree_map: HashMap<FileId, LuaSyntaxTree>,
     emmyrc: Option<Arc<Emmyrc>>,
+    // workspace folders and sub directories with their own config, a file uses the innermost one
+    folder_emmyrcs: Vec<(PathBuf, Arc<Emmyrc>)>,
     node_cache: NodeCache,
 }
 
@@ -38,6 +40,7 @@ impl Vfs {
             line_index_map: HashMap::new(),
             tree_map: HashMap::new(),
             emmyrc: None,
+            folder_emmyrcs: Vec::new(),
             node_cache: NodeCache::default(),
         }
     }
@@ -83,11 +86,14 @@ impl Vfs {
 
         if let Some(data) = &data {
             let line_index = LineIndex::parse(&data);
-            let parse_config = self
-                .emmyrc
-                .as_ref()
-                .unwrap()
-                .get_parse_config(&mut self.node_cache);
+            let emmyrc = self
+                .file_path_map
+                .get(&fid.id)
+                .and_then(|path| find_folder_emmyrc(&self.folder_emmyrcs, path))
+                .map(|(_, emmyrc)| emmyrc)
+                .or(self.emmyrc.as_ref())
+                .unwrap();
+            let parse_config = emmyrc.get_parse_config(&mut self.node_cache);
             let tree = LuaParser::parse(&data, parse_config);
             self.tree_map.insert(fid, tree);
             self.line_index_map.insert(fid, line_index);
@@ -116,6 +122,31 @@ impl Vfs {
         self.emmyrc = Some(emmyrc);
     }
 
+    pub fn update_folder_config(&mut self, root: PathBuf, emmyrc: Arc<Emmyrc>) {
+        self.folder_emmyrcs.retain(|(folder, _)| *folder != root);
+        self.folder_emmyrcs.push((root, emmyrc));
+    }
+
+    /// Also removes the configs of the sub directories of `root`
+    pub fn remove_folder_config(&mut self, root: &Path) {
+        self.folder_emmyrcs
+            .retain(|(folder, _)| !folder.starts_with(root));
+    }
+
+    /// The innermost directory with its own config that contains the file
+    pub fn get_folder_config(&self, id: &FileId) -> Option<(&PathBuf, &Arc<Emmyrc>)> {
+        let path = self.file_path_map.get(&id.id)?;
+        find_folder_emmyrc(&self.folder_emmyrcs, path)
+    }
+
+    pub fn get_file_ids_under(&self, root: &Path) -> Vec<FileId> {
+        self.file_path_map
+            .iter()
+            .filter(|(_, path)| path.starts_with(root))
+            .map(|(id, _)| FileId { id: *id })
+            .collect()
+    }
+
     pub fn get_file_content(&self, id: &FileId) -> Option<&String> {
         let opt = &self.file_data[id.id as usize];
         if let Some(s) = opt {
@@ -160,3 +191,14 @@ impl Vfs {
             .collect()
     }
 }
+
+fn find_folder_emmyrc<'a>(
+    folder_emmyrcs: &'a [(PathBuf, Arc<Emmyrc>)],
+    path: &Path,
+) -> Option<(&'a PathBuf, &'a Arc<Emmyrc>)> {
+    folder_emmyrcs
+        .iter()
+        .filter(|(root, _)| path.starts_with(root))
+        .max_by_key(|(root, _)| root.components().count())
+        .map(|(root, emmyrc)| (root, emmyrc))
+}
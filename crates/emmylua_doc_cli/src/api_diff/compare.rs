@@ -0,0 +1,447 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::Serialize;
+
+use crate::json_generator::{
+    accessors::{base_type_name, global_name, member_name, member_property, type_name},
+    json_types::{Fn, FnParam, Global, Index, Member, Module, Property, Type},
+};
+
+use super::type_compare::TypeComparer;
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Breaking,
+    Compatible,
+}
+
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct ApiChange {
+    pub kind: ChangeKind,
+    /// the changed api, such as `net.Socket:send`
+    pub path: String,
+    pub message: String,
+}
+
+/// Classify the changes of the public api from `old` to `new`
+pub fn diff_index(old: &Index, new: &Index, types: &TypeComparer) -> Vec<ApiChange> {
+    let mut diff = ApiDiff {
+        types,
+        changes: Vec::new(),
+    };
+    diff.modules(&old.modules, &new.modules);
+    diff.types(&old.types, &new.types);
+    diff.globals(&old.globals, &new.globals);
+    diff.changes
+        .sort_by(|a, b| (a.kind, &a.path).cmp(&(b.kind, &b.path)));
+    diff.changes
+}
+
+struct ApiDiff<'a> {
+    types: &'a TypeComparer,
+    changes: Vec<ApiChange>,
+}
+
+impl ApiDiff<'_> {
+    fn push(&mut self, kind: ChangeKind, path: &str, message: String) {
+        self.changes.push(ApiChange {
+            kind,
+            path: path.to_string(),
+            message,
+        });
+    }
+
+    fn modules(&mut self, old: &[Module], new: &[Module]) {
+        let new_modules: BTreeMap<&str, &Module> = new
+            .iter()
+            .map(|module| (module.name.as_str(), module))
+            .collect();
+        for old_module in old {
+            let name = &old_module.name;
+            match new_modules.get(name.as_str()) {
+                Some(new_module) => {
+                    self.property(name, &old_module.property, &new_module.property);
+                    self.members(name, &old_module.members, &new_module.members);
+                }
+                None => self.push(ChangeKind::Breaking, name, "module removed".to_string()),
+            }
+        }
+        let old_names: BTreeSet<&str> = old.iter().map(|module| module.name.as_str()).collect();
+        for name in new_modules.keys() {
+            if !old_names.contains(name) {
+                self.push(ChangeKind::Compatible, name, "module added".to_string());
+            }
+        }
+    }
+
+    fn types(&mut self, old: &[Type], new: &[Type]) {
+        let new_types: BTreeMap<&str, &Type> =
+            new.iter().map(|typ| (type_name(typ), typ)).collect();
+        for old_type in old {
+            let name = type_name(old_type);
+            let Some(new_type) = new_types.get(name) else {
+                self.push(
+                    ChangeKind::Breaking,
+                    name,
+                    format!("{} removed", type_kind(old_type)),
+                );
+                continue;
+            };
+
+            match (old_type, *new_type) {
+                (Type::Class(old_class), Type::Class(new_class)) => {
+                    self.property(name, &old_class.property, &new_class.property);
+                    let new_bases: Vec<&str> = new_class
+                        .bases
+                        .iter()
+                        .map(|base| base_type_name(base))
+                        .collect();
+                    let old_bases: Vec<&str> = old_class
+                        .bases
+                        .iter()
+                        .map(|base| base_type_name(base))
+                        .collect();
+                    for base in &old_bases {
+                        if !new_bases.contains(base) {
+                            self.push(
+                                ChangeKind::Breaking,
+                                name,
+                                format!("no longer extends `{}`", base),
+                            );
+                        }
+                    }
+                    for base in &new_bases {
+                        if !old_bases.contains(base) {
+                            self.push(ChangeKind::Compatible, name, format!("extends `{}`", base));
+                        }
+                    }
+                    self.members(name, &old_class.members, &new_class.members);
+                }
+                (Type::Enum(old_enum), Type::Enum(new_enum)) => {
+                    self.property(name, &old_enum.property, &new_enum.property);
+                    self.members(name, &old_enum.members, &new_enum.members);
+                }
+                (Type::Alias(old_alias), Type::Alias(new_alias)) => {
+                    self.property(name, &old_alias.property, &new_alias.property);
+                    if let (Some(old_typ), Some(new_typ)) = (&old_alias.typ, &new_alias.typ) {
+                        self.widened(name, "type", old_typ, new_typ);
+                    }
+                }
+                _ => self.push(
+                    ChangeKind::Breaking,
+                    name,
+                    format!(
+                        "changed from {} to {}",
+                        type_kind(old_type),
+                        type_kind(new_type)
+                    ),
+                ),
+            }
+        }
+        for (name, typ) in &new_types {
+            if !old.iter().any(|old_type| type_name(old_type) == *name) {
+                self.push(
+                    ChangeKind::Compatible,
+                    name,
+                    format!("{} added", type_kind(typ)),
+                );
+            }
+        }
+    }
+
+    fn globals(&mut self, old: &[Global], new: &[Global]) {
+        let new_globals: BTreeMap<&str, &Global> = new
+            .iter()
+            .map(|global| (global_name(global), global))
+            .collect();
+        for old_global in old {
+            let name = global_name(old_global);
+            match (old_global, new_globals.get(name)) {
+                (_, None) => self.push(ChangeKind::Breaking, name, "global removed".to_string()),
+                (Global::Table(old_table), Some(Global::Table(new_table))) => {
+                    self.property(name, &old_table.property, &new_table.property);
+                    self.members(name, &old_table.members, &new_table.members);
+                }
+                (Global::Field(old_field), Some(Global::Field(new_field))) => {
+                    self.property(name, &old_field.property, &new_field.property);
+                    self.narrowed(name, "type", &old_field.typ, &new_field.typ);
+                }
+                // a table and a field of a table type are used the same way
+                _ => {}
+            }
+        }
+        for name in new_globals.keys() {
+            if !old.iter().any(|global| global_name(global) == *name) {
+                self.push(ChangeKind::Compatible, name, "global added".to_string());
+            }
+        }
+    }
+
+    fn members(&mut self, owner: &str, old: &[Member], new: &[Member]) {
+        let new_members: BTreeMap<&str, &Member> = new
+            .iter()
+            .map(|member| (member_name(member), member))
+            .collect();
+        for old_member in old {
+            let old_rank = visibility_rank(member_property(old_member));
+            // members that were not public are not part of the api
+            if old_rank > PROTECTED {
+                continue;
+            }
+            let path = member_path(owner, old_member);
+            let Some(new_member) = new_members.get(member_name(old_member)) else {
+                self.push(ChangeKind::Breaking, &path, "removed".to_string());
+                continue;
+            };
+            let new_property = member_property(new_member);
+            if visibility_rank(new_property) > old_rank {
+                self.push(
+                    ChangeKind::Breaking,
+                    &path,
+                    format!(
+                        "visibility narrowed to `{}`",
+                        new_property.visibility.as_deref().unwrap_or("public")
+                    ),
+                );
+                continue;
+            }
+            self.property(&path, member_property(old_member), new_property);
+
+            match (old_member, *new_member) {
+                (Member::Fn(old_fn), Member::Fn(new_fn)) => self.function(&path, old_fn, new_fn),
+                (Member::Field(old_field), Member::Field(new_field)) => {
+                    if old_field.literal != new_field.literal && old_field.literal.is_some() {
+                        self.push(
+                            ChangeKind::Breaking,
+                            &path,
+                            format!(
+                                "value changed from {} to {}",
+                                old_field.literal.as_deref().unwrap_or_default(),
+                                new_field.literal.as_deref().unwrap_or("nil")
+                            ),
+                        );
+                    } else {
+                        self.narrowed(&path, "type", &old_field.typ, &new_field.typ);
+                    }
+                }
+                (Member::Fn(_), Member::Field(_)) => self.push(
+                    ChangeKind::Breaking,
+                    &path,
+                    "changed from a function to a field".to_string(),
+                ),
+                (Member::Field(_), Member::Fn(_)) => self.push(
+                    ChangeKind::Breaking,
+                    &path,
+                    "changed from a field to a function".to_string(),
+                ),
+            }
+        }
+
+        for new_member in new {
+            if visibility_rank(member_property(new_member)) > PROTECTED {
+                continue;
+            }
+            let was_public = old.iter().any(|old_member| {
+                member_name(old_member) == member_name(new_member)
+                    && visibility_rank(member_property(old_member)) <= PROTECTED
+            });
+            if !was_public {
+                self.push(
+                    ChangeKind::Compatible,
+                    &member_path(owner, new_member),
+                    "added".to_string(),
+                );
+            }
+        }
+    }
+
+    fn function(&mut self, path: &str, old: &Fn, new: &Fn) {
+        if old.is_meth != new.is_meth {
+            self.push(
+                ChangeKind::Breaking,
+                path,
+                format!(
+                    "changed from a {} to a {}",
+                    fn_kind(old.is_meth),
+                    fn_kind(new.is_meth)
+                ),
+            );
+            return;
+        }
+
+        let param_count = old.params.len().max(new.params.len());
+        for i in 0..param_count {
+            match (old.params.get(i), new.params.get(i)) {
+                (Some(old_param), Some(new_param)) => {
+                    if let (Some(old_typ), Some(new_typ)) = (&old_param.typ, &new_param.typ) {
+                        let what = format!("param `{}`", param_name(new_param));
+                        self.widened(path, &what, old_typ, new_typ);
+                    }
+                }
+                (None, Some(new_param)) => {
+                    if old.params.last().is_some_and(is_vararg) {
+                        continue;
+                    }
+                    if self.is_required(new_param) {
+                        self.push(
+                            ChangeKind::Breaking,
+                            path,
+                            format!("added required param `{}`", param_name(new_param)),
+                        );
+                    } else {
+                        self.push(
+                            ChangeKind::Compatible,
+                            path,
+                            format!("added optional param `{}`", param_name(new_param)),
+                        );
+                    }
+                }
+                (Some(old_param), None) => {
+                    if !new.params.last().is_some_and(is_vararg) {
+                        self.push(
+                            ChangeKind::Breaking,
+                            path,
+                            format!("removed param `{}`", param_name(old_param)),
+                        );
+                    }
+                }
+                (None, None) => {}
+            }
+        }
+
+        let return_count = old.returns.len().max(new.returns.len());
+        for i in 0..return_count {
+            let what = format!("return #{}", i + 1);
+            match (old.returns.get(i), new.returns.get(i)) {
+                (Some(old_return), Some(new_return)) => {
+                    if let (Some(old_typ), Some(new_typ)) = (&old_return.typ, &new_return.typ) {
+                        self.narrowed(path, &what, old_typ, new_typ);
+                    }
+                }
+                (Some(_), None) => {
+                    self.push(ChangeKind::Breaking, path, format!("removed {}", what));
+                }
+                (None, Some(_)) => {
+                    self.push(ChangeKind::Compatible, path, format!("added {}", what));
+                }
+                (None, None) => {}
+            }
+        }
+
+        for overload in &old.overloads {
+            if !new.overloads.contains(overload) {
+                self.push(
+                    ChangeKind::Breaking,
+                    path,
+                    format!("removed overload `{}`", overload),
+                );
+            }
+        }
+        for overload in &new.overloads {
+            if !old.overloads.contains(overload) {
+                self.push(
+                    ChangeKind::Compatible,
+                    path,
+                    format!("added overload `{}`", overload),
+                );
+            }
+        }
+    }
+
+    fn property(&mut self, path: &str, old: &Property, new: &Property) {
+        if !old.deprecated && new.deprecated {
+            self.push(ChangeKind::Compatible, path, "deprecated".to_string());
+        }
+    }
+
+    /// A type values are passed in, such as a param, may only get wider
+    fn widened(&mut self, path: &str, what: &str, old: &str, new: &str) {
+        if old == new {
+            return;
+        }
+        if self.types.is_assignable(new, old) {
+            self.push(
+                ChangeKind::Compatible,
+                path,
+                format!("{} widened from `{}` to `{}`", what, old, new),
+            );
+        } else {
+            self.push(
+                ChangeKind::Breaking,
+                path,
+                format!("{} changed from `{}` to `{}`", what, old, new),
+            );
+        }
+    }
+
+    /// A type values are read from, such as a return, may only get narrower
+    fn narrowed(&mut self, path: &str, what: &str, old: &str, new: &str) {
+        if old == new {
+            return;
+        }
+        if self.types.is_assignable(old, new) {
+            self.push(
+                ChangeKind::Compatible,
+                path,
+                format!("{} narrowed from `{}` to `{}`", what, old, new),
+            );
+        } else {
+            self.push(
+                ChangeKind::Breaking,
+                path,
+                format!("{} changed from `{}` to `{}`", what, old, new),
+            );
+        }
+    }
+
+    // an untyped param accepts nil, so callers may leave it out
+    fn is_required(&self, param: &FnParam) -> bool {
+        match &param.typ {
+            Some(typ) => !is_vararg(param) && !self.types.is_assignable(typ, "nil"),
+            None => false,
+        }
+    }
+}
+
+const PROTECTED: u8 = 1;
+
+fn visibility_rank(property: &Property) -> u8 {
+    match property.visibility.as_deref() {
+        None | Some("public") => 0,
+        Some("protected") => PROTECTED,
+        Some("package") | Some("internal") => 2,
+        _ => 3,
+    }
+}
+
+fn type_kind(typ: &Type) -> &'static str {
+    match typ {
+        Type::Class(_) => "class",
+        Type::Enum(_) => "enum",
+        Type::Alias(_) => "alias",
+    }
+}
+
+fn member_path(owner: &str, member: &Member) -> String {
+    match member {
+        Member::Fn(func) if func.is_meth => format!("{}:{}", owner, func.name),
+        _ => format!("{}.{}", owner, member_name(member)),
+    }
+}
+
+fn fn_kind(is_meth: bool) -> &'static str {
+    if is_meth {
+        "method"
+    } else {
+        "function"
+    }
+}
+
+fn param_name(param: &FnParam) -> &str {
+    param.name.as_deref().unwrap_or("_")
+}
+
+fn is_vararg(param: &FnParam) -> bool {
+    param.name.as_deref() == Some("...")
+}
@@ -0,0 +1,71 @@
+mod compare;
+#[cfg(test)]
+mod test;
+mod type_compare;
+
+use std::path::Path;
+
+use compare::{diff_index, ApiChange, ChangeKind};
+use type_compare::TypeComparer;
+
+use crate::{
+    init::load_workspace,
+    json_generator::{export::export, json_types::Index},
+};
+
+pub fn run_diff(old: &Path, new: &Path, json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let old_index = load_index(old)?;
+    let new_index = load_index(new)?;
+    let changes = diff_api(&old_index, &new_index);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&changes)?);
+    } else {
+        for (kind, title) in [
+            (ChangeKind::Breaking, "Breaking changes:"),
+            (ChangeKind::Compatible, "Compatible changes:"),
+        ] {
+            let group: Vec<&ApiChange> = changes.iter().filter(|c| c.kind == kind).collect();
+            if group.is_empty() {
+                continue;
+            }
+            println!("{}", title);
+            for change in group {
+                println!("  {}: {}", change.path, change.message);
+            }
+        }
+    }
+
+    let breaking = changes
+        .iter()
+        .filter(|change| change.kind == ChangeKind::Breaking)
+        .count();
+    eprintln!("{} api changes, {} breaking", changes.len(), breaking);
+    if breaking > 0 {
+        return Err(format!("{} breaking api changes", breaking).into());
+    }
+    Ok(())
+}
+
+/// Classify the api changes between two exported versions of a library
+pub fn diff_api(old: &Index, new: &Index) -> Vec<ApiChange> {
+    let types = TypeComparer::new(old, new);
+    diff_index(old, new, &types)
+}
+
+/// Read a `--format json` export, or export a lua source tree
+pub fn load_index(path: &Path) -> Result<Index, Box<dyn std::error::Error>> {
+    if path.is_file() {
+        let text = std::fs::read_to_string(path)?;
+        return Ok(serde_json::from_str(&text)?);
+    }
+
+    let path = if path.is_relative() {
+        std::env::current_dir()?.join(path)
+    } else {
+        path.to_path_buf()
+    };
+    let analysis = load_workspace(vec![path.to_str().ok_or("invalid path")?])
+        .ok_or_else(|| format!("failed to analyze {:?}", path))?;
+    Ok(export(analysis.compilation.get_db()))
+}
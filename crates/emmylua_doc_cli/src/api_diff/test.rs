@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        json_generator::{export::export, json_types::Index},
+        test_lib::TempWorkspace,
+    };
+
+    use super::super::{compare::ChangeKind, diff_api};
+
+    const OLD: &str = r#"
+---@class net.Socket
+---@field host string
+local Socket = {}
+
+---@param data string
+---@return integer
+function Socket:send(data) return 0 end
+
+---@param n integer|string
+function Socket:recv(n) end
+
+function Socket:close() end
+
+---@param host string
+---@return net.Socket
+local function connect(host) return setmetatable({}, Socket) end
+
+return { connect = connect }
+"#;
+
+    const NEW: &str = r#"
+---@class net.Socket
+---@field host string
+---@field timeout number?
+local Socket = {}
+
+---@param data string
+---@param flags integer
+---@return integer
+function Socket:send(data, flags) return 0 end
+
+---@param n integer
+---@param opts table?
+function Socket:recv(n, opts) end
+
+---@param host string|nil
+---@return net.Socket
+local function connect(host) return setmetatable({}, Socket) end
+
+return { connect = connect }
+"#;
+
+    fn export_source(name: &str, source: &str) -> Index {
+        let workspace = TempWorkspace::new(&format!("api_diff_{}", name));
+        workspace.write("net.lua", source);
+        let analysis = workspace.load();
+        export(analysis.compilation.get_db())
+    }
+
+    #[test]
+    fn test_api_diff() {
+        let old = export_source("old", OLD);
+        let new = export_source("new", NEW);
+
+        let diff = diff_api(&old, &new);
+        let changes: Vec<(ChangeKind, &str, &str)> = diff
+            .iter()
+            .map(|change| (change.kind, change.path.as_str(), change.message.as_str()))
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                (ChangeKind::Breaking, "net.Socket:close", "removed"),
+                (
+                    ChangeKind::Breaking,
+                    "net.Socket:recv",
+                    "param `n` changed from `(integer|string)` to `integer`"
+                ),
+                (
+                    ChangeKind::Breaking,
+                    "net.Socket:send",
+                    "added required param `flags`"
+                ),
+                (ChangeKind::Compatible, "net.Socket.timeout", "added"),
+                (
+                    ChangeKind::Compatible,
+                    "net.Socket:recv",
+                    "added optional param `opts`"
+                ),
+                (
+                    ChangeKind::Compatible,
+                    "net.connect",
+                    "param `host` widened from `string` to `string?`"
+                ),
+            ]
+        );
+
+        assert!(diff_api(&new, &new).is_empty());
+    }
+}
@@ -0,0 +1,155 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    path::{Path, PathBuf},
+};
+
+use emmylua_code_analysis::{EmmyLuaAnalysis, FileId, LuaType, LuaTypeDeclId};
+
+use crate::{
+    common::annotation_type,
+    json_generator::json_types::{Global, Index, Member, Type},
+    meta_generator::render_meta,
+};
+
+const ALIAS_PREFIX: &str = "__api_diff_";
+
+/// Compares the rendered types of two versions with the type checker, type names are resolved
+/// against the definitions of the new version
+pub struct TypeComparer {
+    analysis: EmmyLuaAnalysis,
+    file_id: Option<FileId>,
+    types: HashMap<String, LuaType>,
+}
+
+impl TypeComparer {
+    pub fn new(old: &Index, new: &Index) -> Self {
+        let mut texts = BTreeSet::new();
+        texts.insert("nil".to_string());
+        collect_type_texts(old, &mut texts);
+        collect_type_texts(new, &mut texts);
+
+        let mut analysis = EmmyLuaAnalysis::new();
+        analysis.init_std_lib(None);
+        // the stubs and aliases are only virtual files
+        let workspace = std::env::temp_dir().join("emmylua_api_diff");
+        analysis.add_main_workspace(workspace.clone());
+
+        let mut files: Vec<(PathBuf, Option<String>)> = render_meta(new, &common_root(new))
+            .into_iter()
+            .map(|(path, content)| (workspace.join("meta").join(path), Some(content)))
+            .collect();
+        let texts: Vec<String> = texts.into_iter().collect();
+        let aliases: Vec<String> = texts
+            .iter()
+            .enumerate()
+            .map(|(i, text)| format!("---@alias {}{} {}", ALIAS_PREFIX, i, annotation_type(text)))
+            .collect();
+        let alias_path = workspace.join("types.lua");
+        files.push((alias_path.clone(), Some(aliases.join("\n"))));
+        analysis.update_files_by_path(files);
+
+        let db = analysis.compilation.get_db();
+        let type_index = db.get_type_index();
+        let types = texts
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, text)| {
+                let id = LuaTypeDeclId::new(&format!("{}{}", ALIAS_PREFIX, i));
+                let typ = type_index.get_type_decl(&id)?.get_alias_ref()?.clone();
+                Some((text, typ))
+            })
+            .collect();
+        let file_id = emmylua_code_analysis::file_path_to_uri(&alias_path)
+            .and_then(|uri| db.get_vfs().get_file_id(&uri));
+
+        Self {
+            analysis,
+            file_id,
+            types,
+        }
+    }
+
+    /// Whether a value of type `source` can be used where `target` is expected
+    pub fn is_assignable(&self, target: &str, source: &str) -> bool {
+        if target == source {
+            return true;
+        }
+        let (Some(target), Some(source)) = (self.types.get(target), self.types.get(source)) else {
+            return false;
+        };
+        let Some(semantic_model) = self
+            .file_id
+            .and_then(|file_id| self.analysis.compilation.get_semantic_model(file_id))
+        else {
+            return false;
+        };
+        semantic_model.type_check(target, source).is_ok()
+    }
+}
+
+fn collect_type_texts(index: &Index, texts: &mut BTreeSet<String>) {
+    for module in &index.modules {
+        add_member_texts(&module.members, texts);
+    }
+    for typ in &index.types {
+        match typ {
+            Type::Class(class) => add_member_texts(&class.members, texts),
+            Type::Enum(enum_) => add_member_texts(&enum_.members, texts),
+            Type::Alias(alias) => {
+                texts.extend(alias.typ.clone());
+                add_member_texts(&alias.members, texts);
+            }
+        }
+    }
+    for global in &index.globals {
+        match global {
+            Global::Table(table) => add_member_texts(&table.members, texts),
+            Global::Field(field) => {
+                texts.insert(field.typ.clone());
+            }
+        }
+    }
+}
+
+fn add_member_texts(members: &[Member], texts: &mut BTreeSet<String>) {
+    for member in members {
+        match member {
+            Member::Fn(func) => {
+                for param in func.params.iter().chain(&func.returns) {
+                    texts.extend(param.typ.clone());
+                }
+            }
+            Member::Field(field) => {
+                texts.insert(field.typ.clone());
+            }
+        }
+    }
+}
+
+// the directory containing every documented file, so the stubs keep the module paths
+fn common_root(index: &Index) -> PathBuf {
+    let mut files = index
+        .modules
+        .iter()
+        .filter_map(|module| module.file.as_deref())
+        .chain(index.types.iter().flat_map(|typ| {
+            let locs = match typ {
+                Type::Class(class) => &class.loc,
+                Type::Enum(enum_) => &enum_.loc,
+                Type::Alias(alias) => &alias.loc,
+            };
+            locs.iter().map(|loc| loc.file.as_path())
+        }));
+    let Some(first) = files.next() else {
+        return PathBuf::new();
+    };
+    let mut root = first.parent().unwrap_or(Path::new("")).to_path_buf();
+    for file in files {
+        while !file.starts_with(&root) {
+            if !root.pop() {
+                return PathBuf::new();
+            }
+        }
+    }
+    root
+}
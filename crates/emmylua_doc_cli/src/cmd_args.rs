@@ -1,32 +1,50 @@
+use serde::Deserialize;
 use std::str::FromStr;
 use structopt::StructOpt;
 
+use crate::markdown_generator::DocLayout;
+
 #[derive(Debug, StructOpt)]
 pub struct CmdArgs {
+    #[structopt(subcommand)]
+    pub command: Option<Command>,
+
     #[structopt(
         parse(from_os_str),
         long = "input",
         short = "i",
-        help = "The path of the lua project"
+        help = "The path of the lua project, required unless a subcommand is given or it is set in the config"
     )]
-    pub input: std::path::PathBuf,
+    pub input: Option<std::path::PathBuf>,
+
+    #[structopt(
+        parse(from_os_str),
+        long = "config",
+        short = "c",
+        help = "The path of the doc config file, default is .emmydoc.json in the current directory"
+    )]
+    pub config: Option<std::path::PathBuf>,
 
     #[structopt(
-        default_value = "markdown",
         long = "format",
         short = "f",
         help = "Format of the output, default is Markdown"
     )]
-    pub format: Format,
+    pub format: Option<Format>,
 
     #[structopt(
         parse(from_os_str),
-        default_value = "./output",
         long = "output",
         short = "o",
-        help = "The output path of the docs file"
+        help = "The output path of the docs file, default is ./output"
     )]
-    pub output: std::path::PathBuf,
+    pub output: Option<std::path::PathBuf>,
+
+    #[structopt(
+        long = "layout",
+        help = "Layout of the markdown pages, pages (default) or single"
+    )]
+    pub layout: Option<DocLayout>,
 
     #[structopt(
         parse(from_os_str),
@@ -41,12 +59,62 @@ pub struct CmdArgs {
         help = "The path of the mixin md file"
     )]
     pub mixin: Option<std::path::PathBuf>,
+
+    #[structopt(
+        long = "source-url",
+        help = "The base url that html source links point to, such as a repository blob url"
+    )]
+    pub source_url: Option<String>,
+
+    #[structopt(
+        long = "coverage",
+        conflicts_with_all = &["doctest", "format"],
+        help = "Report the documentation coverage instead of generating docs, the json report is written to the output path"
+    )]
+    pub coverage: bool,
+
+    #[structopt(
+        long = "fail-under",
+        help = "Fail when the documentation coverage percent is below this threshold"
+    )]
+    pub fail_under: Option<f64>,
+
+    #[structopt(
+        long = "doctest",
+        conflicts_with = "format",
+        help = "Type-check the lua examples in doc comments instead of generating docs"
+    )]
+    pub doctest: bool,
+}
+
+#[derive(Debug, StructOpt)]
+pub enum Command {
+    /// Compare the api of two versions of a library and report breaking changes
+    Diff {
+        #[structopt(
+            parse(from_os_str),
+            help = "The old version, a json export or the path of the lua project"
+        )]
+        old: std::path::PathBuf,
+
+        #[structopt(
+            parse(from_os_str),
+            help = "The new version, a json export or the path of the lua project"
+        )]
+        new: std::path::PathBuf,
+
+        #[structopt(long = "json", help = "Print the changes as json")]
+        json: bool,
+    },
 }
 
-#[derive(Debug, Eq, PartialEq, StructOpt)]
+#[derive(Debug, Eq, PartialEq, StructOpt, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Format {
     Markdown,
     Json,
+    Html,
+    Meta,
 }
 
 impl FromStr for Format {
@@ -56,7 +124,9 @@ impl FromStr for Format {
         match s.to_lowercase().as_ref() {
             "markdown" => Ok(Self::Markdown),
             "json" => Ok(Self::Json),
-            _ => Err("Invalid format, must be one of markdown, json"),
+            "html" => Ok(Self::Html),
+            "meta" => Ok(Self::Meta),
+            _ => Err("Invalid format, must be one of markdown, json, html, meta"),
         }
     }
 }
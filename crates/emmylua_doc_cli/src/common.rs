@@ -1,4 +1,8 @@
+use std::{collections::HashMap, path::PathBuf};
+
 use emmylua_code_analysis::{humanize_type, DbIndex, LuaType, RenderLevel};
+use include_dir::Dir;
+use tera::Tera;
 
 pub fn render_typ(db: &DbIndex, typ: &LuaType) -> String {
     match typ {
@@ -6,6 +10,16 @@ pub fn render_typ(db: &DbIndex, typ: &LuaType) -> String {
         LuaType::FloatConst(_) => "number".to_string(),
         LuaType::StringConst(_) => "string".to_string(),
         LuaType::BooleanConst(_) => "boolean".to_string(),
+        // the exported types are named by their full name, `humanize_type` drops the namespace
+        LuaType::Generic(generic) => {
+            let base_id = generic.get_base_type_id();
+            let params = generic
+                .get_params()
+                .iter()
+                .map(|param| render_typ(db, param))
+                .collect::<Vec<_>>();
+            format!("{}<{}>", base_id.get_name(), params.join(","))
+        }
         _ => humanize_type(db, typ, RenderLevel::Simple),
     }
 }
@@ -20,3 +34,98 @@ pub fn render_const(typ: &LuaType) -> Option<String> {
         _ => None,
     }
 }
+
+/// Exported types are rendered for reading, `fun(a: A) -> R` is `fun(a: A): R` in annotations
+pub fn annotation_type(typ: &str) -> String {
+    typ.replace(" -> ", ": ")
+}
+
+/// Format the type names in a rendered type such as `List<Node>|nil` with `format_name`, e.g. as
+/// links to their pages, and the text between them with `format_text`
+pub fn format_type_names(
+    text: &str,
+    format_name: impl Fn(&str) -> String,
+    format_text: impl Fn(&str) -> String,
+) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(is_name_start) {
+        result.push_str(&format_text(&rest[..start]));
+        let name_len = rest[start..]
+            .find(|c: char| !is_name_char(c))
+            .unwrap_or(rest.len() - start);
+        let name = rest[start..start + name_len].trim_end_matches('.');
+        result.push_str(&format_name(name));
+        rest = &rest[start + name.len()..];
+    }
+    result.push_str(&format_text(rest));
+    result
+}
+
+fn is_name_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '.'
+}
+
+pub fn escape_type_name(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            // Windows Invalid Characters
+            if "<>:\"/\\|?*".contains(c) {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+/// Load the templates of `template_dir`, files in `override_template` replace the ones with the
+/// same name
+pub fn init_tera(template_dir: &Dir, override_template: Option<PathBuf>) -> Option<Tera> {
+    let mut tera = Tera::default();
+    let mut files: HashMap<String, String> = template_dir
+        .files()
+        .map(|file| {
+            let path = file.path().to_string_lossy().into_owned();
+            let content = file.contents_utf8().unwrap().to_string();
+            (path, content)
+        })
+        .collect();
+
+    if let Some(override_template) = override_template {
+        if !override_template.exists() {
+            eprintln!(
+                "Override template directory does not exist: {:?}",
+                override_template
+            );
+            return None;
+        }
+
+        if override_template.is_dir() {
+            for entry in walkdir::WalkDir::new(&override_template)
+                .into_iter()
+                .filter_map(|e| e.ok())
+                .filter(|e| e.file_type().is_file())
+            {
+                let path = entry.path();
+                let content = std::fs::read_to_string(path).expect("Failed to read file");
+                let template_path = path.file_name().unwrap().to_str().unwrap().to_string();
+                files.insert(template_path, content);
+            }
+        }
+    }
+
+    match tera.add_raw_templates(files) {
+        Ok(_) => {}
+        Err(e) => {
+            eprintln!("Failed to add templates: {}", e);
+            return None;
+        }
+    }
+
+    Some(tera)
+}
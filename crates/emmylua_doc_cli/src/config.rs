@@ -0,0 +1,102 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::{
+    cmd_args::{CmdArgs, Format},
+    markdown_generator::{DocLayout, PageKind},
+};
+
+/// Read from the current directory when `--config` is not given
+pub const DEFAULT_CONFIG_FILE: &str = ".emmydoc.json";
+
+/// The settings of the doc generator, the command line flags take precedence over them
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct DocConfig {
+    pub input: Option<PathBuf>,
+    pub format: Option<Format>,
+    pub output: Option<PathBuf>,
+    pub override_template: Option<PathBuf>,
+    pub mixin: Option<PathBuf>,
+    pub source_url: Option<String>,
+    pub fail_under: Option<f64>,
+    #[serde(default)]
+    pub markdown: MarkdownConfig,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct MarkdownConfig {
+    #[serde(default)]
+    pub layout: DocLayout,
+    /// the `site_name` of `mkdocs.yml`
+    pub site_name: Option<String>,
+    /// the front matter fields of each page kind, the values are templates
+    #[serde(default)]
+    pub front_matter: BTreeMap<PageKind, BTreeMap<String, String>>,
+    /// render the mixin files ending with `.tl` instead of copying them
+    #[serde(default)]
+    pub render_mixin: bool,
+}
+
+impl DocConfig {
+    /// Read `path`, relative paths in it are relative to its directory
+    pub fn load(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read config {:?}: {}", path, e))?;
+        let mut config: DocConfig = serde_json::from_str(&text)
+            .map_err(|e| format!("Failed to parse config {:?}: {}", path, e))?;
+
+        let base = path.parent().unwrap_or(Path::new(""));
+        for path in [
+            &mut config.input,
+            &mut config.output,
+            &mut config.override_template,
+            &mut config.mixin,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if path.is_relative() {
+                *path = base.join(&*path);
+            }
+        }
+        Ok(config)
+    }
+
+    /// Fill the flags that were not given on the command line, returns the markdown settings
+    pub fn apply(self, args: &mut CmdArgs) -> MarkdownConfig {
+        args.input = args.input.take().or(self.input);
+        args.format = args.format.take().or(self.format);
+        args.output = args.output.take().or(self.output);
+        args.override_template = args.override_template.take().or(self.override_template);
+        args.mixin = args.mixin.take().or(self.mixin);
+        args.source_url = args.source_url.take().or(self.source_url);
+        args.fail_under = args.fail_under.or(self.fail_under);
+
+        let mut markdown = self.markdown;
+        if let Some(layout) = args.layout {
+            markdown.layout = layout;
+        }
+        markdown
+    }
+}
+
+/// The config given with `--config`, or the default config file when it exists
+pub fn load_config(args: &CmdArgs) -> Result<DocConfig, Box<dyn std::error::Error>> {
+    match &args.config {
+        Some(path) => DocConfig::load(path),
+        None => {
+            let path = Path::new(DEFAULT_CONFIG_FILE);
+            if path.exists() {
+                DocConfig::load(path)
+            } else {
+                Ok(DocConfig::default())
+            }
+        }
+    }
+}
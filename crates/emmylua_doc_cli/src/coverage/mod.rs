@@ -0,0 +1,165 @@
+mod report;
+#[cfg(test)]
+mod test;
+
+use std::{collections::HashMap, path::PathBuf};
+
+use emmylua_code_analysis::{
+    DbIndex, EmmyLuaAnalysis, LuaMemberKey, LuaMemberOwner, LuaSemanticDeclId, LuaSignature,
+    LuaType, SignatureReturnStatus,
+};
+use emmylua_parser::VisibilityKind;
+use report::{Coverage, CoverageEntry, CoverageReport};
+
+use crate::json_generator::export::module_member_owner;
+
+pub fn generate_coverage(
+    analysis: &mut EmmyLuaAnalysis,
+    output: PathBuf,
+    fail_under: Option<f64>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let db = analysis.compilation.get_db();
+    let report = collect_coverage(db);
+    print!("{}", report.to_table());
+
+    let json_path = if output.extension().is_some_and(|ext| ext == "json") {
+        output
+    } else {
+        if !output.exists() {
+            eprintln!("Creating output directory: {:?}", output);
+            std::fs::create_dir_all(&output)?;
+        }
+
+        output.join("coverage.json")
+    };
+    eprintln!("Writing coverage report to {:?}", json_path);
+    std::fs::write(&json_path, serde_json::to_string_pretty(&report)?)?;
+
+    if let Some(threshold) = fail_under {
+        if report.total.percent < threshold {
+            return Err(format!(
+                "documentation coverage {:.1}% is below the threshold {}%",
+                report.total.percent, threshold
+            )
+            .into());
+        }
+    }
+    Ok(())
+}
+
+/// Count the documentation of the public members of the main workspace modules and classes
+pub fn collect_coverage(db: &DbIndex) -> CoverageReport {
+    let module_index = db.get_module_index();
+    let mut owners = HashMap::new();
+
+    let mut modules = Vec::new();
+    for module in module_index.get_module_infos() {
+        if !module_index.is_main(&module.file_id) {
+            continue;
+        }
+        let Some(owner) = module.export_type.as_ref().and_then(module_member_owner) else {
+            continue;
+        };
+        let coverage = owner_coverage(db, &owner);
+        owners.insert(owner, coverage);
+        modules.push(CoverageEntry::new(
+            module.full_module_name.clone(),
+            coverage,
+        ));
+    }
+
+    let mut classes = Vec::new();
+    for type_decl in db.get_type_index().get_all_types() {
+        let is_main = type_decl
+            .get_locations()
+            .iter()
+            .any(|loc| module_index.is_main(&loc.file_id));
+        if !type_decl.is_class() || !is_main {
+            continue;
+        }
+        let owner = LuaMemberOwner::Type(type_decl.get_id());
+        let coverage = owner_coverage(db, &owner);
+        owners.insert(owner, coverage);
+        classes.push(CoverageEntry::new(
+            type_decl.get_full_name().to_string(),
+            coverage,
+        ));
+    }
+
+    modules.sort_by(|a, b| a.name.cmp(&b.name));
+    classes.sort_by(|a, b| a.name.cmp(&b.name));
+    let mut total = Coverage::default();
+    for coverage in owners.values() {
+        total.add(coverage);
+    }
+
+    CoverageReport {
+        modules,
+        classes,
+        total: CoverageEntry::new("total".to_string(), total),
+    }
+}
+
+fn owner_coverage(db: &DbIndex, owner: &LuaMemberOwner) -> Coverage {
+    let mut coverage = Coverage::default();
+    let Some(members) = db.get_member_index().get_sorted_members(owner) else {
+        return coverage;
+    };
+
+    for member in members {
+        if !matches!(member.get_key(), LuaMemberKey::Name(_)) {
+            continue;
+        }
+        let property = db
+            .get_property_index()
+            .get_property(&LuaSemanticDeclId::Member(member.get_id()));
+        // `@private` and `@package` members are not part of the documented api
+        let visibility = property.and_then(|property| property.visibility);
+        if matches!(
+            visibility,
+            Some(VisibilityKind::Private | VisibilityKind::Package | VisibilityKind::Internal)
+        ) {
+            continue;
+        }
+        let described = property.is_some_and(|property| property.description.is_some());
+
+        let signature = match db.get_type_index().get_type_cache(&member.get_id().into()) {
+            Some(type_cache) => match type_cache.as_type() {
+                LuaType::Signature(signature_id) => db.get_signature_index().get(signature_id),
+                _ => None,
+            },
+            None => None,
+        };
+        match signature {
+            Some(signature) => {
+                coverage.functions.count(described);
+                count_signature(&mut coverage, signature);
+            }
+            None => coverage.fields.count(described),
+        }
+    }
+    coverage
+}
+
+fn count_signature(coverage: &mut Coverage, signature: &LuaSignature) {
+    for (idx, name) in signature.params.iter().enumerate() {
+        if name == "self" {
+            continue;
+        }
+        let param_info = signature.param_docs.get(&idx);
+        coverage.param_types.count(param_info.is_some());
+        coverage
+            .param_descriptions
+            .count(param_info.is_some_and(|param_info| param_info.description.is_some()));
+    }
+
+    // without `@return` the returns are inferred, a function returning nothing needs no doc
+    let has_return_doc = signature.resolve_return == SignatureReturnStatus::DocResolve;
+    let returns_value = signature
+        .return_docs
+        .iter()
+        .any(|return_info| !return_info.type_ref.is_nil());
+    if has_return_doc || returns_value {
+        coverage.return_types.count(has_return_doc);
+    }
+}
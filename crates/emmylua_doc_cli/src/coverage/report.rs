@@ -0,0 +1,159 @@
+use serde::Serialize;
+
+#[derive(Debug, Serialize, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Counter {
+    pub documented: usize,
+    pub total: usize,
+}
+
+impl Counter {
+    pub fn count(&mut self, documented: bool) {
+        self.total += 1;
+        if documented {
+            self.documented += 1;
+        }
+    }
+
+    fn add(&mut self, other: &Counter) {
+        self.documented += other.documented;
+        self.total += other.total;
+    }
+}
+
+#[derive(Debug, Serialize, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Coverage {
+    /// public functions with a description
+    pub functions: Counter,
+    /// public fields with a description
+    pub fields: Counter,
+    /// parameters with a `@param` type
+    pub param_types: Counter,
+    /// parameters with a `@param` description
+    pub param_descriptions: Counter,
+    /// functions returning a value with a `@return` type
+    pub return_types: Counter,
+}
+
+impl Coverage {
+    pub fn add(&mut self, other: &Coverage) {
+        self.functions.add(&other.functions);
+        self.fields.add(&other.fields);
+        self.param_types.add(&other.param_types);
+        self.param_descriptions.add(&other.param_descriptions);
+        self.return_types.add(&other.return_types);
+    }
+
+    fn counters(&self) -> [&Counter; 5] {
+        [
+            &self.functions,
+            &self.fields,
+            &self.param_types,
+            &self.param_descriptions,
+            &self.return_types,
+        ]
+    }
+
+    /// The documented share of everything counted, nothing to document is fully covered
+    pub fn percent(&self) -> f64 {
+        let (documented, total) = self
+            .counters()
+            .iter()
+            .fold((0, 0), |(documented, total), counter| {
+                (documented + counter.documented, total + counter.total)
+            });
+        if total == 0 {
+            100.0
+        } else {
+            documented as f64 * 100.0 / total as f64
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CoverageEntry {
+    pub name: String,
+    #[serde(flatten)]
+    pub coverage: Coverage,
+    pub percent: f64,
+}
+
+impl CoverageEntry {
+    pub fn new(name: String, coverage: Coverage) -> Self {
+        Self {
+            name,
+            percent: coverage.percent(),
+            coverage,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CoverageReport {
+    pub modules: Vec<CoverageEntry>,
+    pub classes: Vec<CoverageEntry>,
+    /// everything in `modules` and `classes`, a module returning a class is counted once
+    pub total: CoverageEntry,
+}
+
+const HEADERS: [&str; 7] = [
+    "Name",
+    "Functions",
+    "Fields",
+    "Param types",
+    "Param docs",
+    "Return types",
+    "Coverage",
+];
+
+impl CoverageReport {
+    pub fn to_table(&self) -> String {
+        let mut rows = Vec::new();
+        for (kind, entries) in [("module", &self.modules), ("class", &self.classes)] {
+            for entry in entries {
+                rows.push(table_row(&format!("{} {}", kind, entry.name), entry));
+            }
+        }
+        rows.push(table_row("total", &self.total));
+
+        let mut widths = HEADERS.map(str::len);
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.len());
+            }
+        }
+
+        let mut table = String::new();
+        let header = HEADERS.map(str::to_string);
+        for row in std::iter::once(&header).chain(&rows) {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(widths)
+                .enumerate()
+                .map(|(i, (cell, width))| {
+                    if i == 0 {
+                        format!("{:<width$}", cell)
+                    } else {
+                        format!("{:>width$}", cell)
+                    }
+                })
+                .collect();
+            table.push_str(cells.join("  ").trim_end());
+            table.push('\n');
+        }
+        table
+    }
+}
+
+fn table_row(name: &str, entry: &CoverageEntry) -> [String; 7] {
+    let cell = |counter: &Counter| format!("{}/{}", counter.documented, counter.total);
+    let coverage = &entry.coverage;
+    [
+        name.to_string(),
+        cell(&coverage.functions),
+        cell(&coverage.fields),
+        cell(&coverage.param_types),
+        cell(&coverage.param_descriptions),
+        cell(&coverage.return_types),
+        format!("{:.1}%", entry.percent),
+    ]
+}
@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod tests {
+    use crate::test_lib::TempWorkspace;
+
+    use super::super::{
+        collect_coverage, generate_coverage,
+        report::{Counter, Coverage},
+    };
+
+    const SOURCE: &str = r#"
+---@class Parser
+---@field source string the text being parsed
+---@field pos integer
+local Parser = {}
+
+---Parse the next token
+---@param kind string the expected kind
+---@param strict boolean
+---@return boolean
+function Parser:next(kind, strict)
+    return true
+end
+
+function Parser:reset(pos)
+    self.pos = pos
+    return self
+end
+
+function Parser:skip()
+end
+
+---@private
+function Parser:advance(n)
+end
+
+return Parser
+"#;
+
+    fn counter(documented: usize, total: usize) -> Counter {
+        Counter { documented, total }
+    }
+
+    #[test]
+    fn test_coverage() {
+        let workspace = TempWorkspace::new("coverage");
+        workspace.write("parser.lua", SOURCE);
+        let analysis = workspace.load();
+
+        let report = collect_coverage(analysis.compilation.get_db());
+        let expected = Coverage {
+            functions: counter(1, 3),
+            fields: counter(1, 2),
+            param_types: counter(2, 3),
+            param_descriptions: counter(1, 3),
+            return_types: counter(1, 2),
+        };
+        assert_eq!(report.classes.len(), 1);
+        assert_eq!(report.classes[0].name, "Parser");
+        assert_eq!(report.classes[0].coverage, expected);
+        assert_eq!(report.modules.len(), 1);
+        assert_eq!(report.modules[0].name, "parser");
+        assert_eq!(report.modules[0].coverage, expected);
+        // the module returns the class, so its members are counted once
+        assert_eq!(report.total.coverage, expected);
+        assert_eq!(report.total.percent, 6.0 * 100.0 / 13.0);
+
+        let table = report.to_table();
+        assert!(table.contains("class Parser"));
+        assert!(table.lines().last().unwrap().ends_with("46.2%"));
+    }
+
+    #[test]
+    fn test_coverage_json_output() {
+        let workspace = TempWorkspace::new("coverage_json");
+        workspace.write("parser.lua", SOURCE);
+        let mut analysis = workspace.load();
+
+        let output = workspace.root().join("out").join("cov.json");
+        std::fs::create_dir_all(output.parent().unwrap()).unwrap();
+        let res = generate_coverage(&mut analysis, output.clone(), Some(50.0));
+
+        assert!(output.is_file());
+        assert!(res.is_err());
+    }
+}
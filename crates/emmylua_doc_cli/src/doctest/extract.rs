@@ -0,0 +1,135 @@
+use emmylua_code_analysis::LuaDocument;
+use emmylua_parser::{LuaAstNode, LuaChunk, LuaComment};
+use lsp_types::Position;
+
+/// A Lua example from a doc comment, a fenced `lua` block or a `---@usage` line
+#[derive(Debug, PartialEq)]
+pub struct DocExample {
+    pub lines: Vec<ExampleLine>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ExampleLine {
+    /// the 0-based line in the documented file
+    pub line: usize,
+    /// the byte column where the code starts in that line, after the `---` prefix
+    pub col: usize,
+    pub text: String,
+}
+
+impl DocExample {
+    pub fn source(&self) -> String {
+        let lines: Vec<&str> = self.lines.iter().map(|line| line.text.as_str()).collect();
+        lines.join("\n")
+    }
+
+    /// The 0-based line and byte column in the documented file of a position in the example,
+    /// whose `character` counts UTF-16 code units like every LSP position
+    pub fn source_position(&self, position: Position) -> (usize, usize) {
+        match self.lines.get(position.line as usize) {
+            Some(line) => (
+                line.line,
+                line.col + utf16_to_byte_offset(&line.text, position.character as usize),
+            ),
+            None => match self.lines.last() {
+                Some(line) => (line.line, line.col + line.text.len()),
+                None => (0, 0),
+            },
+        }
+    }
+}
+
+pub fn extract_examples(chunk: &LuaChunk, document: &LuaDocument) -> Vec<DocExample> {
+    let mut examples = Vec::new();
+    for comment in chunk.descendants::<LuaComment>() {
+        let range = comment.get_range();
+        let (Some(start), Some(end)) = (
+            document.get_line(range.start()),
+            document.get_line(range.end()),
+        ) else {
+            continue;
+        };
+
+        // `Some` inside a lua fence, other languages are skipped
+        let mut block: Option<Vec<ExampleLine>> = None;
+        let mut in_other_fence = false;
+        for line in start..=end {
+            let Some(line_range) = document.get_line_range(line) else {
+                continue;
+            };
+            let text = document
+                .get_text_slice(line_range)
+                .trim_end_matches(['\r', '\n']);
+            let Some((col, content)) = doc_line_content(text) else {
+                continue;
+            };
+
+            if let Some(language) = content.trim_start().strip_prefix("```") {
+                if let Some(lines) = block.take() {
+                    push_example(&mut examples, lines);
+                } else if in_other_fence {
+                    in_other_fence = false;
+                } else if matches!(language.trim(), "" | "lua") {
+                    block = Some(Vec::new());
+                } else {
+                    in_other_fence = true;
+                }
+                continue;
+            }
+
+            if let Some(lines) = &mut block {
+                lines.push(ExampleLine {
+                    line,
+                    col,
+                    text: content.to_string(),
+                });
+            } else if !in_other_fence {
+                if let Some(usage) = content.strip_prefix("@usage") {
+                    let code = usage.trim();
+                    if !code.is_empty() {
+                        let code_col = col + content.len() - usage.trim_start().len();
+                        push_example(
+                            &mut examples,
+                            vec![ExampleLine {
+                                line,
+                                col: code_col,
+                                text: code.to_string(),
+                            }],
+                        );
+                    }
+                }
+            }
+        }
+
+        // an unclosed fence ends with its comment
+        if let Some(lines) = block {
+            push_example(&mut examples, lines);
+        }
+    }
+    examples
+}
+
+fn push_example(examples: &mut Vec<DocExample>, lines: Vec<ExampleLine>) {
+    if lines.iter().any(|line| !line.text.trim().is_empty()) {
+        examples.push(DocExample { lines });
+    }
+}
+
+fn utf16_to_byte_offset(text: &str, character: usize) -> usize {
+    let mut units = 0;
+    for (offset, c) in text.char_indices() {
+        if units >= character {
+            return offset;
+        }
+        units += c.len_utf16();
+    }
+    text.len()
+}
+
+// the text after `---` and one space, with its column, `None` for lines that are not doc comment
+fn doc_line_content(text: &str) -> Option<(usize, &str)> {
+    let trimmed = text.trim_start();
+    let rest = trimmed.strip_prefix("---")?;
+    let content = rest.strip_prefix(' ').unwrap_or(rest);
+    Some((text.len() - content.len(), content))
+}
@@ -0,0 +1,120 @@
+mod extract;
+#[cfg(test)]
+mod test;
+
+use std::path::{Path, PathBuf};
+
+use emmylua_code_analysis::{file_path_to_uri, EmmyLuaAnalysis};
+use extract::{extract_examples, DocExample};
+use lsp_types::{DiagnosticSeverity, NumberOrString};
+use tokio_util::sync::CancellationToken;
+
+// the examples are virtual files in the workspace, so they can `require` its modules
+const EXAMPLE_DIR: &str = "__doctest__";
+
+/// A diagnostic of a doc example, at its location in the documented file
+#[derive(Debug)]
+pub struct DocProblem {
+    pub file: PathBuf,
+    /// 0-based
+    pub line: usize,
+    /// 0-based, in bytes
+    pub col: usize,
+    pub severity: DiagnosticSeverity,
+    pub code: Option<String>,
+    pub message: String,
+}
+
+pub fn run_doctests(
+    analysis: &mut EmmyLuaAnalysis,
+    input: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (count, problems) = check_examples(analysis, input);
+    for problem in &problems {
+        let file = problem.file.strip_prefix(input).unwrap_or(&problem.file);
+        let severity = if problem.severity == DiagnosticSeverity::ERROR {
+            "error"
+        } else {
+            "warning"
+        };
+        let code = problem
+            .code
+            .as_ref()
+            .map(|code| format!(" [{}]", code))
+            .unwrap_or_default();
+        println!(
+            "{}:{}:{}: {}: {}{}",
+            file.to_string_lossy().replace('\\', "/"),
+            problem.line + 1,
+            problem.col + 1,
+            severity,
+            problem.message,
+            code
+        );
+    }
+
+    eprintln!("Checked {} doc examples", count);
+    if !problems.is_empty() {
+        return Err(format!("{} problems in doc examples", problems.len()).into());
+    }
+    Ok(())
+}
+
+/// Type-check the examples in the doc comments of the main workspace, returns how many were
+/// checked and the errors and warnings found in them
+pub fn check_examples(analysis: &mut EmmyLuaAnalysis, input: &Path) -> (usize, Vec<DocProblem>) {
+    let db = analysis.compilation.get_db();
+    let vfs = db.get_vfs();
+    let mut file_ids = db.get_module_index().get_main_workspace_file_ids();
+    file_ids.sort();
+
+    let mut examples: Vec<(PathBuf, DocExample)> = Vec::new();
+    for file_id in file_ids {
+        let (Some(tree), Some(document)) =
+            (vfs.get_syntax_tree(&file_id), vfs.get_document(&file_id))
+        else {
+            continue;
+        };
+        for example in extract_examples(&tree.get_chunk_node(), &document) {
+            examples.push((document.get_file_path().clone(), example));
+        }
+    }
+
+    // each example is loaded alone, so a global defined in one is undefined in the others
+    let mut problems = Vec::new();
+    for (i, (file, example)) in examples.iter().enumerate() {
+        let path = input.join(EXAMPLE_DIR).join(format!("example_{}.lua", i));
+        let Some(uri) = file_path_to_uri(&path) else {
+            continue;
+        };
+        let Some(file_id) = analysis.update_file_by_uri(&uri, Some(example.source())) else {
+            continue;
+        };
+        let diagnostics = analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .unwrap_or_default();
+        analysis.remove_file_by_uri(&uri);
+        for diagnostic in diagnostics {
+            let Some(severity) = diagnostic.severity else {
+                continue;
+            };
+            if severity != DiagnosticSeverity::ERROR && severity != DiagnosticSeverity::WARNING {
+                continue;
+            }
+            let (line, col) = example.source_position(diagnostic.range.start);
+            problems.push(DocProblem {
+                file: file.clone(),
+                line,
+                col,
+                severity,
+                code: diagnostic.code.map(|code| match code {
+                    NumberOrString::String(code) => code,
+                    NumberOrString::Number(code) => code.to_string(),
+                }),
+                message: diagnostic.message.trim_end().to_string(),
+            });
+        }
+    }
+
+    (examples.len(), problems)
+}
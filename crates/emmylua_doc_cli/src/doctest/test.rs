@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use crate::test_lib::TempWorkspace;
+
+    use super::super::check_examples;
+
+    const SOURCE: &str = r#"local M = {}
+
+---Add two numbers
+---
+---```lua
+---local calc = require("calc")
+---local n = calc.add(1, 2)
+---```
+---@param a integer
+---@param b integer
+---@return integer
+function M.add(a, b)
+    return a + b
+end
+
+---@usage require("calc").sub("x", 1)
+---@param a integer
+---@param b integer
+---@return integer
+function M.sub(a, b)
+    return a - b
+end
+
+---```text
+---calc.other("not lua")
+---```
+function M.other()
+end
+
+return M
+"#;
+
+    #[test]
+    fn test_check_examples() {
+        let workspace = TempWorkspace::new("doctest");
+        let file = workspace.write("calc.lua", SOURCE);
+        let mut analysis = workspace.load();
+        let (count, problems) = check_examples(&mut analysis, workspace.root());
+
+        assert_eq!(count, 2);
+        assert_eq!(problems.len(), 1);
+        let problem = &problems[0];
+        assert_eq!(problem.file, file);
+        assert_eq!(problem.code.as_deref(), Some("param-type-not-match"));
+        // the `"x"` argument of the `---@usage` line
+        assert_eq!((problem.line, problem.col), (15, 30));
+    }
+
+    #[test]
+    fn test_check_examples_isolated() {
+        let source = r#"local M = {}
+
+---```lua
+---shared = 1
+---```
+function M.set()
+end
+
+---```lua
+---local s = "é"; print(shared + 1)
+---```
+function M.get()
+end
+
+return M
+"#;
+        let workspace = TempWorkspace::new("doctest_isolated");
+        workspace.write("shared.lua", source);
+        let mut analysis = workspace.load();
+        let (count, problems) = check_examples(&mut analysis, workspace.root());
+
+        assert_eq!(count, 2);
+        let undefined: Vec<_> = problems
+            .iter()
+            .filter(|problem| problem.code.as_deref() == Some("undefined-global"))
+            .collect();
+        assert_eq!(undefined.len(), 1);
+        // the byte column of `shared` after the two byte `é`
+        assert_eq!((undefined[0].line, undefined[0].col), (9, 25));
+    }
+}
@@ -0,0 +1,93 @@
+use serde::Serialize;
+
+// the `html` fields are rendered already, urls are relative to the output root
+
+#[derive(Debug, Serialize, Default)]
+pub struct Nav {
+    pub modules: Vec<LinkDoc>,
+    pub types: Vec<LinkDoc>,
+    pub globals: Vec<LinkDoc>,
+}
+
+#[derive(Debug, Serialize, Default, Clone)]
+pub struct LinkDoc {
+    pub name: String,
+    pub url: String,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct SourceDoc {
+    pub text: String,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct PropertyDoc {
+    pub description_html: Option<String>,
+    pub see_html: Option<String>,
+    pub deprecated: bool,
+    pub deprecation_reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct TypeDoc {
+    pub name: String,
+    pub kind: &'static str,
+    pub property: PropertyDoc,
+    pub generics_html: Option<String>,
+    pub alias_html: Option<String>,
+    pub inheritance_html: Option<String>,
+    pub subclasses: Vec<LinkDoc>,
+    pub sources: Vec<SourceDoc>,
+    pub fields: Vec<MemberDoc>,
+    pub methods: Vec<MemberDoc>,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct ModuleDoc {
+    pub name: String,
+    pub property: PropertyDoc,
+    pub source: Option<SourceDoc>,
+    pub fields: Vec<MemberDoc>,
+    pub methods: Vec<MemberDoc>,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct GlobalDoc {
+    pub name: String,
+    pub anchor: String,
+    pub signature_html: Option<String>,
+    pub property: PropertyDoc,
+    pub source: Option<SourceDoc>,
+    pub fields: Vec<MemberDoc>,
+    pub methods: Vec<MemberDoc>,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct MemberDoc {
+    pub name: String,
+    /// `Owner.member`, also for a member of a module or global
+    pub anchor: String,
+    pub kind: &'static str,
+    pub signature_html: String,
+    pub property: PropertyDoc,
+    pub params: Vec<ParamDoc>,
+    pub returns: Vec<ParamDoc>,
+    pub overloads_html: Vec<String>,
+    pub source: Option<SourceDoc>,
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct ParamDoc {
+    pub name: String,
+    pub typ_html: Option<String>,
+    pub description_html: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SearchEntry {
+    pub name: String,
+    pub kind: &'static str,
+    pub url: String,
+    pub summary: Option<String>,
+}
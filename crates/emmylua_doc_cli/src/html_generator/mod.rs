@@ -0,0 +1,348 @@
+mod html_types;
+mod render;
+#[cfg(test)]
+mod test;
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use emmylua_code_analysis::EmmyLuaAnalysis;
+use html_types::{GlobalDoc, LinkDoc, MemberDoc, ModuleDoc, Nav, SearchEntry, TypeDoc};
+use include_dir::{include_dir, Dir};
+use render::{global_url, module_url, type_url, HtmlRender};
+use tera::{Context, Tera};
+
+use crate::{
+    common::init_tera,
+    json_generator::{
+        accessors::{base_type_name, global_name, member_property, type_name},
+        export::export,
+        json_types::{Global, Member, Module, Property, Type},
+    },
+};
+
+static TEMPLATE_DIR: Dir = include_dir!("$CARGO_MANIFEST_DIR/template/html");
+
+// rendered without a context, so they can be overridden like the pages
+const ASSETS: [&str; 2] = ["style.css", "search.js"];
+
+pub fn generate_html(
+    analysis: &mut EmmyLuaAnalysis,
+    input: &Path,
+    output: PathBuf,
+    override_template: Option<PathBuf>,
+    source_url: Option<String>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    for dir in ["types", "modules"] {
+        let dir = output.join(dir);
+        if dir.exists() {
+            eprintln!("Clearing directory: {:?}", dir);
+            std::fs::remove_dir_all(&dir)?;
+        } else {
+            eprintln!("Creating directory: {:?}", dir);
+        }
+        std::fs::create_dir_all(&dir)?;
+    }
+
+    let tl = init_tera(&TEMPLATE_DIR, override_template).ok_or("Failed to initialize TL")?;
+    let db = analysis.compilation.get_db();
+    let index = export(db);
+    let type_names = index
+        .types
+        .iter()
+        .map(|typ| type_name(typ).to_string())
+        .collect();
+    let render = HtmlRender::new(db, type_names, input, source_url.as_deref());
+    let nav = build_nav(&index.types, &index.modules, &index.globals);
+    let mut search_index = Vec::new();
+
+    let bases_of: HashMap<String, Vec<String>> = index
+        .types
+        .iter()
+        .filter_map(|typ| match typ {
+            Type::Class(class) => Some((class.name.clone(), class.bases.clone())),
+            _ => None,
+        })
+        .collect();
+    let mut subclasses_of: HashMap<&str, Vec<LinkDoc>> = HashMap::new();
+    for (name, bases) in &bases_of {
+        for base in bases {
+            subclasses_of
+                .entry(base_type_name(base))
+                .or_default()
+                .push(LinkDoc {
+                    name: name.clone(),
+                    url: type_url(name),
+                });
+        }
+    }
+
+    // pages in `types` and `modules`
+    let root = "../";
+    for typ in &index.types {
+        let (kind, property, members, locs) = match typ {
+            Type::Class(class) => ("class", &class.property, &class.members, &class.loc),
+            Type::Enum(enum_) => ("enum", &enum_.property, &enum_.members, &enum_.loc),
+            Type::Alias(alias) => ("alias", &alias.property, &alias.members, &alias.loc),
+        };
+        let name = type_name(typ);
+        let mut doc = TypeDoc {
+            name: name.to_string(),
+            kind,
+            property: render.property(property, root),
+            sources: locs.iter().map(|loc| render.source(loc)).collect(),
+            ..Default::default()
+        };
+        match typ {
+            Type::Class(class) => {
+                let generics: Vec<String> = class
+                    .generics
+                    .iter()
+                    .map(|generic| match &generic.base {
+                        Some(base) => format!("{}: {}", generic.name, base),
+                        None => generic.name.clone(),
+                    })
+                    .collect();
+                if !generics.is_empty() {
+                    doc.generics_html =
+                        Some(render.type_html(&format!("<{}>", generics.join(", ")), root));
+                }
+                doc.inheritance_html = render.inheritance_html(&class.bases, &bases_of, root);
+                doc.subclasses = subclasses_of.remove(name).unwrap_or_default();
+                doc.subclasses.sort_by(|a, b| a.name.cmp(&b.name));
+            }
+            Type::Enum(enum_) => {
+                doc.alias_html = enum_.typ.as_ref().map(|typ| render.type_html(typ, root));
+            }
+            Type::Alias(alias) => {
+                doc.alias_html = alias.typ.as_ref().map(|typ| render.type_html(typ, root));
+            }
+        }
+        (doc.fields, doc.methods) = render_members(&render, members, name, root);
+
+        let url = type_url(name);
+        search_index.push(SearchEntry {
+            name: name.to_string(),
+            kind,
+            url: url.clone(),
+            summary: summary(property),
+        });
+        add_member_entries(&mut search_index, &url, name, members);
+        let mut context = page_context(&nav, root, name);
+        context.insert("doc", &doc);
+        render_page(&tl, "type.html", &context, &output.join(&url))?;
+    }
+
+    for module in &index.modules {
+        // members are shown as used after `local socket = require("net.socket")`
+        let local_name = module.name.rsplit('.').next().unwrap_or(&module.name);
+        let mut doc = ModuleDoc {
+            name: module.name.clone(),
+            property: render.property(&module.property, root),
+            source: module.file.as_ref().map(|file| render.file_source(file)),
+            ..Default::default()
+        };
+        (doc.fields, doc.methods) = render_members(&render, &module.members, local_name, root);
+
+        let url = module_url(&module.name);
+        search_index.push(SearchEntry {
+            name: module.name.clone(),
+            kind: "module",
+            url: url.clone(),
+            summary: summary(&module.property),
+        });
+        add_member_entries(&mut search_index, &url, local_name, &module.members);
+        let mut context = page_context(&nav, root, &module.name);
+        context.insert("doc", &doc);
+        render_page(&tl, "module.html", &context, &output.join(&url))?;
+    }
+
+    // pages in the output root
+    let root = "";
+    let mut globals = Vec::new();
+    for global in &index.globals {
+        let doc = match global {
+            Global::Table(table) => {
+                let mut doc = GlobalDoc {
+                    name: table.name.clone(),
+                    anchor: table.name.clone(),
+                    property: render.property(&table.property, root),
+                    source: table.loc.as_ref().map(|loc| render.source(loc)),
+                    ..Default::default()
+                };
+                (doc.fields, doc.methods) =
+                    render_members(&render, &table.members, &table.name, root);
+                search_index.push(SearchEntry {
+                    name: table.name.clone(),
+                    kind: "global",
+                    url: global_url(&table.name),
+                    summary: summary(&table.property),
+                });
+                add_member_entries(
+                    &mut search_index,
+                    "globals.html",
+                    &table.name,
+                    &table.members,
+                );
+                doc
+            }
+            Global::Field(field) => {
+                let signature_html = render.field_signature_html(
+                    "",
+                    &field.name,
+                    &field.typ,
+                    field.literal.as_deref(),
+                    root,
+                );
+                search_index.push(SearchEntry {
+                    name: field.name.clone(),
+                    kind: "global",
+                    url: global_url(&field.name),
+                    summary: summary(&field.property),
+                });
+                GlobalDoc {
+                    name: field.name.clone(),
+                    anchor: field.name.clone(),
+                    signature_html: Some(signature_html),
+                    property: render.property(&field.property, root),
+                    source: field.loc.as_ref().map(|loc| render.source(loc)),
+                    ..Default::default()
+                }
+            }
+        };
+        globals.push(doc);
+    }
+    globals.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut context = page_context(&nav, root, "Globals");
+    context.insert("globals", &globals);
+    render_page(&tl, "globals.html", &context, &output.join("globals.html"))?;
+
+    let context = page_context(&nav, root, "Index");
+    render_page(&tl, "index.html", &context, &output.join("index.html"))?;
+
+    for asset in ASSETS {
+        render_page(&tl, asset, &Context::new(), &output.join(asset))?;
+    }
+
+    // a script rather than json, so searching also works for pages opened from disk
+    search_index.sort_by(|a, b| a.name.cmp(&b.name));
+    let search_index_js = format!(
+        "window.searchIndex = {};\n",
+        serde_json::to_string(&search_index)?
+    );
+    std::fs::write(output.join("search_index.js"), search_index_js)?;
+
+    eprintln!("Writing HTML output to {:?}", output);
+    Ok(())
+}
+
+fn build_nav(types: &[Type], modules: &[Module], globals: &[Global]) -> Nav {
+    let mut nav = Nav {
+        modules: modules
+            .iter()
+            .map(|module| LinkDoc {
+                name: module.name.clone(),
+                url: module_url(&module.name),
+            })
+            .collect(),
+        types: types
+            .iter()
+            .map(|typ| LinkDoc {
+                name: type_name(typ).to_string(),
+                url: type_url(type_name(typ)),
+            })
+            .collect(),
+        globals: globals
+            .iter()
+            .map(|global| LinkDoc {
+                name: global_name(global).to_string(),
+                url: global_url(global_name(global)),
+            })
+            .collect(),
+    };
+    nav.modules.sort_by(|a, b| a.name.cmp(&b.name));
+    nav.types.sort_by(|a, b| a.name.cmp(&b.name));
+    nav.globals.sort_by(|a, b| a.name.cmp(&b.name));
+    nav
+}
+
+// the fields and methods a reader can use, like the markdown output
+fn render_members(
+    render: &HtmlRender,
+    members: &[Member],
+    owner: &str,
+    root: &str,
+) -> (Vec<MemberDoc>, Vec<MemberDoc>) {
+    let mut fields = Vec::new();
+    let mut methods = Vec::new();
+    for member in members
+        .iter()
+        .filter(|member| is_public(member_property(member)))
+    {
+        let doc = render.member(member, owner, root);
+        match member {
+            Member::Fn(_) => methods.push(doc),
+            Member::Field(_) => fields.push(doc),
+        }
+    }
+    (fields, methods)
+}
+
+fn add_member_entries(
+    search_index: &mut Vec<SearchEntry>,
+    page_url: &str,
+    owner: &str,
+    members: &[Member],
+) {
+    for member in members
+        .iter()
+        .filter(|member| is_public(member_property(member)))
+    {
+        let (name, kind) = match member {
+            Member::Fn(func) => (&func.name, "method"),
+            Member::Field(field) => (&field.name, "field"),
+        };
+        let anchor = format!("{}.{}", owner, name);
+        search_index.push(SearchEntry {
+            url: format!("{}#{}", page_url, anchor),
+            name: anchor,
+            kind,
+            summary: summary(member_property(member)),
+        });
+    }
+}
+
+fn is_public(property: &Property) -> bool {
+    matches!(property.visibility.as_deref(), None | Some("public"))
+}
+
+// the first line of the description, for search results
+fn summary(property: &Property) -> Option<String> {
+    let line = property.description.as_ref()?.lines().next()?.trim();
+    (!line.is_empty()).then(|| line.to_string())
+}
+
+fn page_context(nav: &Nav, root: &str, title: &str) -> Context {
+    let mut context = Context::new();
+    context.insert("nav", nav);
+    context.insert("root", root);
+    context.insert("title", title);
+    context
+}
+
+fn render_page(
+    tl: &Tera,
+    template: &str,
+    context: &Context,
+    path: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let content = tl.render(template, context)?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, content)?;
+    Ok(())
+}
@@ -0,0 +1,383 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+};
+
+use emmylua_code_analysis::{resolve_doc_reference, DbIndex, LuaMemberOwner, LuaSemanticDeclId};
+use emmylua_parser::{parse_description_items, LuaDescriptionItemKind};
+
+use crate::{
+    common::{escape_type_name, format_type_names},
+    json_generator::{
+        accessors::base_type_name,
+        json_types::{Fn, FnParam, Loc, Member, Property},
+    },
+};
+
+use super::html_types::{MemberDoc, ParamDoc, PropertyDoc, SourceDoc};
+
+pub fn type_url(name: &str) -> String {
+    format!("types/{}.html", escape_type_name(name))
+}
+
+pub fn module_url(name: &str) -> String {
+    format!("modules/{}.html", escape_type_name(name))
+}
+
+pub fn global_url(name: &str) -> String {
+    format!("globals.html#{}", name)
+}
+
+pub fn escape_html(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '&' => result.push_str("&amp;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&#39;"),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// Renders the exported docs as html, every `root` is the path from the page to the output root
+pub struct HtmlRender<'a> {
+    db: &'a DbIndex,
+    type_names: HashSet<String>,
+    source_root: &'a Path,
+    source_url: Option<&'a str>,
+}
+
+impl<'a> HtmlRender<'a> {
+    pub fn new(
+        db: &'a DbIndex,
+        type_names: HashSet<String>,
+        source_root: &'a Path,
+        source_url: Option<&'a str>,
+    ) -> Self {
+        Self {
+            db,
+            type_names,
+            source_root,
+            source_url,
+        }
+    }
+
+    /// Escape a rendered type, the names of documented types link to their page
+    pub fn type_html(&self, text: &str, root: &str) -> String {
+        format_type_names(
+            text,
+            |name| {
+                if self.type_names.contains(name) {
+                    format!(
+                        "<a class=\"type\" href=\"{}{}\">{}</a>",
+                        root,
+                        type_url(name),
+                        escape_html(name)
+                    )
+                } else {
+                    escape_html(name)
+                }
+            },
+            escape_html,
+        )
+    }
+
+    /// Escape a description, `[Class.member]` and `@see` references link to their page and code
+    /// spans and blocks become `<code>`, any other Markdown is kept as text
+    pub fn description_html(&self, text: &str, root: &str) -> String {
+        let mut result = String::with_capacity(text.len());
+        let mut last = 0;
+        for item in parse_description_items(text) {
+            result.push_str(&escape_html(&text[last..item.range.start]));
+            let item_text = &text[item.range.clone()];
+            match &item.kind {
+                LuaDescriptionItemKind::Reference(path) => {
+                    result.push_str(&self.reference_html(path, root));
+                }
+                LuaDescriptionItemKind::See(path) => {
+                    result.push_str("See ");
+                    result.push_str(&self.reference_html(path, root));
+                }
+                LuaDescriptionItemKind::CodeSpan => {
+                    let code = item_text.trim_matches('`');
+                    result.push_str(&format!("<code>{}</code>", escape_html(code)));
+                }
+                LuaDescriptionItemKind::CodeBlock(language) => {
+                    // drop the fences, an unclosed block has no closing one
+                    let mut lines: Vec<&str> = item_text.lines().skip(1).collect();
+                    if lines.last().is_some_and(|line| line.trim() == "```") {
+                        lines.pop();
+                    }
+                    result.push_str(&format!(
+                        "<pre><code class=\"language-{}\">{}</code></pre>",
+                        language.as_deref().unwrap_or("lua"),
+                        escape_html(&lines.join("\n"))
+                    ));
+                }
+            }
+            last = item.range.end;
+        }
+        result.push_str(&escape_html(&text[last..]));
+        result
+    }
+
+    /// The content of a `@see` tag, the reference before any text links to its page
+    pub fn see_html(&self, see: &str, root: &str) -> String {
+        let (path, rest) = see.split_once(' ').unwrap_or((see, ""));
+        if rest.is_empty() {
+            self.reference_html(path, root)
+        } else {
+            format!("{} {}", self.reference_html(path, root), escape_html(rest))
+        }
+    }
+
+    fn reference_html(&self, path: &str, root: &str) -> String {
+        match self.reference_url(path) {
+            Some(url) => format!(
+                "<a href=\"{}{}\"><code>{}</code></a>",
+                root,
+                url,
+                escape_html(path)
+            ),
+            None => format!("<code>{}</code>", escape_html(path)),
+        }
+    }
+
+    fn reference_url(&self, path: &str) -> Option<String> {
+        match resolve_doc_reference(self.db, path)? {
+            LuaSemanticDeclId::TypeDecl(type_decl_id) => {
+                let name = type_decl_id.get_name();
+                self.type_names.contains(name).then(|| type_url(name))
+            }
+            LuaSemanticDeclId::Member(member_id) => {
+                let member_index = self.db.get_member_index();
+                let member = member_index.get_member(&member_id)?;
+                let LuaMemberOwner::Type(type_decl_id) =
+                    member_index.get_current_owner(&member_id)?
+                else {
+                    return None;
+                };
+                let name = type_decl_id.get_name();
+                if !self.type_names.contains(name) {
+                    return None;
+                }
+                Some(format!(
+                    "{}#{}.{}",
+                    type_url(name),
+                    name,
+                    member.get_key().get_name()?
+                ))
+            }
+            LuaSemanticDeclId::LuaDecl(decl_id) => {
+                let decl = self.db.get_decl_index().get_decl(&decl_id)?;
+                decl.is_global().then(|| global_url(decl.get_name()))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn property(&self, property: &Property, root: &str) -> PropertyDoc {
+        PropertyDoc {
+            description_html: property
+                .description
+                .as_ref()
+                .map(|description| self.description_html(description, root)),
+            see_html: property.see.as_ref().map(|see| self.see_html(see, root)),
+            deprecated: property.deprecated,
+            deprecation_reason: property.deprecation_reason.clone(),
+        }
+    }
+
+    /// The file and line relative to the project, it links to `source_url` when one is given
+    pub fn source(&self, loc: &Loc) -> SourceDoc {
+        let mut source = self.file_source(&loc.file);
+        source.text = format!("{}:{}", source.text, loc.line);
+        if let Some(url) = &mut source.url {
+            url.push_str(&format!("#L{}", loc.line));
+        }
+        source
+    }
+
+    pub fn file_source(&self, file: &Path) -> SourceDoc {
+        let path = file
+            .strip_prefix(self.source_root)
+            .unwrap_or(file)
+            .to_string_lossy()
+            .replace('\\', "/");
+        SourceDoc {
+            url: self
+                .source_url
+                .map(|source_url| format!("{}/{}", source_url.trim_end_matches('/'), path)),
+            text: path,
+        }
+    }
+
+    pub fn member(&self, member: &Member, owner: &str, root: &str) -> MemberDoc {
+        match member {
+            Member::Fn(func) => MemberDoc {
+                name: func.name.clone(),
+                anchor: format!("{}.{}", owner, func.name),
+                kind: "method",
+                signature_html: self.signature_html(func, owner, root),
+                property: self.property(&func.property, root),
+                params: func
+                    .params
+                    .iter()
+                    .map(|param| self.param(param, root))
+                    .collect(),
+                returns: func
+                    .returns
+                    .iter()
+                    .map(|ret| self.param(ret, root))
+                    .collect(),
+                overloads_html: func
+                    .overloads
+                    .iter()
+                    .map(|overload| self.type_html(overload, root))
+                    .collect(),
+                source: func.loc.as_ref().map(|loc| self.source(loc)),
+            },
+            Member::Field(field) => MemberDoc {
+                name: field.name.clone(),
+                anchor: format!("{}.{}", owner, field.name),
+                kind: "field",
+                signature_html: self.field_signature_html(
+                    owner,
+                    &field.name,
+                    &field.typ,
+                    field.literal.as_deref(),
+                    root,
+                ),
+                property: self.property(&field.property, root),
+                source: field.loc.as_ref().map(|loc| self.source(loc)),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// `owner.name: type = literal`, a global field has an empty owner
+    pub fn field_signature_html(
+        &self,
+        owner: &str,
+        name: &str,
+        typ: &str,
+        literal: Option<&str>,
+        root: &str,
+    ) -> String {
+        let mut result = format!(
+            "{}{}: {}",
+            owner_prefix(owner, "."),
+            escape_html(name),
+            self.type_html(typ, root)
+        );
+        if let Some(literal) = literal {
+            result.push_str(&format!(" = {}", escape_html(literal)));
+        }
+        result
+    }
+
+    fn signature_html(&self, func: &Fn, owner: &str, root: &str) -> String {
+        let mut result = String::new();
+        if func.is_async {
+            result.push_str("async ");
+        }
+        result.push_str("function ");
+        result.push_str(&owner_prefix(owner, if func.is_meth { ":" } else { "." }));
+        result.push_str(&escape_html(&func.name));
+        if !func.generics.is_empty() {
+            let generics: Vec<String> = func
+                .generics
+                .iter()
+                .map(|generic| escape_html(&generic.name))
+                .collect();
+            result.push_str(&format!("&lt;{}&gt;", generics.join(", ")));
+        }
+
+        let params: Vec<String> = func
+            .params
+            .iter()
+            .map(|param| {
+                let name = escape_html(param.name.as_deref().unwrap_or("_"));
+                match &param.typ {
+                    Some(typ) => format!("{}: {}", name, self.type_html(typ, root)),
+                    None => name,
+                }
+            })
+            .collect();
+        result.push_str(&format!("({})", params.join(", ")));
+
+        let returns: Vec<String> = func
+            .returns
+            .iter()
+            .filter_map(|ret| ret.typ.as_ref())
+            .map(|typ| self.type_html(typ, root))
+            .collect();
+        if !returns.is_empty() {
+            result.push_str(&format!(" -&gt; {}", returns.join(", ")));
+        }
+        result
+    }
+
+    fn param(&self, param: &FnParam, root: &str) -> ParamDoc {
+        ParamDoc {
+            name: param.name.clone().unwrap_or_default(),
+            typ_html: param.typ.as_ref().map(|typ| self.type_html(typ, root)),
+            description_html: param
+                .desc
+                .as_ref()
+                .map(|desc| self.description_html(desc, root)),
+        }
+    }
+
+    /// The bases of a class as a tree, each base with its own bases below it
+    pub fn inheritance_html(
+        &self,
+        bases: &[String],
+        bases_of: &HashMap<String, Vec<String>>,
+        root: &str,
+    ) -> Option<String> {
+        if bases.is_empty() {
+            return None;
+        }
+        let mut visited = HashSet::new();
+        Some(self.base_tree_html(bases, bases_of, root, &mut visited))
+    }
+
+    fn base_tree_html(
+        &self,
+        bases: &[String],
+        bases_of: &HashMap<String, Vec<String>>,
+        root: &str,
+        visited: &mut HashSet<String>,
+    ) -> String {
+        let mut result = String::from("<ul class=\"tree\">");
+        for base in bases {
+            result.push_str("<li><span class=\"tree-node\">");
+            result.push_str(&self.type_html(base, root));
+            result.push_str("</span>");
+            let base_name = base_type_name(base);
+            if visited.insert(base_name.to_string()) {
+                if let Some(base_bases) = bases_of.get(base_name) {
+                    if !base_bases.is_empty() {
+                        result.push_str(&self.base_tree_html(base_bases, bases_of, root, visited));
+                    }
+                }
+            }
+            result.push_str("</li>");
+        }
+        result.push_str("</ul>");
+        result
+    }
+}
+
+fn owner_prefix(owner: &str, separator: &str) -> String {
+    if owner.is_empty() {
+        String::new()
+    } else {
+        format!("{}{}", escape_html(owner), separator)
+    }
+}
@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{HashMap, HashSet},
+        path::Path,
+    };
+
+    use crate::test_lib::TempWorkspace;
+
+    use super::super::{generate_html, render::HtmlRender};
+
+    const SOURCE: &str = r#"
+---A node of the tree, see [Tree.root]
+---@class Node
+---@field children Node[]
+local Node = {}
+
+---@class Tree
+---@field root Node?
+local Tree = {}
+
+---The tree of a document
+---@class Document: Tree
+local Document = {}
+
+---@see Tree
+---@param node Node
+---@return Document
+function Document:append(node) end
+
+return Document
+"#;
+
+    #[test]
+    fn test_render() {
+        let workspace = TempWorkspace::new("html_render");
+        workspace.write("tree.lua", SOURCE);
+        let analysis = workspace.load();
+
+        let db = analysis.compilation.get_db();
+        let type_names: HashSet<String> = ["Node", "Tree", "Document"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+        let render = HtmlRender::new(db, type_names, Path::new(""), None);
+
+        // documented types link to their page, everything else is escaped
+        assert_eq!(
+            render.type_html("Node[]|table<string, Tree>", "../"),
+            "<a class=\"type\" href=\"../types/Node.html\">Node</a>[]|table&lt;string, \
+             <a class=\"type\" href=\"../types/Tree.html\">Tree</a>&gt;"
+        );
+        assert_eq!(
+            render.type_html("\"a<b>\"|Unknown.", ""),
+            "&quot;a&lt;b&gt;&quot;|Unknown."
+        );
+
+        assert_eq!(
+            render.description_html("A node of [Tree.root], `a<b>` & <i>", "../"),
+            "A node of <a href=\"../types/Tree.html#Tree.root\"><code>Tree.root</code></a>, \
+             <code>a&lt;b&gt;</code> &amp; &lt;i&gt;"
+        );
+        assert_eq!(
+            render.description_html("see [Missing]", ""),
+            "see <code>Missing</code>"
+        );
+        assert_eq!(
+            render.see_html("Tree the base", ""),
+            "<a href=\"types/Tree.html\"><code>Tree</code></a> the base"
+        );
+
+        // a cycle in the bases is shown once instead of recursing forever
+        let bases_of = HashMap::from([
+            ("Node".to_string(), vec!["Tree".to_string()]),
+            ("Tree".to_string(), vec!["Node".to_string()]),
+        ]);
+        let tree = render
+            .inheritance_html(&["Tree".to_string()], &bases_of, "")
+            .unwrap();
+        assert_eq!(tree.matches("types/Tree.html").count(), 2);
+        assert_eq!(tree.matches("types/Node.html").count(), 1);
+        assert!(render.inheritance_html(&[], &bases_of, "").is_none());
+    }
+
+    #[test]
+    fn test_generate_html() {
+        let workspace = TempWorkspace::new("html");
+        let src = workspace.root().join("src");
+        workspace.write("src/tree.lua", SOURCE);
+        let mut analysis = workspace.load_dir(&src);
+        let output = workspace.root().join("output");
+        generate_html(
+            &mut analysis,
+            &src,
+            output.clone(),
+            None,
+            Some("https://example.com/src".to_string()),
+        )
+        .unwrap();
+        let page = std::fs::read_to_string(output.join("types/Document.html")).unwrap();
+        let node_page = std::fs::read_to_string(output.join("types/Node.html")).unwrap();
+        let search_index = std::fs::read_to_string(output.join("search_index.js")).unwrap();
+
+        assert!(output.join("style.css").is_file() && output.join("index.html").is_file());
+        assert!(page.contains("The tree of a document"));
+        assert!(page.contains("See <a href=\"../types/Tree.html\"><code>Tree</code></a>"));
+        assert!(node_page
+            .contains("see <a href=\"../types/Tree.html#Tree.root\"><code>Tree.root</code></a>"));
+        assert!(page.contains("Document.append"));
+        assert!(page.contains("<a class=\"type\" href=\"../types/Node.html\">Node</a>"));
+        // tera escapes the `/` of attributes
+        assert!(page.contains("example.com&#x2F;src&#x2F;tree.lua#L12\">tree.lua:12</a>"));
+        assert!(search_index.starts_with("window.searchIndex = ["));
+    }
+}
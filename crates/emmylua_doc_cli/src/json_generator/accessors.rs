@@ -0,0 +1,35 @@
+use super::json_types::{Global, Member, Property, Type};
+
+pub fn type_name(typ: &Type) -> &str {
+    match typ {
+        Type::Class(class) => &class.name,
+        Type::Enum(enum_) => &enum_.name,
+        Type::Alias(alias) => &alias.name,
+    }
+}
+
+pub fn global_name(global: &Global) -> &str {
+    match global {
+        Global::Table(table) => &table.name,
+        Global::Field(field) => &field.name,
+    }
+}
+
+pub fn member_name(member: &Member) -> &str {
+    match member {
+        Member::Fn(func) => &func.name,
+        Member::Field(field) => &field.name,
+    }
+}
+
+pub fn member_property(member: &Member) -> &Property {
+    match member {
+        Member::Fn(func) => &func.property,
+        Member::Field(field) => &field.property,
+    }
+}
+
+/// The type name of a rendered base such as `List<T>`
+pub fn base_type_name(base: &str) -> &str {
+    base.split('<').next().unwrap_or(base).trim()
+}
@@ -2,7 +2,7 @@ use crate::common::{render_const, render_typ};
 use crate::json_generator::json_types::*;
 use emmylua_code_analysis::{
     DbIndex, FileId, LuaDeprecated, LuaMemberKey, LuaMemberOwner, LuaNoDiscard, LuaSemanticDeclId,
-    LuaSignature, LuaType, LuaTypeCache, LuaTypeDecl, Vfs,
+    LuaSignature, LuaType, LuaTypeCache, LuaTypeDecl, LuaUnionType, Vfs,
 };
 use rowan::TextRange;
 
@@ -23,21 +23,7 @@ fn export_modules(db: &DbIndex) -> Vec<Module> {
         .into_iter()
         .filter(|module| module_index.is_main(&module.file_id))
         .filter_map(|module| {
-            let members = match module.export_type.as_ref()? {
-                LuaType::Def(type_id) => {
-                    let member_owner = LuaMemberOwner::Type(type_id.clone());
-                    export_members(db, member_owner)
-                }
-                LuaType::TableConst(t) => {
-                    let member_owner = LuaMemberOwner::Element(t.clone());
-                    export_members(db, member_owner)
-                }
-                LuaType::Instance(i) => {
-                    let member_owner = LuaMemberOwner::Element(i.get_range().clone());
-                    export_members(db, member_owner)
-                }
-                _ => return None,
-            };
+            let members = export_members(db, module_member_owner(module.export_type.as_ref()?)?);
 
             let property = module
                 .property_owner_id
@@ -55,6 +41,16 @@ fn export_modules(db: &DbIndex) -> Vec<Module> {
         .collect()
 }
 
+/// The owner of the members a module exports, `None` when the module does not return a table
+pub(crate) fn module_member_owner(export_type: &LuaType) -> Option<LuaMemberOwner> {
+    match export_type {
+        LuaType::Def(type_id) => Some(LuaMemberOwner::Type(type_id.clone())),
+        LuaType::TableConst(t) => Some(LuaMemberOwner::Element(t.clone())),
+        LuaType::Instance(i) => Some(LuaMemberOwner::Element(i.get_range().clone())),
+        _ => None,
+    }
+}
+
 fn export_types(db: &DbIndex) -> Vec<Type> {
     let type_index = db.get_type_index();
     let module_index = db.get_module_index();
@@ -140,9 +136,10 @@ fn export_class(db: &DbIndex, type_decl: &LuaTypeDecl) -> Class {
             .map(|v| v.as_slice())
             .unwrap_or_default()
             .iter()
-            .map(|(name, typ)| TypeVar {
-                name: name.clone(),
-                base: typ.as_ref().map(|typ| render_typ(db, typ)),
+            .map(|param| TypeVar {
+                name: param.name.clone(),
+                base: param.constraint.as_ref().map(|typ| render_typ(db, typ)),
+                default: param.default.as_ref().map(|typ| render_typ(db, typ)),
             })
             .collect(),
         members: export_members(db, member_owner),
@@ -172,13 +169,43 @@ fn export_enum(db: &DbIndex, type_decl: &LuaTypeDecl) -> Enum {
         name: type_decl.get_full_name().to_string(),
         property,
         loc: export_loc_for_type(db, type_decl),
-        typ: type_decl
-            .get_enum_field_type(db)
-            .map(|typ| render_typ(db, &typ)),
+        typ: export_enum_type(db, type_decl),
         members: export_members(db, member_owner),
     }
 }
 
+/// The union of the enum values in declaration order, like `get_enum_field_type` which follows the
+/// arbitrary order of the member index
+fn export_enum_type(db: &DbIndex, type_decl: &LuaTypeDecl) -> Option<String> {
+    let members = db
+        .get_member_index()
+        .get_sorted_members(&LuaMemberOwner::Type(type_decl.get_id()))?;
+    let types = members
+        .into_iter()
+        .filter_map(|member| {
+            if type_decl.is_enum_key() {
+                return match member.get_key() {
+                    LuaMemberKey::Name(name) => Some(LuaType::DocStringConst(name.clone().into())),
+                    LuaMemberKey::Integer(i) => Some(LuaType::IntegerConst(*i)),
+                    LuaMemberKey::None | LuaMemberKey::Expr(_) => None,
+                };
+            }
+            let type_cache = db
+                .get_type_index()
+                .get_type_cache(&member.get_id().into())?;
+            Some(match type_cache.as_type() {
+                LuaType::StringConst(s) => LuaType::DocStringConst(s.clone()),
+                LuaType::IntegerConst(i) => LuaType::DocIntegerConst(*i),
+                typ => typ.clone(),
+            })
+        })
+        .collect();
+    Some(render_typ(
+        db,
+        &LuaType::Union(LuaUnionType::new(types).into()),
+    ))
+}
+
 fn export_members(db: &DbIndex, member_owner: LuaMemberOwner) -> Vec<Member> {
     let member_index = db.get_member_index();
     let type_index = db.get_type_index();
@@ -235,9 +262,10 @@ fn export_signature(
         generics: signature
             .generic_params
             .iter()
-            .map(|(name, typ)| TypeVar {
-                name: name.clone(),
-                base: typ.as_ref().map(|typ| render_typ(db, typ)),
+            .map(|param| TypeVar {
+                name: param.name.clone(),
+                base: param.constraint.as_ref().map(|typ| render_typ(db, typ)),
+                default: param.default.as_ref().map(|typ| render_typ(db, typ)),
             })
             .collect(),
         params: signature
@@ -136,6 +136,7 @@ pub struct Property {
 pub struct TypeVar {
     pub name: String,
     pub base: Option<String>,
+    pub default: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Default)]
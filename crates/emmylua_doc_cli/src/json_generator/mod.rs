@@ -1,8 +1,9 @@
 use emmylua_code_analysis::EmmyLuaAnalysis;
 use std::path::PathBuf;
 
-mod export;
-mod json_types;
+pub(crate) mod accessors;
+pub(crate) mod export;
+pub(crate) mod json_types;
 
 pub fn generate_json(
     analysis: &mut EmmyLuaAnalysis,
@@ -1,31 +1,76 @@
-use crate::cmd_args::Format;
+use crate::cmd_args::{Command, Format};
 use cmd_args::CmdArgs;
-use std::process::exit;
+use std::{path::PathBuf, process::exit};
 use structopt::StructOpt;
 
+mod api_diff;
 mod cmd_args;
 mod common;
+mod config;
+mod coverage;
+mod doctest;
+mod html_generator;
 mod init;
 mod json_generator;
 mod markdown_generator;
+mod meta_generator;
+#[cfg(test)]
+mod test_lib;
 
 fn main() {
-    let args = CmdArgs::from_args();
-    let mut input = args.input;
+    let mut args = CmdArgs::from_args();
+    if let Some(Command::Diff { old, new, json }) = &args.command {
+        if let Err(err) = api_diff::run_diff(old, new, *json) {
+            eprintln!("Error: {}", err);
+            exit(1);
+        }
+        return;
+    }
+
+    let markdown_config = match config::load_config(&args) {
+        Ok(config) => config.apply(&mut args),
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            exit(1);
+        }
+    };
+    let Some(mut input) = args.input else {
+        structopt::clap::Error::with_description(
+            "The following required arguments were not provided: --input <input>",
+            structopt::clap::ErrorKind::MissingRequiredArgument,
+        )
+        .exit();
+    };
     if input.is_relative() {
         input = std::env::current_dir().ok().unwrap().join(&input);
     }
 
+    let output = args.output.unwrap_or_else(|| PathBuf::from("./output"));
     let analysis = init::load_workspace(vec![input.to_str().unwrap()]);
     if let Some(mut analysis) = analysis {
-        let res = match args.format {
-            Format::Markdown => markdown_generator::generate_markdown(
-                &mut analysis,
-                args.output,
-                args.override_template,
-                args.mixin,
-            ),
-            Format::Json => json_generator::generate_json(&mut analysis, args.output),
+        let res = if args.doctest {
+            doctest::run_doctests(&mut analysis, &input)
+        } else if args.coverage {
+            coverage::generate_coverage(&mut analysis, output, args.fail_under)
+        } else {
+            match args.format.unwrap_or(Format::Markdown) {
+                Format::Markdown => markdown_generator::generate_markdown(
+                    &mut analysis,
+                    output,
+                    args.override_template,
+                    args.mixin,
+                    &markdown_config,
+                ),
+                Format::Json => json_generator::generate_json(&mut analysis, output),
+                Format::Html => html_generator::generate_html(
+                    &mut analysis,
+                    &input,
+                    output,
+                    args.override_template,
+                    args.source_url,
+                ),
+                Format::Meta => meta_generator::generate_meta(&mut analysis, &input, output),
+            }
         };
 
         if let Err(err) = res {
@@ -0,0 +1,105 @@
+use std::{collections::HashMap, sync::Arc};
+
+use tera::{Filter, Tera, Value};
+
+use crate::common::format_type_names;
+
+use super::markdown_types::{DocLayout, Page, PageKind, SignatureDoc};
+
+/// Register the filters templates can use besides the builtin ones:
+/// - `type_link` links the documented types in a rendered type, such as `doc.supers`
+/// - `signature(link=false)` formats the `signature` of a function member or global
+pub fn register_filters(tera: &mut Tera, pages: &[Page], layout: DocLayout) {
+    let links: HashMap<String, String> = pages
+        .iter()
+        .filter(|page| {
+            matches!(
+                page.kind,
+                PageKind::Class | PageKind::Enum | PageKind::Alias
+            )
+        })
+        .map(|page| (page.name.clone(), layout.link(&page.file, None)))
+        .collect();
+    let type_links = TypeLinks(Arc::new(links));
+    tera.register_filter("type_link", TypeLinkFilter(type_links.clone()));
+    tera.register_filter("signature", SignatureFilter(type_links));
+}
+
+#[derive(Clone)]
+struct TypeLinks(Arc<HashMap<String, String>>);
+
+impl TypeLinks {
+    fn link_types(&self, text: &str) -> String {
+        format_type_names(
+            text,
+            |name| match self.0.get(name) {
+                Some(link) => format!("[{}]({})", name, link),
+                None => name.to_string(),
+            },
+            str::to_string,
+        )
+    }
+}
+
+struct TypeLinkFilter(TypeLinks);
+
+impl Filter for TypeLinkFilter {
+    fn filter(&self, value: &Value, _: &HashMap<String, Value>) -> tera::Result<Value> {
+        let text = value
+            .as_str()
+            .ok_or_else(|| tera::Error::msg("Filter `type_link` expects a string"))?;
+        Ok(Value::String(self.0.link_types(text)))
+    }
+}
+
+struct SignatureFilter(TypeLinks);
+
+impl Filter for SignatureFilter {
+    fn filter(&self, value: &Value, args: &HashMap<String, Value>) -> tera::Result<Value> {
+        let signature: SignatureDoc = serde_json::from_value(value.clone())
+            .map_err(|_| tera::Error::msg("Filter `signature` expects a `signature` object"))?;
+        let link = args.get("link").and_then(Value::as_bool).unwrap_or(false);
+        let typ = |typ: &str| {
+            if link {
+                self.0.link_types(typ)
+            } else {
+                typ.to_string()
+            }
+        };
+
+        let params: Vec<String> = signature
+            .params
+            .iter()
+            .map(|param| {
+                let name = param.name.as_deref().unwrap_or("_");
+                match &param.typ {
+                    Some(param_typ) => format!("{}: {}", name, typ(param_typ)),
+                    None => name.to_string(),
+                }
+            })
+            .collect();
+        let returns: Vec<String> = signature
+            .returns
+            .iter()
+            .filter_map(|ret| {
+                let ret_typ = typ(ret.typ.as_deref()?);
+                Some(match &ret.name {
+                    Some(name) => format!("{}: {}", name, ret_typ),
+                    None => ret_typ,
+                })
+            })
+            .collect();
+
+        let mut result = format!(
+            "{}function {}({})",
+            if signature.is_async { "async " } else { "" },
+            signature.name,
+            params.join(", ")
+        );
+        if !returns.is_empty() {
+            result.push_str(" -> ");
+            result.push_str(&returns.join(", "));
+        }
+        Ok(Value::String(result))
+    }
+}
@@ -0,0 +1,52 @@
+use std::collections::BTreeMap;
+
+use tera::{Context, Tera};
+
+use super::markdown_types::PageKind;
+
+/// The front matter of each page kind from the config, every value is a template rendered with
+/// the context of the page
+pub struct FrontMatter {
+    templates: BTreeMap<PageKind, Vec<(String, String)>>,
+}
+
+impl FrontMatter {
+    pub fn register(
+        tera: &mut Tera,
+        config: &BTreeMap<PageKind, BTreeMap<String, String>>,
+    ) -> Result<Self, tera::Error> {
+        let mut templates = BTreeMap::new();
+        for (kind, fields) in config {
+            let mut names = Vec::new();
+            for (key, value) in fields {
+                let name = format!("front_matter/{}/{}", kind.as_str(), key);
+                tera.add_raw_template(&name, value)?;
+                names.push((key.clone(), name));
+            }
+            templates.insert(*kind, names);
+        }
+        Ok(Self { templates })
+    }
+
+    /// The `---` delimited YAML block that starts a page, empty when none is configured
+    pub fn render(&self, tera: &Tera, kind: PageKind, context: &Context) -> Option<String> {
+        let Some(names) = self.templates.get(&kind) else {
+            return Some(String::new());
+        };
+
+        let mut fields = BTreeMap::new();
+        for (key, name) in names {
+            match tera.render(name, context) {
+                Ok(value) => {
+                    fields.insert(key.as_str(), value);
+                }
+                Err(e) => {
+                    eprintln!("Failed to render front matter `{}`: {}", key, e);
+                    return None;
+                }
+            }
+        }
+        let yaml = serde_yml::to_string(&fields).ok()?;
+        Some(format!("---\n{}---\n\n", yaml))
+    }
+}
@@ -1,75 +1,52 @@
-use std::path::Path;
-
 use emmylua_code_analysis::{
     humanize_type, DbIndex, LuaDecl, LuaDeclId, LuaMemberOwner, LuaSemanticDeclId, LuaType,
     RenderLevel,
 };
-use tera::Tera;
 
 use crate::markdown_generator::{
     escape_type_name,
     gen::mod_gen::generate_member_owner_module,
-    markdown_types::{Doc, IndexStruct, MkdocsIndex},
-    render::{render_const_type, render_function_type},
+    markdown_types::{Doc, DocLayout, Page, PageKind},
+    render::{function_signature, render_const_type, render_function_type},
 };
 
 use super::collect_property;
 
 pub fn generate_global_markdown(
     db: &DbIndex,
-    tl: &Tera,
     decl_id: &LuaDeclId,
-    output: &Path,
-    mkdocs_index: &mut MkdocsIndex,
-) -> Option<()> {
+    layout: DocLayout,
+) -> Option<Page> {
     check_filter(db, decl_id)?;
 
-    let mut context = tera::Context::new();
     let mut doc = Doc::default();
 
     let decl = db.get_decl_index().get_decl(decl_id)?;
     let name = decl.get_name();
     doc.name = name.to_string();
-    doc.property = collect_property(db, LuaSemanticDeclId::LuaDecl(decl.get_id()));
+    doc.property = collect_property(db, layout, LuaSemanticDeclId::LuaDecl(decl.get_id()));
 
     let decl_type = db.get_type_index().get_type_cache(&(*decl_id).into())?;
     let mut template_name = "lua_global_template.tl";
     match decl_type.as_type() {
         LuaType::TableConst(table) => {
             let member_owner = LuaMemberOwner::Element(table.clone());
-            generate_member_owner_module(db, member_owner, name, &mut doc)?;
+            generate_member_owner_module(db, member_owner, name, &mut doc, layout)?;
         }
         _ => {
             template_name = "lua_global_template_simple.tl";
-            generate_simple_global(db, decl, &mut doc);
+            generate_simple_global(db, decl, &mut doc, layout);
         }
     }
-    context.insert("doc", &doc);
-
-    let render_text = match tl.render(template_name, &context) {
-        Ok(text) => text,
-        Err(e) => {
-            eprintln!("Failed to render template: {}", e);
-            return None;
-        }
-    };
-
-    let file_name = format!("{}.md", escape_type_name(decl.get_name()));
-    mkdocs_index.globals.push(IndexStruct {
-        name: decl.get_name().to_string(),
-        file: format!("globals/{}", file_name.clone()),
-    });
 
-    let outpath = output.join(file_name);
-    eprintln!("output global file: {}", outpath.display());
-    match std::fs::write(outpath, render_text) {
-        Ok(_) => {}
-        Err(e) => {
-            eprintln!("Failed to write file: {}", e);
-            return None;
-        }
-    }
-    Some(())
+    Some(Page {
+        kind: PageKind::Global,
+        name: name.to_string(),
+        title: name.to_string(),
+        template: template_name,
+        file: format!("globals/{}.md", escape_type_name(name)),
+        doc,
+    })
 }
 
 fn check_filter(db: &DbIndex, decl_id: &LuaDeclId) -> Option<()> {
@@ -87,15 +64,21 @@ fn check_filter(db: &DbIndex, decl_id: &LuaDeclId) -> Option<()> {
     Some(())
 }
 
-fn generate_simple_global(db: &DbIndex, decl: &LuaDecl, doc: &mut Doc) -> Option<()> {
+fn generate_simple_global(
+    db: &DbIndex,
+    decl: &LuaDecl,
+    doc: &mut Doc,
+    layout: DocLayout,
+) -> Option<()> {
     let semantic_decl = LuaSemanticDeclId::LuaDecl(decl.get_id());
-    doc.property = collect_property(db, semantic_decl);
+    doc.property = collect_property(db, layout, semantic_decl);
 
     let name = decl.get_name();
     let ty = db.get_type_index().get_type_cache(&decl.get_id().into())?;
     if ty.is_function() {
         let display = render_function_type(db, ty, name, false);
         doc.display = Some(display);
+        doc.signature = function_signature(db, ty, name);
     } else if ty.is_const() {
         let typ_display = render_const_type(db, ty);
         let display = format!("```lua\n{}: {}\n```\n", name, typ_display);
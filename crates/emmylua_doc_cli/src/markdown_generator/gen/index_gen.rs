@@ -1,17 +1,34 @@
-use tera::Tera;
+use std::path::Path;
 
-use crate::markdown_generator::markdown_types::MkdocsIndex;
+use tera::{Context, Tera};
 
-pub fn generate_index(
-    tl: &Tera,
-    mkdocs: &mut MkdocsIndex,
-    output: &std::path::PathBuf,
-) -> Option<()> {
-    let mut context = tera::Context::new();
+use crate::{
+    config::MarkdownConfig,
+    markdown_generator::{
+        front_matter::FrontMatter,
+        markdown_types::{page_anchor, IndexStruct, MkdocsIndex, Page, PageKind, PageSection},
+    },
+};
+
+/// The context of the index, `mkdocs.yml` and the mixin templates
+pub fn index_context(pages: &[Page], config: &MarkdownConfig) -> Context {
+    let mut mkdocs = MkdocsIndex::default();
+    for page in pages {
+        let entry = IndexStruct {
+            name: page.title.clone(),
+            file: page.file.clone(),
+        };
+        match page.kind {
+            PageKind::Module => mkdocs.modules.push(entry),
+            PageKind::Global => mkdocs.globals.push(entry),
+            _ => mkdocs.types.push(entry),
+        }
+    }
     mkdocs.types.sort_by(|a, b| a.name.cmp(&b.name));
     mkdocs.modules.sort_by(|a, b| a.name.cmp(&b.name));
     mkdocs.globals.sort_by(|a, b| a.name.cmp(&b.name));
 
+    let mut context = Context::new();
     if !mkdocs.types.is_empty() {
         context.insert("types", &mkdocs.types);
     }
@@ -21,19 +38,96 @@ pub fn generate_index(
     if !mkdocs.globals.is_empty() {
         context.insert("globals", &mkdocs.globals);
     }
-    let index_path = output.join("docs/index.md");
-    let index_text = match tl.render("index_template.tl", &context) {
+    if let Some(site_name) = &config.site_name {
+        context.insert("site_name", site_name);
+    }
+    context
+}
+
+pub fn generate_index(
+    tl: &Tera,
+    front_matter: &FrontMatter,
+    context: &Context,
+    output: &Path,
+) -> Option<()> {
+    let index_text = match tl.render("index_template.tl", context) {
         Ok(text) => text,
         Err(e) => {
             eprintln!("Failed to render index: {}", e);
             return None;
         }
     };
+    let index_front_matter = front_matter.render(tl, PageKind::Index, context)?;
+    let index_path = output.join("docs/index.md");
+    std::fs::write(index_path, index_front_matter + &index_text).ok()?;
 
-    std::fs::write(index_path, index_text).ok()?;
+    generate_mkdocs_yml(tl, context, output)
+}
+
+/// Write the rendered pages to `docs/index.md`
+pub fn generate_single_page(
+    tl: &Tera,
+    front_matter: &FrontMatter,
+    rendered: Vec<(&Page, String)>,
+    config: &MarkdownConfig,
+    output: &Path,
+) -> Option<()> {
+    let mut types = Vec::new();
+    let mut modules = Vec::new();
+    let mut globals = Vec::new();
+    for (page, content) in rendered {
+        let section = PageSection {
+            name: page.title.clone(),
+            anchor: page_anchor(&page.file),
+            content,
+        };
+        match page.kind {
+            PageKind::Module => modules.push(section),
+            PageKind::Global => globals.push(section),
+            _ => types.push(section),
+        }
+    }
+    types.sort_by(|a, b| a.name.cmp(&b.name));
+    modules.sort_by(|a, b| a.name.cmp(&b.name));
+    globals.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut context = Context::new();
+    if !types.is_empty() {
+        context.insert("types", &types);
+    }
+    if !modules.is_empty() {
+        context.insert("modules", &modules);
+    }
+    if !globals.is_empty() {
+        context.insert("globals", &globals);
+    }
+    if let Some(site_name) = &config.site_name {
+        context.insert("site_name", site_name);
+    }
+
+    let text = match tl.render("single_page_template.tl", &context) {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("Failed to render single page: {}", e);
+            return None;
+        }
+    };
+    let index_front_matter = front_matter.render(tl, PageKind::Index, &context)?;
+    let index_path = output.join("docs/index.md");
+    eprintln!("output single page file: {}", index_path.display());
+    std::fs::write(index_path, index_front_matter + &text).ok()?;
+
+    // the nav only has the single page
+    let mut mkdocs_context = Context::new();
+    if let Some(site_name) = &config.site_name {
+        mkdocs_context.insert("site_name", site_name);
+    }
+    generate_mkdocs_yml(tl, &mkdocs_context, output)
+}
 
+fn generate_mkdocs_yml(tl: &Tera, context: &Context, output: &Path) -> Option<()> {
     let mkdocs_yml_path = output.join("mkdocs.yml");
-    let mkdocs_yml_text = match tl.render("mkdocs_template.tl", &context) {
+    let mkdocs_yml_text = match tl.render("mkdocs_template.tl", context) {
         Ok(text) => text,
         Err(e) => {
             eprintln!("Failed to render mkdocs.yml: {}", e);
@@ -3,24 +3,30 @@ mod index_gen;
 mod mod_gen;
 mod typ_gen;
 
-use emmylua_code_analysis::{DbIndex, LuaDeprecated, LuaSemanticDeclId};
+use emmylua_code_analysis::{
+    render_doc_description, render_doc_see, DbIndex, LuaDeprecated, LuaMemberOwner,
+    LuaSemanticDeclId,
+};
 pub use global_gen::generate_global_markdown;
-pub use index_gen::generate_index;
+pub use index_gen::{generate_index, generate_single_page, index_context};
 pub use mod_gen::generate_module_markdown;
 pub use typ_gen::generate_type_markdown;
 
-use super::markdown_types::Property;
+use super::{
+    escape_type_name,
+    markdown_types::{DocLayout, Property},
+};
 
-fn collect_property(db: &DbIndex, semantic_decl: LuaSemanticDeclId) -> Property {
+fn collect_property(db: &DbIndex, layout: DocLayout, semantic_decl: LuaSemanticDeclId) -> Property {
     let mut doc_property = Property::default();
     let property = db.get_property_index().get_property(&semantic_decl);
     if let Some(property) = property {
         if let Some(description) = property.description.clone() {
-            doc_property.description = Some(description.to_string());
+            doc_property.description = Some(render_description(db, layout, &description));
         }
 
         if let Some(see) = property.see_content.clone() {
-            doc_property.see = Some(see.to_string());
+            doc_property.see = Some(render_see(db, layout, &see));
         }
 
         if let Some(deprecated) = &property.deprecated {
@@ -40,3 +46,71 @@ fn collect_property(db: &DbIndex, semantic_decl: LuaSemanticDeclId) -> Property
 
     doc_property
 }
+
+// references in descriptions link to the generated page of their target
+fn render_description(db: &DbIndex, layout: DocLayout, text: &str) -> String {
+    render_doc_description(db, text, "@see", |semantic_id| {
+        get_reference_link(db, layout, semantic_id)
+    })
+}
+
+fn render_see(db: &DbIndex, layout: DocLayout, see: &str) -> String {
+    render_doc_see(db, see, |semantic_id| {
+        get_reference_link(db, layout, semantic_id)
+    })
+}
+
+fn get_reference_link(
+    db: &DbIndex,
+    layout: DocLayout,
+    semantic_id: LuaSemanticDeclId,
+) -> Option<String> {
+    match semantic_id {
+        LuaSemanticDeclId::TypeDecl(type_decl_id) => Some(layout.link(
+            &format!("types/{}.md", escape_type_name(type_decl_id.get_name())),
+            None,
+        )),
+        LuaSemanticDeclId::Member(member_id) => {
+            let member_index = db.get_member_index();
+            let member = member_index.get_member(&member_id)?;
+            let LuaMemberOwner::Type(type_decl_id) = member_index.get_current_owner(&member_id)?
+            else {
+                return None;
+            };
+            // member headings are `Class.member`
+            let heading = format!(
+                "{}.{}",
+                type_decl_id.get_simple_name(),
+                member.get_key().get_name()?
+            );
+            Some(layout.link(
+                &format!("types/{}.md", escape_type_name(type_decl_id.get_name())),
+                Some(&heading_anchor(&heading)),
+            ))
+        }
+        LuaSemanticDeclId::LuaDecl(decl_id) => {
+            let decl = db.get_decl_index().get_decl(&decl_id)?;
+            if !decl.is_global() {
+                return None;
+            }
+            Some(layout.link(
+                &format!("globals/{}.md", escape_type_name(decl.get_name())),
+                None,
+            ))
+        }
+        _ => None,
+    }
+}
+
+// the anchor mkdocs gives a heading
+fn heading_anchor(heading: &str) -> String {
+    heading
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c),
+            _ => None,
+        })
+        .collect()
+}
@@ -1,35 +1,29 @@
-use std::path::Path;
-
 use emmylua_code_analysis::{
     humanize_type, DbIndex, FileId, LuaMemberKey, LuaMemberOwner, LuaSemanticDeclId, LuaType,
     LuaTypeCache, ModuleInfo, RenderLevel,
 };
 use emmylua_parser::VisibilityKind;
-use tera::Tera;
 
 use crate::markdown_generator::{
     escape_type_name,
-    markdown_types::{Doc, IndexStruct, MemberDoc, MkdocsIndex},
-    render::{render_const_type, render_function_type},
+    markdown_types::{Doc, DocLayout, MemberDoc, Page, PageKind},
+    render::{function_signature, render_const_type, render_function_type},
 };
 
 use super::collect_property;
 
 pub fn generate_module_markdown(
     db: &DbIndex,
-    tl: &Tera,
     module: &ModuleInfo,
-    output: &Path,
-    mkdocs_index: &mut MkdocsIndex,
-) -> Option<()> {
+    layout: DocLayout,
+) -> Option<Page> {
     check_filter(db, module.file_id)?;
 
-    let mut context = tera::Context::new();
     let mut doc = Doc::default();
     doc.name = module.full_module_name.clone();
     let property_owner_id = module.property_owner_id.clone();
     if let Some(property_id) = property_owner_id {
-        doc.property = collect_property(db, property_id);
+        doc.property = collect_property(db, layout, property_id);
     }
 
     let export_typ = module.export_type.clone()?;
@@ -37,45 +31,27 @@ pub fn generate_module_markdown(
         LuaType::Def(type_id) => {
             let member_owner = LuaMemberOwner::Type(type_id.clone());
             let type_simple_name = type_id.get_simple_name();
-            generate_member_owner_module(db, member_owner, type_simple_name, &mut doc);
+            generate_member_owner_module(db, member_owner, type_simple_name, &mut doc, layout);
         }
         LuaType::TableConst(t) => {
             let member_owner = LuaMemberOwner::Element(t.clone());
-            generate_member_owner_module(db, member_owner, "M", &mut doc);
+            generate_member_owner_module(db, member_owner, "M", &mut doc, layout);
         }
         LuaType::Instance(i) => {
             let member_owner = LuaMemberOwner::Element(i.get_range().clone());
-            generate_member_owner_module(db, member_owner, "M", &mut doc);
+            generate_member_owner_module(db, member_owner, "M", &mut doc, layout);
         }
         _ => {}
     }
 
-    context.insert("doc", &doc);
-
-    let render_text = match tl.render("lua_module_template.tl", &context) {
-        Ok(text) => text,
-        Err(e) => {
-            eprintln!("Failed to render template: {}", e);
-            return None;
-        }
-    };
-
-    let file_name = format!("{}.md", escape_type_name(&module.full_module_name));
-    mkdocs_index.modules.push(IndexStruct {
+    Some(Page {
+        kind: PageKind::Module,
         name: module.full_module_name.clone(),
-        file: format!("modules/{}", file_name.clone()),
-    });
-
-    let outpath = output.join(file_name);
-    eprintln!("output module file: {}", outpath.display());
-    match std::fs::write(outpath, render_text) {
-        Ok(_) => {}
-        Err(e) => {
-            eprintln!("Failed to write file: {}", e);
-            return None;
-        }
-    }
-    Some(())
+        title: module.full_module_name.clone(),
+        template: "lua_module_template.tl",
+        file: format!("modules/{}.md", escape_type_name(&module.full_module_name)),
+        doc,
+    })
 }
 
 fn check_filter(db: &DbIndex, file_id: FileId) -> Option<()> {
@@ -92,6 +68,7 @@ pub fn generate_member_owner_module(
     member_owner: LuaMemberOwner,
     owner_name: &str,
     doc: &mut Doc,
+    layout: DocLayout,
 ) -> Option<()> {
     let members = db.get_member_index().get_sorted_members(&member_owner);
     let mut method_members: Vec<MemberDoc> = Vec::new();
@@ -115,7 +92,7 @@ pub fn generate_member_owner_module(
                 }
             }
 
-            let member_property = collect_property(db, member_property_id);
+            let member_property = collect_property(db, layout, member_property_id);
             let member_key = member.get_key();
             let name = match member_key {
                 LuaMemberKey::Name(name) => name,
@@ -130,6 +107,7 @@ pub fn generate_member_owner_module(
                     name: title_name,
                     display,
                     property: member_property,
+                    signature: function_signature(db, member_type, &func_name),
                 });
             } else if member_type.is_const() {
                 let display = render_const_type(db, member_type);
@@ -137,6 +115,7 @@ pub fn generate_member_owner_module(
                     name: title_name,
                     display: format!("```lua\n{}.{}: {}\n```\n", owner_name, name, display),
                     property: member_property,
+                    signature: None,
                 });
             } else {
                 let typ_display = humanize_type(db, member_type, RenderLevel::Detailed);
@@ -144,6 +123,7 @@ pub fn generate_member_owner_module(
                     name: title_name,
                     display: format!("```lua\n{}.{} : {}\n```\n", owner_name, name, typ_display),
                     property: member_property,
+                    signature: None,
                 });
             }
         }
@@ -1,40 +1,41 @@
-use std::path::Path;
-
 use emmylua_code_analysis::{
     humanize_type, DbIndex, LuaMemberKey, LuaMemberOwner, LuaSemanticDeclId, LuaType, LuaTypeCache,
     LuaTypeDecl, RenderLevel,
 };
 use emmylua_parser::VisibilityKind;
-use tera::{Context, Tera};
 
 use crate::markdown_generator::{
     escape_type_name,
     gen::collect_property,
-    markdown_types::{Doc, IndexStruct, MemberDoc, MkdocsIndex},
-    render::{render_const_type, render_function_type},
+    markdown_types::{Doc, DocLayout, MemberDoc, Page, PageKind},
+    render::{function_signature, render_const_type, render_function_type},
 };
 
-pub fn generate_type_markdown(
-    db: &DbIndex,
-    tl: &Tera,
-    typ: &LuaTypeDecl,
-    output: &Path,
-    mkdocs_index: &mut MkdocsIndex,
-) -> Option<()> {
+pub fn generate_type_markdown(db: &DbIndex, typ: &LuaTypeDecl, layout: DocLayout) -> Option<Page> {
     check_filter(db, typ)?;
-    let mut context = tera::Context::new();
     let typ_name = typ.get_name();
     let mut doc = Doc::default();
     doc.name = typ_name.to_string();
 
-    if typ.is_class() {
-        generate_class_type_markdown(db, tl, typ, &mut doc, &mut context, output, mkdocs_index);
+    let (kind, template) = if typ.is_class() {
+        generate_class_type_markdown(db, typ, &mut doc, layout);
+        (PageKind::Class, "lua_type_template.tl")
     } else if typ.is_enum() {
-        generate_enum_type_markdown(db, tl, typ, &mut doc, &mut context, output, mkdocs_index);
+        generate_enum_type_markdown(db, typ, &mut doc, layout);
+        (PageKind::Enum, "lua_enum_template.tl")
     } else {
-        generate_alias_type_markdown(db, tl, typ, &mut doc, &mut context, output, mkdocs_index);
-    }
-    Some(())
+        generate_alias_type_markdown(db, typ, &mut doc, layout);
+        (PageKind::Alias, "lua_alias_template.tl")
+    };
+
+    Some(Page {
+        kind,
+        name: typ.get_full_name().to_string(),
+        title: format!("{} {}", kind.as_str(), typ_name),
+        template,
+        file: format!("types/{}.md", escape_type_name(typ.get_full_name())),
+        doc,
+    })
 }
 
 fn check_filter(db: &DbIndex, typ: &LuaTypeDecl) -> Option<()> {
@@ -52,12 +53,9 @@ fn check_filter(db: &DbIndex, typ: &LuaTypeDecl) -> Option<()> {
 
 fn generate_class_type_markdown(
     db: &DbIndex,
-    tl: &Tera,
     typ: &LuaTypeDecl,
     doc: &mut Doc,
-    context: &mut Context,
-    output: &Path,
-    mkdocs_index: &mut MkdocsIndex,
+    layout: DocLayout,
 ) -> Option<()> {
     let typ_name = typ.get_name();
     let typ_id = typ.get_id();
@@ -67,7 +65,7 @@ fn generate_class_type_markdown(
     }
 
     let type_property_id = LuaSemanticDeclId::TypeDecl(typ_id.clone());
-    doc.property = collect_property(db, type_property_id);
+    doc.property = collect_property(db, layout, type_property_id);
 
     let supers = db.get_type_index().get_super_types(&typ_id);
     if let Some(supers) = supers {
@@ -101,7 +99,7 @@ fn generate_class_type_markdown(
                 }
             }
 
-            let member_property = collect_property(db, member_property_id);
+            let member_property = collect_property(db, layout, member_property_id);
 
             let member_key = member.get_key();
             let name = match member_key {
@@ -118,6 +116,7 @@ fn generate_class_type_markdown(
                     name: title_name,
                     display,
                     property: member_property,
+                    signature: function_signature(db, member_typ, &func_name),
                 });
             } else if member_typ.is_const() {
                 let const_type_display = render_const_type(db, member_typ);
@@ -128,6 +127,7 @@ fn generate_class_type_markdown(
                         typ_name, name, const_type_display
                     ),
                     property: member_property,
+                    signature: None,
                 });
             } else {
                 let typ_display = humanize_type(db, member_typ, RenderLevel::Detailed);
@@ -135,6 +135,7 @@ fn generate_class_type_markdown(
                     name: title_name,
                     display: format!("```lua\n{}.{} : {}\n```\n", typ_name, name, typ_display),
                     property: member_property,
+                    signature: None,
                 });
             }
         }
@@ -147,49 +148,21 @@ fn generate_class_type_markdown(
         doc.fields = Some(field_members);
     }
 
-    context.insert("doc", &doc);
-    let render_text = match tl.render("lua_type_template.tl", context) {
-        Ok(text) => text,
-        Err(e) => {
-            eprintln!("Failed to render template: {}", e);
-            return None;
-        }
-    };
-
-    let file_type_name = format!("{}.md", escape_type_name(typ.get_full_name()));
-    mkdocs_index.types.push(IndexStruct {
-        name: format!("class {}", typ_name),
-        file: format!("types/{}", file_type_name.clone()),
-    });
-
-    let outpath = output.join(file_type_name);
-    eprintln!("output class file: {}", outpath.display());
-    match std::fs::write(outpath, render_text) {
-        Ok(_) => {}
-        Err(e) => {
-            eprintln!("Failed to write file: {}", e);
-            return None;
-        }
-    }
     Some(())
 }
 
 fn generate_enum_type_markdown(
     db: &DbIndex,
-    tl: &Tera,
     typ: &LuaTypeDecl,
     doc: &mut Doc,
-    context: &mut Context,
-    output: &Path,
-    mkdocs_index: &mut MkdocsIndex,
+    layout: DocLayout,
 ) -> Option<()> {
-    let typ_name = typ.get_name();
     let typ_id = typ.get_id();
     let namespace = typ.get_namespace();
     if let Some(namespace) = namespace {
         doc.namespace = Some(namespace.to_string());
     }
-    doc.property = collect_property(db, LuaSemanticDeclId::TypeDecl(typ_id.clone()));
+    doc.property = collect_property(db, layout, LuaSemanticDeclId::TypeDecl(typ_id.clone()));
 
     let member_owner = LuaMemberOwner::Type(typ_id);
     let members = db.get_member_index().get_sorted_members(&member_owner);
@@ -212,7 +185,7 @@ fn generate_enum_type_markdown(
                 }
             }
 
-            let member_property = collect_property(db, member_property_id);
+            let member_property = collect_property(db, layout, member_property_id);
 
             let member_key = member.get_key();
             let name = match member_key {
@@ -225,6 +198,7 @@ fn generate_enum_type_markdown(
                 name: name.to_string(),
                 display: typ_display,
                 property: member_property,
+                signature: None,
             });
         }
     }
@@ -233,41 +207,14 @@ fn generate_enum_type_markdown(
         doc.fields = Some(field_members);
     }
 
-    context.insert("doc", &doc);
-    let render_text = match tl.render("lua_enum_template.tl", context) {
-        Ok(text) => text,
-        Err(e) => {
-            eprintln!("Failed to render template: {}", e);
-            return None;
-        }
-    };
-
-    let file_type_name = format!("{}.md", escape_type_name(typ.get_full_name()));
-    mkdocs_index.types.push(IndexStruct {
-        name: format!("enum {}", typ_name),
-        file: format!("types/{}", file_type_name.clone()),
-    });
-
-    let outpath = output.join(file_type_name);
-    eprintln!("output enum file: {}", outpath.display());
-    match std::fs::write(outpath, render_text) {
-        Ok(_) => {}
-        Err(e) => {
-            eprintln!("Failed to write file: {}", e);
-            return None;
-        }
-    }
     Some(())
 }
 
 fn generate_alias_type_markdown(
     db: &DbIndex,
-    tl: &Tera,
     typ: &LuaTypeDecl,
     doc: &mut Doc,
-    context: &mut Context,
-    output: &Path,
-    mkdocs_index: &mut MkdocsIndex,
+    layout: DocLayout,
 ) -> Option<()> {
     let typ_name = typ.get_name();
     let typ_id = typ.get_id();
@@ -277,7 +224,7 @@ fn generate_alias_type_markdown(
     }
 
     let type_property_id = LuaSemanticDeclId::TypeDecl(typ_id.clone());
-    doc.property = collect_property(db, type_property_id);
+    doc.property = collect_property(db, layout, type_property_id);
 
     if let Some(origin_typ) = typ.get_alias_origin(db, None) {
         let origin_type_display = humanize_type(db, &origin_typ, RenderLevel::Detailed);
@@ -288,30 +235,5 @@ fn generate_alias_type_markdown(
         doc.display = Some(display);
     }
 
-    context.insert("doc", &doc);
-
-    let render_text = match tl.render("lua_alias_template.tl", context) {
-        Ok(text) => text,
-        Err(e) => {
-            eprintln!("Failed to render template: {}", e);
-            return None;
-        }
-    };
-
-    let file_type_name = format!("{}.md", escape_type_name(typ.get_full_name()));
-    mkdocs_index.types.push(IndexStruct {
-        name: format!("alias {}", typ_name),
-        file: format!("types/{}", file_type_name.clone()),
-    });
-
-    let outpath = output.join(file_type_name);
-    eprintln!("output alias file: {}", outpath.display());
-    match std::fs::write(outpath, render_text) {
-        Ok(_) => {}
-        Err(e) => {
-            eprintln!("Failed to write file: {}", e);
-            return None;
-        }
-    }
     Some(())
 }
@@ -1,3 +1,5 @@
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
@@ -9,6 +11,8 @@ pub struct Doc {
     pub fields: Option<Vec<MemberDoc>>,
     pub methods: Option<Vec<MemberDoc>>,
     pub property: Property,
+    /// set for a global function, for the `signature` filter
+    pub signature: Option<SignatureDoc>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -16,6 +20,21 @@ pub struct MemberDoc {
     pub name: String,
     pub display: String,
     pub property: Property,
+    pub signature: Option<SignatureDoc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct SignatureDoc {
+    pub name: String,
+    pub is_async: bool,
+    pub params: Vec<ParamDoc>,
+    pub returns: Vec<ParamDoc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct ParamDoc {
+    pub name: Option<String>,
+    pub typ: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Default)]
@@ -26,6 +45,84 @@ pub struct Property {
     pub other: Option<String>,
 }
 
+/// A generated page before it is rendered
+#[derive(Debug)]
+pub struct Page {
+    pub kind: PageKind,
+    /// the full name of a type, module or global
+    pub name: String,
+    /// the name in the index
+    pub title: String,
+    pub template: &'static str,
+    /// relative to the `docs` directory, such as `types/Foo.md`
+    pub file: String,
+    pub doc: Doc,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum PageKind {
+    Class,
+    Enum,
+    Alias,
+    Module,
+    Global,
+    Index,
+}
+
+impl PageKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PageKind::Class => "class",
+            PageKind::Enum => "enum",
+            PageKind::Alias => "alias",
+            PageKind::Module => "module",
+            PageKind::Global => "global",
+            PageKind::Index => "index",
+        }
+    }
+}
+
+/// How the pages are written
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DocLayout {
+    /// a page per type, module and global in `docs/types`, `docs/modules` and `docs/globals`
+    #[default]
+    Pages,
+    /// every page in `docs/index.md`
+    Single,
+}
+
+impl FromStr for DocLayout {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_ref() {
+            "pages" => Ok(Self::Pages),
+            "single" => Ok(Self::Single),
+            _ => Err("Invalid layout, must be one of pages, single"),
+        }
+    }
+}
+
+impl DocLayout {
+    /// The link from a page to `file`, which is relative to the `docs` directory
+    pub fn link(self, file: &str, anchor: Option<&str>) -> String {
+        match (self, anchor) {
+            (DocLayout::Pages, Some(anchor)) => format!("../{}#{}", file, anchor),
+            (DocLayout::Pages, None) => format!("../{}", file),
+            (DocLayout::Single, Some(anchor)) => format!("#{}", anchor),
+            (DocLayout::Single, None) => format!("#{}", page_anchor(file)),
+        }
+    }
+}
+
+/// The anchor of a page in the single page layout
+pub fn page_anchor(file: &str) -> String {
+    file.trim_end_matches(".md").replace('/', "-")
+}
+
 #[derive(Debug, Serialize, Deserialize, Default)]
 pub struct MkdocsIndex {
     pub types: Vec<IndexStruct>,
@@ -33,6 +130,14 @@ pub struct MkdocsIndex {
     pub globals: Vec<IndexStruct>,
 }
 
+/// A rendered page in the single page layout
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PageSection {
+    pub name: String,
+    pub anchor: String,
+    pub content: String,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct IndexStruct {
     pub name: String,
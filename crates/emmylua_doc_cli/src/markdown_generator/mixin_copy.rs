@@ -1,24 +1,47 @@
 use std::fs;
-use std::io;
 use std::path::{Path, PathBuf};
 
-fn copy_dir_all(src: &Path, dst: &Path) -> io::Result<()> {
+use tera::{Context, Tera};
+
+// with `render`, files ending with `.tl` are rendered with the index context and written without
+// the extension
+fn copy_dir_all(
+    src: &Path,
+    dst: &Path,
+    render: &mut Option<(&mut Tera, &Context)>,
+) -> Result<(), Box<dyn std::error::Error>> {
     fs::create_dir_all(dst)?;
     for entry in fs::read_dir(src)? {
         let entry = entry?;
         let path = entry.path();
         let dest = dst.join(entry.file_name());
         if path.is_dir() {
-            copy_dir_all(&path, &dest)?;
-        } else {
-            fs::copy(&path, &dest)?;
+            copy_dir_all(&path, &dest, render)?;
+            continue;
+        }
+
+        match render {
+            Some((tl, context)) if path.extension().is_some_and(|ext| ext == "tl") => {
+                let text = fs::read_to_string(&path)?;
+                let rendered = tl
+                    .render_str(&text, context)
+                    .map_err(|e| format!("{:?}: {}", path, e))?;
+                fs::write(dest.with_extension(""), rendered)?;
+            }
+            _ => {
+                fs::copy(&path, &dest)?;
+            }
         }
     }
     Ok(())
 }
 
-pub fn mixin_copy(output: &Path, mixin: PathBuf) -> Option<()> {
-    match copy_dir_all(&mixin, output) {
+pub fn mixin_copy(
+    output: &Path,
+    mixin: PathBuf,
+    mut render: Option<(&mut Tera, &Context)>,
+) -> Option<()> {
+    match copy_dir_all(&mixin, output, &mut render) {
         Ok(_) => {}
         Err(e) => {
             eprintln!("Failed to copy mixin: {}", e);
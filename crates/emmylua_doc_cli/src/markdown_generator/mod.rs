@@ -1,93 +1,110 @@
+mod filters;
+mod front_matter;
 mod gen;
 mod init_tl;
 mod markdown_types;
 mod mixin_copy;
 mod render;
+#[cfg(test)]
+mod test;
 
 use std::path::PathBuf;
 
+use crate::{common::escape_type_name, config::MarkdownConfig};
 use emmylua_code_analysis::EmmyLuaAnalysis;
+use filters::register_filters;
+use front_matter::FrontMatter;
 use gen::{
-    generate_global_markdown, generate_index, generate_module_markdown, generate_type_markdown,
+    generate_global_markdown, generate_index, generate_module_markdown, generate_single_page,
+    generate_type_markdown, index_context,
 };
-use markdown_types::MkdocsIndex;
+pub use markdown_types::{DocLayout, PageKind};
+use tera::Context;
 
 pub fn generate_markdown(
     analysis: &mut EmmyLuaAnalysis,
     output: PathBuf,
     override_template: Option<PathBuf>,
     mixin: Option<PathBuf>,
+    config: &MarkdownConfig,
 ) -> Result<(), Box<dyn std::error::Error>> {
+    let layout = config.layout;
     let docs_dir = output.join("docs");
-    let types_out = docs_dir.join("types");
-    if !types_out.exists() {
-        eprintln!("Creating types directory: {:?}", types_out);
-        std::fs::create_dir_all(&types_out)?;
-    } else {
-        eprintln!("Clearing types directory: {:?}", types_out);
-        std::fs::remove_dir_all(&types_out)?;
-        std::fs::create_dir_all(&types_out)?;
+    for dir in ["types", "modules", "globals"] {
+        let dir_out = docs_dir.join(dir);
+        if dir_out.exists() {
+            eprintln!("Clearing {} directory: {:?}", dir, dir_out);
+            std::fs::remove_dir_all(&dir_out)?;
+        }
+        // the single page layout has no page directories
+        if layout == DocLayout::Pages {
+            eprintln!("Creating {} directory: {:?}", dir, dir_out);
+            std::fs::create_dir_all(&dir_out)?;
+        }
     }
+    std::fs::create_dir_all(&docs_dir)?;
 
-    let module_out = docs_dir.join("modules");
-    if !module_out.exists() {
-        eprintln!("Creating modules directory: {:?}", module_out);
-        std::fs::create_dir_all(&module_out)?;
-    } else {
-        eprintln!("Clearing modules directory: {:?}", module_out);
-        std::fs::remove_dir_all(&module_out)?;
-        std::fs::create_dir_all(&module_out)?;
-    }
-
-    let global_out = docs_dir.join("globals");
-    if !global_out.exists() {
-        eprintln!("Creating globals directory: {:?}", global_out);
-        std::fs::create_dir_all(&global_out)?;
-    } else {
-        eprintln!("Clearing globals directory: {:?}", global_out);
-        std::fs::remove_dir_all(&global_out)?;
-        std::fs::create_dir_all(&global_out)?;
-    }
-
-    let tl = init_tl::init_tl(override_template).ok_or("Failed to initialize TL")?;
-    let mut mkdocs_index = MkdocsIndex::default();
+    let mut pages = Vec::new();
     let db = analysis.compilation.get_db();
     let type_index = db.get_type_index();
     let types = type_index.get_all_types();
     for type_decl in types {
-        generate_type_markdown(db, &tl, type_decl, &types_out, &mut mkdocs_index);
+        pages.extend(generate_type_markdown(db, type_decl, layout));
     }
 
     let module_index = db.get_module_index();
     let modules = module_index.get_module_infos();
     for module in modules {
-        generate_module_markdown(db, &tl, module, &module_out, &mut mkdocs_index);
+        pages.extend(generate_module_markdown(db, module, layout));
     }
 
     let global_index = db.get_global_index();
     let globals = global_index.get_all_global_decl_ids();
     for global_decl_id in globals {
-        generate_global_markdown(db, &tl, &global_decl_id, &global_out, &mut mkdocs_index);
+        pages.extend(generate_global_markdown(db, &global_decl_id, layout));
     }
 
-    generate_index(&tl, &mut mkdocs_index, &output);
+    let mut tl = init_tl::init_tl(override_template).ok_or("Failed to initialize TL")?;
+    register_filters(&mut tl, &pages, layout);
+    let front_matter = FrontMatter::register(&mut tl, &config.front_matter)?;
+
+    let mut rendered = Vec::new();
+    for page in &pages {
+        let mut context = Context::new();
+        context.insert("doc", &page.doc);
+        let render_text = match tl.render(page.template, &context) {
+            Ok(text) => text,
+            Err(e) => {
+                eprintln!("Failed to render template: {}", e);
+                continue;
+            }
+        };
+
+        match layout {
+            DocLayout::Pages => {
+                let Some(page_front_matter) = front_matter.render(&tl, page.kind, &context) else {
+                    continue;
+                };
+                let outpath = docs_dir.join(&page.file);
+                eprintln!("output {} file: {}", page.kind.as_str(), outpath.display());
+                if let Err(e) = std::fs::write(outpath, page_front_matter + &render_text) {
+                    eprintln!("Failed to write file: {}", e);
+                }
+            }
+            DocLayout::Single => rendered.push((page, render_text)),
+        }
+    }
+
+    let context = index_context(&pages, config);
+    match layout {
+        DocLayout::Pages => generate_index(&tl, &front_matter, &context, &output),
+        DocLayout::Single => generate_single_page(&tl, &front_matter, rendered, config, &output),
+    };
 
     if let Some(mixin) = mixin {
-        mixin_copy::mixin_copy(&output, mixin);
+        let render = config.render_mixin.then_some((&mut tl, &context));
+        mixin_copy::mixin_copy(&output, mixin, render);
     }
 
     Ok(())
 }
-
-fn escape_type_name(name: &str) -> String {
-    name.chars()
-        .map(|c| {
-            // Windows Invalid Characters
-            if "<>:\"/\\|?*".contains(c) {
-                '_'
-            } else {
-                c
-            }
-        })
-        .collect()
-}
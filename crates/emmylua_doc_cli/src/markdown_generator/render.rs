@@ -1,4 +1,7 @@
-use crate::common::render_typ;
+use crate::{
+    common::render_typ,
+    markdown_generator::markdown_types::{ParamDoc, SignatureDoc},
+};
 use emmylua_code_analysis::{
     humanize_type, DbIndex, LuaFunctionType, LuaSignatureId, LuaType, RenderLevel,
 };
@@ -48,6 +51,61 @@ pub fn render_function_type(
     }
 }
 
+/// The params and returns of a function, for the `signature` template filter
+pub fn function_signature(db: &DbIndex, typ: &LuaType, func_name: &str) -> Option<SignatureDoc> {
+    let param = |name: &str, typ: &Option<LuaType>| ParamDoc {
+        name: Some(name.to_string()),
+        typ: typ.as_ref().map(|typ| render_typ(db, typ)),
+    };
+    match typ {
+        LuaType::DocFunction(lua_func) => {
+            let ret = lua_func.get_ret();
+            let returns = if ret.is_nil() {
+                Vec::new()
+            } else {
+                vec![ParamDoc {
+                    name: None,
+                    typ: Some(render_typ(db, ret)),
+                }]
+            };
+            Some(SignatureDoc {
+                name: func_name.to_string(),
+                is_async: lua_func.is_async(),
+                params: lua_func
+                    .get_params()
+                    .iter()
+                    .map(|(name, typ)| param(name, typ))
+                    .collect(),
+                returns,
+            })
+        }
+        LuaType::Signature(signature_id) => {
+            let signature = db.get_signature_index().get(signature_id)?;
+            Some(SignatureDoc {
+                name: func_name.to_string(),
+                is_async: signature.is_async,
+                params: signature
+                    .get_type_params()
+                    .iter()
+                    .map(|(name, typ)| param(name, typ))
+                    .collect(),
+                returns: signature
+                    .return_docs
+                    .iter()
+                    .map(|ret| ParamDoc {
+                        name: ret.name.clone(),
+                        typ: Some(render_typ(db, &ret.type_ref)),
+                    })
+                    .collect(),
+            })
+        }
+        _ => Some(SignatureDoc {
+            name: func_name.to_string(),
+            ..Default::default()
+        }),
+    }
+}
+
 fn render_doc_function_type(
     db: &DbIndex,
     lua_func: &LuaFunctionType,
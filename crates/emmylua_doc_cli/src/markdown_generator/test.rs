@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{config::MarkdownConfig, test_lib::TempWorkspace};
+
+    use super::super::{generate_markdown, DocLayout, PageKind};
+
+    const SOURCE: &str = r#"
+---@class net.Socket
+local Socket = {}
+
+---@param data string
+---@return integer
+function Socket:send(data) return 0 end
+
+---A socket over TLS, see [net.Socket]
+---@class net.TlsSocket: net.Socket
+local TlsSocket = {}
+
+return Socket
+"#;
+
+    fn generate(name: &str, config: &MarkdownConfig) -> TempWorkspace {
+        let workspace = TempWorkspace::new(&format!("markdown_{}", name));
+        workspace.write("src/net.lua", SOURCE);
+        workspace.write("mixin/guide.md.tl", "{{ types | length }} types");
+        let mut analysis = workspace.load_dir(&workspace.root().join("src"));
+        generate_markdown(
+            &mut analysis,
+            workspace.root().join("output"),
+            None,
+            Some(workspace.root().join("mixin")),
+            config,
+        )
+        .unwrap();
+        workspace
+    }
+
+    #[test]
+    fn test_pages_layout() {
+        let mut front_matter = BTreeMap::new();
+        front_matter.insert(
+            PageKind::Class,
+            BTreeMap::from([("title".to_string(), "{{ doc.name }} class".to_string())]),
+        );
+        let config = MarkdownConfig {
+            layout: DocLayout::Pages,
+            site_name: None,
+            front_matter,
+            render_mixin: false,
+        };
+        let workspace = generate("pages", &config);
+        let docs = workspace.root().join("output/docs");
+        let page = std::fs::read_to_string(docs.join("types/net.TlsSocket.md")).unwrap();
+        let index = std::fs::read_to_string(docs.join("index.md")).unwrap();
+        let mixin = std::fs::read_to_string(workspace.root().join("output/guide.md.tl")).unwrap();
+
+        // mixin templates are only rendered with `renderMixin`
+        assert_eq!(mixin, "{{ types | length }} types");
+
+        assert!(page.starts_with("---\ntitle: TlsSocket class\n---\n\n# class TlsSocket"));
+        assert!(page.contains("- supers: [net.Socket](../types/net.Socket.md)"));
+        assert!(page.contains("see [`net.Socket`](../types/net.Socket.md)"));
+        assert!(index.starts_with("# index"));
+        assert!(index.contains("- [class TlsSocket](types/net.TlsSocket.md)"));
+    }
+
+    #[test]
+    fn test_single_layout() {
+        let config = MarkdownConfig {
+            layout: DocLayout::Single,
+            site_name: Some("Net".to_string()),
+            front_matter: BTreeMap::new(),
+            render_mixin: true,
+        };
+        let workspace = generate("single", &config);
+        let output = workspace.root().join("output");
+        let index = std::fs::read_to_string(output.join("docs/index.md")).unwrap();
+        let mkdocs = std::fs::read_to_string(output.join("mkdocs.yml")).unwrap();
+        let has_pages = output.join("docs/types").exists();
+        let mixin = std::fs::read_to_string(output.join("guide.md")).unwrap();
+
+        assert_eq!(mixin, "2 types");
+
+        assert!(!has_pages);
+        assert!(index.starts_with("# Net"));
+        assert!(index.contains("- [class TlsSocket](#types-net.TlsSocket)"));
+        assert!(index.contains("<a id=\"types-net.TlsSocket\"></a>\n\n# class TlsSocket"));
+        assert!(index.contains("- supers: [net.Socket](#types-net.Socket)"));
+        assert!(index.contains("see [`net.Socket`](#types-net.Socket)"));
+        assert!(mkdocs.starts_with("site_name: Net\n"));
+        assert!(!mkdocs.contains("Types:"));
+    }
+}
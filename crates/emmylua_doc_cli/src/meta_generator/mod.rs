@@ -0,0 +1,247 @@
+mod stub;
+#[cfg(test)]
+mod test;
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    path::{Path, PathBuf},
+};
+
+use emmylua_code_analysis::EmmyLuaAnalysis;
+use stub::{
+    identifier_for, write_alias, write_class, write_enum, write_global_field, write_member,
+    write_property,
+};
+
+use crate::json_generator::{
+    export::export,
+    json_types::{Global, Index, Member, Type},
+};
+
+pub fn generate_meta(
+    analysis: &mut EmmyLuaAnalysis,
+    input: &Path,
+    output: PathBuf,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let db = analysis.compilation.get_db();
+    let index = export(db);
+
+    for (path, content) in render_meta(&index, input) {
+        let path = output.join(path);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, content)?;
+    }
+
+    eprintln!("Writing meta files to {:?}", output);
+    Ok(())
+}
+
+/// Render the `---@meta` stubs of `index`, keyed by the path of their source file relative to
+/// `root`, so every module keeps its name
+pub fn render_meta(index: &Index, root: &Path) -> BTreeMap<PathBuf, String> {
+    let mut files: BTreeMap<PathBuf, StubFile> = BTreeMap::new();
+
+    // a local must not shadow a global assigned in the same file
+    for global in &index.globals {
+        let (name, loc) = match global {
+            Global::Table(table) => (&table.name, &table.loc),
+            Global::Field(field) => (&field.name, &field.loc),
+        };
+        if let Some(loc) = loc {
+            files
+                .entry(stub_path(root, &loc.file))
+                .or_default()
+                .names
+                .insert(name.clone());
+        }
+    }
+
+    // `---@class Foo` above `Foo = {}` declares the class on the global, which has the class as
+    // its type and is the first such global in the file
+    let mut class_globals: HashMap<&str, &str> = HashMap::new();
+    for typ in &index.types {
+        let Type::Class(class) = typ else {
+            continue;
+        };
+        let declared_on = index
+            .globals
+            .iter()
+            .filter_map(|global| match global {
+                Global::Field(field) if field.typ == class.name => {
+                    let loc = field.loc.as_ref()?;
+                    class
+                        .loc
+                        .iter()
+                        .any(|class_loc| class_loc.file == loc.file && class_loc.line <= loc.line)
+                        .then_some((loc.line, field.name.as_str()))
+                }
+                _ => None,
+            })
+            .min();
+        if let Some((_, global)) = declared_on {
+            class_globals.insert(&class.name, global);
+        }
+    }
+
+    let mut class_bindings: HashMap<&str, String> = HashMap::new();
+    for typ in &index.types {
+        let (name, locs) = match typ {
+            Type::Class(class) => (&class.name, &class.loc),
+            Type::Enum(enum_) => (&enum_.name, &enum_.loc),
+            Type::Alias(alias) => (&alias.name, &alias.loc),
+        };
+        let Some(loc) = locs.first() else {
+            continue;
+        };
+        let file = files.entry(stub_path(root, &loc.file)).or_default();
+        let mut text = String::new();
+        match typ {
+            Type::Class(class) => {
+                let (binding, is_local) = match class_globals.get(name.as_str()) {
+                    Some(global) => (global.to_string(), false),
+                    None => (file.local_name(name), true),
+                };
+                write_class(&mut text, class, &binding, is_local);
+                for member in &class.members {
+                    write_member(&mut text, &binding, member);
+                }
+                class_bindings.insert(name, binding);
+            }
+            Type::Enum(enum_) => {
+                let binding = file.local_name(name);
+                write_enum(&mut text, enum_, &binding);
+            }
+            Type::Alias(alias) => {
+                let Some(alias_typ) = &alias.typ else {
+                    continue;
+                };
+                write_alias(&mut text, alias, alias_typ);
+            }
+        }
+        file.chunks.push((loc.line, text));
+    }
+
+    for global in &index.globals {
+        let mut text = String::new();
+        let loc = match global {
+            Global::Table(table) => {
+                write_property(&mut text, &table.property, "");
+                text.push_str(&format!("{} = {{}}\n", table.name));
+                for member in &table.members {
+                    write_member(&mut text, &table.name, member);
+                }
+                &table.loc
+            }
+            Global::Field(field) => {
+                if class_globals.get(field.typ.as_str()) == Some(&field.name.as_str()) {
+                    continue;
+                }
+                write_global_field(&mut text, field);
+                &field.loc
+            }
+        };
+        let Some(loc) = loc else {
+            continue;
+        };
+        files
+            .entry(stub_path(root, &loc.file))
+            .or_default()
+            .chunks
+            .push((loc.line, text));
+    }
+
+    for module in &index.modules {
+        let Some(module_file) = &module.file else {
+            continue;
+        };
+        let file = files.entry(stub_path(root, module_file)).or_default();
+        // a module returning a class exports exactly the members of that class
+        let exported_class = index.types.iter().find_map(|typ| match typ {
+            Type::Class(class)
+                if !module.members.is_empty()
+                    && class.loc.iter().any(|loc| &loc.file == module_file)
+                    && member_keys(&class.members) == member_keys(&module.members) =>
+            {
+                class_bindings.get(class.name.as_str())
+            }
+            _ => None,
+        });
+
+        let mut text = String::new();
+        match exported_class {
+            Some(binding) => text.push_str(&format!("return {}\n", binding)),
+            None => {
+                let binding = file.local_name("M");
+                write_property(&mut text, &module.property, "");
+                text.push_str(&format!("local {} = {{}}\n", binding));
+                for member in &module.members {
+                    write_member(&mut text, &binding, member);
+                }
+                text.push_str(&format!("\nreturn {}\n", binding));
+            }
+        }
+        file.module = Some(text);
+    }
+
+    files
+        .into_iter()
+        .map(|(path, file)| (path, file.finish()))
+        .collect()
+}
+
+#[derive(Debug, Default)]
+struct StubFile {
+    /// the definitions by their line in the source file, so the stub keeps the source order
+    chunks: Vec<(usize, String)>,
+    /// the module table, `return` has to be the last statement
+    module: Option<String>,
+    names: HashSet<String>,
+}
+
+impl StubFile {
+    fn local_name(&mut self, name: &str) -> String {
+        let base = identifier_for(name);
+        let mut local_name = base.clone();
+        let mut suffix = 1;
+        while self.names.contains(&local_name) {
+            suffix += 1;
+            local_name = format!("{}_{}", base, suffix);
+        }
+        self.names.insert(local_name.clone());
+        local_name
+    }
+
+    fn finish(mut self) -> String {
+        self.chunks.sort_by_key(|(line, _)| *line);
+        let mut content = String::from("---@meta\n");
+        for chunk in self
+            .chunks
+            .into_iter()
+            .map(|(_, chunk)| chunk)
+            .chain(self.module)
+        {
+            content.push('\n');
+            content.push_str(&chunk);
+        }
+        content
+    }
+}
+
+fn stub_path(root: &Path, file: &Path) -> PathBuf {
+    match file.strip_prefix(root) {
+        Ok(path) => path.to_path_buf(),
+        Err(_) => file.file_name().map(PathBuf::from).unwrap_or_default(),
+    }
+}
+
+fn member_keys(members: &[Member]) -> Vec<(&str, Option<usize>)> {
+    members
+        .iter()
+        .map(|member| match member {
+            Member::Fn(func) => (func.name.as_str(), func.loc.as_ref().map(|loc| loc.line)),
+            Member::Field(field) => (field.name.as_str(), field.loc.as_ref().map(|loc| loc.line)),
+        })
+        .collect()
+}
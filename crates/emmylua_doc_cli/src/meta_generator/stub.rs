@@ -0,0 +1,257 @@
+use crate::{
+    common::annotation_type,
+    json_generator::json_types::{
+        Alias, Class, Enum, Field, Fn, GlobalField, Member, Property, TypeVar,
+    },
+};
+
+const LUA_KEYWORDS: [&str; 22] = [
+    "and", "break", "do", "else", "elseif", "end", "false", "for", "function", "goto", "if", "in",
+    "local", "nil", "not", "or", "repeat", "return", "then", "true", "until", "while",
+];
+
+pub fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_ascii_alphanumeric() || c == '_') && !LUA_KEYWORDS.contains(&name)
+}
+
+/// A name usable as a Lua local for `name`, such as `Socket` for `net.Socket`
+pub fn identifier_for(name: &str) -> String {
+    let last = name.rsplit('.').next().unwrap_or(name);
+    let mut result: String = last
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if !is_identifier(&result) {
+        result.insert(0, '_');
+    }
+    result
+}
+
+fn member_path(owner: &str, name: &str, is_meth: bool) -> String {
+    if is_identifier(name) {
+        format!("{}{}{}", owner, if is_meth { ":" } else { "." }, name)
+    } else {
+        format!("{}[{:?}]", owner, name)
+    }
+}
+
+fn single_line(text: &str) -> String {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub fn write_property(out: &mut String, property: &Property, indent: &str) {
+    if let Some(description) = &property.description {
+        for line in description.lines() {
+            if line.is_empty() {
+                out.push_str(&format!("{}---\n", indent));
+            } else {
+                out.push_str(&format!("{}--- {}\n", indent, line));
+            }
+        }
+    }
+    if let Some(visibility) = &property.visibility {
+        out.push_str(&format!("{}---@{}\n", indent, visibility));
+    }
+    if property.deprecated {
+        match &property.deprecation_reason {
+            Some(reason) => out.push_str(&format!(
+                "{}---@deprecated {}\n",
+                indent,
+                single_line(reason)
+            )),
+            None => out.push_str(&format!("{}---@deprecated\n", indent)),
+        }
+    }
+    if let Some(see) = &property.see {
+        out.push_str(&format!("{}---@see {}\n", indent, single_line(see)));
+    }
+}
+
+fn generic_text(generic: &TypeVar) -> String {
+    let mut text = generic.name.clone();
+    if let Some(base) = &generic.base {
+        text.push_str(&format!(": {}", annotation_type(base)));
+    }
+    if let Some(default) = &generic.default {
+        text.push_str(&format!(" = {}", annotation_type(default)));
+    }
+    text
+}
+
+/// `---@class` and the table it is bound to, members are written separately
+pub fn write_class(out: &mut String, class: &Class, binding: &str, is_local: bool) {
+    write_property(out, &class.property, "");
+    let mut header = format!("---@class {}", class.name);
+    if !class.generics.is_empty() {
+        let generics: Vec<String> = class.generics.iter().map(generic_text).collect();
+        header.push_str(&format!("<{}>", generics.join(", ")));
+    }
+    if !class.bases.is_empty() {
+        let bases: Vec<String> = class
+            .bases
+            .iter()
+            .map(|base| annotation_type(base))
+            .collect();
+        header.push_str(&format!(": {}", bases.join(", ")));
+    }
+    out.push_str(&header);
+    out.push('\n');
+    if is_local {
+        out.push_str("local ");
+    }
+    out.push_str(&format!("{} = {{}}\n", binding));
+}
+
+/// Enum members keep their values, so they are written in the table constructor
+pub fn write_enum(out: &mut String, enum_: &Enum, binding: &str) {
+    write_property(out, &enum_.property, "");
+    out.push_str(&format!("---@enum {}\n", enum_.name));
+    out.push_str(&format!("local {} = {{\n", binding));
+    for member in &enum_.members {
+        let Member::Field(field) = member else {
+            continue;
+        };
+        write_property(out, &field.property, "    ");
+        let key = if is_identifier(&field.name) {
+            field.name.clone()
+        } else {
+            format!("[{:?}]", field.name)
+        };
+        let value = literal_value(field).unwrap_or_else(|| "nil".to_string());
+        out.push_str(&format!("    {} = {},\n", key, value));
+    }
+    out.push_str("}\n");
+}
+
+pub fn write_alias(out: &mut String, alias: &Alias, typ: &str) {
+    write_property(out, &alias.property, "");
+    out.push_str(&format!(
+        "---@alias {} {}\n",
+        alias.name,
+        annotation_type(typ)
+    ));
+}
+
+pub fn write_member(out: &mut String, owner: &str, member: &Member) {
+    out.push('\n');
+    match member {
+        Member::Fn(func) => write_fn(out, owner, func),
+        Member::Field(field) => write_field(out, owner, field),
+    }
+}
+
+fn write_field(out: &mut String, owner: &str, field: &Field) {
+    write_property(out, &field.property, "");
+    let value = match literal_value(field) {
+        Some(value) => value,
+        None => {
+            out.push_str(&format!("---@type {}\n", annotation_type(&field.typ)));
+            "nil".to_string()
+        }
+    };
+    out.push_str(&format!(
+        "{} = {}\n",
+        member_path(owner, &field.name, false),
+        value
+    ));
+}
+
+pub fn write_global_field(out: &mut String, field: &GlobalField) {
+    write_property(out, &field.property, "");
+    let value = match const_value(&field.typ, field.literal.as_deref()) {
+        Some(value) => value,
+        None => {
+            out.push_str(&format!("---@type {}\n", annotation_type(&field.typ)));
+            "nil".to_string()
+        }
+    };
+    out.push_str(&format!("{} = {}\n", field.name, value));
+}
+
+fn literal_value(field: &Field) -> Option<String> {
+    const_value(&field.typ, field.literal.as_deref())
+}
+
+// a literal only round-trips as a value when its type was widened from it, `---@field x 1` keeps `1`
+fn const_value(typ: &str, literal: Option<&str>) -> Option<String> {
+    let literal = literal?;
+    match typ {
+        "integer" | "string" => Some(literal.to_string()),
+        "number" if literal.contains(['.', 'e', 'E']) => Some(literal.to_string()),
+        "number" => Some(format!("{}.0", literal)),
+        _ => None,
+    }
+}
+
+fn write_fn(out: &mut String, owner: &str, func: &Fn) {
+    write_property(out, &func.property, "");
+    if !func.generics.is_empty() {
+        let generics: Vec<String> = func.generics.iter().map(generic_text).collect();
+        out.push_str(&format!("---@generic {}\n", generics.join(", ")));
+    }
+    for param in &func.params {
+        let (Some(name), Some(typ)) = (&param.name, &param.typ) else {
+            continue;
+        };
+        out.push_str(&format!("---@param {} {}", name, annotation_type(typ)));
+        if let Some(desc) = &param.desc {
+            out.push_str(&format!(" {}", single_line(desc)));
+        }
+        out.push('\n');
+    }
+    for ret in &func.returns {
+        let Some(typ) = &ret.typ else {
+            continue;
+        };
+        out.push_str(&format!("---@return {}", annotation_type(typ)));
+        if let Some(name) = &ret.name {
+            out.push_str(&format!(" {}", name));
+        }
+        if let Some(desc) = &ret.desc {
+            out.push_str(&format!(" # {}", single_line(desc)));
+        }
+        out.push('\n');
+    }
+    for overload in &func.overloads {
+        out.push_str(&format!("---@overload {}\n", annotation_type(overload)));
+    }
+    if func.is_async {
+        out.push_str("---@async\n");
+    }
+    if func.is_nodiscard {
+        match &func.nodiscard_message {
+            Some(message) => out.push_str(&format!("---@nodiscard {}\n", single_line(message))),
+            None => out.push_str("---@nodiscard\n"),
+        }
+    }
+
+    let params: Vec<&str> = func
+        .params
+        .iter()
+        .map(|param| param.name.as_deref().unwrap_or("_"))
+        .collect();
+    if is_identifier(&func.name) {
+        out.push_str(&format!(
+            "function {}({}) end\n",
+            member_path(owner, &func.name, func.is_meth),
+            params.join(", ")
+        ));
+    } else {
+        let self_param = if func.is_meth { vec!["self"] } else { vec![] };
+        let params: Vec<&str> = self_param.into_iter().chain(params).collect();
+        out.push_str(&format!(
+            "{} = function({}) end\n",
+            member_path(owner, &func.name, false),
+            params.join(", ")
+        ));
+    }
+}
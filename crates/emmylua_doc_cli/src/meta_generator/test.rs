@@ -0,0 +1,180 @@
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use serde_json::Value;
+
+    use crate::{json_generator::export::export, test_lib::TempWorkspace};
+
+    use super::super::render_meta;
+
+    const SOURCES: [(&str, &str); 4] = [
+        (
+            "net/socket.lua",
+            r#"
+            ---A connected socket
+            ---@class net.Socket<T: table = table>: Closeable
+            ---@field timeout number seconds before a read fails
+            ---@field protected fd integer
+            local Socket = {}
+
+            ---Send data
+            ---@param data string the bytes to send
+            ---@param flags? integer
+            ---@return integer sent the number of bytes sent
+            ---@return string? # the error
+            ---@nodiscard
+            function Socket:send(data, flags)
+                return #data, nil
+            end
+
+            ---@deprecated use `send`
+            ---@param ... string
+            function Socket:write(...)
+            end
+
+            ---@generic V: table
+            ---@param value V
+            ---@return V
+            ---@overload fun(value: string): string
+            ---@async
+            function Socket.echo(value)
+                return value
+            end
+
+            Socket.MAX = 1024
+
+            return Socket
+            "#,
+        ),
+        (
+            "net/init.lua",
+            r#"
+            ---Networking helpers
+            local net = {}
+
+            ---@private
+            net.count = 0
+
+            ---@param host string
+            ---@return net.Socket<table>
+            function net.connect(host)
+            end
+
+            return net
+            "#,
+        ),
+        (
+            "types.lua",
+            r#"
+            ---@class Closeable
+            local Closeable = {}
+
+            ---@return boolean
+            function Closeable:close()
+            end
+
+            ---The read modes
+            ---@enum Mode
+            local Mode = {
+                ---read everything
+                All = 1,
+                Line = 2,
+            }
+
+            ---@alias Handler fun(mode: Mode): boolean
+            "#,
+        ),
+        (
+            "globals.lua",
+            r#"
+            ---The version string
+            VERSION = "1.0"
+
+            ---@type number
+            RATE = 0.5
+
+            ---@class Config
+            ---@field name string
+            Config = {}
+
+            util = {}
+
+            ---@param s string
+            ---@return string
+            function util.trim(s)
+                return s
+            end
+            "#,
+        ),
+    ];
+
+    fn write_project(
+        workspace: &TempWorkspace,
+        dir: &str,
+        files: impl IntoIterator<Item = (PathBuf, String)>,
+    ) -> PathBuf {
+        for (path, content) in files {
+            workspace.write(Path::new(dir).join(path), &content);
+        }
+        workspace.root().join(dir)
+    }
+
+    fn export_json(workspace: &TempWorkspace, dir: &Path) -> Value {
+        let analysis = workspace.load_dir(dir);
+        let mut json = serde_json::to_value(export(analysis.compilation.get_db())).unwrap();
+        normalize(&mut json);
+        json
+    }
+
+    // locations differ between the sources and the stubs, the order of types and globals is
+    // arbitrary
+    fn normalize(value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                map.remove("loc");
+                map.remove("file");
+                for (key, child) in map.iter_mut() {
+                    normalize(child);
+                    if matches!(key.as_str(), "modules" | "types" | "globals") {
+                        if let Value::Array(items) = child {
+                            items.sort_by_key(|item| item["name"].to_string());
+                        }
+                    }
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(normalize),
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn test_meta_round_trip() {
+        let workspace = TempWorkspace::new("meta");
+        let source_dir = write_project(
+            &workspace,
+            "source",
+            SOURCES.iter().map(|(path, content)| {
+                let content: Vec<&str> = content.lines().map(str::trim).collect();
+                (PathBuf::from(path), content.join("\n"))
+            }),
+        );
+
+        let source_analysis = workspace.load_dir(&source_dir);
+        let index = export(source_analysis.compilation.get_db());
+        let stubs = render_meta(&index, &source_dir);
+        assert_eq!(stubs.len(), SOURCES.len());
+        for stub in stubs.values() {
+            assert!(stub.starts_with("---@meta\n"));
+            assert!(!stub.contains("return #data"));
+        }
+        let stub_dir = write_project(&workspace, "stub", stubs);
+
+        let expected = export_json(&workspace, &source_dir);
+        let actual = export_json(&workspace, &stub_dir);
+        assert_eq!(
+            serde_json::to_string_pretty(&expected).unwrap(),
+            serde_json::to_string_pretty(&actual).unwrap()
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use std::path::{Path, PathBuf};
+
+use emmylua_code_analysis::EmmyLuaAnalysis;
+
+use crate::init::load_workspace;
+
+/// A project in the temp directory for the tests, removed on drop so a failed assertion does
+/// not leave it behind
+pub struct TempWorkspace {
+    root: PathBuf,
+}
+
+impl TempWorkspace {
+    /// `name` must be unique among the tests, they run in parallel
+    pub fn new(name: &str) -> Self {
+        let root = std::env::temp_dir().join(format!("emmylua_{}_{}", name, std::process::id()));
+        if root.exists() {
+            std::fs::remove_dir_all(&root).unwrap();
+        }
+        std::fs::create_dir_all(&root).unwrap();
+        Self { root }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Write a file relative to the root, with its directories
+    pub fn write(&self, path: impl AsRef<Path>, content: &str) -> PathBuf {
+        let path = self.root.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    /// Load the root as the workspace
+    pub fn load(&self) -> EmmyLuaAnalysis {
+        self.load_dir(&self.root)
+    }
+
+    pub fn load_dir(&self, dir: &Path) -> EmmyLuaAnalysis {
+        load_workspace(vec![dir.to_str().unwrap()]).unwrap()
+    }
+}
+
+impl Drop for TempWorkspace {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.root);
+    }
+}
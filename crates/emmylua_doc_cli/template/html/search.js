@@ -0,0 +1,68 @@
+(function () {
+  var input = document.getElementById("search");
+  var results = document.getElementById("search-results");
+  var entries = window.searchIndex || [];
+  var root = window.docRoot || "";
+
+  function rank(entry, query) {
+    var name = entry.name.toLowerCase();
+    if (name === query) {
+      return 0;
+    }
+    var last = name.split(".").pop();
+    if (last === query) {
+      return 1;
+    }
+    if (name.indexOf(query) === 0 || last.indexOf(query) === 0) {
+      return 2;
+    }
+    if (name.indexOf(query) !== -1) {
+      return 3;
+    }
+    if (entry.summary && entry.summary.toLowerCase().indexOf(query) !== -1) {
+      return 4;
+    }
+    return -1;
+  }
+
+  function search() {
+    var query = input.value.trim().toLowerCase();
+    results.innerHTML = "";
+    if (!query) {
+      return;
+    }
+
+    var matches = [];
+    for (var i = 0; i < entries.length; i++) {
+      var score = rank(entries[i], query);
+      if (score >= 0) {
+        matches.push({ score: score, entry: entries[i] });
+      }
+    }
+    matches.sort(function (a, b) {
+      return a.score - b.score || a.entry.name.length - b.entry.name.length;
+    });
+
+    matches.slice(0, 50).forEach(function (match) {
+      var entry = match.entry;
+      var item = document.createElement("li");
+      var kind = document.createElement("span");
+      kind.className = "kind";
+      kind.textContent = entry.kind;
+      var link = document.createElement("a");
+      link.href = root + entry.url;
+      link.textContent = entry.name;
+      item.appendChild(kind);
+      item.appendChild(link);
+      if (entry.summary) {
+        var summary = document.createElement("span");
+        summary.className = "summary";
+        summary.textContent = entry.summary;
+        item.appendChild(summary);
+      }
+      results.appendChild(item);
+    });
+  }
+
+  input.addEventListener("input", search);
+})();
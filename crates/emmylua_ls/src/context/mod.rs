@@ -16,8 +16,8 @@ pub use status_bar::StatusBar;
 use std::{collections::HashMap, future::Future, sync::Arc};
 use tokio::sync::{Mutex, RwLock};
 use tokio_util::sync::CancellationToken;
-pub use workspace_manager::load_emmy_config;
 pub use workspace_manager::WorkspaceManager;
+pub use workspace_manager::{load_emmy_config, load_folder_emmyrcs, load_nested_emmyrcs};
 
 pub struct ServerContext {
     #[allow(unused)]
@@ -1,15 +1,17 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
 use std::{path::PathBuf, sync::Arc, time::Duration};
 
 use super::{ClientProxy, FileDiagnostic, StatusBar};
 use crate::handlers::{init_analysis, ClientConfig};
 use dirs;
 use emmylua_code_analysis::update_code_style;
-use emmylua_code_analysis::{load_configs, EmmyLuaAnalysis, Emmyrc};
+use emmylua_code_analysis::{load_config_overrides, load_configs, EmmyLuaAnalysis, Emmyrc};
 use log::{debug, info};
 use lsp_types::Uri;
 use tokio::sync::{Mutex, RwLock};
 use tokio_util::sync::CancellationToken;
+use walkdir::WalkDir;
 
 pub struct WorkspaceManager {
     analysis: Arc<RwLock<EmmyLuaAnalysis>>,
@@ -21,6 +23,8 @@ pub struct WorkspaceManager {
     pub workspace_folders: Vec<PathBuf>,
     pub watcher: Option<notify::RecommendedWatcher>,
     pub current_open_files: HashSet<Uri>,
+    // open `.emmyrc.json`/`.luarc.json` documents, kept out of the lua index
+    pub config_documents: HashMap<Uri, String>,
 }
 
 impl WorkspaceManager {
@@ -40,6 +44,7 @@ impl WorkspaceManager {
             file_diagnostic,
             watcher: None,
             current_open_files: HashSet::new(),
+            config_documents: HashMap::new(),
         }
     }
 
@@ -68,13 +73,15 @@ impl WorkspaceManager {
                 return;
             }
 
-            let emmyrc = load_emmy_config(Some(file_dir.clone()), client_config);
+            let emmyrc = load_emmy_config(Some(file_dir.clone()), client_config.clone());
+            let folder_emmyrcs = load_folder_emmyrcs(&workspace_folders, &client_config);
             init_analysis(
                 analysis,
                 client,
                 &status_bar,
                 workspace_folders,
                 emmyrc,
+                folder_emmyrcs,
                 client_id,
                 file_diagnostic,
             )
@@ -85,6 +92,47 @@ impl WorkspaceManager {
         });
     }
 
+    /// A config file below a workspace folder changed, resolve the configs of that folder again
+    /// and reload the files under the changed directory
+    pub async fn update_nested_emmyrc(&self, config_dir: PathBuf) -> Option<()> {
+        let folder = self
+            .workspace_folders
+            .iter()
+            .filter(|folder| config_dir.starts_with(folder))
+            .max_by_key(|folder| folder.components().count())?
+            .clone();
+        let folder_emmyrc = load_folder_emmyrcs(&self.workspace_folders, &self.client_config)
+            .into_iter()
+            .find(|(root, _)| *root == folder)
+            .map(|(_, emmyrc)| emmyrc);
+
+        let mut analysis = self.analysis.write().await;
+        let base = folder_emmyrc
+            .clone()
+            .unwrap_or_else(|| analysis.get_emmyrc());
+        analysis.remove_workspace_config(&folder);
+        if let Some(folder_emmyrc) = folder_emmyrc {
+            analysis.update_workspace_config(folder.clone(), folder_emmyrc);
+        }
+        for (dir, emmyrc) in load_nested_emmyrcs(&folder, &base) {
+            info!("add nested config: {:?}", dir);
+            analysis.update_workspace_config(dir, emmyrc);
+        }
+
+        let file_ids = analysis.reload_files_under(&config_dir);
+        drop(analysis);
+        self.file_diagnostic
+            .add_files_diagnostic_task(file_ids, 0)
+            .await;
+        Some(())
+    }
+
+    pub fn is_nested_config_dir(&self, config_dir: &Path) -> bool {
+        self.workspace_folders
+            .iter()
+            .any(|folder| config_dir != folder && config_dir.starts_with(folder))
+    }
+
     pub fn update_editorconfig(&self, path: PathBuf) {
         let parent_dir = path
             .parent()
@@ -105,6 +153,7 @@ impl WorkspaceManager {
         };
 
         let emmyrc = load_emmy_config(config_root, self.client_config.clone());
+        let folder_emmyrcs = load_folder_emmyrcs(&self.workspace_folders, &self.client_config);
         let analysis = self.analysis.clone();
         let client = self.client.clone();
         let workspace_folders = self.workspace_folders.clone();
@@ -117,6 +166,7 @@ impl WorkspaceManager {
             &status_bar,
             workspace_folders,
             emmyrc,
+            folder_emmyrcs,
             client_id,
             file_diagnostic,
         )
@@ -245,6 +295,82 @@ pub fn load_emmy_config(config_root: Option<PathBuf>, client_config: ClientConfi
     emmyrc.into()
 }
 
+/// In a multi-root workspace every folder is configured by its own `.emmyrc.json`/`.luarc.json`
+pub fn load_folder_emmyrcs(
+    workspace_folders: &[PathBuf],
+    client_config: &ClientConfig,
+) -> Vec<(PathBuf, Arc<Emmyrc>)> {
+    if workspace_folders.len() < 2 {
+        return Vec::new();
+    }
+
+    workspace_folders
+        .iter()
+        .map(|folder| {
+            (
+                folder.clone(),
+                load_emmy_config(Some(folder.clone()), client_config.clone()),
+            )
+        })
+        .collect()
+}
+
+/// `.emmyrc.json`/`.luarc.json` files in the sub directories of a workspace folder, each one is merged
+/// over the config of its closest parent and applies to the files under its directory
+pub fn load_nested_emmyrcs(folder: &Path, base: &Emmyrc) -> Vec<(PathBuf, Arc<Emmyrc>)> {
+    let luarc_file = ".luarc.json";
+    let emmyrc_file = ".emmyrc.json";
+    let ignore_dirs: Vec<PathBuf> = base
+        .workspace
+        .ignore_dir
+        .iter()
+        .map(PathBuf::from)
+        .collect();
+    let mut config_dirs: Vec<PathBuf> = WalkDir::new(folder)
+        .min_depth(1)
+        .into_iter()
+        .filter_entry(|entry| {
+            if entry.file_type().is_dir() {
+                let is_hidden = entry.file_name().to_string_lossy().starts_with('.');
+                !is_hidden && !ignore_dirs.iter().any(|dir| entry.path().starts_with(dir))
+            } else {
+                true
+            }
+        })
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            entry.file_type().is_file()
+                && (entry.file_name() == luarc_file || entry.file_name() == emmyrc_file)
+        })
+        .filter_map(|entry| entry.path().parent().map(Path::to_path_buf))
+        .filter(|dir| dir != folder)
+        .collect();
+    config_dirs.sort();
+    config_dirs.dedup();
+    // parents first, so a nested config is merged over its parent's
+    config_dirs.sort_by_key(|dir| dir.components().count());
+
+    let mut nested_emmyrcs: Vec<(PathBuf, Arc<Emmyrc>)> = Vec::new();
+    for dir in config_dirs {
+        let parent_emmyrc = nested_emmyrcs
+            .iter()
+            .filter(|(parent, _)| dir.starts_with(parent))
+            .max_by_key(|(parent, _)| parent.components().count())
+            .map(|(_, emmyrc)| emmyrc.as_ref())
+            .unwrap_or(base);
+        let config_files = [luarc_file, emmyrc_file]
+            .iter()
+            .map(|file| dir.join(file))
+            .filter(|path| path.exists())
+            .collect();
+        let mut emmyrc = load_config_overrides(parent_emmyrc, config_files);
+        emmyrc.pre_process_emmyrc(&dir);
+        nested_emmyrcs.push((dir, emmyrc.into()));
+    }
+
+    nested_emmyrcs
+}
+
 fn merge_client_config(client_config: ClientConfig, emmyrc: &mut Emmyrc) -> Option<()> {
     emmyrc.runtime.extensions.extend(client_config.extensions);
     emmyrc.workspace.ignore_globs.extend(client_config.exclude);
@@ -3,9 +3,9 @@ use std::collections::HashMap;
 use emmylua_code_analysis::{DiagnosticCode, Emmyrc, LuaDocument, SemanticModel};
 use emmylua_parser::{
     LuaAst, LuaAstNode, LuaComment, LuaCommentOwner, LuaDocTag, LuaDocTagDiagnostic, LuaExpr,
-    LuaKind, LuaStat, LuaSyntaxNode, LuaTokenKind,
+    LuaKind, LuaStat, LuaSyntaxEditor, LuaSyntaxNode, LuaTokenKind,
 };
-use lsp_types::{Position, Range, TextEdit, Uri};
+use lsp_types::{Position, TextEdit, Uri};
 use rowan::{TextSize, TokenAtOffset};
 
 use crate::handlers::command::DisableAction;
@@ -99,101 +99,30 @@ pub fn build_disable_next_line_changes(
         }
     };
 
-    let mut text_edit = None;
-
-    if let Some(comment) = ast.get_left_comment() {
-        if let Some(diagnostic_tag) =
-            find_diagnostic_disable_tag(comment.clone(), DisableAction::DisableLine)
-        {
-            let new_start = if let Some(actions_list) = diagnostic_tag.get_code_list() {
-                actions_list.get_range().end()
-            } else {
-                diagnostic_tag.get_range().end()
+    let mut editor = LuaSyntaxEditor::new(root.syntax());
+    let comment = ast.get_left_comment();
+    match comment
+        .clone()
+        .and_then(|comment| find_diagnostic_disable_tag(comment, DisableAction::DisableLine))
+    {
+        Some(diagnostic_tag) => editor.insert_text(
+            append_offset(&diagnostic_tag),
+            format!(", {}", code.get_name()),
+        ),
+        None => {
+            // above the doc comment of the line, it belongs to the statement
+            let anchor = match &comment {
+                Some(comment) => comment.syntax(),
+                None => ast.syntax(),
             };
-
-            let (line, col) = document.get_line_col(new_start)?;
-            text_edit = Some(TextEdit {
-                range: Range {
-                    start: Position {
-                        line: line as u32,
-                        character: col as u32,
-                    },
-                    end: Position {
-                        line: line as u32,
-                        character: col as u32,
-                    },
-                },
-                new_text: format!(", {}", code.get_name()),
-            });
-        } else {
-            text_edit = get_disable_next_line_text_edit(
-                &document,
-                &emmyrc,
-                comment.syntax().clone(),
-                comment.get_position(),
-                code,
+            editor.insert_text_line_before(
+                anchor,
+                &disable_comment(&emmyrc, "disable-next-line", code),
             );
         }
-    };
-
-    if text_edit.is_none() {
-        text_edit = get_disable_next_line_text_edit(
-            &document,
-            &emmyrc,
-            ast.syntax().clone(),
-            ast.get_position(),
-            code,
-        );
     }
 
-    let mut changes = HashMap::new();
-    let uri = document.get_uri();
-    changes.insert(uri, vec![text_edit?]);
-
-    Some(changes)
-}
-
-fn get_disable_next_line_text_edit(
-    document: &LuaDocument,
-    emmyrc: &Emmyrc,
-    node: LuaSyntaxNode,
-    offset: TextSize,
-    code: DiagnosticCode,
-) -> Option<TextEdit> {
-    let indent_text = if let Some(prefix_token) = node.prev_sibling_or_token() {
-        if prefix_token.kind() == LuaTokenKind::TkWhitespace.into() {
-            prefix_token.into_token()?.text().to_string()
-        } else {
-            "".to_string()
-        }
-    } else {
-        "".to_string()
-    };
-
-    let line = document.get_line(offset)?;
-    let space = if emmyrc.code_action.insert_space {
-        " "
-    } else {
-        ""
-    };
-    Some(TextEdit {
-        range: Range {
-            start: Position {
-                line: line as u32,
-                character: 0,
-            },
-            end: Position {
-                line: line as u32,
-                character: 0,
-            },
-        },
-        new_text: format!(
-            "{}---{}@diagnostic disable-next-line: {}\n",
-            indent_text,
-            space,
-            code.get_name()
-        ),
-    })
+    to_lsp_changes(&document, editor)
 }
 
 pub fn build_disable_file_changes(
@@ -205,70 +134,59 @@ pub fn build_disable_file_changes(
     let first_child = first_block.children::<LuaAst>().next()?;
     let document = semantic_model.get_document();
     let emmyrc = semantic_model.get_emmyrc();
+    let diagnostic_tag = match first_child {
+        LuaAst::LuaComment(comment) => {
+            find_diagnostic_disable_tag(comment, DisableAction::DisableFile)
+        }
+        _ => None,
+    };
+
+    let mut editor = LuaSyntaxEditor::new(root.syntax());
+    match diagnostic_tag {
+        Some(diagnostic_tag) => editor.insert_text(
+            append_offset(&diagnostic_tag),
+            format!(", {}", code.get_name()),
+        ),
+        None => editor.insert_text(
+            0.into(),
+            format!("{}\n", disable_comment(&emmyrc, "disable", code)),
+        ),
+    }
+
+    to_lsp_changes(&document, editor)
+}
+
+fn disable_comment(emmyrc: &Emmyrc, action: &str, code: DiagnosticCode) -> String {
     let space = if emmyrc.code_action.insert_space {
         " "
     } else {
         ""
     };
-    let text_edit = if let LuaAst::LuaComment(comment) = first_child {
-        if let Some(diagnostic_tag) =
-            find_diagnostic_disable_tag(comment.clone(), DisableAction::DisableFile)
-        {
-            let new_start = if let Some(actions_list) = diagnostic_tag.get_code_list() {
-                actions_list.get_range().end()
-            } else {
-                diagnostic_tag.get_range().end()
-            };
+    format!("---{}@diagnostic {}: {}", space, action, code.get_name())
+}
 
-            let (line, col) = document.get_line_col(new_start)?;
-            TextEdit {
-                range: Range {
-                    start: Position {
-                        line: line as u32,
-                        character: col as u32,
-                    },
-                    end: Position {
-                        line: line as u32,
-                        character: col as u32,
-                    },
-                },
-                new_text: format!(", {}", code.get_name()),
-            }
-        } else {
-            TextEdit {
-                range: Range {
-                    start: Position {
-                        line: 0,
-                        character: 0,
-                    },
-                    end: Position {
-                        line: 0,
-                        character: 0,
-                    },
-                },
-                new_text: format!("---{}@diagnostic disable: {}\n", space, code.get_name()),
-            }
-        }
-    } else {
-        TextEdit {
-            range: Range {
-                start: Position {
-                    line: 0,
-                    character: 0,
-                },
-                end: Position {
-                    line: 0,
-                    character: 0,
-                },
-            },
-            new_text: format!("---{}@diagnostic disable: {}\n", space, code.get_name()),
-        }
-    };
+// the end of the code list of an existing tag, the new code is appended there
+fn append_offset(diagnostic_tag: &LuaDocTagDiagnostic) -> TextSize {
+    match diagnostic_tag.get_code_list() {
+        Some(code_list) => code_list.get_range().end(),
+        None => diagnostic_tag.get_range().end(),
+    }
+}
 
-    let mut changes = HashMap::new();
-    let uri = document.get_uri();
-    changes.insert(uri, vec![text_edit]);
+fn to_lsp_changes(
+    document: &LuaDocument,
+    editor: LuaSyntaxEditor,
+) -> Option<HashMap<Uri, Vec<TextEdit>>> {
+    let mut text_edits = Vec::new();
+    for edit in editor.finish() {
+        text_edits.push(TextEdit {
+            range: document.to_lsp_range(edit.range)?,
+            new_text: edit.new_text,
+        });
+    }
 
+    let mut changes = HashMap::new();
+    changes.insert(document.get_uri(), text_edits);
     Some(changes)
 }
 
@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+
+use emmylua_code_analysis::SemanticModel;
+use emmylua_parser::{LuaAstNode, LuaAstToken, LuaIfStat, LuaTokenKind};
+use lsp_types::{Position, Range, TextEdit, Uri};
+use rowan::{TextRange, TokenAtOffset};
+
+/// insert the missing `elseif x == Case then` branches before the `else` clause or `end`,
+/// their bodies raise an error until they are filled in
+pub fn build_missing_cases_changes(
+    semantic_model: &SemanticModel<'_>,
+    range: Range,
+    data: &serde_json::Value,
+) -> Option<HashMap<Uri, Vec<TextEdit>>> {
+    let subject = data.get("subject")?.as_str()?;
+    let cases = data
+        .get("cases")?
+        .as_array()?
+        .iter()
+        .filter_map(|case| case.as_str())
+        .collect::<Vec<_>>();
+    if cases.is_empty() {
+        return None;
+    }
+
+    let document = semantic_model.get_document();
+    let offset = document.get_offset(range.start.line as usize, range.start.character as usize)?;
+    let root = semantic_model.get_root();
+    let token = match root.syntax().token_at_offset(offset) {
+        TokenAtOffset::Single(token) => token,
+        TokenAtOffset::Between(_, token) => token,
+        _ => return None,
+    };
+    let if_stat = token.parent_ancestors().find_map(LuaIfStat::cast)?;
+
+    let insert_offset = match if_stat.get_else_clause() {
+        Some(else_clause) => else_clause.get_position(),
+        None => if_stat.token_by_kind(LuaTokenKind::TkEnd)?.get_position(),
+    };
+
+    let if_line = document.get_line(if_stat.get_position())?;
+    let line_range = document.get_line_range(if_line)?;
+    let indent = document
+        .get_text_slice(TextRange::new(line_range.start(), if_stat.get_position()))
+        .to_string();
+
+    // indent the new bodies like the existing ones
+    let body_indent = if_stat
+        .get_block()
+        .and_then(|block| block.get_stats().next())
+        .and_then(|stat| {
+            let line_range = document.get_line_range(document.get_line(stat.get_position())?)?;
+            Some(
+                document
+                    .get_text_slice(TextRange::new(line_range.start(), stat.get_position()))
+                    .to_string(),
+            )
+        })
+        .filter(|body_indent| body_indent.len() > indent.len())
+        .unwrap_or_else(|| format!("{}    ", indent));
+
+    let mut new_text = String::new();
+    for case in cases {
+        new_text.push_str(&format!(
+            "elseif {subject} == {case} then\n{body_indent}error(\"unhandled \" .. tostring({subject}))\n{indent}"
+        ));
+    }
+
+    let position: Position = document.to_lsp_position(insert_offset)?;
+    let mut changes = HashMap::new();
+    changes.insert(
+        document.get_uri(),
+        vec![TextEdit {
+            range: Range {
+                start: position,
+                end: position,
+            },
+            new_text,
+        }],
+    );
+
+    Some(changes)
+}
@@ -1,3 +1,5 @@
 mod build_disable_code;
+mod build_missing_cases;
 
 pub use build_disable_code::*;
+pub use build_missing_cases::*;
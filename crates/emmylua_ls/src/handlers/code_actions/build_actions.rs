@@ -8,7 +8,9 @@ use lsp_types::{
 
 use crate::handlers::command::{make_disable_code_command, DisableAction};
 
-use super::actions::{build_disable_file_changes, build_disable_next_line_changes};
+use super::actions::{
+    build_disable_file_changes, build_disable_next_line_changes, build_missing_cases_changes,
+};
 
 pub fn build_actions(
     semantic_model: &SemanticModel,
@@ -29,9 +31,15 @@ pub fn build_actions(
         if let Some(code) = diagnostic.code {
             if let NumberOrString::String(action_string) = code {
                 if let Some(diagnostic_code) = DiagnosticCode::from_str(&action_string).ok() {
-                    add_fix_code_action(&mut actions, diagnostic_code, file_id, diagnostic.range);
+                    add_fix_code_action(
+                        semantic_model,
+                        &mut actions,
+                        diagnostic_code,
+                        diagnostic.range,
+                        diagnostic.data.as_ref(),
+                    );
                     add_disable_code_action(
-                        &semantic_model,
+                        semantic_model,
                         &mut actions,
                         diagnostic_code,
                         file_id,
@@ -49,13 +57,26 @@ pub fn build_actions(
     Some(actions)
 }
 
-#[allow(unused_variables)]
 fn add_fix_code_action(
+    semantic_model: &SemanticModel,
     actions: &mut Vec<CodeActionOrCommand>,
     diagnostic_code: DiagnosticCode,
-    file_id: FileId,
     range: Range,
+    data: Option<&serde_json::Value>,
 ) -> Option<()> {
+    if diagnostic_code == DiagnosticCode::MissingCases {
+        let changes = build_missing_cases_changes(semantic_model, range, data?)?;
+        actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+            title: t!("Add missing cases").to_string(),
+            kind: Some(CodeActionKind::QUICKFIX),
+            edit: Some(WorkspaceEdit {
+                changes: Some(changes),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }));
+    }
+
     Some(())
 }
 
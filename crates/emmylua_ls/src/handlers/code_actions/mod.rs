@@ -1,5 +1,7 @@
 mod actions;
 mod build_actions;
+#[cfg(test)]
+mod test;
 
 use build_actions::build_actions;
 use lsp_types::{
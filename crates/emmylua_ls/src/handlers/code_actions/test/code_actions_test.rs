@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use emmylua_code_analysis::{DiagnosticCode, Emmyrc};
+    use lsp_types::{CodeActionOrCommand, NumberOrString, Position, TextEdit, Uri};
+    use tokio_util::sync::CancellationToken;
+
+    use crate::handlers::{
+        code_actions::{
+            actions::{build_disable_file_changes, build_disable_next_line_changes},
+            build_actions::build_actions,
+        },
+        test_lib::ProviderVirtualWorkspace,
+    };
+
+    fn single_edit(changes: Option<HashMap<Uri, Vec<TextEdit>>>) -> TextEdit {
+        let mut edits = changes.unwrap().into_values().next().unwrap();
+        assert_eq!(edits.len(), 1);
+        edits.remove(0)
+    }
+
+    #[test]
+    fn test_disable_code() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let file_id = ws.def("local function f()\n    local a = 1; print(b)\nend\n");
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        let edit = single_edit(build_disable_next_line_changes(
+            &semantic_model,
+            Position::new(1, 17),
+            DiagnosticCode::UndefinedGlobal,
+        ));
+        assert_eq!(edit.range.start, Position::new(1, 0));
+        assert_eq!(
+            edit.new_text,
+            "    ---@diagnostic disable-next-line: undefined-global\n"
+        );
+
+        let file_id = ws.def("---@diagnostic disable: unused\nprint(b)\n");
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        let edit = single_edit(build_disable_file_changes(
+            &semantic_model,
+            DiagnosticCode::UndefinedGlobal,
+        ));
+        assert_eq!(edit.range.start, Position::new(0, 30));
+        assert_eq!(edit.new_text, ", undefined-global");
+    }
+
+    #[test]
+    fn test_add_missing_cases() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let mut emmyrc = Emmyrc::default();
+        emmyrc
+            .diagnostics
+            .enables
+            .push(DiagnosticCode::MissingCases);
+        ws.analysis.update_config(emmyrc.into());
+        let file_id = ws.def(
+            r#"
+            ---@param mode 'r'|'w'|'a'
+            local function f(mode)
+                if mode == 'r' then
+                    print(1)
+                elseif mode == 'w' then
+                    print(2)
+                end
+            end
+            "#,
+        );
+        let diagnostics = ws
+            .analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .unwrap()
+            .into_iter()
+            .filter(|diagnostic| {
+                diagnostic.code == Some(NumberOrString::String("missing-cases".to_string()))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(diagnostics.len(), 1);
+
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        let actions = build_actions(&semantic_model, diagnostics).unwrap();
+        let edit = actions
+            .into_iter()
+            .find_map(|action| match action {
+                CodeActionOrCommand::CodeAction(action) if action.title == "Add missing cases" => {
+                    action
+                        .edit?
+                        .changes?
+                        .into_values()
+                        .next()?
+                        .into_iter()
+                        .next()
+                }
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(
+            edit.new_text,
+            "elseif mode == \"a\" then\n                    error(\"unhandled \" .. tostring(mode))\n                "
+        );
+    }
+}
@@ -0,0 +1 @@
+mod code_actions_test;
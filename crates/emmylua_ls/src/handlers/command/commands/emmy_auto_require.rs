@@ -1,8 +1,11 @@
 use std::{collections::HashMap, time::Duration};
 
 use emmylua_code_analysis::FileId;
-use emmylua_parser::{LuaAstNode, LuaExpr, LuaStat};
-use lsp_types::{ApplyWorkspaceEditParams, Command, Position, TextEdit, WorkspaceEdit};
+use emmylua_parser::{LuaAstNode, LuaExpr, LuaStat, LuaSyntaxEditor, LuaSyntaxFactory};
+use lsp_types::{
+    ApplyWorkspaceEditParams, Command, MessageType, Position, ShowMessageParams, TextEdit,
+    WorkspaceEdit,
+};
 use serde_json::Value;
 
 use crate::{
@@ -17,7 +20,7 @@ pub struct AutoRequireCommand;
 impl CommandSpec for AutoRequireCommand {
     const COMMAND: &str = "emmy.auto.require";
 
-    async fn handle(context: ServerContextSnapshot, args: Vec<Value>) -> Option<()> {
+    async fn handle(context: ServerContextSnapshot, args: Vec<Value>) -> Option<Value> {
         let add_to: FileId = serde_json::from_value(args.get(0)?.clone()).ok()?;
         let need_require_file_id: FileId = serde_json::from_value(args.get(1)?.clone()).ok()?;
         let position: Position = serde_json::from_value(args.get(2)?.clone()).ok()?;
@@ -41,13 +44,27 @@ impl CommandSpec for AutoRequireCommand {
                 .replace(".", &require_separator),
         };
 
-        let require_str = format!(
-            "local {} = {}(\"{}\")",
-            local_name, auto_require_func, full_module_path
-        );
+        let require_value = format!("{}(\"{}\")", auto_require_func, full_module_path);
+        let require_stat = match LuaSyntaxFactory::new(emmyrc.get_language_level())
+            .local_stat(&[&local_name], &[&require_value])
+        {
+            Some(require_stat) => require_stat,
+            // e.g. a module name that is not a valid local name
+            None => {
+                context.client.show_message(ShowMessageParams {
+                    typ: MessageType::ERROR,
+                    message: format!(
+                        "Cannot build `local {} = {}`, the module is not required",
+                        local_name, require_value
+                    ),
+                });
+                return None;
+            }
+        };
         let document = semantic_model.get_document();
         let offset = document.get_offset(position.line as usize, position.character as usize)?;
-        let root_block = semantic_model.get_root().get_block()?;
+        let root = semantic_model.get_root();
+        let root_block = root.get_block()?;
         let mut last_require_stat: Option<LuaStat> = None;
         for stat in root_block.get_stats() {
             if stat.get_position() > offset {
@@ -59,30 +76,25 @@ impl CommandSpec for AutoRequireCommand {
             }
         }
 
-        let line = if let Some(last_require_stat) = last_require_stat {
-            let last_require_stat_end = last_require_stat.get_range().end();
-            document.get_line(last_require_stat_end)? + 1
-        } else {
-            0
-        };
+        let mut editor = LuaSyntaxEditor::new(root.syntax());
+        match last_require_stat {
+            Some(last_require_stat) => {
+                editor.insert_after(last_require_stat.syntax(), require_stat.syntax())
+            }
+            None => editor.insert_text(0.into(), format!("{}\n", require_stat.syntax())),
+        }
 
-        let text_edit = TextEdit {
-            range: lsp_types::Range {
-                start: Position {
-                    line: line as u32,
-                    character: 0,
-                },
-                end: Position {
-                    line: line as u32,
-                    character: 0,
-                },
-            },
-            new_text: format!("{}\n", require_str),
-        };
+        let mut text_edits = Vec::new();
+        for edit in editor.finish() {
+            text_edits.push(TextEdit {
+                range: document.to_lsp_range(edit.range)?,
+                new_text: edit.new_text,
+            });
+        }
 
         let uri = document.get_uri();
         let mut changes = HashMap::new();
-        changes.insert(uri.clone(), vec![text_edit.clone()]);
+        changes.insert(uri.clone(), text_edits);
 
         let client = context.client;
         let cancel_token = time_cancel_token(Duration::from_secs(5));
@@ -104,7 +116,7 @@ impl CommandSpec for AutoRequireCommand {
             }
         });
 
-        Some(())
+        Some(Value::Null)
     }
 }
 
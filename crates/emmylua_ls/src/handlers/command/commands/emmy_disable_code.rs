@@ -23,7 +23,7 @@ pub struct DisableCodeCommand;
 impl CommandSpec for DisableCodeCommand {
     const COMMAND: &str = "emmy.disable.code";
 
-    async fn handle(context: ServerContextSnapshot, args: Vec<Value>) -> Option<()> {
+    async fn handle(context: ServerContextSnapshot, args: Vec<Value>) -> Option<Value> {
         let action: DisableAction = serde_json::from_value(args.get(0)?.clone()).ok()?;
         let code: DiagnosticCode = serde_json::from_value(args.get(3)?.clone()).ok()?;
 
@@ -34,7 +34,7 @@ impl CommandSpec for DisableCodeCommand {
             _ => {}
         }
 
-        Some(())
+        Some(Value::Null)
     }
 }
 
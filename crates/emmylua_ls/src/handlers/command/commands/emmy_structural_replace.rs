@@ -0,0 +1,115 @@
+use std::{collections::HashMap, time::Duration};
+
+use emmylua_code_analysis::{Emmyrc, StructuralSearch};
+use lsp_types::{
+    ApplyWorkspaceEditParams, MessageType, ShowMessageParams, TextEdit, WorkspaceEdit,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{context::ServerContextSnapshot, util::time_cancel_token};
+
+use super::CommandSpec;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StructuralReplaceParams {
+    /// e.g. `foo.bar($x, $y)`
+    pub pattern: String,
+    /// e.g. `foo:baz($y, $x)`
+    pub replacement: String,
+    /// placeholder name to the type its expression must have
+    #[serde(default)]
+    pub constraints: HashMap<String, String>,
+    /// also ask the client to apply the edit, the edit is returned either way
+    #[serde(default)]
+    pub apply: bool,
+}
+
+/// Structural replace over the workspace, returns the `WorkspaceEdit`
+pub struct StructuralReplaceCommand;
+
+impl CommandSpec for StructuralReplaceCommand {
+    const COMMAND: &str = "emmy.structural.replace";
+
+    async fn handle(context: ServerContextSnapshot, args: Vec<Value>) -> Option<Value> {
+        let params: StructuralReplaceParams = serde_json::from_value(args.first()?.clone()).ok()?;
+        let analysis = context.analysis.read().await;
+        let search = match build_search(&params, &analysis.get_emmyrc()) {
+            Ok(search) => search,
+            Err(message) => {
+                context.client.show_message(ShowMessageParams {
+                    typ: MessageType::ERROR,
+                    message,
+                });
+                return None;
+            }
+        };
+
+        let db = analysis.compilation.get_db();
+        let module_index = db.get_module_index();
+        let mut changes = HashMap::new();
+        for file_id in db.get_vfs().get_all_file_ids() {
+            if !module_index.is_main(&file_id) {
+                continue;
+            }
+            let Some(semantic_model) = analysis.compilation.get_semantic_model(file_id) else {
+                continue;
+            };
+            let document = semantic_model.get_document();
+            let mut text_edits = Vec::new();
+            for edit in search.replace(&semantic_model) {
+                // one edit that cannot be mapped should not drop the others
+                let Some(range) = document.to_lsp_range(edit.range) else {
+                    continue;
+                };
+                text_edits.push(TextEdit {
+                    range,
+                    new_text: edit.new_text,
+                });
+            }
+            if !text_edits.is_empty() {
+                changes.insert(document.get_uri(), text_edits);
+            }
+        }
+
+        let edit = WorkspaceEdit {
+            changes: Some(changes),
+            document_changes: None,
+            change_annotations: None,
+        };
+        if params.apply {
+            let client = context.client.clone();
+            let cancel_token = time_cancel_token(Duration::from_secs(5));
+            let apply_edit_params = ApplyWorkspaceEditParams {
+                label: Some("Structural replace".to_string()),
+                edit: edit.clone(),
+            };
+            tokio::spawn(async move {
+                let res = client.apply_edit(apply_edit_params, cancel_token).await;
+                if let Some(res) = res {
+                    if !res.applied {
+                        log::error!("Failed to apply edit: {:?}", res.failure_reason);
+                    }
+                }
+            });
+        }
+
+        serde_json::to_value(edit).ok()
+    }
+}
+
+fn build_search(
+    params: &StructuralReplaceParams,
+    emmyrc: &Emmyrc,
+) -> Result<StructuralSearch, String> {
+    let mut search = StructuralSearch::new(&params.pattern, emmyrc)
+        .and_then(|search| search.with_replacement(&params.replacement))
+        .map_err(|err| err.to_string())?;
+    for (placeholder, type_text) in &params.constraints {
+        search = search
+            .with_constraint(placeholder, type_text)
+            .map_err(|err| err.to_string())?;
+    }
+    Ok(search)
+}
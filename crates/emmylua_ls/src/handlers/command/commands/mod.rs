@@ -3,6 +3,7 @@ use std::sync::LazyLock;
 use emmy_auto_require::AutoRequireCommand;
 use emmy_disable_code::DisableCodeCommand;
 use emmy_fix_format::FixFormatCommand;
+use emmy_structural_replace::StructuralReplaceCommand;
 use serde_json::Value;
 
 use crate::context::ServerContextSnapshot;
@@ -10,6 +11,7 @@ use crate::context::ServerContextSnapshot;
 mod emmy_auto_require;
 mod emmy_disable_code;
 mod emmy_fix_format;
+mod emmy_structural_replace;
 
 pub use emmy_auto_require::make_auto_require;
 pub use emmy_disable_code::{make_disable_code_command, DisableAction};
@@ -17,7 +19,7 @@ pub use emmy_disable_code::{make_disable_code_command, DisableAction};
 pub trait CommandSpec {
     const COMMAND: &str;
 
-    async fn handle(context: ServerContextSnapshot, args: Vec<Value>) -> Option<()>;
+    async fn handle(context: ServerContextSnapshot, args: Vec<Value>) -> Option<Value>;
 }
 
 static COMMANDS: LazyLock<Vec<String>> = LazyLock::new(|| {
@@ -25,6 +27,7 @@ static COMMANDS: LazyLock<Vec<String>> = LazyLock::new(|| {
         AutoRequireCommand::COMMAND.to_string(),
         DisableCodeCommand::COMMAND.to_string(),
         FixFormatCommand::COMMAND.to_string(),
+        StructuralReplaceCommand::COMMAND.to_string(),
     ]
 });
 
@@ -36,11 +39,12 @@ pub async fn dispatch_command(
     context: ServerContextSnapshot,
     command_name: &str,
     args: Vec<Value>,
-) -> Option<()> {
+) -> Option<Value> {
     match command_name {
         AutoRequireCommand::COMMAND => AutoRequireCommand::handle(context, args).await,
         DisableCodeCommand::COMMAND => DisableCodeCommand::handle(context, args).await,
         FixFormatCommand::COMMAND => FixFormatCommand::handle(context, args).await,
-        _ => Some(()),
+        StructuralReplaceCommand::COMMAND => StructuralReplaceCommand::handle(context, args).await,
+        _ => Some(Value::Null),
     }
 }
@@ -19,8 +19,9 @@ pub async fn on_execute_command_handler(
 ) -> Option<Value> {
     let args = params.arguments;
     let command_name = params.command.as_str();
-    commands::dispatch_command(context, command_name, args).await;
-    Some(Value::Null)
+    commands::dispatch_command(context, command_name, args)
+        .await
+        .or(Some(Value::Null))
 }
 
 pub struct CommandCapabilities;
@@ -30,4 +30,5 @@ pub const DOC_TAGS: &[&str] = &[
     "source",
     "readonly",
     "return_cast",
+    "language",
 ];
@@ -21,7 +21,10 @@ use tokio_util::sync::CancellationToken;
 
 use crate::context::{ClientId, ServerContextSnapshot};
 
-use super::RegisterCapabilities;
+use super::{
+    config_document::{config_completion, is_config_document},
+    RegisterCapabilities,
+};
 
 pub async fn on_completion_handler(
     context: ServerContextSnapshot,
@@ -30,6 +33,12 @@ pub async fn on_completion_handler(
 ) -> Option<CompletionResponse> {
     let uri = params.text_document_position.text_document.uri;
     let position = params.text_document_position.position;
+    if is_config_document(&uri) {
+        let workspace = context.workspace_manager.read().await;
+        let text = workspace.config_documents.get(&uri)?;
+        return config_completion(text, position);
+    }
+
     let analysis = context.analysis.read().await;
     let file_id = analysis.get_file_id(&uri)?;
     let semantic_model = analysis.compilation.get_semantic_model(file_id)?;
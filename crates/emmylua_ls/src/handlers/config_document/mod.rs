@@ -0,0 +1,112 @@
+mod test;
+
+use std::path::Path;
+
+use emmylua_code_analysis::{
+    check_config_json, complete_config_json, file_path_to_uri, hover_config_json,
+    read_file_with_encoding, ConfigCompletionKind,
+};
+use emmylua_parser::LineIndex;
+use lsp_types::{
+    CompletionItem, CompletionItemKind, CompletionResponse, CompletionTextEdit, Documentation,
+    Hover, HoverContents, MarkupContent, MarkupKind, Position, PublishDiagnosticsParams, Range,
+    TextEdit, Uri,
+};
+use rowan::{TextRange, TextSize};
+
+use crate::context::ClientProxy;
+
+/// `.emmyrc.json`/`.luarc.json` are validated and completed against the config schema instead of
+/// being indexed as lua files
+pub fn is_config_document(uri: &Uri) -> bool {
+    let path = uri.path().as_str();
+    path.ends_with("/.emmyrc.json") || path.ends_with("/.luarc.json")
+}
+
+pub fn publish_config_diagnostics(client: &ClientProxy, uri: Uri, text: &str) {
+    client.publish_diagnostics(PublishDiagnosticsParams {
+        uri,
+        diagnostics: check_config_json(text),
+        version: None,
+    });
+}
+
+/// Check the config files on disk in `dir`, an empty result clears the diagnostics of a removed file
+pub fn publish_config_file_diagnostics(client: &ClientProxy, dir: &Path) {
+    for file_name in [".luarc.json", ".emmyrc.json"] {
+        let path = dir.join(file_name);
+        let Some(uri) = file_path_to_uri(&path) else {
+            continue;
+        };
+        let text = read_file_with_encoding(&path, "utf-8");
+        match text {
+            Some(text) => publish_config_diagnostics(client, uri, &text),
+            None => client.publish_diagnostics(PublishDiagnosticsParams {
+                uri,
+                diagnostics: vec![],
+                version: None,
+            }),
+        }
+    }
+}
+
+pub fn config_completion(text: &str, position: Position) -> Option<CompletionResponse> {
+    let line_index = LineIndex::parse(text);
+    let offset =
+        line_index.get_offset(position.line as usize, position.character as usize, text)?;
+    let items: Vec<CompletionItem> = complete_config_json(text, offset)
+        .into_iter()
+        .map(|item| CompletionItem {
+            kind: Some(match item.kind {
+                ConfigCompletionKind::Key => CompletionItemKind::PROPERTY,
+                ConfigCompletionKind::Value => CompletionItemKind::ENUM_MEMBER,
+            }),
+            documentation: item.description.map(|description| {
+                Documentation::MarkupContent(MarkupContent {
+                    kind: MarkupKind::Markdown,
+                    value: description,
+                })
+            }),
+            filter_text: Some(item.label.clone()),
+            text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                range: to_lsp_range(&line_index, text, item.range),
+                new_text: item.new_text,
+            })),
+            label: item.label,
+            ..Default::default()
+        })
+        .collect();
+    if items.is_empty() {
+        return None;
+    }
+
+    Some(CompletionResponse::Array(items))
+}
+
+pub fn config_hover(text: &str, position: Position) -> Option<Hover> {
+    let line_index = LineIndex::parse(text);
+    let offset =
+        line_index.get_offset(position.line as usize, position.character as usize, text)?;
+    let markdown = hover_config_json(text, offset)?;
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: markdown,
+        }),
+        range: None,
+    })
+}
+
+fn to_lsp_range(line_index: &LineIndex, text: &str, range: TextRange) -> Range {
+    let position = |offset: TextSize| {
+        let (line, character) = line_index.get_line_col(offset, text).unwrap_or((0, 0));
+        Position {
+            line: line as u32,
+            character: character as u32,
+        }
+    };
+    Range {
+        start: position(range.start()),
+        end: position(range.end()),
+    }
+}
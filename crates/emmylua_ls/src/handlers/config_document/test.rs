@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use lsp_types::{CompletionResponse, CompletionTextEdit, HoverContents, Position, Range, Uri};
+
+    use crate::handlers::config_document::{config_completion, config_hover, is_config_document};
+
+    #[test]
+    fn test_is_config_document() {
+        let uri = |s: &str| Uri::from_str(s).unwrap();
+        assert!(is_config_document(&uri("file:///project/.emmyrc.json")));
+        assert!(is_config_document(&uri("file:///project/sub/.luarc.json")));
+        assert!(!is_config_document(&uri("file:///project/emmyrc.json")));
+        assert!(!is_config_document(&uri("file:///project/main.lua")));
+    }
+
+    #[test]
+    fn test_config_completion() {
+        let text = "{\n  \"diagnostics\": {\n    \"disable\": [\"undefined-\"]\n  }\n}";
+        let Some(CompletionResponse::Array(items)) = config_completion(
+            text,
+            Position {
+                line: 2,
+                character: 27,
+            },
+        ) else {
+            panic!("expected completion items");
+        };
+        let item = items
+            .iter()
+            .find(|item| item.label == "undefined-global")
+            .unwrap();
+        let Some(CompletionTextEdit::Edit(edit)) = &item.text_edit else {
+            panic!("expected a text edit");
+        };
+        assert_eq!(edit.new_text, "undefined-global");
+        assert_eq!(
+            edit.range,
+            Range {
+                start: Position {
+                    line: 2,
+                    character: 17
+                },
+                end: Position {
+                    line: 2,
+                    character: 27
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_config_hover() {
+        let text = "{\n  \"runtime\": {\n    \"version\": \"Lua5.4\"\n  }\n}";
+        let hover = config_hover(
+            text,
+            Position {
+                line: 1,
+                character: 5,
+            },
+        )
+        .unwrap();
+        let HoverContents::Markup(markup) = hover.contents else {
+            panic!("expected markdown");
+        };
+        assert!(markup.value.starts_with("`runtime`"));
+    }
+}
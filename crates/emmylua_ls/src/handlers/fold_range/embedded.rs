@@ -0,0 +1,95 @@
+use emmylua_code_analysis::{LuaEmbeddedRegion, SemanticModel};
+use emmylua_parser::{LuaAst, LuaAstNode, LuaChunk, LuaEmbeddedText};
+use lsp_types::{FoldingRange, FoldingRangeKind};
+use rowan::{TextRange, TextSize};
+
+use super::{builder::FoldingRangeBuilder, get_block_collapsed_range};
+
+// functions, tables and blocks of Lua passed to `load`, objects and arrays of JSON
+pub fn build_embedded_fold_range(
+    builder: &mut FoldingRangeBuilder,
+    semantic_model: &SemanticModel,
+    region: &LuaEmbeddedRegion,
+) -> Option<()> {
+    let text = LuaEmbeddedText::new(&region.token);
+    let ranges = match region.language.as_str() {
+        "lua" => {
+            let embedded_chunk = semantic_model.parse_embedded_lua(&region.token);
+            get_lua_fold_ranges(&embedded_chunk.get_chunk_node())
+        }
+        "json" => get_json_fold_ranges(text.get_text()),
+        _ => return None,
+    };
+
+    for range in ranges {
+        let range = text.to_source_range(range);
+        let document = builder.get_document();
+        let Some(lsp_range) = document.to_lsp_range(range) else {
+            continue;
+        };
+        if lsp_range.start.line == lsp_range.end.line {
+            continue;
+        }
+
+        builder.push(FoldingRange {
+            start_line: lsp_range.start.line,
+            start_character: Some(lsp_range.start.character),
+            end_line: lsp_range.end.line,
+            end_character: Some(lsp_range.end.character),
+            kind: Some(FoldingRangeKind::Region),
+            collapsed_text: Some(" .. ".to_string()),
+        });
+    }
+    Some(())
+}
+
+fn get_lua_fold_ranges(chunk: &LuaChunk) -> Vec<TextRange> {
+    chunk
+        .descendants::<LuaAst>()
+        .filter_map(|node| match node {
+            LuaAst::LuaClosureExpr(closure) => {
+                Some(get_block_collapsed_range(closure.get_block()?))
+            }
+            LuaAst::LuaTableExpr(_)
+            | LuaAst::LuaDoStat(_)
+            | LuaAst::LuaWhileStat(_)
+            | LuaAst::LuaRepeatStat(_)
+            | LuaAst::LuaForStat(_)
+            | LuaAst::LuaForRangeStat(_)
+            | LuaAst::LuaIfStat(_) => Some(node.get_range()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn get_json_fold_ranges(text: &str) -> Vec<TextRange> {
+    let mut ranges = Vec::new();
+    let mut open = Vec::new();
+    let mut in_string = false;
+    let mut escaped = false;
+    for (idx, c) in text.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '{' | '[' => open.push(idx),
+            '}' | ']' => {
+                if let Some(start) = open.pop() {
+                    ranges.push(TextRange::new(
+                        TextSize::from(start as u32),
+                        TextSize::from(idx as u32 + 1),
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+    ranges
+}
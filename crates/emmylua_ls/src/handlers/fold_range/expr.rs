@@ -1,3 +1,4 @@
+use emmylua_code_analysis::LuaEmbeddedRegion;
 use emmylua_parser::{
     LuaAstNode, LuaAstToken, LuaClosureExpr, LuaLiteralExpr, LuaLiteralToken, LuaTableExpr,
 };
@@ -41,6 +42,7 @@ pub fn build_table_expr_fold_range(
 pub fn build_string_fold_range(
     builder: &mut FoldingRangeBuilder,
     literal: LuaLiteralExpr,
+    regions: &[LuaEmbeddedRegion],
 ) -> Option<()> {
     let token = literal.get_literal()?;
     let string_token = match token {
@@ -55,13 +57,21 @@ pub fn build_string_fold_range(
         return None;
     }
 
+    // embedded code shows its language when folded
+    let collapsed_text = match regions
+        .iter()
+        .find(|region| region.token.get_range() == range)
+    {
+        Some(region) => format!("{} '..'", region.language),
+        None => "'..'".to_string(),
+    };
     let folding_range = FoldingRange {
         start_line: lsp_range.start.line,
         start_character: Some(lsp_range.start.character),
         end_line: lsp_range.end.line,
         end_character: Some(lsp_range.end.character),
         kind: Some(FoldingRangeKind::Region),
-        collapsed_text: Some("'..'".to_string()),
+        collapsed_text: Some(collapsed_text),
     };
 
     builder.push(folding_range);
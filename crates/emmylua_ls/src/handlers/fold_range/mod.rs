@@ -1,12 +1,14 @@
 mod builder;
 mod comment;
+mod embedded;
 mod expr;
 mod imports;
 mod stats;
 
 use builder::FoldingRangeBuilder;
 use comment::build_comment_fold_range;
-use emmylua_code_analysis::Emmyrc;
+use embedded::build_embedded_fold_range;
+use emmylua_code_analysis::{Emmyrc, LuaEmbeddedRegion};
 use emmylua_parser::{LuaAst, LuaAstNode, LuaBlock};
 use expr::{build_closure_expr_fold_range, build_string_fold_range, build_table_expr_fold_range};
 use imports::build_imports_fold_range;
@@ -38,11 +40,19 @@ pub async fn on_folding_range_handler(
     let root = semantic_model.get_root();
     let emmyrc = semantic_model.get_emmyrc();
     let mut builder = FoldingRangeBuilder::new(&document, root.clone());
-    build_folding_ranges(&mut builder, emmyrc);
+    let regions = semantic_model.get_embedded_regions();
+    build_folding_ranges(&mut builder, emmyrc, &regions);
+    for region in &regions {
+        build_embedded_fold_range(&mut builder, &semantic_model, region);
+    }
     Some(builder.build())
 }
 
-fn build_folding_ranges(builder: &mut FoldingRangeBuilder, emmyrc: &Emmyrc) {
+fn build_folding_ranges(
+    builder: &mut FoldingRangeBuilder,
+    emmyrc: &Emmyrc,
+    regions: &[LuaEmbeddedRegion],
+) {
     let root = builder.get_root().clone();
     for child in root.descendants::<LuaAst>() {
         match child {
@@ -68,7 +78,7 @@ fn build_folding_ranges(builder: &mut FoldingRangeBuilder, emmyrc: &Emmyrc) {
                 build_comment_fold_range(builder, comment);
             }
             LuaAst::LuaLiteralExpr(literal) => {
-                build_string_fold_range(builder, literal);
+                build_string_fold_range(builder, literal, regions);
             }
             LuaAst::LuaClosureExpr(closure) => {
                 build_closure_expr_fold_range(builder, closure);
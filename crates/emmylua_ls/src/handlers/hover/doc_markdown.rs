@@ -0,0 +1,38 @@
+use emmylua_code_analysis::{
+    render_doc_description, render_doc_see, LuaSemanticDeclId, SemanticModel,
+};
+use lsp_types::GotoDefinitionResponse;
+
+use crate::handlers::definition::goto_def_definition;
+
+/// Render a description as Markdown, `[Class.member]` and `@see` references link to their
+/// definitions and fenced code without a language is Lua
+pub fn render_description(semantic_model: &SemanticModel, text: &str) -> String {
+    render_doc_description(semantic_model.get_db(), text, "@*see*", |semantic_id| {
+        get_reference_link(semantic_model, semantic_id)
+    })
+}
+
+/// Render the content of a `@see` tag, the reference before any text links to its definition
+pub fn render_see(semantic_model: &SemanticModel, see: &str) -> String {
+    render_doc_see(semantic_model.get_db(), see, |semantic_id| {
+        get_reference_link(semantic_model, semantic_id)
+    })
+}
+
+// a file link with the line of the definition, `#L` lines start at 1
+fn get_reference_link(
+    semantic_model: &SemanticModel,
+    semantic_id: LuaSemanticDeclId,
+) -> Option<String> {
+    let location = match goto_def_definition(semantic_model, semantic_id)? {
+        GotoDefinitionResponse::Scalar(location) => location,
+        GotoDefinitionResponse::Array(locations) => locations.into_iter().next()?,
+        GotoDefinitionResponse::Link(_) => return None,
+    };
+    Some(format!(
+        "{}#L{}",
+        location.uri.as_str(),
+        location.range.start.line + 1
+    ))
+}
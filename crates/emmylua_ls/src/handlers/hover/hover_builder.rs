@@ -8,6 +8,7 @@ use crate::handlers::hover::std_hover::hover_std_description;
 
 use super::{
     build_hover::{add_signature_param_description, add_signature_ret_description},
+    doc_markdown::{render_description, render_see},
     std_hover::is_std,
 };
 
@@ -171,11 +172,11 @@ impl<'a> HoverBuilder<'a> {
                 _ => {}
             }
 
-            self.add_annotation_description(description);
+            self.add_annotation_description(render_description(self.semantic_model, &description));
         }
 
         if let Some(see) = &property.see_content {
-            self.see_content = Some(see.to_string());
+            self.see_content = Some(render_see(self.semantic_model, see));
         }
         if let Some(other) = &property.other_content {
             self.other_content = Some(other.to_string());
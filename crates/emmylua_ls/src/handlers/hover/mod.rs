@@ -1,4 +1,5 @@
 mod build_hover;
+mod doc_markdown;
 mod hover_builder;
 mod hover_humanize;
 mod keyword_hover;
@@ -23,7 +24,10 @@ use tokio_util::sync::CancellationToken;
 
 use crate::context::ServerContextSnapshot;
 
-use super::RegisterCapabilities;
+use super::{
+    config_document::{config_hover, is_config_document},
+    RegisterCapabilities,
+};
 
 pub async fn on_hover(
     context: ServerContextSnapshot,
@@ -32,6 +36,12 @@ pub async fn on_hover(
 ) -> Option<Hover> {
     let uri = params.text_document_position_params.text_document.uri;
     let position = params.text_document_position_params.position;
+    if is_config_document(&uri) {
+        let workspace = context.workspace_manager.read().await;
+        let text = workspace.config_documents.get(&uri)?;
+        return config_hover(text, position);
+    }
+
     let analysis = context.analysis.read().await;
     let file_id = analysis.get_file_id(&uri)?;
     hover(&analysis, file_id, position)
@@ -1,7 +1,12 @@
 #[cfg(test)]
 mod tests {
+    use emmylua_code_analysis::{Emmyrc, EmmyrcLuaVersion};
+    use lsp_types::{HoverContents, Position};
 
-    use crate::handlers::test_lib::{ProviderVirtualWorkspace, VirtualHoverResult};
+    use crate::handlers::{
+        hover::hover,
+        test_lib::{ProviderVirtualWorkspace, VirtualHoverResult},
+    };
     #[test]
     fn test_1() {
         let mut ws = ProviderVirtualWorkspace::new();
@@ -118,4 +123,52 @@ mod tests {
             },
         ));
     }
+
+    #[test]
+    fn test_description_references() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let file_id = ws.def(
+            r#"---@class Conn
+---@field close fun()
+
+---Opens a [Conn], close it with [Conn.close] or `[Not.ref]`.
+---```
+---local c = open()
+---```
+---@see Conn#close
+function open() end
+open()"#,
+        );
+        let result = hover(&ws.analysis, file_id, Position::new(9, 1)).unwrap();
+        let HoverContents::Markup(content) = result.contents else {
+            panic!("expected markup");
+        };
+        let value = content.value;
+        assert!(value.contains("[`Conn`](file:"));
+        assert!(value.contains(".lua#L1)"));
+        assert!(value.contains("[`Conn.close`](file:"));
+        assert!(value.contains(".lua#L2)"));
+        assert!(value.contains("`[Not.ref]`"));
+        assert!(value.contains("@*see* [`Conn#close`](file:"));
+        assert!(value.contains("```lua\nlocal c = open()"));
+    }
+
+    #[test]
+    fn test_global_declaration() {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let mut emmyrc = Emmyrc::default();
+        emmyrc.runtime.version = EmmyrcLuaVersion::Lua55;
+        ws.analysis.update_config(emmyrc.into());
+        assert!(ws.check_hover(
+            r#"
+                global<const> VERSION = 1
+                do
+                    global count = <??>VERSION
+                end
+            "#,
+            VirtualHoverResult {
+                value: "\n```lua\n(global) VERSION: integer = 1\n```\n\n---\n".to_string(),
+            },
+        ));
+    }
 }
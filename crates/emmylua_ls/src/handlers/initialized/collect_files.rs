@@ -3,7 +3,7 @@ use std::path::PathBuf;
 use emmylua_code_analysis::{load_workspace_files, Emmyrc, LuaFileInfo};
 use log::{debug, info};
 
-pub fn collect_files(workspaces: &Vec<PathBuf>, emmyrc: &Emmyrc) -> Vec<LuaFileInfo> {
+pub fn collect_files(workspaces: &[PathBuf], emmyrc: &Emmyrc) -> Vec<LuaFileInfo> {
     let mut files = Vec::new();
     let (match_pattern, exclude, exclude_dir) = calculate_include_and_exclude(emmyrc);
 
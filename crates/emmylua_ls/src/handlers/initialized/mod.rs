@@ -8,15 +8,17 @@ use std::{path::PathBuf, str::FromStr, sync::Arc};
 use crate::{
     cmd_args::CmdArgs,
     context::{
-        get_client_id, load_emmy_config, ClientId, ClientProxy, FileDiagnostic, ProgressTask,
-        ServerContextSnapshot, StatusBar,
+        get_client_id, load_emmy_config, load_folder_emmyrcs, load_nested_emmyrcs, ClientId,
+        ClientProxy, FileDiagnostic, ProgressTask, ServerContextSnapshot, StatusBar,
+    },
+    handlers::{
+        config_document::publish_config_file_diagnostics, text_document::register_files_watch,
     },
-    handlers::text_document::register_files_watch,
     logger::init_logger,
 };
 pub use client_config::{get_client_config, ClientConfig};
 use codestyle::load_editorconfig;
-use collect_files::collect_files;
+pub use collect_files::collect_files;
 use emmylua_code_analysis::{uri_to_file_path, EmmyLuaAnalysis, Emmyrc};
 use log::info;
 use lsp_types::InitializeParams;
@@ -51,6 +53,7 @@ pub async fn initialized_handler(
     };
 
     let emmyrc = load_emmy_config(config_root, client_config.clone());
+    let folder_emmyrcs = load_folder_emmyrcs(&workspace_folders, &client_config);
     load_editorconfig(workspace_folders.clone());
 
     // init std lib
@@ -68,6 +71,7 @@ pub async fn initialized_handler(
         &context.status_bar,
         workspace_folders,
         emmyrc,
+        folder_emmyrcs,
         client_id,
         context.file_diagnostic.clone(),
     )
@@ -79,10 +83,11 @@ pub async fn initialized_handler(
 
 pub async fn init_analysis(
     analysis: Arc<RwLock<EmmyLuaAnalysis>>,
-    _: Arc<ClientProxy>,
+    client: Arc<ClientProxy>,
     status_bar: &StatusBar,
     workspace_folders: Vec<PathBuf>,
     emmyrc: Arc<Emmyrc>,
+    folder_emmyrcs: Vec<(PathBuf, Arc<Emmyrc>)>,
     client_id: ClientId,
     file_diagnostic: Arc<FileDiagnostic>,
 ) {
@@ -102,12 +107,46 @@ pub async fn init_analysis(
         Some("Loading workspace files".to_string()),
     );
 
-    let mut workspace_folders = workspace_folders;
     for workspace_root in &workspace_folders {
         info!("add workspace root: {:?}", workspace_root);
+        publish_config_file_diagnostics(&client, workspace_root);
         mut_analysis.add_main_workspace(workspace_root.clone());
+        // drop the configs of a previous load, they are resolved again below
+        mut_analysis.remove_workspace_config(workspace_root);
     }
 
+    // folders with their own config are collected with it, the others with the main config
+    let mut folder_files = Vec::new();
+    for (folder_root, folder_emmyrc) in &folder_emmyrcs {
+        info!("add workspace config: {:?}", folder_root);
+        mut_analysis.update_workspace_config(folder_root.clone(), folder_emmyrc.clone());
+        let mut folder_roots = vec![folder_root.clone()];
+        for lib in &folder_emmyrc.workspace.library {
+            info!("add library: {:?}", lib);
+            mut_analysis.add_folder_library(folder_root.clone(), PathBuf::from_str(lib).unwrap());
+            folder_roots.push(PathBuf::from_str(lib).unwrap());
+        }
+        folder_files.extend(collect_files(&folder_roots, folder_emmyrc));
+    }
+
+    for workspace_root in &workspace_folders {
+        let base = folder_emmyrcs
+            .iter()
+            .find(|(root, _)| root == workspace_root)
+            .map(|(_, folder_emmyrc)| folder_emmyrc.as_ref())
+            .unwrap_or(emmyrc.as_ref());
+        for (dir, nested_emmyrc) in load_nested_emmyrcs(workspace_root, base) {
+            info!("add nested config: {:?}", dir);
+            publish_config_file_diagnostics(&client, &dir);
+            mut_analysis.update_workspace_config(dir, nested_emmyrc);
+        }
+    }
+
+    let mut workspace_folders: Vec<PathBuf> = workspace_folders
+        .into_iter()
+        .filter(|folder| !folder_emmyrcs.iter().any(|(root, _)| root == folder))
+        .collect();
+
     for workspace_root in &emmyrc.workspace.workspace_roots {
         info!("add workspace root: {:?}", workspace_root);
         mut_analysis.add_main_workspace(PathBuf::from_str(workspace_root).unwrap());
@@ -127,7 +166,8 @@ pub async fn init_analysis(
     );
 
     // load files
-    let files = collect_files(&workspace_folders, &emmyrc);
+    let mut files = collect_files(&workspace_folders, &emmyrc);
+    files.extend(folder_files);
     let files: Vec<(PathBuf, Option<String>)> =
         files.into_iter().map(|file| file.into_tuple()).collect();
 
@@ -3,6 +3,7 @@ mod code_actions;
 mod code_lens;
 mod command;
 mod completion;
+mod config_document;
 mod configuration;
 mod definition;
 mod document_color;
@@ -28,6 +29,7 @@ mod semantic_token;
 mod signature_helper;
 mod test_lib;
 mod text_document;
+mod workspace_folders;
 mod workspace_symbol;
 
 pub use initialized::initialized_handler;
@@ -127,6 +129,10 @@ pub fn server_capabilities(client_capabilities: &ClientCapabilities) -> ServerCa
         &mut server_capabilities,
         client_capabilities,
     );
+    register::<workspace_folders::WorkspaceFoldersCapabilities>(
+        &mut server_capabilities,
+        client_capabilities,
+    );
     // register::<document_type_formatting::DocumentTypeFormatting>(
     //     &mut server_capabilities,
     //     client_capabilities,
@@ -5,7 +5,7 @@ use lsp_server::Notification;
 use lsp_types::{
     notification::{
         Cancel, DidChangeConfiguration, DidChangeTextDocument, DidChangeWatchedFiles,
-        DidCloseTextDocument, DidOpenTextDocument, DidSaveTextDocument,
+        DidChangeWorkspaceFolders, DidCloseTextDocument, DidOpenTextDocument, DidSaveTextDocument,
         Notification as lsp_notification, SetTrace,
     },
     CancelParams, NumberOrString,
@@ -20,6 +20,7 @@ use super::{
         on_did_change_text_document, on_did_change_watched_files, on_did_close_document,
         on_did_open_text_document, on_did_save_text_document, on_set_trace,
     },
+    workspace_folders::on_did_change_workspace_folders,
 };
 
 pub async fn on_notification_handler(
@@ -37,6 +38,7 @@ pub async fn on_notification_handler(
         .on_parallel::<DidChangeWatchedFiles, _, _>(on_did_change_watched_files)
         .on_parallel::<SetTrace, _, _>(on_set_trace)
         .on_parallel::<DidChangeConfiguration, _, _>(on_did_change_configuration)
+        .on_parallel::<DidChangeWorkspaceFolders, _, _>(on_did_change_workspace_folders)
         .finish();
 
     Ok(())
@@ -1,18 +1,23 @@
+use std::collections::HashSet;
+
 use emmylua_code_analysis::{
-    LuaMemberId, LuaMemberOwner, LuaSemanticDeclId, LuaType, SemanticDeclLevel, SemanticModel,
+    LuaEmbeddedRegion, LuaMemberId, LuaMemberOwner, LuaSemanticDeclId, LuaType, SemanticDeclLevel,
+    SemanticModel,
 };
 use emmylua_parser::{
-    LuaAst, LuaAstNode, LuaAstToken, LuaDocFieldKey, LuaDocObjectFieldKey, LuaExpr,
-    LuaGeneralToken, LuaLiteralToken, LuaNameToken, LuaSyntaxNode, LuaSyntaxToken, LuaTokenKind,
-    LuaVarExpr,
+    LuaAst, LuaAstNode, LuaAstToken, LuaCallArgList, LuaCallExpr, LuaDocFieldKey,
+    LuaDocObjectFieldKey, LuaEmbeddedText, LuaExpr, LuaGeneralToken, LuaLiteralExpr,
+    LuaLiteralToken, LuaNameToken, LuaPattern, LuaPatternItemKind, LuaStringToken, LuaSyntaxNode,
+    LuaSyntaxToken, LuaTokenKind, LuaVarExpr,
 };
 use lsp_types::{SemanticToken, SemanticTokenModifier, SemanticTokenType};
-use rowan::NodeOrToken;
+use rowan::{NodeOrToken, TextRange};
 
 use crate::context::ClientId;
 
 use super::{
-    semantic_token_builder::SemanticBuilder, SEMANTIC_TOKEN_MODIFIERS, SEMANTIC_TOKEN_TYPES,
+    embedded_tokens::tokenize_embedded, semantic_token_builder::SemanticBuilder,
+    SEMANTIC_TOKEN_MODIFIERS, SEMANTIC_TOKEN_TYPES,
 };
 
 pub fn build_semantic_tokens(
@@ -29,11 +34,19 @@ pub fn build_semantic_tokens(
         SEMANTIC_TOKEN_MODIFIERS.to_vec(),
     );
 
+    let mut embedded_tokens = HashSet::new();
+    for region in semantic_model.get_embedded_regions() {
+        if build_embedded_semantic_token(semantic_model, &mut builder, &region).is_some() {
+            embedded_tokens.insert(region.token.get_range());
+        }
+    }
+
     for node_or_token in root.syntax().descendants_with_tokens() {
         match node_or_token {
             NodeOrToken::Node(node) => {
                 build_node_semantic_token(semantic_model, &mut builder, node, client_id);
             }
+            NodeOrToken::Token(token) if embedded_tokens.contains(&token.text_range()) => {}
             NodeOrToken::Token(token) => {
                 build_tokens_semantic_token(semantic_model, &mut builder, &token, client_id);
             }
@@ -52,7 +65,9 @@ fn build_tokens_semantic_token(
 ) {
     match token.kind().into() {
         LuaTokenKind::TkLongString | LuaTokenKind::TkString => {
-            builder.push(token, SemanticTokenType::STRING);
+            if build_pattern_semantic_token(semantic_model, builder, token).is_none() {
+                builder.push(token, SemanticTokenType::STRING);
+            }
         }
         LuaTokenKind::TkAnd
         | LuaTokenKind::TkBreak
@@ -90,6 +105,7 @@ fn build_tokens_semantic_token(
         | LuaTokenKind::TkGe
         | LuaTokenKind::TkLe
         | LuaTokenKind::TkNe
+        | LuaTokenKind::TkBangEq
         | LuaTokenKind::TkShl
         | LuaTokenKind::TkShr
         | LuaTokenKind::TkLt
@@ -136,7 +152,8 @@ fn build_tokens_semantic_token(
         | LuaTokenKind::TkTagNamespace
         | LuaTokenKind::TkTagUsing
         | LuaTokenKind::TkTagSource
-        | LuaTokenKind::TkTagReturnCast => {
+        | LuaTokenKind::TkTagReturnCast
+        | LuaTokenKind::TkTagLanguage => {
             builder.push_with_modifier(
                 token,
                 SemanticTokenType::KEYWORD,
@@ -147,6 +164,7 @@ fn build_tokens_semantic_token(
         | LuaTokenKind::TkDocExtends
         | LuaTokenKind::TkDocAs
         | LuaTokenKind::TkDocIn
+        | LuaTokenKind::TkDocOut
         | LuaTokenKind::TkDocInfer => {
             builder.push(token, SemanticTokenType::KEYWORD);
         }
@@ -201,6 +219,91 @@ fn build_tokens_semantic_token(
     }
 }
 
+// the pattern argument of `string.find`, `match`, `gmatch` and `gsub` highlights its classes
+fn build_pattern_semantic_token(
+    semantic_model: &SemanticModel,
+    builder: &mut SemanticBuilder,
+    token: &LuaSyntaxToken,
+) -> Option<()> {
+    let string_token = LuaStringToken::cast(token.clone())?;
+    let literal_expr = LuaLiteralExpr::cast(token.parent()?)?;
+    let call_expr = literal_expr
+        .get_parent::<LuaCallArgList>()?
+        .get_parent::<LuaCallExpr>()?;
+    let string_call = semantic_model.get_string_lib_call(&call_expr)?;
+    if string_call.get_pattern_arg()?.syntax() != literal_expr.syntax()
+        || string_call.is_plain_find()
+    {
+        return None;
+    }
+
+    let value = string_token.get_value();
+    let pattern = LuaPattern::parse(&value);
+    // escapes and line breaks keep the string in one piece
+    if pattern.items.is_empty()
+        || token.text().contains(['\r', '\n'])
+        || string_token.get_value_range(0..value.len()).is_none()
+    {
+        return None;
+    }
+
+    let mut start = token.text_range().start();
+    for item in &pattern.items {
+        let range = string_token.get_value_range(item.range.clone())?;
+        builder.push_range(
+            TextRange::new(start, range.start()),
+            SemanticTokenType::STRING,
+        );
+        let ty = match item.kind {
+            LuaPatternItemKind::Anchor
+            | LuaPatternItemKind::Quantifier
+            | LuaPatternItemKind::Capture => SemanticTokenType::OPERATOR,
+            _ => SemanticTokenType::REGEXP,
+        };
+        builder.push_range(range, ty);
+        start = range.end();
+    }
+    builder.push_range(
+        TextRange::new(start, token.text_range().end()),
+        SemanticTokenType::STRING,
+    );
+    Some(())
+}
+
+// code in strings marked with `---@language` or passed to `load` is highlighted as that language
+fn build_embedded_semantic_token(
+    semantic_model: &SemanticModel,
+    builder: &mut SemanticBuilder,
+    region: &LuaEmbeddedRegion,
+) -> Option<()> {
+    let text = LuaEmbeddedText::new(&region.token);
+    let embedded_chunk = if region.language == "lua" {
+        Some(semantic_model.parse_embedded_lua(&region.token))
+    } else {
+        None
+    };
+    let tokens = tokenize_embedded(&region.language, text.get_text(), embedded_chunk.as_ref());
+    if tokens.is_empty() {
+        return None;
+    }
+
+    let token_range = region.token.get_range();
+    let content_start = text.to_source_offset(0);
+    let content_end = text.to_source_offset(text.get_text().len());
+    builder.push_range(
+        TextRange::new(token_range.start(), content_start),
+        SemanticTokenType::STRING,
+    );
+    for (range, ty) in tokens {
+        builder.push_range(text.to_source_range(range), ty);
+    }
+    builder.push_range(
+        TextRange::new(content_end.max(content_start), token_range.end()),
+        SemanticTokenType::STRING,
+    );
+    Some(())
+}
+
 fn build_node_semantic_token(
     semantic_model: &SemanticModel,
     builder: &mut SemanticBuilder,
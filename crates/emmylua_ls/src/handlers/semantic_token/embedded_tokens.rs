@@ -0,0 +1,221 @@
+use emmylua_parser::{LuaAstNode, LuaEmbeddedChunk, LuaSyntaxKind, LuaTokenKind};
+use lsp_types::SemanticTokenType;
+use rowan::{TextRange, TextSize};
+
+/// Highlighted ranges of the code in an embedded string, relative to the string value
+pub fn tokenize_embedded(
+    language: &str,
+    text: &str,
+    embedded_chunk: Option<&LuaEmbeddedChunk>,
+) -> Vec<(TextRange, SemanticTokenType)> {
+    match (language, embedded_chunk) {
+        ("lua", Some(embedded_chunk)) => tokenize_lua(embedded_chunk),
+        ("sql", _) => tokenize_sql(text),
+        ("json", _) => tokenize_json(text),
+        _ => Vec::new(),
+    }
+}
+
+fn tokenize_lua(embedded_chunk: &LuaEmbeddedChunk) -> Vec<(TextRange, SemanticTokenType)> {
+    let mut tokens = Vec::new();
+    let root = embedded_chunk.get_chunk_node();
+    for token in root
+        .syntax()
+        .descendants_with_tokens()
+        .filter_map(|it| it.into_token())
+    {
+        let kind: LuaTokenKind = token.kind().into();
+        let in_comment = token
+            .parent_ancestors()
+            .any(|node| node.kind() == LuaSyntaxKind::Comment.into());
+        let ty = if in_comment {
+            SemanticTokenType::COMMENT
+        } else {
+            match kind {
+                LuaTokenKind::TkWhitespace | LuaTokenKind::TkEndOfLine => continue,
+                LuaTokenKind::TkAnd
+                | LuaTokenKind::TkBreak
+                | LuaTokenKind::TkDo
+                | LuaTokenKind::TkElse
+                | LuaTokenKind::TkElseIf
+                | LuaTokenKind::TkEnd
+                | LuaTokenKind::TkFalse
+                | LuaTokenKind::TkFor
+                | LuaTokenKind::TkFunction
+                | LuaTokenKind::TkGoto
+                | LuaTokenKind::TkIf
+                | LuaTokenKind::TkIn
+                | LuaTokenKind::TkLocal
+                | LuaTokenKind::TkNil
+                | LuaTokenKind::TkNot
+                | LuaTokenKind::TkOr
+                | LuaTokenKind::TkRepeat
+                | LuaTokenKind::TkReturn
+                | LuaTokenKind::TkThen
+                | LuaTokenKind::TkTrue
+                | LuaTokenKind::TkUntil
+                | LuaTokenKind::TkWhile
+                | LuaTokenKind::TkGlobal => SemanticTokenType::KEYWORD,
+                LuaTokenKind::TkPlus
+                | LuaTokenKind::TkMinus
+                | LuaTokenKind::TkMul
+                | LuaTokenKind::TkDiv
+                | LuaTokenKind::TkIDiv
+                | LuaTokenKind::TkDot
+                | LuaTokenKind::TkConcat
+                | LuaTokenKind::TkEq
+                | LuaTokenKind::TkGe
+                | LuaTokenKind::TkLe
+                | LuaTokenKind::TkNe
+                | LuaTokenKind::TkBangEq
+                | LuaTokenKind::TkShl
+                | LuaTokenKind::TkShr
+                | LuaTokenKind::TkLt
+                | LuaTokenKind::TkGt
+                | LuaTokenKind::TkMod
+                | LuaTokenKind::TkPow
+                | LuaTokenKind::TkLen
+                | LuaTokenKind::TkBitAnd
+                | LuaTokenKind::TkBitOr
+                | LuaTokenKind::TkBitXor
+                | LuaTokenKind::TkLeftBrace
+                | LuaTokenKind::TkRightBrace
+                | LuaTokenKind::TkLeftBracket
+                | LuaTokenKind::TkRightBracket => SemanticTokenType::OPERATOR,
+                LuaTokenKind::TkInt | LuaTokenKind::TkFloat | LuaTokenKind::TkComplex => {
+                    SemanticTokenType::NUMBER
+                }
+                LuaTokenKind::TkString | LuaTokenKind::TkLongString => SemanticTokenType::STRING,
+                LuaTokenKind::TkName => SemanticTokenType::VARIABLE,
+                _ => continue,
+            }
+        };
+        tokens.push((token.text_range(), ty));
+    }
+    tokens
+}
+
+const SQL_KEYWORDS: &str = "\
+    add all alter and as asc begin between by case commit create cross default delete desc \
+    distinct drop else end exists from full group having if in index inner insert into is \
+    join key left like limit not null offset on or order outer primary references replace \
+    right rollback select set table then transaction union unique update values view when \
+    where with";
+
+fn tokenize_sql(text: &str) -> Vec<(TextRange, SemanticTokenType)> {
+    let mut tokens = Vec::new();
+    let bytes = text.as_bytes();
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let start = pos;
+        let c = bytes[pos];
+        let ty = if c == b'-' && bytes.get(pos + 1) == Some(&b'-') {
+            pos = text[pos..].find('\n').map_or(bytes.len(), |idx| pos + idx);
+            SemanticTokenType::COMMENT
+        } else if c == b'\'' {
+            pos = skip_quoted(bytes, pos, b'\'');
+            SemanticTokenType::STRING
+        } else if c.is_ascii_digit() {
+            while pos < bytes.len() && (bytes[pos].is_ascii_digit() || bytes[pos] == b'.') {
+                pos += 1;
+            }
+            SemanticTokenType::NUMBER
+        } else if c.is_ascii_alphabetic() || c == b'_' {
+            while pos < bytes.len() && (bytes[pos].is_ascii_alphanumeric() || bytes[pos] == b'_') {
+                pos += 1;
+            }
+            let word = text[start..pos].to_ascii_lowercase();
+            if SQL_KEYWORDS
+                .split_whitespace()
+                .any(|keyword| keyword == word)
+            {
+                SemanticTokenType::KEYWORD
+            } else {
+                continue;
+            }
+        } else if c == b'?' || ((c == b':' || c == b'@') && next_is_word(bytes, pos + 1)) {
+            // placeholders `?`, `:name` and `@name`
+            pos += 1;
+            while pos < bytes.len() && (bytes[pos].is_ascii_alphanumeric() || bytes[pos] == b'_') {
+                pos += 1;
+            }
+            SemanticTokenType::PARAMETER
+        } else {
+            pos += 1;
+            continue;
+        };
+        tokens.push((text_range(start, pos), ty));
+    }
+    tokens
+}
+
+fn tokenize_json(text: &str) -> Vec<(TextRange, SemanticTokenType)> {
+    let mut tokens = Vec::new();
+    let bytes = text.as_bytes();
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let start = pos;
+        let c = bytes[pos];
+        let ty = if c == b'"' {
+            pos = skip_quoted(bytes, pos, b'"');
+            let rest = text[pos..].trim_start();
+            if rest.starts_with(':') {
+                SemanticTokenType::PROPERTY
+            } else {
+                SemanticTokenType::STRING
+            }
+        } else if c == b'-' || c.is_ascii_digit() {
+            pos += 1;
+            while pos < bytes.len()
+                && (bytes[pos].is_ascii_digit()
+                    || matches!(bytes[pos], b'.' | b'e' | b'E' | b'+' | b'-'))
+            {
+                pos += 1;
+            }
+            SemanticTokenType::NUMBER
+        } else if c.is_ascii_alphabetic() {
+            while pos < bytes.len() && bytes[pos].is_ascii_alphabetic() {
+                pos += 1;
+            }
+            if matches!(&text[start..pos], "true" | "false" | "null") {
+                SemanticTokenType::KEYWORD
+            } else {
+                continue;
+            }
+        } else {
+            pos += 1;
+            continue;
+        };
+        tokens.push((text_range(start, pos), ty));
+    }
+    tokens
+}
+
+// the position after the closing quote, a backslash or a doubled quote escapes it
+fn skip_quoted(bytes: &[u8], start: usize, quote: u8) -> usize {
+    let mut pos = start + 1;
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b'\\' if quote == b'"' => pos += 2,
+            b if b == quote => {
+                if quote == b'\'' && bytes.get(pos + 1) == Some(&b'\'') {
+                    pos += 2;
+                } else {
+                    return pos + 1;
+                }
+            }
+            _ => pos += 1,
+        }
+    }
+    bytes.len()
+}
+
+fn next_is_word(bytes: &[u8], pos: usize) -> bool {
+    bytes
+        .get(pos)
+        .is_some_and(|b| b.is_ascii_alphabetic() || *b == b'_')
+}
+
+fn text_range(start: usize, end: usize) -> TextRange {
+    TextRange::new(TextSize::from(start as u32), TextSize::from(end as u32))
+}
@@ -1,5 +1,7 @@
 mod build_semantic_tokens;
+mod embedded_tokens;
 mod semantic_token_builder;
+mod test;
 
 use crate::context::ServerContextSnapshot;
 use build_semantic_tokens::build_semantic_tokens;
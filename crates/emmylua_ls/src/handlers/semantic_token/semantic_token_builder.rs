@@ -1,7 +1,7 @@
 use emmylua_code_analysis::LuaDocument;
 use emmylua_parser::LuaSyntaxToken;
 use lsp_types::{SemanticToken, SemanticTokenModifier, SemanticTokenType};
-use rowan::TextSize;
+use rowan::{TextRange, TextSize};
 use std::{collections::HashMap, vec::Vec};
 
 pub const SEMANTIC_TOKEN_TYPES: &[SemanticTokenType] = &[
@@ -192,6 +192,29 @@ impl<'a> SemanticBuilder<'a> {
         Some(())
     }
 
+    /// Pushes a part of a single line token
+    pub fn push_range(&mut self, range: TextRange, ty: SemanticTokenType) -> Option<()> {
+        if range.is_empty() || self.data.contains_key(&range.start()) {
+            return Some(());
+        }
+        let lsp_range = self.document.to_lsp_range(range)?;
+        if lsp_range.start.line != lsp_range.end.line {
+            return None;
+        }
+
+        self.data.insert(
+            range.start(),
+            SemanticTokenData::Basic(BasicSemanticTokenData {
+                line: lsp_range.start.line,
+                col: lsp_range.start.character,
+                length: lsp_range.end.character - lsp_range.start.character,
+                typ: *self.type_to_id.get(&ty)?,
+                modifiers: 0,
+            }),
+        );
+        Some(())
+    }
+
     #[allow(unused)]
     pub fn push_with_modifiers(
         &mut self,
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use lsp_types::SemanticTokenType;
+
+    use crate::{
+        context::ClientId,
+        handlers::{
+            semantic_token::{build_semantic_tokens::build_semantic_tokens, SEMANTIC_TOKEN_TYPES},
+            test_lib::ProviderVirtualWorkspace,
+        },
+    };
+
+    // (column, length, type) of the tokens on the last line
+    fn last_line_tokens(
+        ws: &mut ProviderVirtualWorkspace,
+        code: &str,
+    ) -> Vec<(u32, u32, SemanticTokenType)> {
+        let file_id = ws.def(code);
+        let mut semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        let tokens =
+            build_semantic_tokens(&mut semantic_model, false, ClientId::default()).unwrap();
+        let last_line = code.lines().count() as u32 - 1;
+        let mut result = Vec::new();
+        let (mut line, mut col) = (0, 0);
+        for token in tokens {
+            if token.delta_line != 0 {
+                col = 0;
+            }
+            line += token.delta_line;
+            col += token.delta_start;
+            if line == last_line {
+                let typ = SEMANTIC_TOKEN_TYPES[token.token_type as usize].clone();
+                result.push((col, token.length, typ));
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn test_pattern_classes() {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        let tokens = last_line_tokens(&mut ws, "local s = ''\nlocal a = s:match('^(%d+)x')");
+        let pattern_tokens = tokens
+            .into_iter()
+            .filter(|(col, _, _)| *col >= 18)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            pattern_tokens,
+            vec![
+                (18, 1, SemanticTokenType::STRING),
+                (19, 1, SemanticTokenType::OPERATOR),
+                (20, 1, SemanticTokenType::OPERATOR),
+                (21, 2, SemanticTokenType::REGEXP),
+                (23, 1, SemanticTokenType::OPERATOR),
+                (24, 1, SemanticTokenType::OPERATOR),
+                (25, 2, SemanticTokenType::STRING),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plain_string_is_one_token() {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        let tokens = last_line_tokens(&mut ws, "local s = ''\nlocal a = s:find('%d', 1, true)");
+        assert!(tokens.contains(&(17, 4, SemanticTokenType::STRING)));
+        assert!(!tokens
+            .iter()
+            .any(|(_, _, typ)| *typ == SemanticTokenType::REGEXP));
+    }
+
+    #[test]
+    fn test_embedded_sql() {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        let tokens = last_line_tokens(&mut ws, "---@language sql\nlocal q = \"SELECT a FROM t\"");
+        let embedded_tokens = tokens
+            .into_iter()
+            .filter(|(col, _, _)| *col >= 10)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            embedded_tokens,
+            vec![
+                (10, 1, SemanticTokenType::STRING),
+                (11, 6, SemanticTokenType::KEYWORD),
+                (20, 4, SemanticTokenType::KEYWORD),
+                (26, 1, SemanticTokenType::STRING),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_embedded_lua() {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+        let tokens = last_line_tokens(&mut ws, "local s = ''\nlocal f = load(\"return 1\")");
+        assert!(tokens.contains(&(16, 6, SemanticTokenType::KEYWORD)));
+        assert!(tokens.contains(&(23, 1, SemanticTokenType::NUMBER)));
+        assert!(!tokens.contains(&(15, 10, SemanticTokenType::STRING)));
+    }
+}
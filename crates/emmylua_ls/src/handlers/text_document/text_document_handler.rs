@@ -5,15 +5,25 @@ use lsp_types::{
     DidSaveTextDocumentParams,
 };
 
-use crate::context::ServerContextSnapshot;
+use crate::{
+    context::ServerContextSnapshot,
+    handlers::config_document::{is_config_document, publish_config_diagnostics},
+};
 
 pub async fn on_did_open_text_document(
     context: ServerContextSnapshot,
     params: DidOpenTextDocumentParams,
 ) -> Option<()> {
-    let mut analysis = context.analysis.write().await;
     let uri = params.text_document.uri;
     let text = params.text_document.text;
+    if is_config_document(&uri) {
+        publish_config_diagnostics(&context.client, uri.clone(), &text);
+        let mut workspace = context.workspace_manager.write().await;
+        workspace.config_documents.insert(uri, text);
+        return Some(());
+    }
+
+    let mut analysis = context.analysis.write().await;
 
     let file_id = analysis.update_file_by_uri(&uri, Some(text));
     let emmyrc = analysis.get_emmyrc();
@@ -57,9 +67,16 @@ pub async fn on_did_change_text_document(
     context: ServerContextSnapshot,
     params: DidChangeTextDocumentParams,
 ) -> Option<()> {
-    let mut analysis = context.analysis.write().await;
     let uri = params.text_document.uri;
     let text = params.content_changes.first()?.text.clone();
+    if is_config_document(&uri) {
+        publish_config_diagnostics(&context.client, uri.clone(), &text);
+        let mut workspace = context.workspace_manager.write().await;
+        workspace.config_documents.insert(uri, text);
+        return Some(());
+    }
+
+    let mut analysis = context.analysis.write().await;
     let file_id = analysis.update_file_by_uri(&uri, Some(text));
     let emmyrc = analysis.get_emmyrc();
     let interval = emmyrc.diagnostics.diagnostic_interval.unwrap_or(500);
@@ -88,6 +105,7 @@ pub async fn on_did_close_document(
     workspace
         .current_open_files
         .remove(&params.text_document.uri);
+    workspace.config_documents.remove(&params.text_document.uri);
     drop(workspace);
     Some(())
 }
@@ -1,7 +1,9 @@
 use emmylua_code_analysis::{read_file_with_encoding, uri_to_file_path};
 use lsp_types::{DidChangeWatchedFilesParams, FileChangeType, Uri};
 
-use crate::context::ServerContextSnapshot;
+use crate::{
+    context::ServerContextSnapshot, handlers::config_document::publish_config_file_diagnostics,
+};
 
 pub async fn on_did_change_watched_files(
     context: ServerContextSnapshot,
@@ -13,6 +15,7 @@ pub async fn on_did_change_watched_files(
     let encoding = &emmyrc.workspace.encoding;
     let interval = emmyrc.diagnostics.diagnostic_interval.unwrap_or(500);
     let mut watched_lua_files: Vec<(Uri, Option<String>)> = Vec::new();
+    let mut nested_config_dirs = Vec::new();
     // let
     for file_event in params.changes.into_iter() {
         let file_type = get_file_type(&file_event.uri);
@@ -44,11 +47,19 @@ pub async fn on_did_change_watched_files(
                     .update_editorconfig(editorconfig_path);
             }
             Some(WatchedFileType::Emmyrc) => {
+                let emmyrc_path = uri_to_file_path(&file_event.uri).unwrap();
+                let file_dir = emmyrc_path.parent().unwrap().to_path_buf();
+                // an open config is checked as it is edited
+                if !workspace.config_documents.contains_key(&file_event.uri) {
+                    publish_config_file_diagnostics(&context.client, &file_dir);
+                }
+                if workspace.is_nested_config_dir(&file_dir) {
+                    nested_config_dirs.push(file_dir);
+                    continue;
+                }
                 if file_event.typ == FileChangeType::DELETED {
                     continue;
                 }
-                let emmyrc_path = uri_to_file_path(&file_event.uri).unwrap();
-                let file_dir = emmyrc_path.parent().unwrap().to_path_buf();
                 context
                     .workspace_manager
                     .read()
@@ -66,6 +77,12 @@ pub async fn on_did_change_watched_files(
         .add_files_diagnostic_task(file_ids, interval)
         .await;
 
+    // the nested config reload takes the analysis lock itself
+    drop(analysis);
+    for config_dir in nested_config_dirs {
+        workspace.update_nested_emmyrc(config_dir).await;
+    }
+
     Some(())
 }
 
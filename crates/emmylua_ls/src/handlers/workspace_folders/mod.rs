@@ -0,0 +1,104 @@
+use std::path::PathBuf;
+
+use emmylua_code_analysis::uri_to_file_path;
+use lsp_types::{
+    ClientCapabilities, DidChangeWorkspaceFoldersParams, OneOf, PublishDiagnosticsParams,
+    ServerCapabilities, WorkspaceFoldersServerCapabilities, WorkspaceServerCapabilities,
+};
+
+use crate::{
+    context::{load_emmy_config, ServerContextSnapshot},
+    handlers::initialized::collect_files,
+};
+
+use super::RegisterCapabilities;
+
+pub async fn on_did_change_workspace_folders(
+    context: ServerContextSnapshot,
+    params: DidChangeWorkspaceFoldersParams,
+) -> Option<()> {
+    let added: Vec<PathBuf> = params
+        .event
+        .added
+        .iter()
+        .filter_map(|folder| uri_to_file_path(&folder.uri))
+        .collect();
+    let removed: Vec<PathBuf> = params
+        .event
+        .removed
+        .iter()
+        .filter_map(|folder| uri_to_file_path(&folder.uri))
+        .collect();
+    log::info!(
+        "on_did_change_workspace_folders added: {:?}, removed: {:?}",
+        added,
+        removed
+    );
+
+    let mut workspace_manager = context.workspace_manager.write().await;
+    workspace_manager
+        .workspace_folders
+        .retain(|folder| !removed.contains(folder));
+    for folder in &added {
+        if !workspace_manager.workspace_folders.contains(folder) {
+            workspace_manager.workspace_folders.push(folder.clone());
+        }
+    }
+    let client_config = workspace_manager.client_config.clone();
+    drop(workspace_manager);
+
+    let mut analysis = context.analysis.write().await;
+    for folder in &removed {
+        for uri in analysis.remove_workspace(folder) {
+            context
+                .client
+                .publish_diagnostics(PublishDiagnosticsParams {
+                    uri,
+                    diagnostics: vec![],
+                    version: None,
+                });
+        }
+    }
+
+    // an added folder is configured by its own config files, with its libraries
+    for folder in &added {
+        let emmyrc = load_emmy_config(Some(folder.clone()), client_config.clone());
+        analysis.add_main_workspace(folder.clone());
+        analysis.update_workspace_config(folder.clone(), emmyrc.clone());
+        let mut roots = vec![folder.clone()];
+        for lib in &emmyrc.workspace.library {
+            let lib = PathBuf::from(lib);
+            analysis.add_folder_library(folder.clone(), lib.clone());
+            roots.push(lib);
+        }
+        let files = collect_files(&roots, &emmyrc)
+            .into_iter()
+            .map(|file| file.into_tuple())
+            .collect();
+        analysis.update_files_by_path(files);
+    }
+    drop(analysis);
+
+    if !added.is_empty() {
+        context
+            .file_diagnostic
+            .add_workspace_diagnostic_task(client_config.client_id, 0, true)
+            .await;
+    }
+
+    Some(())
+}
+
+pub struct WorkspaceFoldersCapabilities;
+
+impl RegisterCapabilities for WorkspaceFoldersCapabilities {
+    fn register_capabilities(server_capabilities: &mut ServerCapabilities, _: &ClientCapabilities) {
+        let workspace = server_capabilities
+            .workspace
+            .get_or_insert_with(WorkspaceServerCapabilities::default);
+        workspace.workspace_folders = Some(WorkspaceFoldersServerCapabilities {
+            supported: Some(true),
+            change_notifications: Some(OneOf::Left(true)),
+        });
+    }
+}
@@ -55,6 +55,7 @@ fn parse_tag_detail(p: &mut LuaDocParser) -> ParseResult {
         LuaTokenKind::TkTagNamespace => parse_tag_namespace(p),
         LuaTokenKind::TkTagUsing => parse_tag_using(p),
         LuaTokenKind::TkTagMeta => parse_tag_meta(p),
+        LuaTokenKind::TkTagLanguage => parse_tag_language(p),
 
         // simple tag
         LuaTokenKind::TkTagVisibility => parse_tag_simple(p, LuaSyntaxKind::DocTagVisibility),
@@ -118,14 +119,16 @@ fn parse_tag_attribute(p: &mut LuaDocParser) -> ParseResult {
 // <T, R, C: AAA>
 fn parse_generic_decl_list(p: &mut LuaDocParser, allow_angle_brackets: bool) -> ParseResult {
     let m = p.mark(LuaSyntaxKind::DocGenericDeclareList);
+    p.set_state(LuaDocLexerState::GenericParams);
     if allow_angle_brackets {
         expect_token(p, LuaTokenKind::TkLt)?;
     }
-    parse_generic_param(p)?;
+    parse_generic_param(p, allow_angle_brackets)?;
     while p.current_token() == LuaTokenKind::TkComma {
         p.bump();
-        parse_generic_param(p)?;
+        parse_generic_param(p, allow_angle_brackets)?;
     }
+    p.set_state(LuaDocLexerState::Normal);
     if allow_angle_brackets {
         expect_token(p, LuaTokenKind::TkGt)?;
     }
@@ -134,16 +137,45 @@ fn parse_generic_decl_list(p: &mut LuaDocParser, allow_angle_brackets: bool) ->
 
 // A : type
 // A
-fn parse_generic_param(p: &mut LuaDocParser) -> ParseResult {
+// A : type = default
+// in A, out A, in out A
+fn parse_generic_param(p: &mut LuaDocParser, allow_variance: bool) -> ParseResult {
     let m = p.mark(LuaSyntaxKind::DocGenericParameter);
+    if allow_variance {
+        parse_generic_variance(p);
+    }
+
     expect_token(p, LuaTokenKind::TkName)?;
     if p.current_token() == LuaTokenKind::TkColon {
         p.bump();
         parse_type(p)?;
     }
+
+    if p.current_token() == LuaTokenKind::TkAssign {
+        p.bump();
+        parse_type(p)?;
+    }
     Ok(m.complete(p))
 }
 
+// `in` and `out` are names unless another name follows, `X<out>` declares `out`
+fn parse_generic_variance(p: &mut LuaDocParser) {
+    for (text, kind) in [
+        ("in", LuaTokenKind::TkDocIn),
+        ("out", LuaTokenKind::TkDocOut),
+    ] {
+        if p.current_token() == LuaTokenKind::TkName
+            && p.current_token_text() == text
+            && p.rest_of_line()
+                .trim_start()
+                .starts_with(|ch: char| ch.is_alphabetic() || ch == '_')
+        {
+            p.set_current_token_kind(kind);
+            p.bump();
+        }
+    }
+}
+
 // ---@enum A
 // ---@enum A : number
 fn parse_tag_enum(p: &mut LuaDocParser) -> ParseResult {
@@ -577,6 +609,20 @@ fn parse_tag_using(p: &mut LuaDocParser) -> ParseResult {
     Ok(m.complete(p))
 }
 
+// ---@language <language> [<param name>]
+// ---@language sql
+// ---@language sql query
+fn parse_tag_language(p: &mut LuaDocParser) -> ParseResult {
+    p.set_state(LuaDocLexerState::Normal);
+    let m = p.mark(LuaSyntaxKind::DocTagLanguage);
+    p.bump();
+    expect_token(p, LuaTokenKind::TkName)?;
+    if_token_bump(p, LuaTokenKind::TkName);
+    p.set_state(LuaDocLexerState::Description);
+    parse_description(p);
+    Ok(m.complete(p))
+}
+
 fn parse_tag_meta(p: &mut LuaDocParser) -> ParseResult {
     p.set_state(LuaDocLexerState::Normal);
     let m = p.mark(LuaSyntaxKind::DocTagMeta);
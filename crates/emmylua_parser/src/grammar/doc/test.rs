@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod tests {
-    use crate::{parser::ParserConfig, LuaParser};
+    use crate::{
+        parser::ParserConfig, LuaAstNode, LuaDocGenericDecl, LuaParser, LuaTokenKind, VarianceKind,
+    };
 
     macro_rules! assert_ast_eq {
         ($lua_code:expr, $expected:expr) => {
@@ -1001,6 +1003,107 @@ Syntax(Chunk)@0..92
         assert_ast_eq!(code, result);
     }
 
+    #[test]
+    fn test_generic_default_and_variance_doc() {
+        let code = r#"
+        ---@generic T: Base = Base
+        ---@class Box<in out T, out R = any>
+        "#;
+
+        let result = r#"
+Syntax(Chunk)@0..89
+  Syntax(Block)@0..89
+    Token(TkEndOfLine)@0..1 "\n"
+    Token(TkWhitespace)@1..9 "        "
+    Syntax(Comment)@9..80
+      Token(TkDocStart)@9..13 "---@"
+      Syntax(DocTagGeneric)@13..35
+        Token(TkTagGeneric)@13..20 "generic"
+        Token(TkWhitespace)@20..21 " "
+        Syntax(DocGenericDeclareList)@21..35
+          Syntax(DocGenericParameter)@21..35
+            Token(TkName)@21..22 "T"
+            Token(TkColon)@22..23 ":"
+            Token(TkWhitespace)@23..24 " "
+            Syntax(TypeName)@24..28
+              Token(TkName)@24..28 "Base"
+            Token(TkWhitespace)@28..29 " "
+            Token(TkAssign)@29..30 "="
+            Token(TkWhitespace)@30..31 " "
+            Syntax(TypeName)@31..35
+              Token(TkName)@31..35 "Base"
+      Token(TkEndOfLine)@35..36 "\n"
+      Token(TkWhitespace)@36..44 "        "
+      Token(TkDocStart)@44..48 "---@"
+      Syntax(DocTagClass)@48..80
+        Token(TkTagClass)@48..53 "class"
+        Token(TkWhitespace)@53..54 " "
+        Token(TkName)@54..57 "Box"
+        Syntax(DocGenericDeclareList)@57..80
+          Token(TkLt)@57..58 "<"
+          Syntax(DocGenericParameter)@58..66
+            Token(TkDocIn)@58..60 "in"
+            Token(TkWhitespace)@60..61 " "
+            Token(TkDocOut)@61..64 "out"
+            Token(TkWhitespace)@64..65 " "
+            Token(TkName)@65..66 "T"
+          Token(TkComma)@66..67 ","
+          Token(TkWhitespace)@67..68 " "
+          Syntax(DocGenericParameter)@68..79
+            Token(TkDocOut)@68..71 "out"
+            Token(TkWhitespace)@71..72 " "
+            Token(TkName)@72..73 "R"
+            Token(TkWhitespace)@73..74 " "
+            Token(TkAssign)@74..75 "="
+            Token(TkWhitespace)@75..76 " "
+            Syntax(TypeName)@76..79
+              Token(TkName)@76..79 "any"
+          Token(TkGt)@79..80 ">"
+    Token(TkEndOfLine)@80..81 "\n"
+    Token(TkWhitespace)@81..89 "        "
+        "#;
+        assert_ast_eq!(code, result);
+    }
+
+    #[test]
+    fn test_generic_variance_names_doc() {
+        let code = r#"
+        ---@class Out<out>
+        ---@class InOut<in, out = any>
+        ---@class Pair<in out T, out in>
+        ---@type string = default
+        "#;
+        let tree = LuaParser::parse(code, ParserConfig::default());
+        assert!(tree.get_errors().is_empty());
+
+        let params: Vec<_> = tree
+            .get_chunk_node()
+            .descendants::<LuaDocGenericDecl>()
+            .map(|param| {
+                let name = param.get_name_token().unwrap();
+                (name.get_name_text().to_string(), param.get_variance())
+            })
+            .collect();
+        assert_eq!(
+            params,
+            vec![
+                ("out".to_string(), VarianceKind::None),
+                ("in".to_string(), VarianceKind::None),
+                ("out".to_string(), VarianceKind::None),
+                ("T".to_string(), VarianceKind::InOut),
+                ("in".to_string(), VarianceKind::Out),
+            ]
+        );
+
+        // `=` is only a token in the generic declarations
+        let assigns = tree
+            .get_red_root()
+            .descendants_with_tokens()
+            .filter(|element| element.kind() == LuaTokenKind::TkAssign.into())
+            .count();
+        assert_eq!(assigns, 1);
+    }
+
     #[test]
     fn test_diagnostic_doc() {
         let code = r#"
@@ -1460,6 +1563,43 @@ Syntax(Chunk)@0..69
         assert_ast_eq!(code, result);
     }
 
+    #[test]
+    fn test_language_doc() {
+        let code = r#"
+        ---@language sql
+        ---@language json body request body
+        "#;
+
+        let result = r#"
+Syntax(Chunk)@0..78
+  Syntax(Block)@0..78
+    Token(TkEndOfLine)@0..1 "\n"
+    Token(TkWhitespace)@1..9 "        "
+    Syntax(Comment)@9..69
+      Token(TkDocStart)@9..13 "---@"
+      Syntax(DocTagLanguage)@13..25
+        Token(TkTagLanguage)@13..21 "language"
+        Token(TkWhitespace)@21..22 " "
+        Token(TkName)@22..25 "sql"
+      Token(TkEndOfLine)@25..26 "\n"
+      Token(TkWhitespace)@26..34 "        "
+      Token(TkDocStart)@34..38 "---@"
+      Syntax(DocTagLanguage)@38..69
+        Token(TkTagLanguage)@38..46 "language"
+        Token(TkWhitespace)@46..47 " "
+        Token(TkName)@47..51 "json"
+        Token(TkWhitespace)@51..52 " "
+        Token(TkName)@52..56 "body"
+        Token(TkWhitespace)@56..57 " "
+        Syntax(DocDescription)@57..69
+          Token(TkDocDetail)@57..69 "request body"
+    Token(TkEndOfLine)@69..70 "\n"
+    Token(TkWhitespace)@70..78 "        "
+        "#;
+
+        assert_ast_eq!(code, result);
+    }
+
     #[test]
     fn test_simple_doc() {
         let code = r#"
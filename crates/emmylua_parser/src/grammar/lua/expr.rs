@@ -3,10 +3,14 @@ use crate::{
     kind::{BinaryOperator, LuaOpKind, LuaSyntaxKind, LuaTokenKind, UnaryOperator, UNARY_PRIORITY},
     parser::{LuaParser, MarkerEventContainer},
     parser_error::LuaParseError,
+    text::SourceRange,
     SpecialFunction,
 };
 
-use super::{expect_token, if_token_bump, parse_block};
+use super::{
+    expect_block_token, expect_token, if_token_bump, parse_block,
+    types::{parse_generic_decl_list, parse_return_type_list, parse_type, parse_type_annotation},
+};
 
 pub fn parse_expr(p: &mut LuaParser) -> ParseResult {
     parse_sub_expr(p, 0)
@@ -58,6 +62,19 @@ fn parse_sub_expr(p: &mut LuaParser, limit: i32) -> ParseResult {
 }
 
 fn parse_simple_expr(p: &mut LuaParser) -> ParseResult {
+    let mut cm = parse_simple_expr_without_cast(p)?;
+    // a :: T
+    while p.current_token() == LuaTokenKind::TkDbColon && p.parse_config.support_type_annotation() {
+        let m = cm.precede(p, LuaSyntaxKind::CastExpr);
+        p.bump();
+        parse_type(p)?;
+        cm = m.complete(p);
+    }
+
+    Ok(cm)
+}
+
+fn parse_simple_expr_without_cast(p: &mut LuaParser) -> ParseResult {
     match p.current_token() {
         LuaTokenKind::TkInt
         | LuaTokenKind::TkFloat
@@ -73,22 +90,87 @@ fn parse_simple_expr(p: &mut LuaParser) -> ParseResult {
             Ok(m.complete(p))
         }
         LuaTokenKind::TkLeftBrace => parse_table_expr(p),
-        LuaTokenKind::TkFunction => parse_closure_expr(p),
+        LuaTokenKind::TkFunction => {
+            let function_range = p.current_token_range();
+            parse_closure_expr(p, function_range)
+        }
+        LuaTokenKind::TkIf if p.parse_config.support_if_expr() => parse_if_expr(p),
+        LuaTokenKind::TkInterpString | LuaTokenKind::TkInterpStringBegin => {
+            parse_interp_string_expr(p)
+        }
         _ => parse_suffixed_expr(p),
     }
 }
 
-pub fn parse_closure_expr(p: &mut LuaParser) -> ParseResult {
+// if <expr> then <expr> elseif <expr> then <expr> else <expr>
+fn parse_if_expr(p: &mut LuaParser) -> ParseResult {
+    let m = p.mark(LuaSyntaxKind::IfExpr);
+    let if_range = p.current_token_range();
+    p.bump();
+    parse_expr(p)?;
+    expect_block_token(p, LuaTokenKind::TkThen, LuaTokenKind::TkIf, if_range)?;
+    parse_expr(p)?;
+    while p.current_token() == LuaTokenKind::TkElseIf {
+        let elseif_range = p.current_token_range();
+        p.bump();
+        parse_expr(p)?;
+        expect_block_token(
+            p,
+            LuaTokenKind::TkThen,
+            LuaTokenKind::TkElseIf,
+            elseif_range,
+        )?;
+        parse_expr(p)?;
+    }
+    expect_token(p, LuaTokenKind::TkElse)?;
+    parse_expr(p)?;
+    Ok(m.complete(p))
+}
+
+// `text{<expr>}text{<expr>}text`
+fn parse_interp_string_expr(p: &mut LuaParser) -> ParseResult {
+    let m = p.mark(LuaSyntaxKind::InterpStringExpr);
+    if p.current_token() == LuaTokenKind::TkInterpStringBegin {
+        p.bump();
+        loop {
+            parse_expr(p)?;
+            match p.current_token() {
+                LuaTokenKind::TkInterpStringMid => p.bump(),
+                _ => break,
+            }
+        }
+        expect_token(p, LuaTokenKind::TkInterpStringEnd)?;
+    } else {
+        p.bump();
+    }
+
+    Ok(m.complete(p))
+}
+
+// `function_range` is the `function` keyword, which a function stat consumes before the closure
+pub fn parse_closure_expr(p: &mut LuaParser, function_range: SourceRange) -> ParseResult {
     let m = p.mark(LuaSyntaxKind::ClosureExpr);
 
     if_token_bump(p, LuaTokenKind::TkFunction);
+    if p.current_token() == LuaTokenKind::TkLt && p.parse_config.support_type_annotation() {
+        parse_generic_decl_list(p)?;
+    }
     parse_param_list(p)?;
+    if p.current_token() == LuaTokenKind::TkColon && p.parse_config.support_type_annotation() {
+        p.bump();
+        parse_return_type_list(p)?;
+    }
 
     if p.current_token() != LuaTokenKind::TkEnd {
         parse_block(p)?;
     }
 
-    expect_token(p, LuaTokenKind::TkEnd)?;
+    expect_block_token(
+        p,
+        LuaTokenKind::TkEnd,
+        LuaTokenKind::TkFunction,
+        function_range,
+    )?;
     Ok(m.complete(p))
 }
 
@@ -119,6 +201,7 @@ fn parse_param_name(p: &mut LuaParser) -> ParseResult {
             p.current_token_range(),
         ));
     }
+    parse_type_annotation(p)?;
 
     Ok(m.complete(p))
 }
@@ -3,7 +3,8 @@ use stat::parse_stats;
 use crate::{
     kind::{LuaSyntaxKind, LuaTokenKind},
     parser::{LuaParser, MarkerEventContainer},
-    parser_error::LuaParseError,
+    parser_error::{LuaParseError, LuaParseErrorCode},
+    text::SourceRange,
 };
 
 use super::ParseResult;
@@ -11,6 +12,7 @@ use super::ParseResult;
 mod expr;
 mod stat;
 mod test;
+mod types;
 
 pub fn parse_chunk(p: &mut LuaParser) {
     let m = p.mark(LuaSyntaxKind::Block);
@@ -22,11 +24,13 @@ pub fn parse_chunk(p: &mut LuaParser) {
 
         if p.current_token_index() == consume_count {
             let m = p.mark(LuaSyntaxKind::UnknownStat);
+            let range = p.current_token_range();
+            let token = describe_token(p.current_token());
             p.bump();
-            p.push_error(LuaParseError::syntax_error_from(
-                &t!("unexpected token"),
-                p.current_token_range(),
-            ));
+            p.push_error(
+                LuaParseError::syntax_error_from(&t!("unexpected %{token}", token = token), range)
+                    .with_code(LuaParseErrorCode::UnexpectedToken),
+            );
 
             m.complete(p);
         }
@@ -48,15 +52,123 @@ fn expect_token(p: &mut LuaParser, token: LuaTokenKind) -> Result<(), LuaParseEr
         p.bump();
         Ok(())
     } else {
-        Err(LuaParseError::syntax_error_from(
-            &t!(
-                "expected %{token}, but get %{current}",
-                token = token,
-                current = p.current_token()
-            ),
-            p.current_token_range(),
-        ))
+        Err(unexpected_token(p, &[token]))
+    }
+}
+
+// the current token is none of `expected`
+fn unexpected_token(p: &LuaParser, expected: &[LuaTokenKind]) -> LuaParseError {
+    let mut names: Vec<String> = expected.iter().map(|kind| describe_token(*kind)).collect();
+    let token = match names.pop() {
+        Some(last) if !names.is_empty() => format!("{} or {}", names.join(", "), last),
+        Some(last) => last,
+        None => String::new(),
+    };
+    LuaParseError::syntax_error_from(
+        &t!(
+            "expected %{token}, but get %{current}",
+            token = token,
+            current = describe_token(p.current_token())
+        ),
+        p.current_token_range(),
+    )
+    .with_code(LuaParseErrorCode::UnexpectedToken)
+    .with_expected(expected)
+}
+
+// `then`, `do`, `end` and `until` belong to the statement started by `opener`,
+// the error points back at it so an unmatched `end` is easy to find
+fn expect_block_token(
+    p: &mut LuaParser,
+    token: LuaTokenKind,
+    opener: LuaTokenKind,
+    opener_range: SourceRange,
+) -> Result<(), LuaParseError> {
+    if p.current_token() == token {
+        p.bump();
+        return Ok(());
     }
+
+    let code = match token {
+        LuaTokenKind::TkThen => LuaParseErrorCode::MissingThen,
+        LuaTokenKind::TkDo => LuaParseErrorCode::MissingDo,
+        _ => LuaParseErrorCode::UnclosedBlock,
+    };
+    let message = if code == LuaParseErrorCode::UnclosedBlock {
+        t!(
+            "expected %{token} to close %{opener}, but get %{current}",
+            token = describe_token(token),
+            opener = describe_token(opener),
+            current = describe_token(p.current_token())
+        )
+    } else {
+        t!(
+            "expected %{token} after %{opener}, but get %{current}",
+            token = describe_token(token),
+            opener = describe_token(opener),
+            current = describe_token(p.current_token())
+        )
+    };
+
+    Err(
+        LuaParseError::syntax_error_from(&message, p.current_token_range())
+            .with_code(code)
+            .with_expected(&[token])
+            .with_secondary(
+                &t!("%{opener} starts here", opener = describe_token(opener)),
+                opener_range.into(),
+            ),
+    )
+}
+
+// how a token is written in messages, e.g. `'end'` instead of `TkEnd`
+fn describe_token(kind: LuaTokenKind) -> String {
+    let text = match kind {
+        LuaTokenKind::TkAnd => "and",
+        LuaTokenKind::TkBreak => "break",
+        LuaTokenKind::TkDo => "do",
+        LuaTokenKind::TkElse => "else",
+        LuaTokenKind::TkElseIf => "elseif",
+        LuaTokenKind::TkEnd => "end",
+        LuaTokenKind::TkFalse => "false",
+        LuaTokenKind::TkFor => "for",
+        LuaTokenKind::TkFunction => "function",
+        LuaTokenKind::TkGoto => "goto",
+        LuaTokenKind::TkIf => "if",
+        LuaTokenKind::TkIn => "in",
+        LuaTokenKind::TkLocal => "local",
+        LuaTokenKind::TkNil => "nil",
+        LuaTokenKind::TkNot => "not",
+        LuaTokenKind::TkOr => "or",
+        LuaTokenKind::TkRepeat => "repeat",
+        LuaTokenKind::TkReturn => "return",
+        LuaTokenKind::TkThen => "then",
+        LuaTokenKind::TkTrue => "true",
+        LuaTokenKind::TkUntil => "until",
+        LuaTokenKind::TkWhile => "while",
+        LuaTokenKind::TkGlobal => "global",
+        LuaTokenKind::TkDot => ".",
+        LuaTokenKind::TkComma => ",",
+        LuaTokenKind::TkAssign => "=",
+        LuaTokenKind::TkBangEq => "!=",
+        LuaTokenKind::TkLt => "<",
+        LuaTokenKind::TkGt => ">",
+        LuaTokenKind::TkColon => ":",
+        LuaTokenKind::TkDbColon => "::",
+        LuaTokenKind::TkSemicolon => ";",
+        LuaTokenKind::TkLeftBracket => "[",
+        LuaTokenKind::TkRightBracket => "]",
+        LuaTokenKind::TkLeftParen => "(",
+        LuaTokenKind::TkRightParen => ")",
+        LuaTokenKind::TkLeftBrace => "{",
+        LuaTokenKind::TkRightBrace => "}",
+        LuaTokenKind::TkArrow => "->",
+        LuaTokenKind::TkName => return t!("name").to_string(),
+        LuaTokenKind::TkEof => return t!("end of file").to_string(),
+        _ => return kind.to_string(),
+    };
+
+    format!("'{}'", text)
 }
 
 fn if_token_bump(p: &mut LuaParser, token: LuaTokenKind) -> bool {
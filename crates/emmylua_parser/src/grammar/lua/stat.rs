@@ -2,13 +2,15 @@ use crate::{
     grammar::ParseResult,
     kind::{LuaSyntaxKind, LuaTokenKind},
     parser::{LuaParser, MarkerEventContainer},
-    parser_error::LuaParseError,
+    parser_error::{LuaParseError, LuaParseErrorCode},
 };
 
 use super::{
-    expect_token,
+    expect_block_token, expect_token,
     expr::{parse_closure_expr, parse_expr},
     if_token_bump, parse_block,
+    types::{parse_generic_decl_list, parse_type, parse_type_annotation},
+    unexpected_token,
 };
 
 pub fn parse_stats(p: &mut LuaParser) {
@@ -55,6 +57,8 @@ fn parse_stat(p: &mut LuaParser) -> ParseResult {
         LuaTokenKind::TkDbColon => parse_label_stat(p)?,
         LuaTokenKind::TkSemicolon => parse_empty_stat(p)?,
         LuaTokenKind::TkGlobal => parse_global_stat(p)?,
+        LuaTokenKind::TkName if is_type_alias_stat(p) => parse_type_alias_stat(p)?,
+        LuaTokenKind::TkName if is_continue_stat(p) => parse_continue_stat(p)?,
         _ => parse_assign_or_expr_stat(p)?,
     };
 
@@ -63,9 +67,10 @@ fn parse_stat(p: &mut LuaParser) -> ParseResult {
 
 fn parse_if(p: &mut LuaParser) -> ParseResult {
     let m = p.mark(LuaSyntaxKind::IfStat);
+    let if_range = p.current_token_range();
     p.bump();
     parse_expr(p)?;
-    expect_token(p, LuaTokenKind::TkThen)?;
+    expect_block_token(p, LuaTokenKind::TkThen, LuaTokenKind::TkIf, if_range)?;
     parse_block(p)?;
 
     while p.current_token() == LuaTokenKind::TkElseIf {
@@ -76,7 +81,7 @@ fn parse_if(p: &mut LuaParser) -> ParseResult {
         parse_else_clause(p)?;
     }
 
-    expect_token(p, LuaTokenKind::TkEnd)?;
+    expect_block_token(p, LuaTokenKind::TkEnd, LuaTokenKind::TkIf, if_range)?;
 
     if_token_bump(p, LuaTokenKind::TkSemicolon);
     Ok(m.complete(p))
@@ -84,9 +89,15 @@ fn parse_if(p: &mut LuaParser) -> ParseResult {
 
 fn parse_elseif_clause(p: &mut LuaParser) -> ParseResult {
     let m = p.mark(LuaSyntaxKind::ElseIfClauseStat);
+    let elseif_range = p.current_token_range();
     p.bump();
     parse_expr(p)?;
-    expect_token(p, LuaTokenKind::TkThen)?;
+    expect_block_token(
+        p,
+        LuaTokenKind::TkThen,
+        LuaTokenKind::TkElseIf,
+        elseif_range,
+    )?;
     parse_block(p)?;
 
     Ok(m.complete(p))
@@ -102,21 +113,23 @@ fn parse_else_clause(p: &mut LuaParser) -> ParseResult {
 
 fn parse_while(p: &mut LuaParser) -> ParseResult {
     let m = p.mark(LuaSyntaxKind::WhileStat);
+    let while_range = p.current_token_range();
     p.bump();
     parse_expr(p)?;
-    expect_token(p, LuaTokenKind::TkDo)?;
+    expect_block_token(p, LuaTokenKind::TkDo, LuaTokenKind::TkWhile, while_range)?;
     parse_block(p)?;
 
-    expect_token(p, LuaTokenKind::TkEnd)?;
+    expect_block_token(p, LuaTokenKind::TkEnd, LuaTokenKind::TkWhile, while_range)?;
     if_token_bump(p, LuaTokenKind::TkSemicolon);
     Ok(m.complete(p))
 }
 
 fn parse_do(p: &mut LuaParser) -> ParseResult {
     let m = p.mark(LuaSyntaxKind::DoStat);
+    let do_range = p.current_token_range();
     p.bump();
     parse_block(p)?;
-    expect_token(p, LuaTokenKind::TkEnd)?;
+    expect_block_token(p, LuaTokenKind::TkEnd, LuaTokenKind::TkDo, do_range)?;
 
     if_token_bump(p, LuaTokenKind::TkSemicolon);
     Ok(m.complete(p))
@@ -124,8 +137,10 @@ fn parse_do(p: &mut LuaParser) -> ParseResult {
 
 fn parse_for(p: &mut LuaParser) -> ParseResult {
     let mut m = p.mark(LuaSyntaxKind::ForStat);
+    let for_range = p.current_token_range();
     p.bump();
     expect_token(p, LuaTokenKind::TkName)?;
+    parse_type_annotation(p)?;
     match p.current_token() {
         LuaTokenKind::TkAssign => {
             p.bump();
@@ -142,6 +157,7 @@ fn parse_for(p: &mut LuaParser) -> ParseResult {
             while p.current_token() == LuaTokenKind::TkComma {
                 p.bump();
                 expect_token(p, LuaTokenKind::TkName)?;
+                parse_type_annotation(p)?;
             }
 
             expect_token(p, LuaTokenKind::TkIn)?;
@@ -152,15 +168,19 @@ fn parse_for(p: &mut LuaParser) -> ParseResult {
             }
         }
         _ => {
-            return Err(LuaParseError::syntax_error_from(
-                &t!("unexpected token"),
-                p.current_token_range(),
+            return Err(unexpected_token(
+                p,
+                &[
+                    LuaTokenKind::TkAssign,
+                    LuaTokenKind::TkComma,
+                    LuaTokenKind::TkIn,
+                ],
             ));
         }
     }
-    expect_token(p, LuaTokenKind::TkDo)?;
+    expect_block_token(p, LuaTokenKind::TkDo, LuaTokenKind::TkFor, for_range)?;
     parse_block(p)?;
-    expect_token(p, LuaTokenKind::TkEnd)?;
+    expect_block_token(p, LuaTokenKind::TkEnd, LuaTokenKind::TkFor, for_range)?;
 
     if_token_bump(p, LuaTokenKind::TkSemicolon);
     Ok(m.complete(p))
@@ -168,9 +188,10 @@ fn parse_for(p: &mut LuaParser) -> ParseResult {
 
 fn parse_function(p: &mut LuaParser) -> ParseResult {
     let m = p.mark(LuaSyntaxKind::FuncStat);
+    let function_range = p.current_token_range();
     p.bump();
     parse_func_name(p)?;
-    parse_closure_expr(p)?;
+    parse_closure_expr(p, function_range)?;
     if_token_bump(p, LuaTokenKind::TkSemicolon);
     Ok(m.complete(p))
 }
@@ -209,10 +230,11 @@ fn parse_local(p: &mut LuaParser) -> ParseResult {
     p.bump();
     match p.current_token() {
         LuaTokenKind::TkFunction => {
+            let function_range = p.current_token_range();
             p.bump();
             m.set_kind(p, LuaSyntaxKind::LocalFuncStat);
             parse_local_name(p, false)?;
-            parse_closure_expr(p)?;
+            parse_closure_expr(p, function_range)?;
         }
         LuaTokenKind::TkName => {
             parse_local_name(p, true)?;
@@ -231,9 +253,9 @@ fn parse_local(p: &mut LuaParser) -> ParseResult {
             }
         }
         _ => {
-            return Err(LuaParseError::syntax_error_from(
-                &t!("unexpected token %{token}", token = p.current_token()),
-                p.current_token_range(),
+            return Err(unexpected_token(
+                p,
+                &[LuaTokenKind::TkFunction, LuaTokenKind::TkName],
             ));
         }
     }
@@ -245,6 +267,9 @@ fn parse_local(p: &mut LuaParser) -> ParseResult {
 fn parse_local_name(p: &mut LuaParser, support_attrib: bool) -> ParseResult {
     let m = p.mark(LuaSyntaxKind::LocalName);
     expect_token(p, LuaTokenKind::TkName)?;
+    if support_attrib {
+        parse_type_annotation(p)?;
+    }
     if support_attrib && p.current_token() == LuaTokenKind::TkLt {
         parse_attrib(p)?;
     }
@@ -259,13 +284,16 @@ fn parse_attrib(p: &mut LuaParser) -> ParseResult {
     expect_token(p, LuaTokenKind::TkName)?;
     expect_token(p, LuaTokenKind::TkGt)?;
     if !p.parse_config.support_local_attrib() {
-        p.errors.push(LuaParseError::syntax_error_from(
-            &t!(
-                "local attribute is not supported for current version: %{level}",
-                level = p.parse_config.level
-            ),
-            range,
-        ));
+        p.errors.push(
+            LuaParseError::syntax_error_from(
+                &t!(
+                    "local attribute is not supported for current version: %{level}",
+                    level = p.parse_config.level
+                ),
+                range,
+            )
+            .with_code(LuaParseErrorCode::UnsupportedSyntax),
+        );
     }
 
     Ok(m.complete(p))
@@ -295,9 +323,15 @@ fn parse_break(p: &mut LuaParser) -> ParseResult {
 
 fn parse_repeat(p: &mut LuaParser) -> ParseResult {
     let m = p.mark(LuaSyntaxKind::RepeatStat);
+    let repeat_range = p.current_token_range();
     p.bump();
     parse_block(p)?;
-    expect_token(p, LuaTokenKind::TkUntil)?;
+    expect_block_token(
+        p,
+        LuaTokenKind::TkUntil,
+        LuaTokenKind::TkRepeat,
+        repeat_range,
+    )?;
     parse_expr(p)?;
     if_token_bump(p, LuaTokenKind::TkSemicolon);
     Ok(m.complete(p))
@@ -317,20 +351,59 @@ fn parse_empty_stat(p: &mut LuaParser) -> ParseResult {
     Ok(m.complete(p))
 }
 
+// global function Name funcbody
+// global [attrib] *
+// global [attrib] Name [attrib] {, Name [attrib]} [= explist]
 fn parse_global_stat(p: &mut LuaParser) -> ParseResult {
     let m = p.mark(LuaSyntaxKind::GlobalStat);
     p.bump();
-    expect_token(p, LuaTokenKind::TkName)?;
+    match p.current_token() {
+        LuaTokenKind::TkFunction => {
+            let function_range = p.current_token_range();
+            p.bump();
+            parse_global_name(p, false)?;
+            parse_closure_expr(p, function_range)?;
+        }
+        _ => {
+            if p.current_token() == LuaTokenKind::TkLt {
+                parse_attrib(p)?;
+            }
 
-    while p.current_token() == LuaTokenKind::TkComma {
-        p.bump();
-        expect_token(p, LuaTokenKind::TkName)?;
+            if p.current_token() == LuaTokenKind::TkMul {
+                p.bump();
+            } else {
+                parse_global_name(p, true)?;
+                while p.current_token() == LuaTokenKind::TkComma {
+                    p.bump();
+                    parse_global_name(p, true)?;
+                }
+
+                if p.current_token() == LuaTokenKind::TkAssign {
+                    p.bump();
+                    parse_expr(p)?;
+                    while p.current_token() == LuaTokenKind::TkComma {
+                        p.bump();
+                        parse_expr(p)?;
+                    }
+                }
+            }
+        }
     }
 
     if_token_bump(p, LuaTokenKind::TkSemicolon);
     Ok(m.complete(p))
 }
 
+fn parse_global_name(p: &mut LuaParser, support_attrib: bool) -> ParseResult {
+    let m = p.mark(LuaSyntaxKind::GlobalName);
+    expect_token(p, LuaTokenKind::TkName)?;
+    if support_attrib && p.current_token() == LuaTokenKind::TkLt {
+        parse_attrib(p)?;
+    }
+
+    Ok(m.complete(p))
+}
+
 fn parse_assign_or_expr_stat(p: &mut LuaParser) -> ParseResult {
     let mut m = p.mark(LuaSyntaxKind::AssignStat);
     let range = p.current_token_range();
@@ -374,6 +447,11 @@ fn parse_assign_or_expr_stat(p: &mut LuaParser) -> ParseResult {
             p.bump();
             parse_expr(p)?;
         }
+    } else if is_compound_assign_op(p.current_token()) && p.parse_config.support_compound_assign() {
+        // a += 1
+        m.set_kind(p, LuaSyntaxKind::CompoundAssignStat);
+        p.bump();
+        parse_expr(p)?;
     } else {
         return Err(LuaParseError::syntax_error_from(
             &t!("unfinished stat"),
@@ -392,3 +470,79 @@ fn parse_label_stat(p: &mut LuaParser) -> ParseResult {
     expect_token(p, LuaTokenKind::TkDbColon)?;
     Ok(m.complete(p))
 }
+
+fn is_compound_assign_op(kind: LuaTokenKind) -> bool {
+    matches!(
+        kind,
+        LuaTokenKind::TkPlusAssign
+            | LuaTokenKind::TkMinusAssign
+            | LuaTokenKind::TkMulAssign
+            | LuaTokenKind::TkDivAssign
+            | LuaTokenKind::TkIDivAssign
+            | LuaTokenKind::TkModAssign
+            | LuaTokenKind::TkPowAssign
+            | LuaTokenKind::TkConcatAssign
+    )
+}
+
+// `type` and `export` are contextual keywords in luau
+fn is_type_alias_stat(p: &LuaParser) -> bool {
+    if !p.parse_config.support_type_annotation() || p.peek_next_token() != LuaTokenKind::TkName {
+        return false;
+    }
+
+    matches!(p.current_token_text(), "type" | "export")
+}
+
+// `continue` is a contextual keyword, `continue = 1` or `continue()` still use it as a name
+fn is_continue_stat(p: &LuaParser) -> bool {
+    if !p.parse_config.support_continue() || p.current_token_text() != "continue" {
+        return false;
+    }
+
+    !matches!(
+        p.peek_next_token(),
+        LuaTokenKind::TkLeftParen
+            | LuaTokenKind::TkLeftBrace
+            | LuaTokenKind::TkLeftBracket
+            | LuaTokenKind::TkDot
+            | LuaTokenKind::TkColon
+            | LuaTokenKind::TkComma
+            | LuaTokenKind::TkAssign
+            | LuaTokenKind::TkString
+            | LuaTokenKind::TkLongString
+    ) && !is_compound_assign_op(p.peek_next_token())
+}
+
+// [export] type <name> [<generic list>] = <type>
+fn parse_type_alias_stat(p: &mut LuaParser) -> ParseResult {
+    let m = p.mark(LuaSyntaxKind::TypeAliasStat);
+    if p.current_token_text() == "export" {
+        p.bump();
+        if p.current_token() != LuaTokenKind::TkName || p.current_token_text() != "type" {
+            return Err(LuaParseError::syntax_error_from(
+                &t!("unexpected token %{token}", token = p.current_token()),
+                p.current_token_range(),
+            )
+            .with_code(LuaParseErrorCode::UnexpectedToken));
+        }
+    }
+
+    p.bump();
+    expect_token(p, LuaTokenKind::TkName)?;
+    if p.current_token() == LuaTokenKind::TkLt {
+        parse_generic_decl_list(p)?;
+    }
+    expect_token(p, LuaTokenKind::TkAssign)?;
+    parse_type(p)?;
+
+    if_token_bump(p, LuaTokenKind::TkSemicolon);
+    Ok(m.complete(p))
+}
+
+fn parse_continue_stat(p: &mut LuaParser) -> ParseResult {
+    let m = p.mark(LuaSyntaxKind::ContinueStat);
+    p.bump();
+    if_token_bump(p, LuaTokenKind::TkSemicolon);
+    Ok(m.complete(p))
+}
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use crate::{parser::ParserConfig, LuaLanguageLevel, LuaParser};
+    use crate::{parser::ParserConfig, LuaLanguageLevel, LuaParser, LuaSyntaxExtension};
 
     macro_rules! assert_ast_eq {
         ($lua_code:expr, $expected:expr) => {
@@ -1137,10 +1137,12 @@ Syntax(Chunk)@0..12
     Syntax(GlobalStat)@0..12
       Token(TkGlobal)@0..6 "global"
       Token(TkWhitespace)@6..7 " "
-      Token(TkName)@7..8 "a"
+      Syntax(GlobalName)@7..8
+        Token(TkName)@7..8 "a"
       Token(TkComma)@8..9 ","
       Token(TkWhitespace)@9..10 " "
-      Token(TkName)@10..11 "b"
+      Syntax(GlobalName)@10..11
+        Token(TkName)@10..11 "b"
       Token(TkSemicolon)@11..12 ";"
         "#;
 
@@ -1150,4 +1152,312 @@ Syntax(Chunk)@0..12
             ParserConfig::with_level(LuaLanguageLevel::Lua55)
         );
     }
+
+    #[test]
+    fn test_lua55_global_attrib_grammar() {
+        let code = "global<const> x, y<close> = 1\nglobal *\nglobal function f() end";
+        let result = r#"
+Syntax(Chunk)@0..62
+  Syntax(Block)@0..62
+    Syntax(GlobalStat)@0..29
+      Token(TkGlobal)@0..6 "global"
+      Syntax(Attribute)@6..13
+        Token(TkLt)@6..7 "<"
+        Token(TkName)@7..12 "const"
+        Token(TkGt)@12..13 ">"
+      Token(TkWhitespace)@13..14 " "
+      Syntax(GlobalName)@14..15
+        Token(TkName)@14..15 "x"
+      Token(TkComma)@15..16 ","
+      Token(TkWhitespace)@16..17 " "
+      Syntax(GlobalName)@17..25
+        Token(TkName)@17..18 "y"
+        Syntax(Attribute)@18..25
+          Token(TkLt)@18..19 "<"
+          Token(TkName)@19..24 "close"
+          Token(TkGt)@24..25 ">"
+      Token(TkWhitespace)@25..26 " "
+      Token(TkAssign)@26..27 "="
+      Token(TkWhitespace)@27..28 " "
+      Syntax(LiteralExpr)@28..29
+        Token(TkInt)@28..29 "1"
+    Token(TkEndOfLine)@29..30 "\n"
+    Syntax(GlobalStat)@30..38
+      Token(TkGlobal)@30..36 "global"
+      Token(TkWhitespace)@36..37 " "
+      Token(TkMul)@37..38 "*"
+    Token(TkEndOfLine)@38..39 "\n"
+    Syntax(GlobalStat)@39..62
+      Token(TkGlobal)@39..45 "global"
+      Token(TkWhitespace)@45..46 " "
+      Token(TkFunction)@46..54 "function"
+      Token(TkWhitespace)@54..55 " "
+      Syntax(GlobalName)@55..56
+        Token(TkName)@55..56 "f"
+      Syntax(ClosureExpr)@56..62
+        Syntax(ParamList)@56..58
+          Token(TkLeftParen)@56..57 "("
+          Token(TkRightParen)@57..58 ")"
+        Token(TkWhitespace)@58..59 " "
+        Token(TkEnd)@59..62 "end"
+        "#;
+
+        assert_ast_eq!(
+            code,
+            result,
+            ParserConfig::with_level(LuaLanguageLevel::Lua55)
+        );
+    }
+
+    #[test]
+    fn test_luau_type_alias() {
+        let code = "export type Pair<T> = { first: T, second: T? }";
+        let result = r#"
+Syntax(Chunk)@0..46
+  Syntax(Block)@0..46
+    Syntax(TypeAliasStat)@0..46
+      Token(TkName)@0..6 "export"
+      Token(TkWhitespace)@6..7 " "
+      Token(TkName)@7..11 "type"
+      Token(TkWhitespace)@11..12 " "
+      Token(TkName)@12..16 "Pair"
+      Syntax(DocGenericDeclareList)@16..19
+        Token(TkLt)@16..17 "<"
+        Syntax(DocGenericParameter)@17..18
+          Token(TkName)@17..18 "T"
+        Token(TkGt)@18..19 ">"
+      Token(TkWhitespace)@19..20 " "
+      Token(TkAssign)@20..21 "="
+      Token(TkWhitespace)@21..22 " "
+      Syntax(TypeObject)@22..46
+        Token(TkLeftBrace)@22..23 "{"
+        Token(TkWhitespace)@23..24 " "
+        Syntax(DocObjectField)@24..32
+          Token(TkName)@24..29 "first"
+          Token(TkColon)@29..30 ":"
+          Token(TkWhitespace)@30..31 " "
+          Syntax(TypeName)@31..32
+            Token(TkName)@31..32 "T"
+        Token(TkComma)@32..33 ","
+        Token(TkWhitespace)@33..34 " "
+        Syntax(DocObjectField)@34..44
+          Token(TkName)@34..40 "second"
+          Token(TkColon)@40..41 ":"
+          Token(TkWhitespace)@41..42 " "
+          Syntax(TypeNullable)@42..44
+            Syntax(TypeName)@42..43
+              Token(TkName)@42..43 "T"
+            Token(TkDocQuestion)@43..44 "?"
+        Token(TkWhitespace)@44..45 " "
+        Token(TkRightBrace)@45..46 "}"
+        "#;
+
+        assert_ast_eq!(
+            code,
+            result,
+            ParserConfig::with_level(LuaLanguageLevel::Luau)
+        );
+    }
+
+    #[test]
+    fn test_luau_function_annotation() {
+        let code = "local function f<T>(a: T, ...: number): (T, string) end";
+        let result = r#"
+Syntax(Chunk)@0..55
+  Syntax(Block)@0..55
+    Syntax(LocalFuncStat)@0..55
+      Token(TkLocal)@0..5 "local"
+      Token(TkWhitespace)@5..6 " "
+      Token(TkFunction)@6..14 "function"
+      Token(TkWhitespace)@14..15 " "
+      Syntax(LocalName)@15..16
+        Token(TkName)@15..16 "f"
+      Syntax(ClosureExpr)@16..55
+        Syntax(DocGenericDeclareList)@16..19
+          Token(TkLt)@16..17 "<"
+          Syntax(DocGenericParameter)@17..18
+            Token(TkName)@17..18 "T"
+          Token(TkGt)@18..19 ">"
+        Syntax(ParamList)@19..38
+          Token(TkLeftParen)@19..20 "("
+          Syntax(ParamName)@20..24
+            Token(TkName)@20..21 "a"
+            Token(TkColon)@21..22 ":"
+            Token(TkWhitespace)@22..23 " "
+            Syntax(TypeName)@23..24
+              Token(TkName)@23..24 "T"
+          Token(TkComma)@24..25 ","
+          Token(TkWhitespace)@25..26 " "
+          Syntax(ParamName)@26..37
+            Token(TkDots)@26..29 "..."
+            Token(TkColon)@29..30 ":"
+            Token(TkWhitespace)@30..31 " "
+            Syntax(TypeName)@31..37
+              Token(TkName)@31..37 "number"
+          Token(TkRightParen)@37..38 ")"
+        Token(TkColon)@38..39 ":"
+        Token(TkWhitespace)@39..40 " "
+        Syntax(DocTypeList)@40..51
+          Token(TkLeftParen)@40..41 "("
+          Syntax(DocNamedReturnType)@41..42
+            Syntax(TypeName)@41..42
+              Token(TkName)@41..42 "T"
+          Token(TkComma)@42..43 ","
+          Token(TkWhitespace)@43..44 " "
+          Syntax(DocNamedReturnType)@44..50
+            Syntax(TypeName)@44..50
+              Token(TkName)@44..50 "string"
+          Token(TkRightParen)@50..51 ")"
+        Token(TkWhitespace)@51..52 " "
+        Token(TkEnd)@52..55 "end"
+        "#;
+
+        assert_ast_eq!(
+            code,
+            result,
+            ParserConfig::with_level(LuaLanguageLevel::Luau)
+        );
+    }
+
+    #[test]
+    fn test_luau_compound_assign_and_continue() {
+        let code = "x += 1\nfor i = 1, 3 do continue end";
+        let result = r#"
+Syntax(Chunk)@0..35
+  Syntax(Block)@0..35
+    Syntax(CompoundAssignStat)@0..6
+      Syntax(NameExpr)@0..1
+        Token(TkName)@0..1 "x"
+      Token(TkWhitespace)@1..2 " "
+      Token(TkPlusAssign)@2..4 "+="
+      Token(TkWhitespace)@4..5 " "
+      Syntax(LiteralExpr)@5..6
+        Token(TkInt)@5..6 "1"
+    Token(TkEndOfLine)@6..7 "\n"
+    Syntax(ForStat)@7..35
+      Token(TkFor)@7..10 "for"
+      Token(TkWhitespace)@10..11 " "
+      Token(TkName)@11..12 "i"
+      Token(TkWhitespace)@12..13 " "
+      Token(TkAssign)@13..14 "="
+      Token(TkWhitespace)@14..15 " "
+      Syntax(LiteralExpr)@15..16
+        Token(TkInt)@15..16 "1"
+      Token(TkComma)@16..17 ","
+      Token(TkWhitespace)@17..18 " "
+      Syntax(LiteralExpr)@18..19
+        Token(TkInt)@18..19 "3"
+      Token(TkWhitespace)@19..20 " "
+      Token(TkDo)@20..22 "do"
+      Syntax(Block)@22..32
+        Token(TkWhitespace)@22..23 " "
+        Syntax(ContinueStat)@23..31
+          Token(TkName)@23..31 "continue"
+        Token(TkWhitespace)@31..32 " "
+      Token(TkEnd)@32..35 "end"
+        "#;
+
+        assert_ast_eq!(
+            code,
+            result,
+            ParserConfig::with_level(LuaLanguageLevel::Luau)
+        );
+    }
+
+    #[test]
+    fn test_luau_if_expr_interp_string_and_cast() {
+        let code = "local v = if a then `a{b}c` else c :: string";
+        let result = r#"
+Syntax(Chunk)@0..44
+  Syntax(Block)@0..44
+    Syntax(LocalStat)@0..44
+      Token(TkLocal)@0..5 "local"
+      Token(TkWhitespace)@5..6 " "
+      Syntax(LocalName)@6..7
+        Token(TkName)@6..7 "v"
+      Token(TkWhitespace)@7..8 " "
+      Token(TkAssign)@8..9 "="
+      Token(TkWhitespace)@9..10 " "
+      Syntax(IfExpr)@10..44
+        Token(TkIf)@10..12 "if"
+        Token(TkWhitespace)@12..13 " "
+        Syntax(NameExpr)@13..14
+          Token(TkName)@13..14 "a"
+        Token(TkWhitespace)@14..15 " "
+        Token(TkThen)@15..19 "then"
+        Token(TkWhitespace)@19..20 " "
+        Syntax(InterpStringExpr)@20..27
+          Token(TkInterpStringBegin)@20..23 "`a{"
+          Syntax(NameExpr)@23..24
+            Token(TkName)@23..24 "b"
+          Token(TkInterpStringEnd)@24..27 "}c`"
+        Token(TkWhitespace)@27..28 " "
+        Token(TkElse)@28..32 "else"
+        Token(TkWhitespace)@32..33 " "
+        Syntax(CastExpr)@33..44
+          Syntax(NameExpr)@33..34
+            Token(TkName)@33..34 "c"
+          Token(TkWhitespace)@34..35 " "
+          Token(TkDbColon)@35..37 "::"
+          Token(TkWhitespace)@37..38 " "
+          Syntax(TypeName)@38..44
+            Token(TkName)@38..44 "string"
+        "#;
+
+        assert_ast_eq!(
+            code,
+            result,
+            ParserConfig::with_level(LuaLanguageLevel::Luau)
+        );
+    }
+
+    #[test]
+    fn test_syntax_extensions() {
+        let code = "x ..= \"a\"\nwhile x != y do continue end";
+        let result = r#"
+Syntax(Chunk)@0..38
+  Syntax(Block)@0..38
+    Syntax(CompoundAssignStat)@0..9
+      Syntax(NameExpr)@0..1
+        Token(TkName)@0..1 "x"
+      Token(TkWhitespace)@1..2 " "
+      Token(TkConcatAssign)@2..5 "..="
+      Token(TkWhitespace)@5..6 " "
+      Syntax(LiteralExpr)@6..9
+        Token(TkString)@6..9 "\"a\""
+    Token(TkEndOfLine)@9..10 "\n"
+    Syntax(WhileStat)@10..38
+      Token(TkWhile)@10..15 "while"
+      Token(TkWhitespace)@15..16 " "
+      Syntax(BinaryExpr)@16..22
+        Syntax(NameExpr)@16..17
+          Token(TkName)@16..17 "x"
+        Token(TkWhitespace)@17..18 " "
+        Token(TkBangEq)@18..20 "!="
+        Token(TkWhitespace)@20..21 " "
+        Syntax(NameExpr)@21..22
+          Token(TkName)@21..22 "y"
+      Token(TkWhitespace)@22..23 " "
+      Token(TkDo)@23..25 "do"
+      Syntax(Block)@25..35
+        Token(TkWhitespace)@25..26 " "
+        Syntax(ContinueStat)@26..34
+          Token(TkName)@26..34 "continue"
+        Token(TkWhitespace)@34..35 " "
+      Token(TkEnd)@35..38 "end"
+        "#;
+
+        let extensions = [
+            LuaSyntaxExtension::CompoundAssign,
+            LuaSyntaxExtension::Continue,
+            LuaSyntaxExtension::NotEqual,
+        ]
+        .into_iter()
+        .collect();
+        assert_ast_eq!(
+            code,
+            result,
+            ParserConfig::with_level(LuaLanguageLevel::Lua54).with_extensions(extensions)
+        );
+    }
 }
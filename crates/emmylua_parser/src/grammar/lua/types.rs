@@ -0,0 +1,350 @@
+use crate::{
+    grammar::ParseResult,
+    kind::{LuaSyntaxKind, LuaTokenKind, LuaTypeBinaryOperator},
+    parser::{CompleteMarker, LuaParser, MarkerEventContainer},
+    parser_error::LuaParseError,
+};
+
+use super::{expect_token, expr::parse_expr, if_token_bump};
+
+// luau type annotations are lowered to the same syntax nodes as doc types
+
+pub fn parse_type(p: &mut LuaParser) -> ParseResult {
+    // leading `|` or `&`
+    if matches!(
+        p.current_token(),
+        LuaTokenKind::TkBitOr | LuaTokenKind::TkBitAnd
+    ) {
+        p.bump();
+    }
+
+    parse_sub_type(p, 0)
+}
+
+// <type> | <type>, <type> & <type>
+fn parse_sub_type(p: &mut LuaParser, limit: i32) -> ParseResult {
+    let mut cm = parse_simple_type(p)?;
+
+    loop {
+        let (kind, bop) = match p.current_token() {
+            LuaTokenKind::TkBitOr => (LuaTokenKind::TkDocOr, LuaTypeBinaryOperator::Union),
+            LuaTokenKind::TkBitAnd => (LuaTokenKind::TkDocAnd, LuaTypeBinaryOperator::Intersection),
+            _ => break,
+        };
+        if bop.get_priority().left <= limit {
+            break;
+        }
+
+        let range = p.current_token_range();
+        let m = cm.precede(p, LuaSyntaxKind::TypeBinary);
+        p.set_current_token_kind(kind);
+        p.bump();
+        match parse_sub_type(p, bop.get_priority().right) {
+            Ok(_) => {}
+            Err(err) => {
+                p.push_error(LuaParseError::syntax_error_from(
+                    &t!("binary operator not followed by type"),
+                    range,
+                ));
+                return Err(err);
+            }
+        }
+        cm = m.complete(p);
+    }
+
+    Ok(cm)
+}
+
+// <type>?
+fn parse_simple_type(p: &mut LuaParser) -> ParseResult {
+    let mut cm = parse_primary_type(p)?;
+    while p.current_token() == LuaTokenKind::TkDocQuestion {
+        let m = cm.precede(p, LuaSyntaxKind::TypeNullable);
+        p.bump();
+        cm = m.complete(p);
+    }
+
+    Ok(cm)
+}
+
+fn parse_primary_type(p: &mut LuaParser) -> ParseResult {
+    match p.current_token() {
+        LuaTokenKind::TkNil
+        | LuaTokenKind::TkTrue
+        | LuaTokenKind::TkFalse
+        | LuaTokenKind::TkString
+        | LuaTokenKind::TkInt => {
+            let m = p.mark(LuaSyntaxKind::TypeLiteral);
+            p.bump();
+            Ok(m.complete(p))
+        }
+        LuaTokenKind::TkName => {
+            if p.current_token_text() == "typeof"
+                && p.peek_next_token() == LuaTokenKind::TkLeftParen
+            {
+                parse_typeof_type(p)
+            } else {
+                parse_name_type(p)
+            }
+        }
+        LuaTokenKind::TkLeftBrace => parse_table_type(p),
+        LuaTokenKind::TkLeftParen => parse_paren_or_fun_type(p),
+        LuaTokenKind::TkLt => parse_fun_type(p),
+        LuaTokenKind::TkFunction => {
+            // `function` is a valid type name in luau
+            p.set_current_token_kind(LuaTokenKind::TkName);
+            parse_name_type(p)
+        }
+        LuaTokenKind::TkDots => {
+            let m = p.mark(LuaSyntaxKind::TypeVariadic);
+            p.bump();
+            parse_simple_type(p)?;
+            Ok(m.complete(p))
+        }
+        _ => Err(LuaParseError::syntax_error_from(
+            &t!("expect type"),
+            p.current_token_range(),
+        )),
+    }
+}
+
+// typeof(<expr>)
+fn parse_typeof_type(p: &mut LuaParser) -> ParseResult {
+    let m = p.mark(LuaSyntaxKind::TypeOf);
+    p.bump();
+    expect_token(p, LuaTokenKind::TkLeftParen)?;
+    parse_expr(p)?;
+    expect_token(p, LuaTokenKind::TkRightParen)?;
+    Ok(m.complete(p))
+}
+
+// <name>, <module>.<name>, <name><<type list>>, <name>...
+fn parse_name_type(p: &mut LuaParser) -> ParseResult {
+    let m = p.mark(LuaSyntaxKind::TypeName);
+    p.bump();
+    while p.current_token() == LuaTokenKind::TkDot {
+        p.bump();
+        expect_token(p, LuaTokenKind::TkName)?;
+    }
+    let mut cm = m.complete(p);
+
+    match p.current_token() {
+        LuaTokenKind::TkLt => {
+            let m = cm.precede(p, LuaSyntaxKind::TypeGeneric);
+            p.bump();
+            let list = p.mark(LuaSyntaxKind::DocTypeList);
+            if p.current_token() != LuaTokenKind::TkGt {
+                parse_type(p)?;
+                while p.current_token() == LuaTokenKind::TkComma {
+                    p.bump();
+                    parse_type(p)?;
+                }
+            }
+            list.complete(p);
+            expect_token(p, LuaTokenKind::TkGt)?;
+            cm = m.complete(p);
+        }
+        LuaTokenKind::TkDots => {
+            let m = cm.precede(p, LuaSyntaxKind::TypeVariadic);
+            p.bump();
+            cm = m.complete(p);
+        }
+        _ => {}
+    }
+
+    Ok(cm)
+}
+
+// { <type> }
+// { <name>: <type>, [<type>]: <type> }
+fn parse_table_type(p: &mut LuaParser) -> ParseResult {
+    let mut m = p.mark(LuaSyntaxKind::TypeObject);
+    p.bump();
+
+    let is_object = match p.current_token() {
+        LuaTokenKind::TkRightBrace | LuaTokenKind::TkLeftBracket => true,
+        LuaTokenKind::TkName => p.peek_next_token() == LuaTokenKind::TkColon,
+        _ => false,
+    };
+
+    if !is_object {
+        m.set_kind(p, LuaSyntaxKind::TypeArray);
+        parse_type(p)?;
+        expect_token(p, LuaTokenKind::TkRightBrace)?;
+        return Ok(m.complete(p));
+    }
+
+    while p.current_token() != LuaTokenKind::TkRightBrace {
+        parse_typed_field(p)?;
+        if !matches!(
+            p.current_token(),
+            LuaTokenKind::TkComma | LuaTokenKind::TkSemicolon
+        ) {
+            break;
+        }
+        p.bump();
+    }
+
+    expect_token(p, LuaTokenKind::TkRightBrace)?;
+    Ok(m.complete(p))
+}
+
+// <name>: <type>
+// [<string>]: <type>
+// [<type>]: <type>
+fn parse_typed_field(p: &mut LuaParser) -> ParseResult {
+    let m = p.mark(LuaSyntaxKind::DocObjectField);
+    match p.current_token() {
+        LuaTokenKind::TkName => {
+            p.bump();
+        }
+        LuaTokenKind::TkLeftBracket => {
+            p.bump();
+            if matches!(
+                p.current_token(),
+                LuaTokenKind::TkString | LuaTokenKind::TkInt
+            ) && p.peek_next_token() == LuaTokenKind::TkRightBracket
+            {
+                p.bump();
+            } else {
+                parse_type(p)?;
+            }
+            expect_token(p, LuaTokenKind::TkRightBracket)?;
+        }
+        _ => {
+            return Err(LuaParseError::syntax_error_from(
+                &t!("expect name or [<type>]"),
+                p.current_token_range(),
+            ));
+        }
+    }
+
+    expect_token(p, LuaTokenKind::TkColon)?;
+    parse_type(p)?;
+    Ok(m.complete(p))
+}
+
+// ( <type> )
+// ( <params> ) -> <return types>
+fn parse_paren_or_fun_type(p: &mut LuaParser) -> ParseResult {
+    if p.peek_token_after_paren() == LuaTokenKind::TkArrow {
+        return parse_fun_type(p);
+    }
+
+    p.bump();
+    let cm = parse_type(p)?;
+    expect_token(p, LuaTokenKind::TkRightParen)?;
+    Ok(cm)
+}
+
+// <generic list>? ( <name>: <type>, <type>, ...<type> ) -> <return types>
+fn parse_fun_type(p: &mut LuaParser) -> ParseResult {
+    let m = p.mark(LuaSyntaxKind::TypeFun);
+    if p.current_token() == LuaTokenKind::TkLt {
+        parse_generic_decl_list(p)?;
+    }
+
+    expect_token(p, LuaTokenKind::TkLeftParen)?;
+    if p.current_token() != LuaTokenKind::TkRightParen {
+        parse_typed_param(p)?;
+        while p.current_token() == LuaTokenKind::TkComma {
+            p.bump();
+            parse_typed_param(p)?;
+        }
+    }
+    expect_token(p, LuaTokenKind::TkRightParen)?;
+    expect_token(p, LuaTokenKind::TkArrow)?;
+    parse_return_type_list(p)?;
+    Ok(m.complete(p))
+}
+
+// <name>: <type>
+// <type>
+// ...<type>
+fn parse_typed_param(p: &mut LuaParser) -> ParseResult {
+    let m = p.mark(LuaSyntaxKind::DocTypedParameter);
+    match p.current_token() {
+        LuaTokenKind::TkName if p.peek_next_token() == LuaTokenKind::TkColon => {
+            p.bump();
+            p.bump();
+            parse_type(p)?;
+        }
+        LuaTokenKind::TkDots => {
+            p.bump();
+            if !matches!(
+                p.current_token(),
+                LuaTokenKind::TkComma | LuaTokenKind::TkRightParen
+            ) {
+                parse_type(p)?;
+            }
+        }
+        _ => {
+            parse_type(p)?;
+        }
+    }
+
+    Ok(m.complete(p))
+}
+
+// <type>
+// ( <type>, <type> )
+pub fn parse_return_type_list(p: &mut LuaParser) -> ParseResult {
+    let m = p.mark(LuaSyntaxKind::DocTypeList);
+    if p.current_token() == LuaTokenKind::TkLeftParen
+        && p.peek_token_after_paren() != LuaTokenKind::TkArrow
+    {
+        p.bump();
+        if p.current_token() != LuaTokenKind::TkRightParen {
+            parse_return_type(p)?;
+            while p.current_token() == LuaTokenKind::TkComma {
+                p.bump();
+                parse_return_type(p)?;
+            }
+        }
+        expect_token(p, LuaTokenKind::TkRightParen)?;
+    } else {
+        parse_return_type(p)?;
+    }
+
+    Ok(m.complete(p))
+}
+
+fn parse_return_type(p: &mut LuaParser) -> ParseResult {
+    let m = p.mark(LuaSyntaxKind::DocNamedReturnType);
+    parse_type(p)?;
+    Ok(m.complete(p))
+}
+
+// < T, U = <type>, V... >
+pub fn parse_generic_decl_list(p: &mut LuaParser) -> ParseResult {
+    let m = p.mark(LuaSyntaxKind::DocGenericDeclareList);
+    expect_token(p, LuaTokenKind::TkLt)?;
+    parse_generic_param(p)?;
+    while p.current_token() == LuaTokenKind::TkComma {
+        p.bump();
+        parse_generic_param(p)?;
+    }
+    expect_token(p, LuaTokenKind::TkGt)?;
+    Ok(m.complete(p))
+}
+
+fn parse_generic_param(p: &mut LuaParser) -> ParseResult {
+    let m = p.mark(LuaSyntaxKind::DocGenericParameter);
+    expect_token(p, LuaTokenKind::TkName)?;
+    if_token_bump(p, LuaTokenKind::TkDots);
+    if p.current_token() == LuaTokenKind::TkAssign {
+        p.bump();
+        parse_type(p)?;
+    }
+    Ok(m.complete(p))
+}
+
+/// `: <type>` after a local name or a parameter
+pub fn parse_type_annotation(p: &mut LuaParser) -> Result<Option<CompleteMarker>, LuaParseError> {
+    if !p.parse_config.support_type_annotation() || p.current_token() != LuaTokenKind::TkColon {
+        return Ok(None);
+    }
+
+    p.bump();
+    parse_type(p).map(Some)
+}
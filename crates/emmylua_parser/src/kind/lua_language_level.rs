@@ -1,5 +1,7 @@
 use core::fmt;
 
+/// The versions are ordered, `Luau` is a dialect and comes last, so compare
+/// versions with `is_version_at_least` instead of `>=`
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum LuaLanguageLevel {
     Lua51,
@@ -8,6 +10,18 @@ pub enum LuaLanguageLevel {
     Lua53,
     Lua54,
     Lua55,
+    Luau,
+}
+
+impl LuaLanguageLevel {
+    /// Whether this is `level` or a newer version, `Luau` never is
+    pub fn is_version_at_least(&self, level: LuaLanguageLevel) -> bool {
+        *self != LuaLanguageLevel::Luau && *self >= level
+    }
+
+    pub fn is_luau(&self) -> bool {
+        *self == LuaLanguageLevel::Luau
+    }
 }
 
 impl fmt::Display for LuaLanguageLevel {
@@ -19,6 +33,7 @@ impl fmt::Display for LuaLanguageLevel {
             LuaLanguageLevel::Lua54 => write!(f, "Lua 5.4"),
             LuaLanguageLevel::LuaJIT => write!(f, "LuaJIT"),
             LuaLanguageLevel::Lua55 => write!(f, "Lua 5.5"),
+            LuaLanguageLevel::Luau => write!(f, "Luau"),
         }
     }
 }
@@ -9,7 +9,7 @@ pub enum UnaryOperator {
     OpNop,  // (empty)
 }
 
-#[derive(Debug, PartialEq, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
 pub enum BinaryOperator {
     OpAdd,    // +
     OpSub,    // -
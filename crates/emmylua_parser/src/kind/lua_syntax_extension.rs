@@ -0,0 +1,46 @@
+/// Syntax accepted by some embedded runtimes (Pluto, GLua, Ravi, ...) on top of
+/// the language level they are based on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LuaSyntaxExtension {
+    /// `a += 1`, `a -= 1`, `a ..= "x"` ...
+    CompoundAssign,
+    /// `continue` inside loops
+    Continue,
+    /// `a != b` as an alias of `a ~= b`
+    NotEqual,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct LuaSyntaxExtensions(u8);
+
+impl LuaSyntaxExtensions {
+    pub fn empty() -> Self {
+        Self(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn contains(&self, extension: LuaSyntaxExtension) -> bool {
+        self.0 & Self::bit(extension) != 0
+    }
+
+    pub fn insert(&mut self, extension: LuaSyntaxExtension) {
+        self.0 |= Self::bit(extension);
+    }
+
+    fn bit(extension: LuaSyntaxExtension) -> u8 {
+        1 << extension as u8
+    }
+}
+
+impl FromIterator<LuaSyntaxExtension> for LuaSyntaxExtensions {
+    fn from_iter<T: IntoIterator<Item = LuaSyntaxExtension>>(iter: T) -> Self {
+        let mut extensions = Self::empty();
+        for extension in iter {
+            extensions.insert(extension);
+        }
+        extensions
+    }
+}
@@ -27,7 +27,10 @@ pub enum LuaSyntaxKind {
     GotoStat,
     CallExprStat,
     AssignStat,
+    CompoundAssignStat, // a += 1, luau or the `compoundAssign` syntax extension
     GlobalStat,
+    TypeAliasStat, // type A = B, luau only
+    ContinueStat,  // continue, luau only
     UnknownStat,
 
     // expressions
@@ -47,9 +50,13 @@ pub enum LuaSyntaxKind {
     SetmetatableCallExpr, // setmetatable(a, b)
     IndexExpr,
     NameExpr,
+    CastExpr,         // a :: T, luau only
+    IfExpr,           // if a then b else c, luau only
+    InterpStringExpr, // `a{b}c`, luau only
 
     // other
     LocalName,
+    GlobalName,
     ParamName,
     ParamList,
     CallArgList,
@@ -90,6 +97,7 @@ pub enum LuaSyntaxKind {
     DocTagSource,
     DocTagReadonly,
     DocTagReturnCast,
+    DocTagLanguage,
 
     // doc Type
     TypeArray,          // baseType []
@@ -111,6 +119,7 @@ pub enum LuaSyntaxKind {
     TypeMatch,
     TypeIndexAccess, // type[keyType]
     TypeMapped,      // { [p in KeyType]+? : ValueType }
+    TypeOf,          // typeof(expr), luau only
 
     // doc other
     DocObjectField,
@@ -45,6 +45,7 @@ pub enum LuaTokenKind {
     TkGe,           // >=
     TkLe,           // <=
     TkNe,           // ~=
+    TkBangEq,       // !=, the `notEqual` syntax extension
     TkShl,          // <<
     TkShr,          // >>
     TkLt,           // <
@@ -68,6 +69,21 @@ pub enum LuaTokenKind {
     TkInt,          // int
     TkFloat,        // float
 
+    // luau
+    TkPlusAssign,        // +=
+    TkMinusAssign,       // -=
+    TkMulAssign,         // *=
+    TkDivAssign,         // /=
+    TkIDivAssign,        // //=
+    TkModAssign,         // %=
+    TkPowAssign,         // ^=
+    TkConcatAssign,      // ..=
+    TkArrow,             // ->
+    TkInterpString,      // `string`
+    TkInterpStringBegin, // `string{
+    TkInterpStringMid,   // }string{
+    TkInterpStringEnd,   // }string`
+
     TkName,         // name
     TkString,       // string
     TkLongString,   // long string
@@ -118,6 +134,7 @@ pub enum LuaTokenKind {
     TkTagUsing,      // using
     TkTagSource,     // source
     TkTagReturnCast, // return cast
+    TkTagLanguage,   // language
 
     TkDocOr,              // |
     TkDocAnd,             // &
@@ -125,6 +142,7 @@ pub enum LuaTokenKind {
     TkDocExtends,         // extends
     TkDocAs,              // as
     TkDocIn,              // in
+    TkDocOut,             // out
     TkDocInfer,           // infer
     TkDocContinue,        // ---
     TkDocContinueOr,      // ---| or ---|+  or ---|>
@@ -0,0 +1,7 @@
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum VarianceKind {
+    None,
+    In,
+    Out,
+    InOut,
+}
@@ -1,18 +1,22 @@
 mod lua_language_level;
 mod lua_operator_kind;
+mod lua_syntax_extension;
 mod lua_syntax_kind;
 mod lua_token_kind;
 mod lua_type_operator_kind;
+mod lua_variance_kind;
 mod lua_version;
 mod lua_visibility_kind;
 
 pub use lua_language_level::LuaLanguageLevel;
 pub use lua_operator_kind::{BinaryOperator, UnaryOperator, UNARY_PRIORITY};
+pub use lua_syntax_extension::{LuaSyntaxExtension, LuaSyntaxExtensions};
 pub use lua_syntax_kind::LuaSyntaxKind;
 pub use lua_token_kind::LuaTokenKind;
 pub use lua_type_operator_kind::{
     LuaTypeBinaryOperator, LuaTypeTernaryOperator, LuaTypeUnaryOperator,
 };
+pub use lua_variance_kind::VarianceKind;
 pub use lua_version::{LuaVersionCondition, LuaVersionNumber};
 pub use lua_visibility_kind::VisibilityKind;
 
@@ -155,13 +159,27 @@ impl LuaOpKind {
             LuaTokenKind::TkGt => BinaryOperator::OpGt,
             LuaTokenKind::TkGe => BinaryOperator::OpGe,
             LuaTokenKind::TkEq => BinaryOperator::OpEq,
-            LuaTokenKind::TkNe => BinaryOperator::OpNe,
+            LuaTokenKind::TkNe | LuaTokenKind::TkBangEq => BinaryOperator::OpNe,
             LuaTokenKind::TkAnd => BinaryOperator::OpAnd,
             LuaTokenKind::TkOr => BinaryOperator::OpOr,
             _ => BinaryOperator::OpNop,
         }
     }
 
+    pub fn to_compound_assign_operator(kind: LuaTokenKind) -> BinaryOperator {
+        match kind {
+            LuaTokenKind::TkPlusAssign => BinaryOperator::OpAdd,
+            LuaTokenKind::TkMinusAssign => BinaryOperator::OpSub,
+            LuaTokenKind::TkMulAssign => BinaryOperator::OpMul,
+            LuaTokenKind::TkDivAssign => BinaryOperator::OpDiv,
+            LuaTokenKind::TkIDivAssign => BinaryOperator::OpIDiv,
+            LuaTokenKind::TkModAssign => BinaryOperator::OpMod,
+            LuaTokenKind::TkPowAssign => BinaryOperator::OpPow,
+            LuaTokenKind::TkConcatAssign => BinaryOperator::OpConcat,
+            _ => BinaryOperator::OpNop,
+        }
+    }
+
     pub fn to_type_unary_operator(kind: LuaTokenKind) -> LuaTypeUnaryOperator {
         match kind {
             LuaTokenKind::TkDocKeyOf => LuaTypeUnaryOperator::Keyof,
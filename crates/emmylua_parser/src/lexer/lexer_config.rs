@@ -1,13 +1,15 @@
-use crate::kind::LuaLanguageLevel;
+use crate::kind::{LuaLanguageLevel, LuaSyntaxExtension, LuaSyntaxExtensions};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct LexerConfig {
     pub language_level: LuaLanguageLevel,
+    pub extensions: LuaSyntaxExtensions,
 }
 
 impl LexerConfig {
     pub fn support_goto(&self) -> bool {
-        self.language_level >= LuaLanguageLevel::Lua52
+        self.language_level
+            .is_version_at_least(LuaLanguageLevel::Lua52)
             || self.language_level == LuaLanguageLevel::LuaJIT
     }
 
@@ -24,16 +26,46 @@ impl LexerConfig {
     }
 
     pub fn support_integer_operation(&self) -> bool {
-        self.language_level >= LuaLanguageLevel::Lua53
+        self.language_level
+            .is_version_at_least(LuaLanguageLevel::Lua53)
+    }
+
+    pub fn support_integer_division(&self) -> bool {
+        self.support_integer_operation() || self.language_level.is_luau()
     }
 
     pub fn support_pow_operator(&self) -> bool {
-        self.language_level >= LuaLanguageLevel::Lua52
+        self.language_level
+            .is_version_at_least(LuaLanguageLevel::Lua52)
             || self.language_level == LuaLanguageLevel::LuaJIT
+            || self.language_level.is_luau()
     }
 
     pub fn support_global_decl(&self) -> bool {
-        self.language_level >= LuaLanguageLevel::Lua55
+        self.language_level
+            .is_version_at_least(LuaLanguageLevel::Lua55)
+    }
+
+    pub fn support_compound_assign(&self) -> bool {
+        self.language_level.is_luau()
+            || self.extensions.contains(LuaSyntaxExtension::CompoundAssign)
+    }
+
+    pub fn support_not_equal(&self) -> bool {
+        self.extensions.contains(LuaSyntaxExtension::NotEqual)
+    }
+
+    pub fn support_type_annotation(&self) -> bool {
+        self.language_level.is_luau()
+    }
+
+    pub fn support_interpolated_string(&self) -> bool {
+        self.language_level.is_luau()
+    }
+
+    /// `?` only marks an optional type in Luau type annotations
+    pub fn support_optional_type(&self) -> bool {
+        self.language_level.is_luau()
     }
 }
 
@@ -41,6 +73,7 @@ impl Default for LexerConfig {
     fn default() -> Self {
         LexerConfig {
             language_level: LuaLanguageLevel::Lua54,
+            extensions: LuaSyntaxExtensions::empty(),
         }
     }
 }
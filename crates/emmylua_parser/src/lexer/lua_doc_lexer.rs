@@ -18,6 +18,8 @@ pub enum LuaDocLexerState {
     Init,
     Tag,
     Normal,
+    // `Normal` inside a generic declaration list, where `=` starts a default
+    GenericParams,
     FieldStart,
     Description,
     LongDescription,
@@ -61,7 +63,7 @@ impl LuaDocLexer<'_> {
         match self.state {
             LuaDocLexerState::Init => self.lex_init(),
             LuaDocLexerState::Tag => self.lex_tag(),
-            LuaDocLexerState::Normal => self.lex_normal(),
+            LuaDocLexerState::Normal | LuaDocLexerState::GenericParams => self.lex_normal(),
             LuaDocLexerState::FieldStart => self.lex_field_start(),
             LuaDocLexerState::Description => self.lex_description(),
             LuaDocLexerState::LongDescription => self.lex_long_description(),
@@ -235,6 +237,10 @@ impl LuaDocLexer<'_> {
                 reader.bump();
                 LuaTokenKind::TkPlus
             }
+            '=' if self.state == LuaDocLexerState::GenericParams => {
+                reader.bump();
+                LuaTokenKind::TkAssign
+            }
             '-' => {
                 let count = reader.eat_when('-');
                 match count {
@@ -570,6 +576,7 @@ fn to_tag(text: &str) -> LuaTokenKind {
         "namespace" => LuaTokenKind::TkTagNamespace,
         "using" => LuaTokenKind::TkTagUsing,
         "source" => LuaTokenKind::TkTagSource,
+        "language" => LuaTokenKind::TkTagLanguage,
         _ => LuaTokenKind::TkTagOther,
     }
 }
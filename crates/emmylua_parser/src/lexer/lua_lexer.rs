@@ -1,4 +1,8 @@
-use crate::{kind::LuaTokenKind, parser_error::LuaParseError, text::Reader};
+use crate::{
+    kind::LuaTokenKind,
+    parser_error::{LuaParseError, LuaParseErrorCode},
+    text::Reader,
+};
 
 use super::{is_name_continue, is_name_start, lexer_config::LexerConfig, token_data::LuaTokenData};
 
@@ -6,6 +10,8 @@ pub struct LuaLexer<'a> {
     reader: Reader<'a>,
     lexer_config: LexerConfig,
     errors: &'a mut Vec<LuaParseError>,
+    // brace depth of each open interpolated string `...{
+    interp_brace_stack: Vec<usize>,
 }
 
 impl LuaLexer<'_> {
@@ -18,6 +24,7 @@ impl LuaLexer<'_> {
             reader: Reader::new(text),
             lexer_config,
             errors,
+            interp_brace_stack: Vec::new(),
         }
     }
 
@@ -85,8 +92,17 @@ impl LuaLexer<'_> {
             ' ' | '\t' => self.lex_white_space(),
             '-' => {
                 self.reader.bump();
-                if self.reader.current_char() != '-' {
-                    return LuaTokenKind::TkMinus;
+                match self.reader.current_char() {
+                    '-' => {}
+                    '=' if self.lexer_config.support_compound_assign() => {
+                        self.reader.bump();
+                        return LuaTokenKind::TkMinusAssign;
+                    }
+                    '>' if self.lexer_config.support_type_annotation() => {
+                        self.reader.bump();
+                        return LuaTokenKind::TkArrow;
+                    }
+                    _ => return LuaTokenKind::TkMinus,
                 }
 
                 self.reader.bump();
@@ -110,10 +126,13 @@ impl LuaLexer<'_> {
                     return LuaTokenKind::TkLeftBracket;
                 }
                 if self.reader.current_char() != '[' {
-                    self.errors.push(LuaParseError::syntax_error_from(
-                        &t!("invalid long string delimiter"),
-                        self.reader.saved_range(),
-                    ));
+                    self.errors.push(
+                        LuaParseError::syntax_error_from(
+                            &t!("invalid long string delimiter"),
+                            self.reader.saved_range(),
+                        )
+                        .with_code(LuaParseErrorCode::UnfinishedLongString),
+                    );
                     return LuaTokenKind::TkLongString;
                 }
 
@@ -137,10 +156,13 @@ impl LuaLexer<'_> {
                     }
                     '<' => {
                         if !self.lexer_config.support_integer_operation() {
-                            self.errors.push(LuaParseError::syntax_error_from(
-                                &t!("bitwise operation is not supported"),
-                                self.reader.saved_range(),
-                            ));
+                            self.errors.push(
+                                LuaParseError::syntax_error_from(
+                                    &t!("bitwise operation is not supported"),
+                                    self.reader.saved_range(),
+                                )
+                                .with_code(LuaParseErrorCode::UnsupportedSyntax),
+                            );
                         }
 
                         self.reader.bump();
@@ -156,12 +178,16 @@ impl LuaLexer<'_> {
                         self.reader.bump();
                         LuaTokenKind::TkGe
                     }
-                    '>' => {
+                    // `Array<Array<T>>` closes two generic lists in luau
+                    '>' if !self.lexer_config.support_type_annotation() => {
                         if !self.lexer_config.support_integer_operation() {
-                            self.errors.push(LuaParseError::syntax_error_from(
-                                &t!("bitwise operation is not supported"),
-                                self.reader.saved_range(),
-                            ));
+                            self.errors.push(
+                                LuaParseError::syntax_error_from(
+                                    &t!("bitwise operation is not supported"),
+                                    self.reader.saved_range(),
+                                )
+                                .with_code(LuaParseErrorCode::UnsupportedSyntax),
+                            );
                         }
 
                         self.reader.bump();
@@ -174,10 +200,13 @@ impl LuaLexer<'_> {
                 self.reader.bump();
                 if self.reader.current_char() != '=' {
                     if !self.lexer_config.support_integer_operation() {
-                        self.errors.push(LuaParseError::syntax_error_from(
-                            &t!("bitwise operation is not supported"),
-                            self.reader.saved_range(),
-                        ));
+                        self.errors.push(
+                            LuaParseError::syntax_error_from(
+                                &t!("bitwise operation is not supported"),
+                                self.reader.saved_range(),
+                            )
+                            .with_code(LuaParseErrorCode::UnsupportedSyntax),
+                        );
                     }
                     return LuaTokenKind::TkBitXor;
                 }
@@ -223,10 +252,13 @@ impl LuaLexer<'_> {
                 }
 
                 if self.reader.current_char() != quote {
-                    self.errors.push(LuaParseError::syntax_error_from(
-                        &t!("unfinished string"),
-                        self.reader.saved_range(),
-                    ));
+                    self.errors.push(
+                        LuaParseError::syntax_error_from(
+                            &t!("unfinished string"),
+                            self.reader.saved_range(),
+                        )
+                        .with_code(LuaParseErrorCode::UnfinishedString),
+                    );
                     return LuaTokenKind::TkString;
                 }
 
@@ -243,6 +275,11 @@ impl LuaLexer<'_> {
                     return LuaTokenKind::TkDot;
                 }
                 self.reader.bump();
+                if self.reader.current_char() == '=' && self.lexer_config.support_compound_assign()
+                {
+                    self.reader.bump();
+                    return LuaTokenKind::TkConcatAssign;
+                }
                 if self.reader.current_char() != '.' {
                     return LuaTokenKind::TkConcat;
                 }
@@ -253,40 +290,47 @@ impl LuaLexer<'_> {
             '/' => {
                 self.reader.bump();
                 if self.reader.current_char() != '/' {
-                    return LuaTokenKind::TkDiv;
+                    return self
+                        .lex_compound_assign(LuaTokenKind::TkDiv, LuaTokenKind::TkDivAssign);
                 }
-                if !self.lexer_config.support_integer_operation() {
-                    self.errors.push(LuaParseError::syntax_error_from(
-                        &t!("integer division is not supported"),
-                        self.reader.saved_range(),
-                    ));
+                if !self.lexer_config.support_integer_division() {
+                    self.errors.push(
+                        LuaParseError::syntax_error_from(
+                            &t!("integer division is not supported"),
+                            self.reader.saved_range(),
+                        )
+                        .with_code(LuaParseErrorCode::UnsupportedSyntax),
+                    );
                 }
 
                 self.reader.bump();
-                LuaTokenKind::TkIDiv
+                self.lex_compound_assign(LuaTokenKind::TkIDiv, LuaTokenKind::TkIDivAssign)
             }
             '*' => {
                 self.reader.bump();
-                LuaTokenKind::TkMul
+                self.lex_compound_assign(LuaTokenKind::TkMul, LuaTokenKind::TkMulAssign)
             }
             '+' => {
                 self.reader.bump();
-                LuaTokenKind::TkPlus
+                self.lex_compound_assign(LuaTokenKind::TkPlus, LuaTokenKind::TkPlusAssign)
             }
             '%' => {
                 self.reader.bump();
-                LuaTokenKind::TkMod
+                self.lex_compound_assign(LuaTokenKind::TkMod, LuaTokenKind::TkModAssign)
             }
             '^' => {
                 if !self.lexer_config.support_pow_operator() {
-                    self.errors.push(LuaParseError::syntax_error_from(
-                        &t!("power operator is not supported, Please use `math.pow` instead"),
-                        self.reader.saved_range(),
-                    ));
+                    self.errors.push(
+                        LuaParseError::syntax_error_from(
+                            &t!("power operator is not supported, Please use `math.pow` instead"),
+                            self.reader.saved_range(),
+                        )
+                        .with_code(LuaParseErrorCode::UnsupportedSyntax),
+                    );
                 }
 
                 self.reader.bump();
-                LuaTokenKind::TkPow
+                self.lex_compound_assign(LuaTokenKind::TkPow, LuaTokenKind::TkPowAssign)
             }
             '#' => {
                 self.reader.bump();
@@ -297,22 +341,32 @@ impl LuaLexer<'_> {
                 LuaTokenKind::TkShebang
             }
             '&' => {
-                if !self.lexer_config.support_integer_operation() {
-                    self.errors.push(LuaParseError::syntax_error_from(
-                        &t!("bitwise operation is not supported"),
-                        self.reader.saved_range(),
-                    ));
+                if !self.lexer_config.support_integer_operation()
+                    && !self.lexer_config.support_type_annotation()
+                {
+                    self.errors.push(
+                        LuaParseError::syntax_error_from(
+                            &t!("bitwise operation is not supported"),
+                            self.reader.saved_range(),
+                        )
+                        .with_code(LuaParseErrorCode::UnsupportedSyntax),
+                    );
                 }
 
                 self.reader.bump();
                 LuaTokenKind::TkBitAnd
             }
             '|' => {
-                if !self.lexer_config.support_integer_operation() {
-                    self.errors.push(LuaParseError::syntax_error_from(
-                        &t!("bitwise operation is not supported"),
-                        self.reader.saved_range(),
-                    ));
+                if !self.lexer_config.support_integer_operation()
+                    && !self.lexer_config.support_type_annotation()
+                {
+                    self.errors.push(
+                        LuaParseError::syntax_error_from(
+                            &t!("bitwise operation is not supported"),
+                            self.reader.saved_range(),
+                        )
+                        .with_code(LuaParseErrorCode::UnsupportedSyntax),
+                    );
                 }
 
                 self.reader.bump();
@@ -327,13 +381,29 @@ impl LuaLexer<'_> {
                 LuaTokenKind::TkRightParen
             }
             '{' => {
+                if let Some(depth) = self.interp_brace_stack.last_mut() {
+                    *depth += 1;
+                }
                 self.reader.bump();
                 LuaTokenKind::TkLeftBrace
             }
             '}' => {
+                match self.interp_brace_stack.last_mut() {
+                    Some(0) => {
+                        self.interp_brace_stack.pop();
+                        return self.lex_interp_string(false);
+                    }
+                    Some(depth) => *depth -= 1,
+                    None => {}
+                }
                 self.reader.bump();
                 LuaTokenKind::TkRightBrace
             }
+            '`' if self.lexer_config.support_interpolated_string() => self.lex_interp_string(true),
+            '?' if self.lexer_config.support_optional_type() => {
+                self.reader.bump();
+                LuaTokenKind::TkDocQuestion
+            }
             ']' => {
                 self.reader.bump();
                 LuaTokenKind::TkRightBracket
@@ -357,6 +427,22 @@ impl LuaLexer<'_> {
                 let name = self.reader.current_saved_text();
                 self.name_to_kind(name)
             }
+            '!' if self.lexer_config.support_not_equal() => {
+                self.reader.bump();
+                if self.reader.current_char() != '=' {
+                    self.errors.push(
+                        LuaParseError::syntax_error_from(
+                            &t!("expected '!='"),
+                            self.reader.saved_range(),
+                        )
+                        .with_code(LuaParseErrorCode::UnexpectedToken)
+                        .with_expected(&[LuaTokenKind::TkBangEq]),
+                    );
+                    return LuaTokenKind::TkUnknown;
+                }
+                self.reader.bump();
+                LuaTokenKind::TkBangEq
+            }
             _ => {
                 self.reader.bump();
                 LuaTokenKind::TkUnknown
@@ -364,6 +450,66 @@ impl LuaLexer<'_> {
         }
     }
 
+    fn lex_compound_assign(&mut self, op: LuaTokenKind, assign_op: LuaTokenKind) -> LuaTokenKind {
+        if self.reader.current_char() == '=' && self.lexer_config.support_compound_assign() {
+            self.reader.bump();
+            return assign_op;
+        }
+
+        op
+    }
+
+    // `text`, `text{ or }text{ or }text`
+    fn lex_interp_string(&mut self, is_begin: bool) -> LuaTokenKind {
+        self.reader.bump();
+        while !self.reader.is_eof() {
+            match self.reader.current_char() {
+                '`' => {
+                    self.reader.bump();
+                    return if is_begin {
+                        LuaTokenKind::TkInterpString
+                    } else {
+                        LuaTokenKind::TkInterpStringEnd
+                    };
+                }
+                '{' => {
+                    self.reader.bump();
+                    self.interp_brace_stack.push(0);
+                    return if is_begin {
+                        LuaTokenKind::TkInterpStringBegin
+                    } else {
+                        LuaTokenKind::TkInterpStringMid
+                    };
+                }
+                '\\' => {
+                    self.reader.bump();
+                    match self.reader.current_char() {
+                        '\r' | '\n' => {
+                            self.lex_new_line();
+                        }
+                        _ => {
+                            self.reader.bump();
+                        }
+                    }
+                }
+                '\r' | '\n' => break,
+                _ => {
+                    self.reader.bump();
+                }
+            }
+        }
+
+        self.errors.push(
+            LuaParseError::syntax_error_from(&t!("unfinished string"), self.reader.saved_range())
+                .with_code(LuaParseErrorCode::UnfinishedString),
+        );
+        if is_begin {
+            LuaTokenKind::TkInterpString
+        } else {
+            LuaTokenKind::TkInterpStringEnd
+        }
+    }
+
     fn lex_new_line(&mut self) -> LuaTokenKind {
         match self.reader.current_char() {
             // support \n or \n\r
@@ -415,10 +561,13 @@ impl LuaLexer<'_> {
         }
 
         if !end {
-            self.errors.push(LuaParseError::syntax_error_from(
-                &t!("unfinished long string or comment"),
-                self.reader.saved_range(),
-            ));
+            self.errors.push(
+                LuaParseError::syntax_error_from(
+                    &t!("unfinished long string or comment"),
+                    self.reader.saved_range(),
+                )
+                .with_code(LuaParseErrorCode::UnfinishedLongString),
+            );
         }
 
         LuaTokenKind::TkLongString
@@ -537,13 +686,16 @@ impl LuaLexer<'_> {
         }
 
         if self.reader.current_char().is_alphabetic() {
-            self.errors.push(LuaParseError::syntax_error_from(
-                &format!(
-                    "unexpected character '{}' after number literal",
-                    self.reader.current_char()
-                ),
-                self.reader.saved_range(),
-            ));
+            self.errors.push(
+                LuaParseError::syntax_error_from(
+                    &format!(
+                        "unexpected character '{}' after number literal",
+                        self.reader.current_char()
+                    ),
+                    self.reader.saved_range(),
+                )
+                .with_code(LuaParseErrorCode::MalformedNumber),
+            );
         }
 
         match state {
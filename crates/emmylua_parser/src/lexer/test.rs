@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests {
     use crate::{
+        kind::{LuaLanguageLevel, LuaSyntaxExtensions, LuaTokenKind},
         lexer::{LexerConfig, LuaLexer},
         parser_error::LuaParseError,
     };
@@ -1089,4 +1090,40 @@ LuaTokenData { kind: TkWhitespace, range: SourceRange { start_offset: 2036, leng
 
         assert_eq!(expected.trim(), test_str);
     }
+
+    fn lex_kinds(text: &str, language_level: LuaLanguageLevel) -> Vec<LuaTokenKind> {
+        let config = LexerConfig {
+            language_level,
+            extensions: LuaSyntaxExtensions::empty(),
+        };
+        let mut errors: Vec<LuaParseError> = Vec::new();
+        let mut lexer = LuaLexer::new(text, config, &mut errors);
+        lexer
+            .tokenize()
+            .iter()
+            .map(|token| token.kind)
+            .filter(|kind| *kind != LuaTokenKind::TkWhitespace)
+            .collect()
+    }
+
+    #[test]
+    fn test_luau_level_gates() {
+        // `?` is only an optional type marker in Luau
+        assert!(lex_kinds("a?", LuaLanguageLevel::Luau).contains(&LuaTokenKind::TkDocQuestion));
+        for level in [
+            LuaLanguageLevel::Lua51,
+            LuaLanguageLevel::LuaJIT,
+            LuaLanguageLevel::Lua54,
+            LuaLanguageLevel::Lua55,
+        ] {
+            assert!(!lex_kinds("a?", level).contains(&LuaTokenKind::TkDocQuestion));
+        }
+
+        // Luau is not a newer version than Lua 5.2
+        assert!(!lex_kinds("goto a", LuaLanguageLevel::Luau).contains(&LuaTokenKind::TkGoto));
+        assert!(lex_kinds("goto a", LuaLanguageLevel::Lua52).contains(&LuaTokenKind::TkGoto));
+        assert!(lex_kinds("a // b", LuaLanguageLevel::Luau).contains(&LuaTokenKind::TkIDiv));
+        assert!(!LuaLanguageLevel::Luau.is_version_at_least(LuaLanguageLevel::Lua52));
+        assert!(LuaLanguageLevel::Lua55.is_version_at_least(LuaLanguageLevel::Lua54));
+    }
 }
@@ -8,7 +8,7 @@ mod text;
 
 pub use kind::*;
 pub use parser::{LuaParser, ParserConfig, SpecialFunction};
-pub use parser_error::{LuaParseError, LuaParseErrorKind};
+pub use parser_error::{LuaParseError, LuaParseErrorCode, LuaParseErrorKind, LuaParseErrorLabel};
 pub use syntax::*;
 pub use text::LineIndex;
 
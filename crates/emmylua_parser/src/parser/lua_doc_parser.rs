@@ -83,7 +83,9 @@ impl LuaDocParser<'_, '_> {
         }
 
         match self.lexer.state {
-            LuaDocLexerState::Normal | LuaDocLexerState::Version => {
+            LuaDocLexerState::Normal
+            | LuaDocLexerState::GenericParams
+            | LuaDocLexerState::Version => {
                 while matches!(
                     self.current_token,
                     LuaTokenKind::TkDocContinue
@@ -165,6 +167,10 @@ impl LuaDocParser<'_, '_> {
         self.current_token
     }
 
+    pub fn set_current_token_kind(&mut self, kind: LuaTokenKind) {
+        self.current_token = kind;
+    }
+
     pub fn current_token_range(&self) -> SourceRange {
         self.current_token_range
     }
@@ -175,6 +181,13 @@ impl LuaDocParser<'_, '_> {
         &source_text[range.start_offset..range.end_offset()]
     }
 
+    /// The source text after the current token to the end of its line, to look ahead
+    pub fn rest_of_line(&self) -> &str {
+        let source_text = self.lua_parser.origin_text();
+        let rest = &source_text[self.current_token_range.end_offset()..];
+        rest.split(['\r', '\n']).next().unwrap_or_default()
+    }
+
     pub fn set_state(&mut self, state: LuaDocLexerState) {
         match state {
             LuaDocLexerState::Description => {
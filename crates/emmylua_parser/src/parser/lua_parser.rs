@@ -119,6 +119,14 @@ impl<'a> LuaParser<'a> {
         &self.text[range.start_offset..range.end_offset()]
     }
 
+    // used for contextual keywords and luau type operators
+    pub fn set_current_token_kind(&mut self, kind: LuaTokenKind) {
+        if self.token_index < self.tokens.len() {
+            self.tokens[self.token_index].kind = kind;
+        }
+        self.current_token = kind;
+    }
+
     pub fn bump(&mut self) {
         if !is_invalid_kind(self.current_token) && self.token_index < self.tokens.len() {
             let token = &self.tokens[self.token_index];
@@ -152,6 +160,33 @@ impl<'a> LuaParser<'a> {
         }
     }
 
+    /// the token after the parenthesized group starting at the current token
+    pub fn peek_token_after_paren(&self) -> LuaTokenKind {
+        let mut depth = 0;
+        let mut index = self.token_index;
+        while index < self.tokens.len() {
+            match self.tokens[index].kind {
+                LuaTokenKind::TkLeftParen => depth += 1,
+                LuaTokenKind::TkRightParen => {
+                    depth -= 1;
+                    if depth == 0 {
+                        index += 1;
+                        self.skip_trivia(&mut index);
+                        return match self.tokens.get(index) {
+                            Some(token) => token.kind,
+                            None => LuaTokenKind::TkEof,
+                        };
+                    }
+                }
+                LuaTokenKind::TkEof => break,
+                _ => {}
+            }
+            index += 1;
+        }
+
+        LuaTokenKind::TkEof
+    }
+
     fn skip_trivia(&self, index: &mut usize) {
         if index >= &mut self.tokens.len() {
             return;
@@ -2,7 +2,10 @@ use std::collections::HashMap;
 
 use rowan::NodeCache;
 
-use crate::{kind::LuaLanguageLevel, lexer::LexerConfig};
+use crate::{
+    kind::{LuaLanguageLevel, LuaSyntaxExtension, LuaSyntaxExtensions},
+    lexer::LexerConfig,
+};
 
 pub struct ParserConfig<'cache> {
     pub level: LuaLanguageLevel,
@@ -21,6 +24,7 @@ impl<'cache> ParserConfig<'cache> {
             level,
             lexer_config: LexerConfig {
                 language_level: level,
+                extensions: LuaSyntaxExtensions::empty(),
             },
             node_cache,
             special_like,
@@ -32,7 +36,27 @@ impl<'cache> ParserConfig<'cache> {
     }
 
     pub fn support_local_attrib(&self) -> bool {
-        self.level >= LuaLanguageLevel::Lua54
+        self.level.is_version_at_least(LuaLanguageLevel::Lua54)
+    }
+
+    pub fn support_type_annotation(&self) -> bool {
+        self.lexer_config.support_type_annotation()
+    }
+
+    pub fn support_compound_assign(&self) -> bool {
+        self.lexer_config.support_compound_assign()
+    }
+
+    pub fn support_continue(&self) -> bool {
+        self.level.is_luau()
+            || self
+                .lexer_config
+                .extensions
+                .contains(LuaSyntaxExtension::Continue)
+    }
+
+    pub fn support_if_expr(&self) -> bool {
+        self.level.is_luau()
     }
 
     pub fn node_cache(&mut self) -> Option<&mut NodeCache> {
@@ -53,11 +77,17 @@ impl<'cache> ParserConfig<'cache> {
         }
     }
 
+    pub fn with_extensions(mut self, extensions: LuaSyntaxExtensions) -> Self {
+        self.lexer_config.extensions = extensions;
+        self
+    }
+
     pub fn with_level(level: LuaLanguageLevel) -> Self {
         Self {
             level,
             lexer_config: LexerConfig {
                 language_level: level,
+                extensions: LuaSyntaxExtensions::empty(),
             },
             node_cache: None,
             special_like: HashMap::new(),
@@ -71,6 +101,7 @@ impl Default for ParserConfig<'_> {
             level: LuaLanguageLevel::Lua54,
             lexer_config: LexerConfig {
                 language_level: LuaLanguageLevel::Lua54,
+                extensions: LuaSyntaxExtensions::empty(),
             },
             node_cache: None,
             special_like: HashMap::new(),
@@ -0,0 +1,367 @@
+#[cfg(test)]
+mod test {
+    //! Round-trip harness: whatever the input, `LuaParser::parse` must terminate without
+    //! panicking and the tree text must equal the source byte for byte.
+    //!
+    //! Generated input is deterministic, `EMMYLUA_FUZZ_SEED` and `EMMYLUA_FUZZ_ITERATIONS`
+    //! run other or longer sessions locally, e.g.
+    //! `EMMYLUA_FUZZ_ITERATIONS=100000 cargo test -p emmylua_parser round_trip`.
+    use std::{
+        sync::{mpsc, Arc},
+        thread,
+        time::Duration,
+    };
+
+    use crate::{LuaLanguageLevel, LuaParser, LuaSyntaxExtension, ParserConfig};
+
+    const PARSE_TIMEOUT: Duration = Duration::from_secs(10);
+
+    const LEVELS: [LuaLanguageLevel; 5] = [
+        LuaLanguageLevel::Lua51,
+        LuaLanguageLevel::LuaJIT,
+        LuaLanguageLevel::Lua54,
+        LuaLanguageLevel::Lua55,
+        LuaLanguageLevel::Luau,
+    ];
+
+    const FRAGMENTS: &[&str] = &[
+        // keywords and punctuation
+        "local",
+        "function",
+        "end",
+        "if",
+        "then",
+        "elseif",
+        "else",
+        "while",
+        "do",
+        "for",
+        "in",
+        "repeat",
+        "until",
+        "return",
+        "break",
+        "goto",
+        "continue",
+        "and",
+        "or",
+        "not",
+        "nil",
+        "true",
+        "false",
+        "global",
+        "=",
+        "==",
+        "~=",
+        "!=",
+        "<",
+        "<=",
+        ">",
+        ">=",
+        "+",
+        "-",
+        "*",
+        "/",
+        "//",
+        "%",
+        "^",
+        "#",
+        "&",
+        "|",
+        "~",
+        "<<",
+        ">>",
+        "..",
+        "...",
+        "+=",
+        "..=",
+        "(",
+        ")",
+        "{",
+        "}",
+        "[",
+        "]",
+        ";",
+        ":",
+        "::",
+        ",",
+        ".",
+        "!",
+        "?",
+        "@",
+        "$",
+        "`",
+        "\\",
+        // names, numbers and strings
+        "a",
+        "self",
+        "_ENV",
+        "中文",
+        "x1",
+        "0",
+        "1.5",
+        "0x1p4",
+        "0xG",
+        "1e",
+        "1..2",
+        "3LL",
+        "\"s\"",
+        "'s'",
+        "\"unfinished",
+        "'\\",
+        "\"\\z\n\"",
+        "\"\\u{7FFFFFFF}\"",
+        "[[long]]",
+        "[==[long]==]",
+        "[=[unfinished",
+        "[==",
+        "<const>",
+        "<close>",
+        "<T>",
+        // comments and doc tags
+        "--",
+        "-- c",
+        "--[[ c ]]",
+        "--[==[ c",
+        "---",
+        "---@",
+        "---@class A: B",
+        "---@class (partial) C<T>",
+        "---@field x integer",
+        "---@field [string] any",
+        "---@param a string|nil",
+        "---@param ... any",
+        "---@return fun(a: integer): string",
+        "---@type table<string, { x: number }>",
+        "---@alias N 1 | 2 | \"3\"",
+        "---@generic T",
+        "---@overload fun(a)",
+        "---@enum E",
+        "---@cast x -?",
+        "---@diagnostic disable: a",
+        "---@see",
+        "---@language lua",
+        "---@version >5.1",
+        "---| \"a\" # d",
+        "--- # doc",
+        "---@type [string, integer]",
+        "---@type `T`",
+        "---@param x T?",
+        "---@type (fun())[]",
+        // trivia
+        " ",
+        "  ",
+        "\t",
+        "\n",
+        "\r\n",
+        "\r",
+        "\n\n",
+        "\u{feff}",
+        "\u{a0}",
+    ];
+
+    // xorshift64*, enough for generating input and independent of external crates
+    struct Rng(u64);
+
+    impl Rng {
+        fn new(seed: u64) -> Self {
+            Rng(seed.max(1))
+        }
+
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 >> 12;
+            self.0 ^= self.0 << 25;
+            self.0 ^= self.0 >> 27;
+            self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+        }
+
+        fn below(&mut self, n: usize) -> usize {
+            (self.next() % n.max(1) as u64) as usize
+        }
+
+        fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+            items[self.below(items.len())]
+        }
+
+        // a char boundary of `text`
+        fn offset(&mut self, text: &str) -> usize {
+            let mut offset = self.below(text.len() + 1);
+            while !text.is_char_boundary(offset) {
+                offset -= 1;
+            }
+            offset
+        }
+    }
+
+    fn env_number(name: &str, default: u64) -> u64 {
+        std::env::var(name)
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(default)
+    }
+
+    fn token_soup(rng: &mut Rng) -> String {
+        let mut text = String::new();
+        for _ in 0..rng.below(64) {
+            text.push_str(rng.pick(FRAGMENTS));
+            if rng.below(3) == 0 {
+                text.push(' ');
+            }
+        }
+        text
+    }
+
+    fn mutate(rng: &mut Rng, source: &str) -> String {
+        let mut text = source.to_string();
+        for _ in 0..=rng.below(4) {
+            let start = rng.offset(&text);
+            let end = start + rng.offset(&text[start..]);
+            match rng.below(5) {
+                0 => text.replace_range(start..end, ""),
+                1 => text.insert_str(start, rng.pick(FRAGMENTS)),
+                2 => {
+                    let copy = text[start..end].to_string();
+                    text.insert_str(end, &copy);
+                }
+                3 => text.truncate(start),
+                _ => {
+                    let byte = rng.below(0x80) as u8 as char;
+                    text.insert(start, byte);
+                }
+            }
+        }
+        text
+    }
+
+    // the code snippets of the grammar tests, expected trees are left out
+    fn corpus() -> Vec<String> {
+        let sources = [
+            include_str!("../grammar/lua/test.rs"),
+            include_str!("../grammar/doc/test.rs"),
+        ];
+        let mut cases = Vec::new();
+        for source in sources {
+            let mut rest = source;
+            while let Some(start) = rest.find("r#\"") {
+                rest = &rest[start + 3..];
+                let Some(end) = rest.find("\"#") else {
+                    break;
+                };
+                let text = &rest[..end];
+                if !text.trim_start().starts_with("Syntax(") {
+                    cases.push(text.to_string());
+                }
+                rest = &rest[end + 2..];
+            }
+        }
+        cases
+    }
+
+    fn config(level: LuaLanguageLevel) -> ParserConfig<'static> {
+        ParserConfig::with_level(level).with_extensions(
+            [
+                LuaSyntaxExtension::CompoundAssign,
+                LuaSyntaxExtension::Continue,
+                LuaSyntaxExtension::NotEqual,
+            ]
+            .into_iter()
+            .collect(),
+        )
+    }
+
+    /// Parses every case on a worker thread so a hang or panic is reported with its input
+    fn assert_round_trip(cases: Vec<String>) {
+        let cases = Arc::new(cases);
+        for level in LEVELS {
+            let (sender, receiver) = mpsc::channel();
+            let worker_cases = cases.clone();
+            let worker = thread::spawn(move || {
+                for (idx, text) in worker_cases.iter().enumerate() {
+                    sender.send(idx).unwrap();
+                    let tree = LuaParser::parse(text, config(level));
+                    let tree_text = tree.get_red_root().text().to_string();
+                    assert!(
+                        tree_text == *text,
+                        "{:?} text is not preserved\nsource: {:?}\ntree:   {:?}",
+                        level,
+                        text,
+                        tree_text
+                    );
+                }
+            });
+
+            let mut current = None;
+            loop {
+                match receiver.recv_timeout(PARSE_TIMEOUT) {
+                    Ok(idx) => current = Some(idx),
+                    Err(mpsc::RecvTimeoutError::Disconnected) => break,
+                    Err(mpsc::RecvTimeoutError::Timeout) => panic!(
+                        "{:?} parse did not terminate: {:?}",
+                        level,
+                        current.map(|idx| &cases[idx])
+                    ),
+                }
+            }
+            if worker.join().is_err() {
+                panic!(
+                    "{:?} parse failed: {:?}",
+                    level,
+                    current.map(|idx| &cases[idx])
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_round_trip_corpus() {
+        let cases = corpus();
+        assert!(cases.len() > 50);
+
+        // every prefix of a case is what an editor sees while the case is typed
+        let mut prefixes = Vec::new();
+        for case in &cases {
+            for (offset, _) in case.char_indices().step_by(7) {
+                prefixes.push(case[..offset].to_string());
+            }
+        }
+
+        assert_round_trip(cases);
+        assert_round_trip(prefixes);
+    }
+
+    // minimized inputs that once lost text
+    #[test]
+    fn test_round_trip_regressions() {
+        let cases = [
+            // `\0` was taken for the end of the text
+            "\0",
+            "local a = 'x\0y'\n---@type string\0",
+            // a removed empty doc node left a mark level behind, so error recovery closed
+            // the chunk early
+            "-\n---@param...y---@a\n=",
+        ];
+        assert_round_trip(cases.iter().map(|case| case.to_string()).collect());
+    }
+
+    #[test]
+    fn test_round_trip_token_soup() {
+        let mut rng = Rng::new(env_number("EMMYLUA_FUZZ_SEED", 0x5EED));
+        let iterations = env_number("EMMYLUA_FUZZ_ITERATIONS", 2000);
+        let cases = (0..iterations).map(|_| token_soup(&mut rng)).collect();
+        assert_round_trip(cases);
+    }
+
+    #[test]
+    fn test_round_trip_mutated_corpus() {
+        let mut rng = Rng::new(env_number("EMMYLUA_FUZZ_SEED", 0x5EED));
+        let iterations = env_number("EMMYLUA_FUZZ_ITERATIONS", 2000);
+        let corpus = corpus();
+        let cases = (0..iterations)
+            .map(|_| {
+                let source = &corpus[rng.below(corpus.len())];
+                mutate(&mut rng, source)
+            })
+            .collect();
+        assert_round_trip(cases);
+    }
+}
@@ -1,6 +1,6 @@
 use rowan::TextRange;
 
-use crate::text::SourceRange;
+use crate::{kind::LuaTokenKind, text::SourceRange};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum LuaParseErrorKind {
@@ -8,35 +8,95 @@ pub enum LuaParseErrorKind {
     DocError,
 }
 
+/// Stable code of a parse error, the message is only meant for humans
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LuaParseErrorCode {
+    /// no more specific code applies
+    Unknown,
+    /// a token is not one of the tokens expected here
+    UnexpectedToken,
+    /// `end` or `until` is missing, the opener is the secondary range
+    UnclosedBlock,
+    /// `if <cond>` or `elseif <cond>` is not followed by `then`
+    MissingThen,
+    /// `while <cond>` or `for ...` is not followed by `do`
+    MissingDo,
+    MalformedNumber,
+    UnfinishedString,
+    UnfinishedLongString,
+    /// the syntax is not supported by the configured language level
+    UnsupportedSyntax,
+}
+
+impl LuaParseErrorCode {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            LuaParseErrorCode::Unknown => "unknown",
+            LuaParseErrorCode::UnexpectedToken => "unexpected-token",
+            LuaParseErrorCode::UnclosedBlock => "unclosed-block",
+            LuaParseErrorCode::MissingThen => "missing-then",
+            LuaParseErrorCode::MissingDo => "missing-do",
+            LuaParseErrorCode::MalformedNumber => "malformed-number",
+            LuaParseErrorCode::UnfinishedString => "unfinished-string",
+            LuaParseErrorCode::UnfinishedLongString => "unfinished-long-string",
+            LuaParseErrorCode::UnsupportedSyntax => "unsupported-syntax",
+        }
+    }
+}
+
+/// A range related to the error, such as the `function` an `end` is missing for
+#[derive(Debug, Clone, PartialEq)]
+pub struct LuaParseErrorLabel {
+    pub message: String,
+    pub range: TextRange,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct LuaParseError {
     pub kind: LuaParseErrorKind,
+    pub code: LuaParseErrorCode,
     pub message: String,
     pub range: TextRange,
+    pub secondary: Vec<LuaParseErrorLabel>,
+    /// the tokens that would have been accepted, for `UnexpectedToken`, `MissingThen` and so on
+    pub expected: Vec<LuaTokenKind>,
 }
 
 impl LuaParseError {
     pub fn new(kind: LuaParseErrorKind, message: &str, range: TextRange) -> Self {
         LuaParseError {
             kind,
+            code: LuaParseErrorCode::Unknown,
             message: message.to_string(),
             range,
+            secondary: Vec::new(),
+            expected: Vec::new(),
         }
     }
 
     pub fn syntax_error_from(message: &str, range: SourceRange) -> Self {
-        LuaParseError {
-            kind: LuaParseErrorKind::SyntaxError,
-            message: message.to_string(),
-            range: range.into(),
-        }
+        LuaParseError::new(LuaParseErrorKind::SyntaxError, message, range.into())
     }
 
     pub fn doc_error_from(message: &str, range: SourceRange) -> Self {
-        LuaParseError {
-            kind: LuaParseErrorKind::DocError,
+        LuaParseError::new(LuaParseErrorKind::DocError, message, range.into())
+    }
+
+    pub fn with_code(mut self, code: LuaParseErrorCode) -> Self {
+        self.code = code;
+        self
+    }
+
+    pub fn with_expected(mut self, expected: &[LuaTokenKind]) -> Self {
+        self.expected = expected.to_vec();
+        self
+    }
+
+    pub fn with_secondary(mut self, message: &str, range: TextRange) -> Self {
+        self.secondary.push(LuaParseErrorLabel {
             message: message.to_string(),
-            range: range.into(),
-        }
+            range,
+        });
+        self
     }
 }
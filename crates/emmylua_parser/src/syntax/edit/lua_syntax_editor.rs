@@ -0,0 +1,283 @@
+use rowan::{TextRange, TextSize};
+
+use crate::{
+    kind::{LuaSyntaxKind, LuaTokenKind},
+    syntax::{LuaSyntaxElement, LuaSyntaxNode},
+};
+
+use super::LuaTextEdit;
+
+/// Collects edits against one syntax tree and turns them into text edits
+///
+/// Anchors are nodes of the edited tree, new content usually comes from `LuaSyntaxFactory`.
+/// A statement or table field on its own line is inserted and removed as a whole line and new
+/// content gets the indentation of its anchor; table fields also keep their separators.
+pub struct LuaSyntaxEditor {
+    text: String,
+    newline: &'static str,
+    edits: Vec<LuaTextEdit>,
+}
+
+impl LuaSyntaxEditor {
+    pub fn new(root: &LuaSyntaxNode) -> Self {
+        let text = root.text().to_string();
+        let newline = if text.contains("\r\n") { "\r\n" } else { "\n" };
+        Self {
+            text,
+            newline,
+            edits: Vec::new(),
+        }
+    }
+
+    pub fn insert_before(&mut self, anchor: &LuaSyntaxNode, new: &LuaSyntaxNode) {
+        self.insert_text_before(anchor, &new.text().to_string());
+    }
+
+    pub fn insert_text_before(&mut self, anchor: &LuaSyntaxNode, text: &str) {
+        let start = anchor.text_range().start();
+        let indent = self.line_indent(start).to_string();
+        let text = self.reindent(text, &indent);
+        let separator = if is_list_element(anchor) { "," } else { "" };
+        let new_text = if self.starts_line(start) {
+            format!("{}{}{}{}", text, separator, self.newline, indent)
+        } else {
+            format!("{}{} ", text, separator)
+        };
+        self.edits.push(LuaTextEdit::insert(start, new_text));
+    }
+
+    /// a line of its own above the line of `anchor`, e.g. a doc comment for an expression
+    /// that does not start its line
+    pub fn insert_line_before(&mut self, anchor: &LuaSyntaxNode, new: &LuaSyntaxNode) {
+        self.insert_text_line_before(anchor, &new.text().to_string());
+    }
+
+    pub fn insert_text_line_before(&mut self, anchor: &LuaSyntaxNode, text: &str) {
+        let start = anchor.text_range().start();
+        let line_start = self.line_start(start);
+        let line = &self.text[usize::from(line_start)..usize::from(start)];
+        let indent = line[..line.len() - line.trim_start_matches([' ', '\t']).len()].to_string();
+        let text = self.reindent(text, &indent);
+        self.edits.push(LuaTextEdit::insert(
+            line_start,
+            format!("{}{}{}", indent, text, self.newline),
+        ));
+    }
+
+    pub fn insert_after(&mut self, anchor: &LuaSyntaxNode, new: &LuaSyntaxNode) {
+        self.insert_text_after(anchor, &new.text().to_string());
+    }
+
+    pub fn insert_text_after(&mut self, anchor: &LuaSyntaxNode, text: &str) {
+        let range = anchor.text_range();
+        let indent = self.line_indent(range.start()).to_string();
+        let text = self.reindent(text, &indent);
+        let own_line = self.starts_line(range.start());
+
+        if is_list_element(anchor) {
+            // `a = 1,| b = 2` or `a = 1|}`
+            let edit = match next_separator(anchor) {
+                Some(separator) => {
+                    let gap = self.gap_after(separator.end(), own_line, &indent);
+                    LuaTextEdit::insert(separator.end(), format!("{}{},", gap, text))
+                }
+                None => {
+                    let gap = self.gap_after(range.end(), own_line, &indent);
+                    LuaTextEdit::insert(range.end(), format!(",{}{}", gap, text))
+                }
+            };
+            self.edits.push(edit);
+            return;
+        }
+
+        // a trailing comment stays with its statement
+        let offset = if own_line && self.rest_of_line_is_trivia(range.end()) {
+            self.line_end(range.end())
+        } else {
+            range.end()
+        };
+        let gap = self.gap_after(offset, own_line, &indent);
+        self.edits
+            .push(LuaTextEdit::insert(offset, format!("{}{}", gap, text)));
+    }
+
+    pub fn replace(&mut self, old: &LuaSyntaxNode, new: &LuaSyntaxNode) {
+        self.replace_with_text(old, &new.text().to_string());
+    }
+
+    pub fn replace_with_text(&mut self, old: &LuaSyntaxNode, text: &str) {
+        let range = old.text_range();
+        let indent = self.line_indent(range.start()).to_string();
+        let text = self.reindent(text, &indent);
+        let edit = LuaTextEdit::minimal_replace(range, &self.text[range], &text);
+        if !edit.range.is_empty() || !edit.new_text.is_empty() {
+            self.edits.push(edit);
+        }
+    }
+
+    pub fn remove(&mut self, node: &LuaSyntaxNode) {
+        let range = node.text_range();
+        let (mut start, mut end) = (range.start(), range.end());
+        // the last field takes the separator before it, `{ a = 1|, b = 2| }`
+        let mut takes_prev = false;
+        if is_list_element(node) {
+            if let Some(separator) = next_separator(node) {
+                end = separator.end();
+            } else if let Some(separator) = prev_separator(node) {
+                start = separator.start();
+                takes_prev = true;
+            }
+        }
+
+        if self.starts_line(start) && self.rest_of_line_is_trivia(end) {
+            start = self.line_start(start);
+            end = self.next_line_start(end);
+        } else if !takes_prev {
+            end = self.skip_spaces(end);
+        }
+
+        self.edits
+            .push(LuaTextEdit::delete(TextRange::new(start, end)));
+    }
+
+    /// raw text at `offset`, for places that are not next to a node
+    pub fn insert_text(&mut self, offset: TextSize, text: String) {
+        self.edits.push(LuaTextEdit::insert(offset, text));
+    }
+
+    /// sorted edits, inserts at the same offset are merged in the order they were made
+    pub fn finish(self) -> Vec<LuaTextEdit> {
+        let mut edits = self.edits;
+        edits.sort_by_key(|edit| (edit.range.start(), edit.range.end()));
+
+        let mut result: Vec<LuaTextEdit> = Vec::with_capacity(edits.len());
+        for edit in edits {
+            if let Some(last) = result.last_mut() {
+                if last.range.is_empty() && edit.range.is_empty() && last.range == edit.range {
+                    last.new_text.push_str(&edit.new_text);
+                    continue;
+                }
+            }
+            result.push(edit);
+        }
+        result
+    }
+
+    pub fn apply(self) -> String {
+        let text = self.text.clone();
+        LuaTextEdit::apply(&text, &self.finish())
+    }
+
+    fn gap_after(&self, offset: TextSize, own_line: bool, indent: &str) -> String {
+        if own_line && self.rest_of_line_is_trivia(offset) {
+            format!("{}{}", self.newline, indent)
+        } else {
+            " ".to_string()
+        }
+    }
+
+    // continuation lines of multi-line content line up with the anchor
+    fn reindent(&self, text: &str, indent: &str) -> String {
+        let mut lines = text.lines();
+        let mut result = lines.next().unwrap_or_default().to_string();
+        for line in lines {
+            result.push_str(self.newline);
+            if !line.is_empty() {
+                result.push_str(indent);
+            }
+            result.push_str(line);
+        }
+        result
+    }
+
+    fn line_start(&self, offset: TextSize) -> TextSize {
+        let offset = usize::from(offset);
+        let start = self.text[..offset].rfind('\n').map_or(0, |idx| idx + 1);
+        TextSize::from(start as u32)
+    }
+
+    // the offset of the `\n` (or `\r\n`) ending the line, or the end of the text
+    fn line_end(&self, offset: TextSize) -> TextSize {
+        let offset = usize::from(offset);
+        let end = match self.text[offset..].find('\n') {
+            Some(idx) if self.text[..offset + idx].ends_with('\r') => offset + idx - 1,
+            Some(idx) => offset + idx,
+            None => self.text.len(),
+        };
+        TextSize::from(end as u32)
+    }
+
+    fn next_line_start(&self, offset: TextSize) -> TextSize {
+        let offset = usize::from(offset);
+        let start = match self.text[offset..].find('\n') {
+            Some(idx) => offset + idx + 1,
+            None => self.text.len(),
+        };
+        TextSize::from(start as u32)
+    }
+
+    fn line_indent(&self, offset: TextSize) -> &str {
+        let start = usize::from(self.line_start(offset));
+        let line = &self.text[start..usize::from(offset)];
+        let len = line.len() - line.trim_start_matches([' ', '\t']).len();
+        &line[..len]
+    }
+
+    fn starts_line(&self, offset: TextSize) -> bool {
+        let start = usize::from(self.line_start(offset));
+        self.text[start..usize::from(offset)].trim().is_empty()
+    }
+
+    fn rest_of_line_is_trivia(&self, offset: TextSize) -> bool {
+        let rest = &self.text[usize::from(offset)..usize::from(self.line_end(offset))];
+        let rest = rest.trim();
+        rest.is_empty() || (rest.starts_with("--") && !rest.starts_with("--["))
+    }
+
+    fn skip_spaces(&self, offset: TextSize) -> TextSize {
+        let rest = &self.text[usize::from(offset)..];
+        let len = rest.len() - rest.trim_start_matches([' ', '\t']).len();
+        offset + TextSize::from(len as u32)
+    }
+}
+
+fn is_list_element(node: &LuaSyntaxNode) -> bool {
+    matches!(
+        node.kind().into(),
+        LuaSyntaxKind::TableFieldAssign | LuaSyntaxKind::TableFieldValue
+    )
+}
+
+fn next_separator(node: &LuaSyntaxNode) -> Option<TextRange> {
+    let mut next = node.next_sibling_or_token();
+    while let Some(element) = next {
+        match element_token_kind(&element) {
+            Some(LuaTokenKind::TkWhitespace | LuaTokenKind::TkEndOfLine) => {}
+            Some(LuaTokenKind::TkComma | LuaTokenKind::TkSemicolon) => {
+                return Some(element.text_range())
+            }
+            _ => return None,
+        }
+        next = element.next_sibling_or_token();
+    }
+    None
+}
+
+fn prev_separator(node: &LuaSyntaxNode) -> Option<TextRange> {
+    let mut prev = node.prev_sibling_or_token();
+    while let Some(element) = prev {
+        match element_token_kind(&element) {
+            Some(LuaTokenKind::TkWhitespace | LuaTokenKind::TkEndOfLine) => {}
+            Some(LuaTokenKind::TkComma | LuaTokenKind::TkSemicolon) => {
+                return Some(element.text_range())
+            }
+            _ => return None,
+        }
+        prev = element.prev_sibling_or_token();
+    }
+    None
+}
+
+fn element_token_kind(element: &LuaSyntaxElement) -> Option<LuaTokenKind> {
+    element.as_token().map(|token| token.kind().into())
+}
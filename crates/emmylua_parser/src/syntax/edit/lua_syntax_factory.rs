@@ -0,0 +1,107 @@
+use crate::{
+    kind::LuaLanguageLevel,
+    parser::{LuaParser, ParserConfig},
+    syntax::{
+        node::{LuaCallExprStat, LuaComment, LuaExpr, LuaLocalStat, LuaReturnStat, LuaTableField},
+        traits::LuaAstNode,
+    },
+};
+
+/// Builds detached nodes by parsing a small snippet, a snippet with syntax errors gives `None`
+///
+/// The pieces are source text, e.g. `local_stat(&["json"], &["require(\"json\")"])`
+#[derive(Debug, Clone, Copy)]
+pub struct LuaSyntaxFactory {
+    level: LuaLanguageLevel,
+}
+
+impl Default for LuaSyntaxFactory {
+    fn default() -> Self {
+        Self::new(LuaLanguageLevel::Lua54)
+    }
+}
+
+impl LuaSyntaxFactory {
+    pub fn new(level: LuaLanguageLevel) -> Self {
+        Self { level }
+    }
+
+    /// `<name>, <name> = <value>, <value>`, values may be empty
+    pub fn local_stat(&self, names: &[&str], values: &[&str]) -> Option<LuaLocalStat> {
+        let mut text = format!("local {}", names.join(", "));
+        if !values.is_empty() {
+            text.push_str(" = ");
+            text.push_str(&values.join(", "));
+        }
+        self.parse_whole(&text)
+    }
+
+    /// `<callee>(<arg>, <arg>)`
+    pub fn call_expr_stat(&self, callee: &str, args: &[&str]) -> Option<LuaCallExprStat> {
+        self.parse_whole(&format!("{}({})", callee, args.join(", ")))
+    }
+
+    pub fn expr(&self, text: &str) -> Option<LuaExpr> {
+        let return_stat = self.parse_whole::<LuaReturnStat>(&format!("return {}", text))?;
+        let mut exprs = return_stat.get_expr_list();
+        let expr = exprs.next()?;
+        if exprs.next().is_some() {
+            return None;
+        }
+        LuaExpr::cast(expr.syntax().clone_subtree())
+    }
+
+    /// `<key> = <value>` when `key` is a name, `[<key>] = <value>` for any other key and
+    /// `<value>` without a key
+    pub fn table_field(&self, key: Option<&str>, value: &str) -> Option<LuaTableField> {
+        let text = match key {
+            Some(key) if is_name(key) => format!("local _ = {{ {} = {} }}", key, value),
+            Some(key) => format!("local _ = {{ [{}] = {} }}", key, value),
+            None => format!("local _ = {{ {} }}", value),
+        };
+        let local_stat = self.parse_whole::<LuaLocalStat>(&text)?;
+        let field = local_stat.descendants::<LuaTableField>().next()?;
+        LuaTableField::cast(field.syntax().clone_subtree())
+    }
+
+    /// one `---@<tag>` line per tag, e.g. `doc_comment(&["type string"])`
+    pub fn doc_comment(&self, tags: &[&str]) -> Option<LuaComment> {
+        let text = tags
+            .iter()
+            .map(|tag| format!("---@{}", tag))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let tree = LuaParser::parse(&text, ParserConfig::with_level(self.level));
+        if !tree.get_errors().is_empty() {
+            return None;
+        }
+        let comment = tree.get_chunk_node().descendants::<LuaComment>().next()?;
+        if comment.syntax().text() != text.as_str() {
+            return None;
+        }
+        LuaComment::cast(comment.syntax().clone_subtree())
+    }
+
+    // the snippet must parse without errors into exactly one `N`
+    fn parse_whole<N: LuaAstNode>(&self, text: &str) -> Option<N> {
+        let tree = LuaParser::parse(text, ParserConfig::with_level(self.level));
+        if !tree.get_errors().is_empty() {
+            return None;
+        }
+        let block = tree.get_chunk_node().get_block()?;
+        let mut children = block.syntax().children();
+        let node = children.next()?;
+        if children.next().is_some() || node.text() != text {
+            return None;
+        }
+        N::cast(node.clone_subtree())
+    }
+}
+
+fn is_name(text: &str) -> bool {
+    let mut chars = text.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
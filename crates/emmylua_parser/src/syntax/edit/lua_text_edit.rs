@@ -0,0 +1,71 @@
+use rowan::{TextRange, TextSize};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LuaTextEdit {
+    pub range: TextRange,
+    pub new_text: String,
+}
+
+impl LuaTextEdit {
+    pub fn insert(offset: TextSize, new_text: String) -> Self {
+        Self {
+            range: TextRange::empty(offset),
+            new_text,
+        }
+    }
+
+    pub fn replace(range: TextRange, new_text: String) -> Self {
+        Self { range, new_text }
+    }
+
+    pub fn delete(range: TextRange) -> Self {
+        Self {
+            range,
+            new_text: String::new(),
+        }
+    }
+
+    /// `old` is the text currently at `range`, the common prefix and suffix are left untouched
+    pub fn minimal_replace(range: TextRange, old: &str, new: &str) -> Self {
+        let prefix = old
+            .char_indices()
+            .zip(new.chars())
+            .find(|((_, a), b)| a != b)
+            .map(|((idx, _), _)| idx)
+            .unwrap_or(old.len().min(new.len()));
+        let old_rest = &old[prefix..];
+        let new_rest = &new[prefix..];
+        let suffix = old_rest
+            .chars()
+            .rev()
+            .zip(new_rest.chars().rev())
+            .take_while(|(a, b)| a == b)
+            .map(|(c, _)| c.len_utf8())
+            .sum::<usize>();
+
+        let start = range.start() + TextSize::from(prefix as u32);
+        let end = range.end() - TextSize::from(suffix as u32);
+        Self {
+            range: TextRange::new(start, end),
+            new_text: new_rest[..new_rest.len() - suffix].to_string(),
+        }
+    }
+
+    /// `edits` must not overlap, they may come in any order
+    pub fn apply(text: &str, edits: &[LuaTextEdit]) -> String {
+        let mut edits = edits.iter().collect::<Vec<_>>();
+        edits.sort_by_key(|edit| (edit.range.start(), edit.range.end()));
+
+        let mut result = String::with_capacity(text.len());
+        let mut last = 0;
+        for edit in edits {
+            let start = usize::from(edit.range.start());
+            let end = usize::from(edit.range.end());
+            result.push_str(&text[last..start]);
+            result.push_str(&edit.new_text);
+            last = end;
+        }
+        result.push_str(&text[last..]);
+        result
+    }
+}
@@ -0,0 +1,8 @@
+mod lua_syntax_editor;
+mod lua_syntax_factory;
+mod lua_text_edit;
+mod test;
+
+pub use lua_syntax_editor::LuaSyntaxEditor;
+pub use lua_syntax_factory::LuaSyntaxFactory;
+pub use lua_text_edit::LuaTextEdit;
@@ -0,0 +1,236 @@
+#[cfg(test)]
+mod test {
+    use crate::{
+        LuaAstNode, LuaLocalStat, LuaParser, LuaSyntaxEditor, LuaSyntaxFactory, LuaTableField,
+        LuaTextEdit, ParserConfig,
+    };
+    use rowan::{TextRange, TextSize};
+
+    // the edited text must be the expected one and re-parse to the same tree as it
+    fn assert_edit<F>(code: &str, expected: &str, edit: F)
+    where
+        F: FnOnce(&crate::LuaChunk, &mut LuaSyntaxEditor),
+    {
+        let tree = LuaParser::parse(code, ParserConfig::default());
+        let chunk = tree.get_chunk_node();
+        let mut editor = LuaSyntaxEditor::new(chunk.syntax());
+        edit(&chunk, &mut editor);
+        let edits = editor.finish();
+        let result = LuaTextEdit::apply(code, &edits);
+        assert_eq!(result, expected);
+
+        let new_tree = LuaParser::parse(&result, ParserConfig::default());
+        assert!(
+            new_tree.get_errors().is_empty(),
+            "{:?}",
+            new_tree.get_errors()
+        );
+        let expected_tree = LuaParser::parse(expected, ParserConfig::default());
+        assert_eq!(
+            format!("{:#?}", new_tree.get_red_root()),
+            format!("{:#?}", expected_tree.get_red_root())
+        );
+    }
+
+    #[test]
+    fn test_factory() {
+        let factory = LuaSyntaxFactory::default();
+        let local_stat = factory
+            .local_stat(&["json"], &["require(\"json\")"])
+            .unwrap();
+        assert_eq!(local_stat.syntax().text(), "local json = require(\"json\")");
+        assert_eq!(local_stat.syntax().text_range().start(), TextSize::from(0));
+        assert!(factory.local_stat(&["1"], &[]).is_none());
+
+        let call = factory.call_expr_stat("print", &["a", "b"]).unwrap();
+        assert_eq!(call.syntax().text(), "print(a, b)");
+
+        let expr = factory.expr("a + 1").unwrap();
+        assert_eq!(expr.syntax().text(), "a + 1");
+        assert_eq!(expr.syntax().text_range().start(), TextSize::from(0));
+        assert!(factory.expr("a, b").is_none());
+
+        let field = factory.table_field(Some("name"), "1").unwrap();
+        assert_eq!(field.syntax().text(), "name = 1");
+        let field = factory.table_field(Some("\"a b\""), "1").unwrap();
+        assert_eq!(field.syntax().text(), "[\"a b\"] = 1");
+        let field = factory.table_field(None, "1").unwrap();
+        assert_eq!(field.syntax().text(), "1");
+
+        let comment = factory
+            .doc_comment(&["param a string", "return number"])
+            .unwrap();
+        assert_eq!(
+            comment.syntax().text(),
+            "---@param a string\n---@return number"
+        );
+    }
+
+    #[test]
+    fn test_minimal_replace() {
+        let edit = LuaTextEdit::minimal_replace(
+            TextRange::new(10.into(), 25.into()),
+            "local a = 1 + 2",
+            "local a = 1 * 2",
+        );
+        assert_eq!(edit.range, TextRange::new(22.into(), 23.into()));
+        assert_eq!(edit.new_text, "*");
+    }
+
+    #[test]
+    fn test_insert_stat() {
+        let factory = LuaSyntaxFactory::default();
+        assert_edit(
+            r#"
+            local a = require("a") -- a
+            print(a)
+            "#,
+            r#"
+            local a = require("a") -- a
+            local b = require("b")
+            print(a)
+            "#,
+            |chunk, editor| {
+                let anchor = chunk.descendants::<LuaLocalStat>().next().unwrap();
+                let new = factory.local_stat(&["b"], &["require(\"b\")"]).unwrap();
+                editor.insert_after(anchor.syntax(), new.syntax());
+            },
+        );
+        assert_edit(
+            "if a then\n    print(a)\nend\n",
+            "if a then\n    local b = a\n    print(a)\nend\n",
+            |chunk, editor| {
+                let anchor = chunk.get_block().unwrap();
+                let anchor = anchor
+                    .descendants::<crate::LuaCallExprStat>()
+                    .next()
+                    .unwrap();
+                let new = factory.local_stat(&["b"], &["a"]).unwrap();
+                editor.insert_before(anchor.syntax(), new.syntax());
+            },
+        );
+    }
+
+    #[test]
+    fn test_insert_multi_line() {
+        let factory = LuaSyntaxFactory::default();
+        assert_edit(
+            "do\n    local a = 1\nend\n",
+            "do\n    local a = 1\n    local t = {\n        x = 1\n    }\nend\n",
+            |chunk, editor| {
+                let anchor = chunk.descendants::<LuaLocalStat>().next().unwrap();
+                let new = factory.local_stat(&["t"], &["{\n    x = 1\n}"]).unwrap();
+                editor.insert_after(anchor.syntax(), new.syntax());
+            },
+        );
+    }
+
+    #[test]
+    fn test_insert_line() {
+        let factory = LuaSyntaxFactory::default();
+        assert_edit(
+            "do\n    local a = 1; print(a)\nend\n",
+            "do\n    ---@diagnostic disable-next-line: unused\n    local a = 1; print(a)\nend\n",
+            |chunk, editor| {
+                let anchor = chunk
+                    .descendants::<crate::LuaCallExprStat>()
+                    .next()
+                    .unwrap();
+                let new = factory
+                    .doc_comment(&["diagnostic disable-next-line: unused"])
+                    .unwrap();
+                editor.insert_line_before(anchor.syntax(), new.syntax());
+            },
+        );
+    }
+
+    #[test]
+    fn test_table_field() {
+        let factory = LuaSyntaxFactory::default();
+        assert_edit(
+            "local t = { a = 1 }",
+            "local t = { a = 1, b = 2 }",
+            |chunk, editor| {
+                let anchor = chunk.descendants::<LuaTableField>().next().unwrap();
+                let new = factory.table_field(Some("b"), "2").unwrap();
+                editor.insert_after(anchor.syntax(), new.syntax());
+            },
+        );
+        assert_edit(
+            "local t = {\n    a = 1,\n}\n",
+            "local t = {\n    a = 1,\n    b = 2,\n}\n",
+            |chunk, editor| {
+                let anchor = chunk.descendants::<LuaTableField>().next().unwrap();
+                let new = factory.table_field(Some("b"), "2").unwrap();
+                editor.insert_after(anchor.syntax(), new.syntax());
+            },
+        );
+        assert_edit(
+            "local t = { b = 2 }",
+            "local t = { a = 1, b = 2 }",
+            |chunk, editor| {
+                let anchor = chunk.descendants::<LuaTableField>().next().unwrap();
+                let new = factory.table_field(Some("a"), "1").unwrap();
+                editor.insert_before(anchor.syntax(), new.syntax());
+            },
+        );
+    }
+
+    #[test]
+    fn test_remove() {
+        assert_edit(
+            "local a = 1\n    local b = 2\nprint(a)\n",
+            "local a = 1\nprint(a)\n",
+            |chunk, editor| {
+                let node = chunk.descendants::<LuaLocalStat>().nth(1).unwrap();
+                editor.remove(node.syntax());
+            },
+        );
+        assert_edit(
+            "local t = { a = 1, b = 2 }",
+            "local t = { b = 2 }",
+            |chunk, editor| {
+                let node = chunk.descendants::<LuaTableField>().next().unwrap();
+                editor.remove(node.syntax());
+            },
+        );
+        assert_edit(
+            "local t = { a = 1, b = 2 }",
+            "local t = { a = 1 }",
+            |chunk, editor| {
+                let node = chunk.descendants::<LuaTableField>().nth(1).unwrap();
+                editor.remove(node.syntax());
+            },
+        );
+        assert_edit(
+            "local t = {\n    a = 1,\n    b = 2\n}\n",
+            "local t = {\n    a = 1\n}\n",
+            |chunk, editor| {
+                let node = chunk.descendants::<LuaTableField>().nth(1).unwrap();
+                editor.remove(node.syntax());
+            },
+        );
+    }
+
+    #[test]
+    fn test_replace() {
+        let factory = LuaSyntaxFactory::default();
+        assert_edit("local a = 1 + 2", "local a = 1 * 2", |chunk, editor| {
+            let old = chunk.descendants::<crate::LuaBinaryExpr>().next().unwrap();
+            let new = factory.expr("1 * 2").unwrap();
+            editor.replace(old.syntax(), new.syntax());
+        });
+        let tree = LuaParser::parse("local a = 1 + 2", ParserConfig::default());
+        let chunk = tree.get_chunk_node();
+        let mut editor = LuaSyntaxEditor::new(chunk.syntax());
+        let old = chunk.descendants::<crate::LuaBinaryExpr>().next().unwrap();
+        editor.replace(old.syntax(), factory.expr("1 * 2").unwrap().syntax());
+        assert_eq!(
+            editor.finish(),
+            vec![LuaTextEdit::replace(
+                TextRange::new(12.into(), 13.into()),
+                "*".to_string()
+            )]
+        );
+    }
+}
@@ -1,4 +1,5 @@
 mod comment_trait;
+mod edit;
 mod node;
 mod traits;
 mod tree;
@@ -12,9 +13,10 @@ use rowan::{Language, TextRange, TextSize};
 
 use crate::kind::{LuaKind, LuaSyntaxKind, LuaTokenKind};
 pub use comment_trait::*;
+pub use edit::*;
 pub use node::*;
 pub use traits::*;
-pub use tree::{LuaSyntaxTree, LuaTreeBuilder};
+pub use tree::{LuaEmbeddedChunk, LuaSyntaxTree, LuaTreeBuilder};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct LuaLanguage;
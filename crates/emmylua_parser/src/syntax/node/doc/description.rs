@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use crate::{LuaAstNode, LuaDocDetailToken, LuaSyntaxKind, LuaSyntaxNode, LuaTokenKind};
 
 #[allow(unused)]
@@ -50,6 +52,10 @@ impl LuaAstNode for LuaDocDescription {
 impl LuaDocDetailOwner for LuaDocDescription {}
 
 impl LuaDocDescription {
+    pub fn get_description_items(&self) -> Vec<LuaDescriptionItem> {
+        parse_description_items(&self.get_description_text())
+    }
+
     pub fn get_description_text(&self) -> String {
         let mut text = String::new();
         for token in self
@@ -87,3 +93,134 @@ impl LuaDocDescription {
         text
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LuaDescriptionItemKind {
+    /// `[Class.member]`, the path of the target
+    Reference(String),
+    /// `@see Class.member`, the path of the target
+    See(String),
+    /// `` `code` ``
+    CodeSpan,
+    /// a fenced code block with its info string, such as `lua`
+    CodeBlock(Option<String>),
+}
+
+/// A Markdown element of a description, its range is in the description text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LuaDescriptionItem {
+    pub kind: LuaDescriptionItemKind,
+    pub range: Range<usize>,
+}
+
+/// Find cross references, code spans and fenced code blocks in a description
+///
+/// Text in code is kept as it is, `[text](url)` Markdown links are not references.
+pub fn parse_description_items(text: &str) -> Vec<LuaDescriptionItem> {
+    let mut items = Vec::new();
+    let mut fence: Option<(usize, Option<String>)> = None;
+    let mut line_start = 0;
+    for line in text.split_inclusive('\n') {
+        let line_end = line_start + line.len();
+        let trimmed = line.trim();
+        if let Some(info) = trimmed.strip_prefix("```") {
+            match fence.take() {
+                Some((start, language)) => items.push(LuaDescriptionItem {
+                    kind: LuaDescriptionItemKind::CodeBlock(language),
+                    range: start..line_start + line.trim_end().len(),
+                }),
+                None => {
+                    let info = info.trim();
+                    let language = (!info.is_empty()).then(|| info.to_string());
+                    let indent = line.len() - line.trim_start().len();
+                    fence = Some((line_start + indent, language));
+                }
+            }
+        } else if fence.is_none() {
+            parse_line_items(line, line_start, &mut items);
+        }
+        line_start = line_end;
+    }
+
+    // an unclosed fence runs to the end
+    if let Some((start, language)) = fence {
+        items.push(LuaDescriptionItem {
+            kind: LuaDescriptionItemKind::CodeBlock(language),
+            range: start..text.trim_end().len().max(start),
+        });
+    }
+    items
+}
+
+fn parse_line_items(line: &str, offset: usize, items: &mut Vec<LuaDescriptionItem>) {
+    let bytes = line.as_bytes();
+    let mut pos = 0;
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b'`' => {
+                let Some(len) = line[pos + 1..].find('`') else {
+                    return;
+                };
+                let end = pos + len + 2;
+                items.push(LuaDescriptionItem {
+                    kind: LuaDescriptionItemKind::CodeSpan,
+                    range: offset + pos..offset + end,
+                });
+                pos = end;
+            }
+            b'[' if pos == 0 || bytes[pos - 1] != b']' => {
+                let path_len = reference_path_len(&line[pos + 1..]);
+                let end = pos + 1 + path_len;
+                let is_reference = path_len > 0
+                    && bytes.get(end) == Some(&b']')
+                    && !matches!(bytes.get(end + 1), Some(b'(' | b'[' | b':'));
+                if is_reference {
+                    items.push(LuaDescriptionItem {
+                        kind: LuaDescriptionItemKind::Reference(line[pos + 1..end].to_string()),
+                        range: offset + pos..offset + end + 1,
+                    });
+                    pos = end + 1;
+                } else {
+                    pos += 1;
+                }
+            }
+            b'@' if line[pos..].starts_with("@see ") => {
+                let path_start = pos + "@see ".len();
+                let path_len = reference_path_len(&line[path_start..]);
+                if path_len > 0 {
+                    let end = path_start + path_len;
+                    items.push(LuaDescriptionItem {
+                        kind: LuaDescriptionItemKind::See(line[path_start..end].to_string()),
+                        range: offset + pos..offset + end,
+                    });
+                    pos = end;
+                } else {
+                    pos = path_start;
+                }
+            }
+            _ => pos += 1,
+        }
+    }
+}
+
+// `Class.member`, `Class:method` or `Class#member`, each part is a name
+fn reference_path_len(text: &str) -> usize {
+    let mut len = 0;
+    let mut expect_name = true;
+    for (idx, c) in text.char_indices() {
+        if expect_name {
+            if c.is_alphabetic() || c == '_' {
+                expect_name = false;
+            } else {
+                break;
+            }
+        } else if matches!(c, '.' | ':' | '#') {
+            expect_name = true;
+            continue;
+        } else if !(c.is_alphanumeric() || c == '_') {
+            break;
+        }
+        len = idx + c.len_utf8();
+    }
+    len
+}
@@ -9,7 +9,7 @@ pub use types::*;
 
 use super::{LuaAst, LuaBinaryOpToken, LuaNameToken, LuaNumberToken, LuaStringToken};
 use crate::{
-    kind::{LuaSyntaxKind, LuaTokenKind},
+    kind::{LuaSyntaxKind, LuaTokenKind, VarianceKind},
     syntax::traits::LuaAstNode,
     LuaAstChildren, LuaAstToken, LuaAstTokenChildren, LuaKind, LuaSyntaxNode,
 };
@@ -204,9 +204,30 @@ impl LuaDocGenericDecl {
         self.token()
     }
 
+    /// the constraint type, `T: Base`
     pub fn get_type(&self) -> Option<LuaDocType> {
+        self.token_by_kind(LuaTokenKind::TkColon)?;
         self.child()
     }
+
+    /// the default type argument, `T = any`
+    pub fn get_default_type(&self) -> Option<LuaDocType> {
+        let assign_token = self.token_by_kind(LuaTokenKind::TkAssign)?;
+        let position = assign_token.get_position();
+        self.children::<LuaDocType>()
+            .find(|typ| typ.get_position() > position)
+    }
+
+    pub fn get_variance(&self) -> VarianceKind {
+        let is_in = self.token_by_kind(LuaTokenKind::TkDocIn).is_some();
+        let is_out = self.token_by_kind(LuaTokenKind::TkDocOut).is_some();
+        match (is_in, is_out) {
+            (true, true) => VarianceKind::InOut,
+            (true, false) => VarianceKind::In,
+            (false, true) => VarianceKind::Out,
+            (false, false) => VarianceKind::None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
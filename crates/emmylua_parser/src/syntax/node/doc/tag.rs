@@ -39,6 +39,7 @@ pub enum LuaDocTag {
     As(LuaDocTagAs),
     Visibility(LuaDocTagVisibility),
     ReturnCast(LuaDocTagReturnCast),
+    Language(LuaDocTagLanguage),
 }
 
 impl LuaAstNode for LuaDocTag {
@@ -71,6 +72,7 @@ impl LuaAstNode for LuaDocTag {
             LuaDocTag::As(it) => it.syntax(),
             LuaDocTag::Visibility(it) => it.syntax(),
             LuaDocTag::ReturnCast(it) => it.syntax(),
+            LuaDocTag::Language(it) => it.syntax(),
         }
     }
 
@@ -105,6 +107,7 @@ impl LuaAstNode for LuaDocTag {
             || kind == LuaSyntaxKind::DocTagAs
             || kind == LuaSyntaxKind::DocTagVisibility
             || kind == LuaSyntaxKind::DocTagReturnCast
+            || kind == LuaSyntaxKind::DocTagLanguage
     }
 
     fn cast(syntax: LuaSyntaxNode) -> Option<Self>
@@ -189,6 +192,9 @@ impl LuaAstNode for LuaDocTag {
             LuaSyntaxKind::DocTagReturnCast => Some(LuaDocTag::ReturnCast(
                 LuaDocTagReturnCast::cast(syntax).unwrap(),
             )),
+            LuaSyntaxKind::DocTagLanguage => Some(LuaDocTag::Language(
+                LuaDocTagLanguage::cast(syntax).unwrap(),
+            )),
             _ => None,
         }
     }
@@ -1452,3 +1458,43 @@ impl LuaDocTagReturnCast {
         self.token()
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LuaDocTagLanguage {
+    syntax: LuaSyntaxNode,
+}
+
+impl LuaAstNode for LuaDocTagLanguage {
+    fn syntax(&self) -> &LuaSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: LuaSyntaxKind) -> bool {
+        kind == LuaSyntaxKind::DocTagLanguage
+    }
+
+    fn cast(syntax: LuaSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl LuaDocDescriptionOwner for LuaDocTagLanguage {}
+
+impl LuaDocTagLanguage {
+    /// `sql` in `---@language sql query`
+    pub fn get_language_token(&self) -> Option<LuaNameToken> {
+        self.token()
+    }
+
+    /// `query` in `---@language sql query`, the parameter the language applies to
+    pub fn get_param_name_token(&self) -> Option<LuaNameToken> {
+        self.tokens().nth(1)
+    }
+}
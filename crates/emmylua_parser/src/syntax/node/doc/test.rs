@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod test {
-    use crate::{LuaAstNode, LuaComment, LuaDocDescriptionOwner, LuaParser, ParserConfig};
+    use crate::{
+        parse_description_items, LuaAstNode, LuaComment, LuaDescriptionItemKind,
+        LuaDocDescriptionOwner, LuaParser, ParserConfig,
+    };
 
     #[allow(unused)]
     fn print_ast(lua_code: &str) {
@@ -66,4 +69,45 @@ mod test {
         );
         assert_eq!(comment_5.get_owner().unwrap().syntax().text(), "qi = 123");
     }
+
+    #[test]
+    fn test_description_items() {
+        let text = "Uses [Foo.bar] and `[Not.ref]`, a [link](https://a.b).\n@see Foo:baz.\n```lua\nlocal a = [Skip]\n```";
+        let items = parse_description_items(text);
+        let kinds = items
+            .iter()
+            .map(|item| (item.kind.clone(), &text[item.range.clone()]))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![
+                (
+                    LuaDescriptionItemKind::Reference("Foo.bar".to_string()),
+                    "[Foo.bar]"
+                ),
+                (LuaDescriptionItemKind::CodeSpan, "`[Not.ref]`"),
+                (
+                    LuaDescriptionItemKind::See("Foo:baz".to_string()),
+                    "@see Foo:baz"
+                ),
+                (
+                    LuaDescriptionItemKind::CodeBlock(Some("lua".to_string())),
+                    "```lua\nlocal a = [Skip]\n```"
+                ),
+            ]
+        );
+
+        let code = "---Returns [Foo]\nlocal function f() end";
+        let tree = LuaParser::parse(code, ParserConfig::default());
+        let comment = tree
+            .get_chunk_node()
+            .descendants::<LuaComment>()
+            .next()
+            .unwrap();
+        let items = comment.get_description().unwrap().get_description_items();
+        assert_eq!(
+            items[0].kind,
+            LuaDescriptionItemKind::Reference("Foo".to_string())
+        );
+    }
 }
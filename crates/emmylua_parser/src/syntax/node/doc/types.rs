@@ -134,8 +134,9 @@ impl LuaAstNode for LuaDocNameType {
 }
 
 impl LuaDocNameType {
+    /// for a qualified luau type `Module.Name` this is the last name
     pub fn get_name_token(&self) -> Option<LuaNameToken> {
-        self.token()
+        self.tokens::<LuaNameToken>().last()
     }
 
     pub fn get_name_text(&self) -> Option<String> {
@@ -2,7 +2,10 @@ use crate::{
     kind::LuaSyntaxKind,
     syntax::{
         comment_trait::LuaCommentOwner,
-        node::{LuaBinaryOpToken, LuaNameToken, LuaUnaryOpToken},
+        node::{
+            LuaBinaryOpToken, LuaDocGenericDeclList, LuaDocType, LuaDocTypeList, LuaNameToken,
+            LuaUnaryOpToken,
+        },
         traits::{LuaAstChildren, LuaAstNode},
     },
     LuaAstToken, LuaIndexToken, LuaLiteralToken, LuaSyntaxNode, LuaSyntaxToken, LuaTokenKind,
@@ -23,6 +26,9 @@ pub enum LuaExpr {
     ParenExpr(LuaParenExpr),
     NameExpr(LuaNameExpr),
     IndexExpr(LuaIndexExpr),
+    CastExpr(LuaCastExpr),
+    IfExpr(LuaIfExpr),
+    InterpStringExpr(LuaInterpStringExpr),
 }
 
 impl LuaAstNode for LuaExpr {
@@ -37,6 +43,9 @@ impl LuaAstNode for LuaExpr {
             LuaExpr::ParenExpr(node) => node.syntax(),
             LuaExpr::NameExpr(node) => node.syntax(),
             LuaExpr::IndexExpr(node) => node.syntax(),
+            LuaExpr::CastExpr(node) => node.syntax(),
+            LuaExpr::IfExpr(node) => node.syntax(),
+            LuaExpr::InterpStringExpr(node) => node.syntax(),
         }
     }
 
@@ -61,6 +70,9 @@ impl LuaAstNode for LuaExpr {
             LuaSyntaxKind::ParenExpr => true,
             LuaSyntaxKind::NameExpr => true,
             LuaSyntaxKind::IndexExpr => true,
+            LuaSyntaxKind::CastExpr => true,
+            LuaSyntaxKind::IfExpr => true,
+            LuaSyntaxKind::InterpStringExpr => true,
             _ => false,
         }
     }
@@ -88,6 +100,11 @@ impl LuaAstNode for LuaExpr {
             LuaSyntaxKind::ParenExpr => LuaParenExpr::cast(syntax).map(LuaExpr::ParenExpr),
             LuaSyntaxKind::NameExpr => LuaNameExpr::cast(syntax).map(LuaExpr::NameExpr),
             LuaSyntaxKind::IndexExpr => LuaIndexExpr::cast(syntax).map(LuaExpr::IndexExpr),
+            LuaSyntaxKind::CastExpr => LuaCastExpr::cast(syntax).map(LuaExpr::CastExpr),
+            LuaSyntaxKind::IfExpr => LuaIfExpr::cast(syntax).map(LuaExpr::IfExpr),
+            LuaSyntaxKind::InterpStringExpr => {
+                LuaInterpStringExpr::cast(syntax).map(LuaExpr::InterpStringExpr)
+            }
             _ => None,
         }
     }
@@ -674,6 +691,16 @@ impl LuaClosureExpr {
     pub fn get_params_list(&self) -> Option<LuaParamList> {
         self.child()
     }
+
+    /// `function<T>(a: T)`, luau only
+    pub fn get_generic_decl_list(&self) -> Option<LuaDocGenericDeclList> {
+        self.child()
+    }
+
+    /// `function(): (number, string)`, luau only
+    pub fn get_return_type_list(&self) -> Option<LuaDocTypeList> {
+        self.child()
+    }
 }
 
 impl From<LuaClosureExpr> for LuaExpr {
@@ -722,3 +749,142 @@ impl From<LuaParenExpr> for LuaExpr {
         LuaExpr::ParenExpr(expr)
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LuaCastExpr {
+    syntax: LuaSyntaxNode,
+}
+
+impl LuaAstNode for LuaCastExpr {
+    fn syntax(&self) -> &LuaSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: LuaSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == LuaSyntaxKind::CastExpr
+    }
+
+    fn cast(syntax: LuaSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl LuaCastExpr {
+    pub fn get_expr(&self) -> Option<LuaExpr> {
+        self.child()
+    }
+
+    pub fn get_type(&self) -> Option<LuaDocType> {
+        self.child()
+    }
+}
+
+impl From<LuaCastExpr> for LuaExpr {
+    fn from(expr: LuaCastExpr) -> Self {
+        LuaExpr::CastExpr(expr)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LuaIfExpr {
+    syntax: LuaSyntaxNode,
+}
+
+impl LuaAstNode for LuaIfExpr {
+    fn syntax(&self) -> &LuaSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: LuaSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == LuaSyntaxKind::IfExpr
+    }
+
+    fn cast(syntax: LuaSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl LuaIfExpr {
+    /// conditions and branches in source order
+    pub fn get_exprs(&self) -> LuaAstChildren<LuaExpr> {
+        self.children()
+    }
+
+    /// the `then` branches followed by the `else` branch
+    pub fn get_branch_exprs(&self) -> Vec<LuaExpr> {
+        let exprs: Vec<LuaExpr> = self.get_exprs().collect();
+        let mut branches: Vec<LuaExpr> = exprs.iter().skip(1).step_by(2).cloned().collect();
+        if exprs.len() % 2 == 1 {
+            branches.extend(exprs.last().cloned());
+        }
+        branches
+    }
+}
+
+impl From<LuaIfExpr> for LuaExpr {
+    fn from(expr: LuaIfExpr) -> Self {
+        LuaExpr::IfExpr(expr)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LuaInterpStringExpr {
+    syntax: LuaSyntaxNode,
+}
+
+impl LuaAstNode for LuaInterpStringExpr {
+    fn syntax(&self) -> &LuaSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: LuaSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == LuaSyntaxKind::InterpStringExpr
+    }
+
+    fn cast(syntax: LuaSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl LuaInterpStringExpr {
+    /// the expressions inside `{}`
+    pub fn get_exprs(&self) -> LuaAstChildren<LuaExpr> {
+        self.children()
+    }
+}
+
+impl From<LuaInterpStringExpr> for LuaExpr {
+    fn from(expr: LuaInterpStringExpr) -> Self {
+        LuaExpr::InterpStringExpr(expr)
+    }
+}
@@ -14,7 +14,7 @@ pub use path_trait::*;
 use rowan::TextRange;
 pub use stat::*;
 
-use super::{LuaLiteralToken, LuaNameToken, LuaNumberToken, LuaStringToken};
+use super::{LuaDocType, LuaLiteralToken, LuaNameToken, LuaNumberToken, LuaStringToken};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct LuaChunk {
@@ -123,6 +123,50 @@ impl LuaLocalName {
     pub fn get_attrib(&self) -> Option<LuaLocalAttribute> {
         self.child()
     }
+
+    /// `local a: number`, luau only
+    pub fn get_type(&self) -> Option<LuaDocType> {
+        self.child()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LuaGlobalName {
+    syntax: LuaSyntaxNode,
+}
+
+impl LuaAstNode for LuaGlobalName {
+    fn syntax(&self) -> &LuaSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: LuaSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == LuaSyntaxKind::GlobalName
+    }
+
+    fn cast(syntax: LuaSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::can_cast(syntax.kind().into()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl LuaGlobalName {
+    pub fn get_name_token(&self) -> Option<LuaNameToken> {
+        self.token()
+    }
+
+    pub fn get_attrib(&self) -> Option<LuaLocalAttribute> {
+        self.child()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -439,6 +483,11 @@ impl LuaParamName {
     pub fn is_dots(&self) -> bool {
         self.token_by_kind(LuaTokenKind::TkDots).is_some()
     }
+
+    /// `function(a: number, ...: string)`, luau only
+    pub fn get_type(&self) -> Option<LuaDocType> {
+        self.child()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -1,16 +1,16 @@
 use crate::{
-    kind::{LuaSyntaxKind, LuaTokenKind},
+    kind::{BinaryOperator, LuaOpKind, LuaSyntaxKind, LuaTokenKind},
     syntax::{
         comment_trait::LuaCommentOwner,
-        node::LuaNameToken,
-        traits::{LuaAstChildren, LuaAstNode, LuaAstTokenChildren},
+        node::{LuaDocGenericDeclList, LuaDocType, LuaGeneralToken, LuaNameToken},
+        traits::{LuaAstChildren, LuaAstNode, LuaAstToken, LuaAstTokenChildren},
     },
     LuaSyntaxNode,
 };
 
 use super::{
     expr::{LuaCallExpr, LuaClosureExpr, LuaExpr, LuaVarExpr},
-    LuaBlock, LuaLocalName,
+    LuaBlock, LuaGlobalName, LuaLocalAttribute, LuaLocalName,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -31,6 +31,9 @@ pub enum LuaStat {
     GotoStat(LuaGotoStat),
     LabelStat(LuaLabelStat),
     EmptyStat(LuaEmptyStat),
+    TypeAliasStat(LuaTypeAliasStat),
+    ContinueStat(LuaContinueStat),
+    GlobalStat(LuaGlobalStat),
 }
 
 impl LuaAstNode for LuaStat {
@@ -52,6 +55,9 @@ impl LuaAstNode for LuaStat {
             LuaStat::GotoStat(node) => node.syntax(),
             LuaStat::LabelStat(node) => node.syntax(),
             LuaStat::EmptyStat(node) => node.syntax(),
+            LuaStat::TypeAliasStat(node) => node.syntax(),
+            LuaStat::ContinueStat(node) => node.syntax(),
+            LuaStat::GlobalStat(node) => node.syntax(),
         }
     }
 
@@ -61,7 +67,7 @@ impl LuaAstNode for LuaStat {
     {
         match kind {
             LuaSyntaxKind::LocalStat => true,
-            LuaSyntaxKind::AssignStat => true,
+            LuaSyntaxKind::AssignStat | LuaSyntaxKind::CompoundAssignStat => true,
             LuaSyntaxKind::CallExprStat => true,
             LuaSyntaxKind::FuncStat => true,
             LuaSyntaxKind::LocalFuncStat => true,
@@ -76,6 +82,8 @@ impl LuaAstNode for LuaStat {
             LuaSyntaxKind::GotoStat => true,
             LuaSyntaxKind::LabelStat => true,
             LuaSyntaxKind::EmptyStat => true,
+            LuaSyntaxKind::TypeAliasStat => true,
+            LuaSyntaxKind::ContinueStat => true,
             _ => false,
         }
     }
@@ -86,7 +94,9 @@ impl LuaAstNode for LuaStat {
     {
         match syntax.kind().into() {
             LuaSyntaxKind::LocalStat => Some(LuaStat::LocalStat(LuaLocalStat::cast(syntax)?)),
-            LuaSyntaxKind::AssignStat => Some(LuaStat::AssignStat(LuaAssignStat::cast(syntax)?)),
+            LuaSyntaxKind::AssignStat | LuaSyntaxKind::CompoundAssignStat => {
+                Some(LuaStat::AssignStat(LuaAssignStat::cast(syntax)?))
+            }
             LuaSyntaxKind::CallExprStat => {
                 Some(LuaStat::CallExprStat(LuaCallExprStat::cast(syntax)?))
             }
@@ -107,6 +117,13 @@ impl LuaAstNode for LuaStat {
             LuaSyntaxKind::GotoStat => Some(LuaStat::GotoStat(LuaGotoStat::cast(syntax)?)),
             LuaSyntaxKind::LabelStat => Some(LuaStat::LabelStat(LuaLabelStat::cast(syntax)?)),
             LuaSyntaxKind::EmptyStat => Some(LuaStat::EmptyStat(LuaEmptyStat::cast(syntax)?)),
+            LuaSyntaxKind::TypeAliasStat => {
+                Some(LuaStat::TypeAliasStat(LuaTypeAliasStat::cast(syntax)?))
+            }
+            LuaSyntaxKind::ContinueStat => {
+                Some(LuaStat::ContinueStat(LuaContinueStat::cast(syntax)?))
+            }
+            LuaSyntaxKind::GlobalStat => Some(LuaStat::GlobalStat(LuaGlobalStat::cast(syntax)?)),
             _ => None,
         }
     }
@@ -244,14 +261,17 @@ impl LuaAstNode for LuaAssignStat {
     where
         Self: Sized,
     {
-        kind == LuaSyntaxKind::AssignStat
+        matches!(
+            kind,
+            LuaSyntaxKind::AssignStat | LuaSyntaxKind::CompoundAssignStat
+        )
     }
 
     fn cast(syntax: LuaSyntaxNode) -> Option<Self>
     where
         Self: Sized,
     {
-        if syntax.kind() == LuaSyntaxKind::AssignStat.into() {
+        if Self::can_cast(syntax.kind().into()) {
             Some(Self { syntax })
         } else {
             None
@@ -267,8 +287,10 @@ impl LuaAssignStat {
         let mut exprs = Vec::new();
         let mut meet_assign = false;
         for child in self.syntax.children_with_tokens() {
-            if child.kind() == LuaTokenKind::TkAssign.into() {
-                meet_assign = true;
+            if let Some(token) = child.as_token() {
+                if is_assign_op(token.kind().into()) {
+                    meet_assign = true;
+                }
             }
 
             if let Some(node) = child.into_node() {
@@ -284,6 +306,51 @@ impl LuaAssignStat {
 
         (vars, exprs)
     }
+
+    /// `=` or a compound operator such as `+=`
+    pub fn get_assign_op(&self) -> Option<LuaGeneralToken> {
+        let token = self
+            .syntax
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .find(|it| is_assign_op(it.kind().into()))?;
+        LuaGeneralToken::cast(token)
+    }
+
+    /// `a += 1`, luau or the `compoundAssign` syntax extension
+    pub fn is_compound_assign(&self) -> bool {
+        self.syntax.kind() == LuaSyntaxKind::CompoundAssignStat.into()
+    }
+
+    /// the binary operator of `a += 1`
+    pub fn get_compound_op(&self) -> Option<BinaryOperator> {
+        self.syntax.children_with_tokens().find_map(|child| {
+            let kind = child.as_token()?.kind().into();
+            if is_compound_assign_op(kind) {
+                Some(LuaOpKind::to_compound_assign_operator(kind))
+            } else {
+                None
+            }
+        })
+    }
+}
+
+fn is_assign_op(kind: LuaTokenKind) -> bool {
+    kind == LuaTokenKind::TkAssign || is_compound_assign_op(kind)
+}
+
+fn is_compound_assign_op(kind: LuaTokenKind) -> bool {
+    matches!(
+        kind,
+        LuaTokenKind::TkPlusAssign
+            | LuaTokenKind::TkMinusAssign
+            | LuaTokenKind::TkMulAssign
+            | LuaTokenKind::TkDivAssign
+            | LuaTokenKind::TkIDivAssign
+            | LuaTokenKind::TkModAssign
+            | LuaTokenKind::TkPowAssign
+            | LuaTokenKind::TkConcatAssign
+    )
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -974,3 +1041,143 @@ impl LuaAstNode for LuaEmptyStat {
 }
 
 impl LuaCommentOwner for LuaEmptyStat {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LuaTypeAliasStat {
+    syntax: LuaSyntaxNode,
+}
+
+impl LuaAstNode for LuaTypeAliasStat {
+    fn syntax(&self) -> &LuaSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: LuaSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == LuaSyntaxKind::TypeAliasStat
+    }
+
+    fn cast(syntax: LuaSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if syntax.kind() == LuaSyntaxKind::TypeAliasStat.into() {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl LuaCommentOwner for LuaTypeAliasStat {}
+
+impl LuaTypeAliasStat {
+    /// `export` and `type` are lexed as names, the alias name is the last one
+    pub fn get_name_token(&self) -> Option<LuaNameToken> {
+        self.tokens::<LuaNameToken>().last()
+    }
+
+    pub fn is_export(&self) -> bool {
+        self.tokens::<LuaNameToken>()
+            .next()
+            .is_some_and(|token| token.get_name_text() == "export")
+    }
+
+    pub fn get_generic_decl_list(&self) -> Option<LuaDocGenericDeclList> {
+        self.child()
+    }
+
+    pub fn get_type(&self) -> Option<LuaDocType> {
+        self.child()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LuaContinueStat {
+    syntax: LuaSyntaxNode,
+}
+
+impl LuaAstNode for LuaContinueStat {
+    fn syntax(&self) -> &LuaSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: LuaSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == LuaSyntaxKind::ContinueStat
+    }
+
+    fn cast(syntax: LuaSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if syntax.kind() == LuaSyntaxKind::ContinueStat.into() {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl LuaCommentOwner for LuaContinueStat {}
+
+/// `global x`, `global<const> x, y = 1, 2`, `global *` or `global function f() end`, Lua 5.5
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LuaGlobalStat {
+    syntax: LuaSyntaxNode,
+}
+
+impl LuaAstNode for LuaGlobalStat {
+    fn syntax(&self) -> &LuaSyntaxNode {
+        &self.syntax
+    }
+
+    fn can_cast(kind: LuaSyntaxKind) -> bool
+    where
+        Self: Sized,
+    {
+        kind == LuaSyntaxKind::GlobalStat
+    }
+
+    fn cast(syntax: LuaSyntaxNode) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if syntax.kind() == LuaSyntaxKind::GlobalStat.into() {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+}
+
+impl LuaCommentOwner for LuaGlobalStat {}
+
+impl LuaGlobalStat {
+    pub fn get_global_name_list(&self) -> LuaAstChildren<LuaGlobalName> {
+        self.children()
+    }
+
+    /// The attribute before the names, it applies to every name, `global<const> *`
+    pub fn get_attrib(&self) -> Option<LuaLocalAttribute> {
+        self.child()
+    }
+
+    /// `global *`, every free name after it is a global
+    pub fn is_all(&self) -> bool {
+        self.token_by_kind(LuaTokenKind::TkMul).is_some()
+    }
+
+    pub fn get_value_exprs(&self) -> LuaAstChildren<LuaExpr> {
+        self.children()
+    }
+
+    /// The function of `global function f() end`
+    pub fn get_closure(&self) -> Option<LuaClosureExpr> {
+        self.child()
+    }
+}
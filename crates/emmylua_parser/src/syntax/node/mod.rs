@@ -25,6 +25,8 @@ pub enum LuaAst {
     LuaCallExprStat(LuaCallExprStat),
     LuaLabelStat(LuaLabelStat),
     LuaBreakStat(LuaBreakStat),
+    LuaTypeAliasStat(LuaTypeAliasStat),
+    LuaContinueStat(LuaContinueStat),
     LuaGotoStat(LuaGotoStat),
     LuaDoStat(LuaDoStat),
     LuaWhileStat(LuaWhileStat),
@@ -35,6 +37,7 @@ pub enum LuaAst {
     LuaFuncStat(LuaFuncStat),
     LuaLocalFuncStat(LuaLocalFuncStat),
     LuaReturnStat(LuaReturnStat),
+    LuaGlobalStat(LuaGlobalStat),
 
     // exprs
     LuaNameExpr(LuaNameExpr),
@@ -46,6 +49,9 @@ pub enum LuaAst {
     LuaCallExpr(LuaCallExpr),
     LuaLiteralExpr(LuaLiteralExpr),
     LuaClosureExpr(LuaClosureExpr),
+    LuaCastExpr(LuaCastExpr),
+    LuaIfExpr(LuaIfExpr),
+    LuaInterpStringExpr(LuaInterpStringExpr),
 
     // other lua struct
     LuaTableField(LuaTableField),
@@ -53,6 +59,7 @@ pub enum LuaAst {
     LuaParamName(LuaParamName),
     LuaCallArgList(LuaCallArgList),
     LuaLocalName(LuaLocalName),
+    LuaGlobalName(LuaGlobalName),
     LuaLocalAttribute(LuaLocalAttribute),
     LuaElseIfClauseStat(LuaElseIfClauseStat),
     LuaElseClauseStat(LuaElseClauseStat),
@@ -86,6 +93,7 @@ pub enum LuaAst {
     LuaDocTagAsync(LuaDocTagAsync),
     LuaDocTagAs(LuaDocTagAs),
     LuaDocTagReturnCast(LuaDocTagReturnCast),
+    LuaDocTagLanguage(LuaDocTagLanguage),
 
     // doc type
     LuaDocNameType(LuaDocNameType),
@@ -112,9 +120,12 @@ impl LuaAstNode for LuaAst {
             LuaAst::LuaBlock(node) => node.syntax(),
             LuaAst::LuaAssignStat(node) => node.syntax(),
             LuaAst::LuaLocalStat(node) => node.syntax(),
+            LuaAst::LuaGlobalStat(node) => node.syntax(),
             LuaAst::LuaCallExprStat(node) => node.syntax(),
             LuaAst::LuaLabelStat(node) => node.syntax(),
             LuaAst::LuaBreakStat(node) => node.syntax(),
+            LuaAst::LuaTypeAliasStat(node) => node.syntax(),
+            LuaAst::LuaContinueStat(node) => node.syntax(),
             LuaAst::LuaGotoStat(node) => node.syntax(),
             LuaAst::LuaDoStat(node) => node.syntax(),
             LuaAst::LuaWhileStat(node) => node.syntax(),
@@ -134,12 +145,16 @@ impl LuaAstNode for LuaAst {
             LuaAst::LuaCallExpr(node) => node.syntax(),
             LuaAst::LuaLiteralExpr(node) => node.syntax(),
             LuaAst::LuaClosureExpr(node) => node.syntax(),
+            LuaAst::LuaCastExpr(node) => node.syntax(),
+            LuaAst::LuaIfExpr(node) => node.syntax(),
+            LuaAst::LuaInterpStringExpr(node) => node.syntax(),
             LuaAst::LuaComment(node) => node.syntax(),
             LuaAst::LuaTableField(node) => node.syntax(),
             LuaAst::LuaParamList(node) => node.syntax(),
             LuaAst::LuaParamName(node) => node.syntax(),
             LuaAst::LuaCallArgList(node) => node.syntax(),
             LuaAst::LuaLocalName(node) => node.syntax(),
+            LuaAst::LuaGlobalName(node) => node.syntax(),
             LuaAst::LuaLocalAttribute(node) => node.syntax(),
             LuaAst::LuaElseIfClauseStat(node) => node.syntax(),
             LuaAst::LuaElseClauseStat(node) => node.syntax(),
@@ -169,6 +184,7 @@ impl LuaAstNode for LuaAst {
             LuaAst::LuaDocTagAsync(node) => node.syntax(),
             LuaAst::LuaDocTagAs(node) => node.syntax(),
             LuaAst::LuaDocTagReturnCast(node) => node.syntax(),
+            LuaAst::LuaDocTagLanguage(node) => node.syntax(),
             LuaAst::LuaDocNameType(node) => node.syntax(),
             LuaAst::LuaDocArrayType(node) => node.syntax(),
             LuaAst::LuaDocFuncType(node) => node.syntax(),
@@ -193,11 +209,14 @@ impl LuaAstNode for LuaAst {
         match kind {
             LuaSyntaxKind::Chunk => true,
             LuaSyntaxKind::Block => true,
-            LuaSyntaxKind::AssignStat => true,
+            LuaSyntaxKind::AssignStat | LuaSyntaxKind::CompoundAssignStat => true,
             LuaSyntaxKind::LocalStat => true,
+            LuaSyntaxKind::GlobalStat => true,
             LuaSyntaxKind::CallExprStat => true,
             LuaSyntaxKind::LabelStat => true,
             LuaSyntaxKind::BreakStat => true,
+            LuaSyntaxKind::TypeAliasStat => true,
+            LuaSyntaxKind::ContinueStat => true,
             LuaSyntaxKind::GotoStat => true,
             LuaSyntaxKind::DoStat => true,
             LuaSyntaxKind::WhileStat => true,
@@ -224,9 +243,13 @@ impl LuaAstNode for LuaAst {
             | LuaSyntaxKind::SetmetatableCallExpr => true,
             LuaSyntaxKind::LiteralExpr => true,
             LuaSyntaxKind::ClosureExpr => true,
+            LuaSyntaxKind::CastExpr => true,
+            LuaSyntaxKind::IfExpr => true,
+            LuaSyntaxKind::InterpStringExpr => true,
             LuaSyntaxKind::ParamList => true,
             LuaSyntaxKind::CallArgList => true,
             LuaSyntaxKind::LocalName => true,
+            LuaSyntaxKind::GlobalName => true,
             LuaSyntaxKind::TableFieldAssign | LuaSyntaxKind::TableFieldValue => true,
             LuaSyntaxKind::ParamName => true,
             LuaSyntaxKind::Attribute => true,
@@ -259,6 +282,7 @@ impl LuaAstNode for LuaAst {
             LuaSyntaxKind::DocTagAsync => true,
             LuaSyntaxKind::DocTagAs => true,
             LuaSyntaxKind::DocTagReturnCast => true,
+            LuaSyntaxKind::DocTagLanguage => true,
             LuaSyntaxKind::TypeName => true,
             LuaSyntaxKind::TypeArray => true,
             LuaSyntaxKind::TypeFun => true,
@@ -284,13 +308,22 @@ impl LuaAstNode for LuaAst {
         match syntax.kind().into() {
             LuaSyntaxKind::Chunk => LuaChunk::cast(syntax).map(LuaAst::LuaChunk),
             LuaSyntaxKind::Block => LuaBlock::cast(syntax).map(LuaAst::LuaBlock),
-            LuaSyntaxKind::AssignStat => LuaAssignStat::cast(syntax).map(LuaAst::LuaAssignStat),
+            LuaSyntaxKind::AssignStat | LuaSyntaxKind::CompoundAssignStat => {
+                LuaAssignStat::cast(syntax).map(LuaAst::LuaAssignStat)
+            }
             LuaSyntaxKind::LocalStat => LuaLocalStat::cast(syntax).map(LuaAst::LuaLocalStat),
+            LuaSyntaxKind::GlobalStat => LuaGlobalStat::cast(syntax).map(LuaAst::LuaGlobalStat),
             LuaSyntaxKind::CallExprStat => {
                 LuaCallExprStat::cast(syntax).map(LuaAst::LuaCallExprStat)
             }
             LuaSyntaxKind::LabelStat => LuaLabelStat::cast(syntax).map(LuaAst::LuaLabelStat),
             LuaSyntaxKind::BreakStat => LuaBreakStat::cast(syntax).map(LuaAst::LuaBreakStat),
+            LuaSyntaxKind::TypeAliasStat => {
+                LuaTypeAliasStat::cast(syntax).map(LuaAst::LuaTypeAliasStat)
+            }
+            LuaSyntaxKind::ContinueStat => {
+                LuaContinueStat::cast(syntax).map(LuaAst::LuaContinueStat)
+            }
             LuaSyntaxKind::GotoStat => LuaGotoStat::cast(syntax).map(LuaAst::LuaGotoStat),
             LuaSyntaxKind::DoStat => LuaDoStat::cast(syntax).map(LuaAst::LuaDoStat),
             LuaSyntaxKind::WhileStat => LuaWhileStat::cast(syntax).map(LuaAst::LuaWhileStat),
@@ -325,6 +358,11 @@ impl LuaAstNode for LuaAst {
             }
             LuaSyntaxKind::LiteralExpr => LuaLiteralExpr::cast(syntax).map(LuaAst::LuaLiteralExpr),
             LuaSyntaxKind::ClosureExpr => LuaClosureExpr::cast(syntax).map(LuaAst::LuaClosureExpr),
+            LuaSyntaxKind::CastExpr => LuaCastExpr::cast(syntax).map(LuaAst::LuaCastExpr),
+            LuaSyntaxKind::IfExpr => LuaIfExpr::cast(syntax).map(LuaAst::LuaIfExpr),
+            LuaSyntaxKind::InterpStringExpr => {
+                LuaInterpStringExpr::cast(syntax).map(LuaAst::LuaInterpStringExpr)
+            }
             LuaSyntaxKind::Comment => LuaComment::cast(syntax).map(LuaAst::LuaComment),
             LuaSyntaxKind::TableFieldAssign | LuaSyntaxKind::TableFieldValue => {
                 LuaTableField::cast(syntax).map(LuaAst::LuaTableField)
@@ -333,6 +371,7 @@ impl LuaAstNode for LuaAst {
             LuaSyntaxKind::ParamName => LuaParamName::cast(syntax).map(LuaAst::LuaParamName),
             LuaSyntaxKind::CallArgList => LuaCallArgList::cast(syntax).map(LuaAst::LuaCallArgList),
             LuaSyntaxKind::LocalName => LuaLocalName::cast(syntax).map(LuaAst::LuaLocalName),
+            LuaSyntaxKind::GlobalName => LuaGlobalName::cast(syntax).map(LuaAst::LuaGlobalName),
             LuaSyntaxKind::Attribute => {
                 LuaLocalAttribute::cast(syntax).map(LuaAst::LuaLocalAttribute)
             }
@@ -394,6 +433,9 @@ impl LuaAstNode for LuaAst {
             LuaSyntaxKind::DocTagReturnCast => {
                 LuaDocTagReturnCast::cast(syntax).map(LuaAst::LuaDocTagReturnCast)
             }
+            LuaSyntaxKind::DocTagLanguage => {
+                LuaDocTagLanguage::cast(syntax).map(LuaAst::LuaDocTagLanguage)
+            }
             LuaSyntaxKind::TypeName => LuaDocNameType::cast(syntax).map(LuaAst::LuaDocNameType),
             LuaSyntaxKind::TypeArray => LuaDocArrayType::cast(syntax).map(LuaAst::LuaDocArrayType),
             LuaSyntaxKind::TypeFun => LuaDocFuncType::cast(syntax).map(LuaAst::LuaDocFuncType),
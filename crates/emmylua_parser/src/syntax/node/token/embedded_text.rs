@@ -0,0 +1,176 @@
+use rowan::{TextRange, TextSize};
+
+use crate::{syntax::traits::LuaAstToken, LuaStringToken};
+
+/// The value of a string literal together with where each part of it is in the source
+///
+/// Embedded code, e.g. SQL in a long string or Lua passed to `load`, is analyzed on the value
+/// and its ranges are mapped back through this.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LuaEmbeddedText {
+    text: String,
+    segments: Vec<EmbeddedSegment>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct EmbeddedSegment {
+    text_offset: usize,
+    source_offset: TextSize,
+    // plain text advances with the source, an escape sequence maps to its start
+    plain: bool,
+}
+
+impl LuaEmbeddedText {
+    pub fn new(token: &LuaStringToken) -> Self {
+        let source = token.syntax().text();
+        let base = token.get_position();
+        let (start, end) = token.get_content_bounds().unwrap_or((0, 0));
+        let content = source.get(start..end).unwrap_or_default();
+        let content_start = base + TextSize::from(start as u32);
+        if token.is_long_string() {
+            return Self {
+                text: content.to_string(),
+                segments: vec![EmbeddedSegment {
+                    text_offset: 0,
+                    source_offset: content_start,
+                    plain: true,
+                }],
+            };
+        }
+
+        // escapes such as `\xE9` are single bytes, the value is collected as bytes so that the
+        // offsets in it are the offsets Lua sees
+        let mut text: Vec<u8> = Vec::with_capacity(content.len());
+        let mut segments = Vec::new();
+        let mut chars = content.char_indices().peekable();
+        let mut in_plain = false;
+        while let Some((idx, c)) = chars.next() {
+            let source_offset = content_start + TextSize::from(idx as u32);
+            if c != '\\' {
+                if !in_plain {
+                    segments.push(EmbeddedSegment {
+                        text_offset: text.len(),
+                        source_offset,
+                        plain: true,
+                    });
+                    in_plain = true;
+                }
+                push_char(&mut text, c);
+                continue;
+            }
+
+            in_plain = false;
+            segments.push(EmbeddedSegment {
+                text_offset: text.len(),
+                source_offset,
+                plain: false,
+            });
+            let Some((_, escape)) = chars.next() else {
+                break;
+            };
+            match escape {
+                'a' => text.push(0x07),
+                'b' => text.push(0x08),
+                'f' => text.push(0x0C),
+                'n' => text.push(b'\n'),
+                'r' => text.push(b'\r'),
+                't' => text.push(b'\t'),
+                'v' => text.push(0x0B),
+                '\r' | '\n' => {
+                    // `\` followed by a line break is a line break, `\r\n` counts once
+                    text.push(b'\n');
+                    chars.next_if(|(_, next)| matches!(next, '\r' | '\n') && *next != escape);
+                }
+                'x' => {
+                    let mut hex = String::new();
+                    while hex.len() < 2 {
+                        match chars.next_if(|(_, c)| c.is_ascii_hexdigit()) {
+                            Some((_, c)) => hex.push(c),
+                            None => break,
+                        }
+                    }
+                    if let Ok(value) = u8::from_str_radix(&hex, 16) {
+                        text.push(value);
+                    }
+                }
+                'u' => {
+                    if chars.next_if(|(_, c)| *c == '{').is_some() {
+                        let mut hex = String::new();
+                        while let Some((_, c)) = chars.next_if(|(_, c)| c.is_ascii_hexdigit()) {
+                            hex.push(c);
+                        }
+                        chars.next_if(|(_, c)| *c == '}');
+                        if let Some(c) = u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32)
+                        {
+                            push_char(&mut text, c);
+                        }
+                    }
+                }
+                'z' => while chars.next_if(|(_, c)| c.is_whitespace()).is_some() {},
+                '0'..='9' => {
+                    let mut dec = String::from(escape);
+                    while dec.len() < 3 {
+                        match chars.next_if(|(_, c)| c.is_ascii_digit()) {
+                            Some((_, c)) => dec.push(c),
+                            None => break,
+                        }
+                    }
+                    if let Ok(value) = dec.parse::<u8>() {
+                        text.push(value);
+                    }
+                }
+                c => push_char(&mut text, c),
+            }
+        }
+
+        Self {
+            text: bytes_to_text(&text),
+            segments,
+        }
+    }
+
+    /// The value, a byte that is not part of valid UTF-8 is U+001A so the offsets stay byte
+    /// offsets of the value
+    pub fn get_text(&self) -> &str {
+        &self.text
+    }
+
+    /// The source offset of a byte offset in the text
+    pub fn to_source_offset(&self, offset: usize) -> TextSize {
+        let idx = self
+            .segments
+            .partition_point(|segment| segment.text_offset <= offset);
+        let Some(segment) = idx.checked_sub(1).and_then(|idx| self.segments.get(idx)) else {
+            return self
+                .segments
+                .first()
+                .map(|segment| segment.source_offset)
+                .unwrap_or_default();
+        };
+        if segment.plain {
+            segment.source_offset + TextSize::from((offset - segment.text_offset) as u32)
+        } else {
+            segment.source_offset
+        }
+    }
+
+    /// The source range of a range in the text
+    pub fn to_source_range(&self, range: TextRange) -> TextRange {
+        let start = self.to_source_offset(range.start().into());
+        let end = self.to_source_offset(range.end().into());
+        TextRange::new(start, end.max(start))
+    }
+}
+
+fn push_char(bytes: &mut Vec<u8>, c: char) {
+    bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+}
+
+fn bytes_to_text(bytes: &[u8]) -> String {
+    let mut text = String::with_capacity(bytes.len());
+    for chunk in bytes.utf8_chunks() {
+        text.push_str(chunk.valid());
+        text.extend(chunk.invalid().iter().map(|_| '\u{001A}'));
+    }
+    text
+}
@@ -0,0 +1,111 @@
+use std::ops::Range;
+
+use crate::LuaLanguageLevel;
+
+// the flags, width and precision `string.format` accepts
+const FORMAT_FLAGS: &str = "-+ #0";
+const MAX_FLAGS: usize = 5;
+const MAX_DIGITS: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LuaFormatSpecKind {
+    /// `%c`, `%d`, `%i`, `%o`, `%u`, `%x` and `%X`
+    Integer,
+    /// `%e`, `%E`, `%f`, `%g` and `%G`, `%a` and `%A` from Lua 5.2, `%F` from Lua 5.4
+    Float,
+    /// `%s`
+    String,
+    /// `%q`
+    Quoted,
+    /// `%p`, from Lua 5.4
+    Pointer,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LuaFormatSpec {
+    pub kind: LuaFormatSpecKind,
+    /// byte range of the whole specifier in the format text, e.g. `%-5.2f`
+    pub range: Range<usize>,
+}
+
+/// A `string.format` format string
+///
+/// `%%` takes no argument and is not a spec. Analysis stops at the first invalid conversion or
+/// one the runtime does not support, `error` is its byte range. LuaJIT 2.1 has all of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LuaFormatString {
+    pub specs: Vec<LuaFormatSpec>,
+    pub error: Option<Range<usize>>,
+}
+
+impl LuaFormatString {
+    pub fn parse(text: &str, level: LuaLanguageLevel) -> Self {
+        let has_hex_float = level != LuaLanguageLevel::Lua51;
+        let has_lua54_conversions =
+            level.is_version_at_least(LuaLanguageLevel::Lua54) || level == LuaLanguageLevel::LuaJIT;
+        let mut specs = Vec::new();
+        let mut chars = text.char_indices().peekable();
+        while let Some((start, c)) = chars.next() {
+            if c != '%' {
+                continue;
+            }
+            if let Some((_, '%')) = chars.peek() {
+                chars.next();
+                continue;
+            }
+
+            let mut flags = 0;
+            while chars.next_if(|(_, c)| FORMAT_FLAGS.contains(*c)).is_some() {
+                flags += 1;
+            }
+            let mut width = 0;
+            while chars.next_if(|(_, c)| c.is_ascii_digit()).is_some() {
+                width += 1;
+            }
+            let mut precision = 0;
+            let has_precision = chars.next_if(|(_, c)| *c == '.').is_some();
+            if has_precision {
+                while chars.next_if(|(_, c)| c.is_ascii_digit()).is_some() {
+                    precision += 1;
+                }
+            }
+
+            let Some((idx, conversion)) = chars.next() else {
+                return LuaFormatString {
+                    specs,
+                    error: Some(start..text.len()),
+                };
+            };
+            let end = idx + conversion.len_utf8();
+            let has_modifiers = flags > 0 || width > 0 || has_precision;
+            let kind = match conversion {
+                'c' | 'd' | 'i' | 'o' | 'u' | 'x' | 'X' => Some(LuaFormatSpecKind::Integer),
+                'e' | 'E' | 'f' | 'g' | 'G' => Some(LuaFormatSpecKind::Float),
+                'a' | 'A' if has_hex_float => Some(LuaFormatSpecKind::Float),
+                'F' if has_lua54_conversions => Some(LuaFormatSpecKind::Float),
+                's' => Some(LuaFormatSpecKind::String),
+                'q' if !has_modifiers => Some(LuaFormatSpecKind::Quoted),
+                'p' if has_lua54_conversions => Some(LuaFormatSpecKind::Pointer),
+                _ => None,
+            };
+            match kind {
+                Some(kind)
+                    if flags <= MAX_FLAGS && width <= MAX_DIGITS && precision <= MAX_DIGITS =>
+                {
+                    specs.push(LuaFormatSpec {
+                        kind,
+                        range: start..end,
+                    });
+                }
+                _ => {
+                    return LuaFormatString {
+                        specs,
+                        error: Some(start..end),
+                    }
+                }
+            }
+        }
+
+        LuaFormatString { specs, error: None }
+    }
+}
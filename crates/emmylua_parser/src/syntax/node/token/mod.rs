@@ -1,9 +1,15 @@
+mod embedded_text;
+mod format_analyzer;
 mod number_analyzer;
+mod pattern_analyzer;
 mod string_analyzer;
 mod test;
 mod tokens;
 
+pub use embedded_text::LuaEmbeddedText;
+pub use format_analyzer::*;
 pub use number_analyzer::{float_token_value, int_token_value};
+pub use pattern_analyzer::*;
 pub use string_analyzer::string_token_value;
 #[allow(unused)]
 pub use tokens::*;
@@ -1,5 +1,5 @@
 use crate::{
-    parser_error::{LuaParseError, LuaParseErrorKind},
+    parser_error::{LuaParseError, LuaParseErrorCode, LuaParseErrorKind},
     LuaSyntaxToken,
 };
 
@@ -66,6 +66,7 @@ pub fn float_token_value(token: &LuaSyntaxToken) -> Result<f64, LuaParseError> {
                 ),
                 token.text_range(),
             )
+            .with_code(LuaParseErrorCode::MalformedNumber)
         })?;
 
         if !exponent_part.is_empty() {
@@ -123,7 +124,8 @@ pub fn int_token_value(token: &LuaSyntaxToken) -> Result<i64, LuaParseError> {
                         text = text
                     ),
                     range,
-                ))
+                )
+                .with_code(LuaParseErrorCode::MalformedNumber))
             } else {
                 Err(LuaParseError::new(
                     LuaParseErrorKind::SyntaxError,
@@ -133,7 +135,8 @@ pub fn int_token_value(token: &LuaSyntaxToken) -> Result<i64, LuaParseError> {
                         err = e
                     ),
                     range,
-                ))
+                )
+                .with_code(LuaParseErrorCode::MalformedNumber))
             }
         }
     }
@@ -0,0 +1,328 @@
+use std::ops::Range;
+
+use crate::LuaLanguageLevel;
+
+// LUA_MAXCAPTURES
+const MAX_CAPTURES: usize = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LuaPatternItemKind {
+    /// `^` at the start and `$` at the end
+    Anchor,
+    /// `.` and `%a`, `%d` ...
+    Class,
+    /// `%.`, `%%` ...
+    Escape,
+    /// `[...]` and `[^...]`
+    Set,
+    /// `*`, `+`, `-` and `?` after a single char class
+    Quantifier,
+    /// `(`, `)` and `()`
+    Capture,
+    /// `%1` ... `%9`
+    BackReference,
+    /// `%bxy`
+    Balance,
+    /// `%f[set]`
+    Frontier,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LuaPatternItem {
+    pub kind: LuaPatternItemKind,
+    /// byte range in the pattern text
+    pub range: Range<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LuaPatternErrorKind {
+    /// malformed pattern (ends with '%')
+    EndsWithPercent,
+    /// malformed pattern (missing ']')
+    MissingBracket,
+    /// malformed pattern (missing arguments to '%b')
+    MissingBalanceArgs,
+    /// missing '[' after '%f' in pattern
+    MissingFrontierSet,
+    /// invalid capture index
+    InvalidCaptureIndex,
+    /// invalid pattern capture, a `)` without `(`
+    InvalidCapture,
+    /// unfinished capture
+    UnfinishedCapture,
+    /// too many captures
+    TooManyCaptures,
+    /// invalid use of '%' in replacement string
+    InvalidReplacement,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LuaPatternError {
+    pub kind: LuaPatternErrorKind,
+    /// byte range in the pattern text
+    pub range: Range<usize>,
+}
+
+/// A Lua pattern as used by `string.find`, `match`, `gmatch` and `gsub`
+///
+/// Analysis stops at the first error like the Lua runtime does, the items before it are kept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LuaPattern {
+    pub items: Vec<LuaPatternItem>,
+    pub capture_count: usize,
+    pub error: Option<LuaPatternError>,
+}
+
+impl LuaPattern {
+    pub fn parse(text: &str) -> Self {
+        let mut analyzer = PatternAnalyzer {
+            chars: text.char_indices().collect(),
+            len: text.len(),
+            items: Vec::new(),
+            captures: Vec::new(),
+        };
+        let error = analyzer.analyze().err();
+        LuaPattern {
+            items: analyzer.items,
+            capture_count: analyzer.captures.len(),
+            error,
+        }
+    }
+
+    /// Checks the replacement string of `gsub`, `%0` to `%9` and `%%` are the only escapes.
+    /// Before Lua 5.2 any other `%x` is a literal `x`, so only the capture indexes are checked
+    pub fn check_replacement(
+        &self,
+        replacement: &str,
+        level: LuaLanguageLevel,
+    ) -> Option<LuaPatternError> {
+        let strict_escapes = level.is_version_at_least(LuaLanguageLevel::Lua52);
+        let mut chars = replacement.char_indices();
+        while let Some((start, c)) = chars.next() {
+            if c != '%' {
+                continue;
+            }
+            let Some((idx, next)) = chars.next() else {
+                return strict_escapes.then_some(LuaPatternError {
+                    kind: LuaPatternErrorKind::InvalidReplacement,
+                    range: start..replacement.len(),
+                });
+            };
+            let end = idx + next.len_utf8();
+            match next {
+                '%' | '0' => {}
+                '1'..='9' => {
+                    // without captures `%1` is the whole match
+                    let capture = next as usize - '0' as usize;
+                    if capture > self.capture_count.max(1) {
+                        return Some(LuaPatternError {
+                            kind: LuaPatternErrorKind::InvalidCaptureIndex,
+                            range: start..end,
+                        });
+                    }
+                }
+                _ if strict_escapes => {
+                    return Some(LuaPatternError {
+                        kind: LuaPatternErrorKind::InvalidReplacement,
+                        range: start..end,
+                    })
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+struct PatternAnalyzer {
+    chars: Vec<(usize, char)>,
+    len: usize,
+    items: Vec<LuaPatternItem>,
+    // whether each capture is closed
+    captures: Vec<bool>,
+}
+
+impl PatternAnalyzer {
+    fn offset(&self, idx: usize) -> usize {
+        self.chars
+            .get(idx)
+            .map(|(offset, _)| *offset)
+            .unwrap_or(self.len)
+    }
+
+    fn char_at(&self, idx: usize) -> Option<char> {
+        self.chars.get(idx).map(|(_, c)| *c)
+    }
+
+    fn push(&mut self, kind: LuaPatternItemKind, start: usize, end: usize) {
+        self.items.push(LuaPatternItem {
+            kind,
+            range: self.offset(start)..self.offset(end),
+        });
+    }
+
+    fn error(&self, kind: LuaPatternErrorKind, start: usize, end: usize) -> LuaPatternError {
+        LuaPatternError {
+            kind,
+            range: self.offset(start)..self.offset(end),
+        }
+    }
+
+    fn analyze(&mut self) -> Result<(), LuaPatternError> {
+        let count = self.chars.len();
+        let mut open_captures = Vec::new();
+        // a quantifier only follows a single char class, otherwise it is a plain char
+        let mut after_single = false;
+        let mut i = 0;
+        if self.char_at(0) == Some('^') {
+            self.push(LuaPatternItemKind::Anchor, 0, 1);
+            i = 1;
+        }
+
+        while let Some(c) = self.char_at(i) {
+            let start = i;
+            match c {
+                '(' => {
+                    if self.char_at(i + 1) == Some(')') {
+                        self.captures.push(true);
+                        i += 2;
+                    } else {
+                        open_captures.push((self.captures.len(), i));
+                        self.captures.push(false);
+                        i += 1;
+                    }
+                    if self.captures.len() > MAX_CAPTURES {
+                        return Err(self.error(LuaPatternErrorKind::TooManyCaptures, start, i));
+                    }
+                    self.push(LuaPatternItemKind::Capture, start, i);
+                    after_single = false;
+                }
+                ')' => {
+                    let Some((capture, _)) = open_captures.pop() else {
+                        return Err(self.error(LuaPatternErrorKind::InvalidCapture, i, i + 1));
+                    };
+                    self.captures[capture] = true;
+                    i += 1;
+                    self.push(LuaPatternItemKind::Capture, start, i);
+                    after_single = false;
+                }
+                '$' if i + 1 == count => {
+                    i += 1;
+                    self.push(LuaPatternItemKind::Anchor, start, i);
+                    after_single = false;
+                }
+                '%' => {
+                    let Some(next) = self.char_at(i + 1) else {
+                        return Err(self.error(LuaPatternErrorKind::EndsWithPercent, i, count));
+                    };
+                    match next {
+                        'b' => {
+                            if i + 4 > count {
+                                return Err(self.error(
+                                    LuaPatternErrorKind::MissingBalanceArgs,
+                                    i,
+                                    count,
+                                ));
+                            }
+                            i += 4;
+                            self.push(LuaPatternItemKind::Balance, start, i);
+                            after_single = false;
+                        }
+                        'f' => {
+                            i += 2;
+                            if self.char_at(i) != Some('[') {
+                                return Err(self.error(
+                                    LuaPatternErrorKind::MissingFrontierSet,
+                                    start,
+                                    i,
+                                ));
+                            }
+                            i = self.set_end(i)?;
+                            self.push(LuaPatternItemKind::Frontier, start, i);
+                            after_single = false;
+                        }
+                        '0'..='9' => {
+                            let capture = next as usize - '0' as usize;
+                            i += 2;
+                            if capture == 0 || self.captures.get(capture - 1) != Some(&true) {
+                                return Err(self.error(
+                                    LuaPatternErrorKind::InvalidCaptureIndex,
+                                    start,
+                                    i,
+                                ));
+                            }
+                            self.push(LuaPatternItemKind::BackReference, start, i);
+                            after_single = false;
+                        }
+                        _ => {
+                            i += 2;
+                            let kind = if is_class_letter(next) {
+                                LuaPatternItemKind::Class
+                            } else {
+                                LuaPatternItemKind::Escape
+                            };
+                            self.push(kind, start, i);
+                            after_single = true;
+                        }
+                    }
+                }
+                '[' => {
+                    i = self.set_end(i)?;
+                    self.push(LuaPatternItemKind::Set, start, i);
+                    after_single = true;
+                }
+                '.' => {
+                    i += 1;
+                    self.push(LuaPatternItemKind::Class, start, i);
+                    after_single = true;
+                }
+                '*' | '+' | '-' | '?' if after_single => {
+                    i += 1;
+                    self.push(LuaPatternItemKind::Quantifier, start, i);
+                    after_single = false;
+                }
+                _ => {
+                    i += 1;
+                    after_single = true;
+                }
+            }
+        }
+
+        if let Some((_, start)) = open_captures.last() {
+            return Err(self.error(LuaPatternErrorKind::UnfinishedCapture, *start, start + 1));
+        }
+        Ok(())
+    }
+
+    // the index after the `]` closing the set that starts at `start`
+    fn set_end(&self, start: usize) -> Result<usize, LuaPatternError> {
+        let count = self.chars.len();
+        let mut i = start + 1;
+        if self.char_at(i) == Some('^') {
+            i += 1;
+        }
+        // the first char of a set is never the closing `]`
+        loop {
+            let Some(c) = self.char_at(i) else {
+                return Err(self.error(LuaPatternErrorKind::MissingBracket, start, count));
+            };
+            i += 1;
+            if c == '%' {
+                if i >= count {
+                    return Err(self.error(LuaPatternErrorKind::MissingBracket, start, count));
+                }
+                i += 1;
+            }
+            if self.char_at(i) == Some(']') {
+                return Ok(i + 1);
+            }
+        }
+    }
+}
+
+fn is_class_letter(c: char) -> bool {
+    matches!(
+        c.to_ascii_lowercase(),
+        'a' | 'c' | 'd' | 'g' | 'l' | 'p' | 's' | 'u' | 'w' | 'x'
+    )
+}
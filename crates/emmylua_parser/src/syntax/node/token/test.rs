@@ -3,8 +3,11 @@ mod tests {
     use crate::{
         kind::{LuaSyntaxKind, LuaTokenKind},
         syntax::node::{float_token_value, int_token_value, string_token_value},
-        LuaSyntaxNode, LuaSyntaxToken,
+        LuaAstNode, LuaAstToken, LuaEmbeddedText, LuaFormatSpecKind, LuaFormatString,
+        LuaLanguageLevel, LuaParser, LuaPattern, LuaPatternErrorKind, LuaPatternItemKind,
+        LuaStringToken, LuaSyntaxNode, LuaSyntaxToken, ParserConfig,
     };
+    use rowan::TextRange;
 
     fn get_token(text: &str, kind: LuaTokenKind) -> LuaSyntaxToken {
         let mut builder = rowan::GreenNodeBuilder::new();
@@ -181,4 +184,235 @@ mod tests {
     test_int_token_value!(test_number_token_value_int7, "0x0", 0);
     test_int_token_value!(test_number_token_value_int8, "0x11LL", 17);
     test_int_token_value!(test_number_token_value_int9, "0b10101", 21);
+
+    fn pattern_error(pattern: &str) -> Option<LuaPatternErrorKind> {
+        LuaPattern::parse(pattern).error.map(|error| error.kind)
+    }
+
+    #[test]
+    fn test_lua_pattern_items() {
+        let pattern = LuaPattern::parse("^(%d+)[%a_]-.%.$");
+        let items = pattern
+            .items
+            .iter()
+            .map(|item| (item.kind, item.range.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            items,
+            vec![
+                (LuaPatternItemKind::Anchor, 0..1),
+                (LuaPatternItemKind::Capture, 1..2),
+                (LuaPatternItemKind::Class, 2..4),
+                (LuaPatternItemKind::Quantifier, 4..5),
+                (LuaPatternItemKind::Capture, 5..6),
+                (LuaPatternItemKind::Set, 6..11),
+                (LuaPatternItemKind::Quantifier, 11..12),
+                (LuaPatternItemKind::Class, 12..13),
+                (LuaPatternItemKind::Escape, 13..15),
+                (LuaPatternItemKind::Anchor, 15..16),
+            ]
+        );
+        assert_eq!(pattern.capture_count, 1);
+        assert_eq!(pattern.error, None);
+    }
+
+    #[test]
+    fn test_lua_pattern_errors() {
+        assert_eq!(pattern_error("[]]"), None);
+        assert_eq!(pattern_error("[^%]]"), None);
+        assert_eq!(pattern_error("%bxy%f[%w]()"), None);
+        assert_eq!(pattern_error("(a)%1"), None);
+        assert_eq!(pattern_error("**"), None);
+        assert_eq!(
+            pattern_error("a%"),
+            Some(LuaPatternErrorKind::EndsWithPercent)
+        );
+        assert_eq!(
+            pattern_error("[a"),
+            Some(LuaPatternErrorKind::MissingBracket)
+        );
+        assert_eq!(
+            pattern_error("[%]"),
+            Some(LuaPatternErrorKind::MissingBracket)
+        );
+        assert_eq!(
+            pattern_error("%b("),
+            Some(LuaPatternErrorKind::MissingBalanceArgs)
+        );
+        assert_eq!(
+            pattern_error("%fa"),
+            Some(LuaPatternErrorKind::MissingFrontierSet)
+        );
+        assert_eq!(
+            pattern_error("(a%1)"),
+            Some(LuaPatternErrorKind::InvalidCaptureIndex)
+        );
+        assert_eq!(
+            pattern_error("a)"),
+            Some(LuaPatternErrorKind::InvalidCapture)
+        );
+        assert_eq!(
+            pattern_error("(a"),
+            Some(LuaPatternErrorKind::UnfinishedCapture)
+        );
+        assert_eq!(
+            pattern_error(&"()".repeat(33)),
+            Some(LuaPatternErrorKind::TooManyCaptures)
+        );
+
+        let pattern = LuaPattern::parse("(%w+)=(%w+)");
+        assert_eq!(
+            pattern.check_replacement("%2=%1%%%0", LuaLanguageLevel::Lua54),
+            None
+        );
+        assert_eq!(
+            pattern
+                .check_replacement("%3", LuaLanguageLevel::Lua51)
+                .map(|error| error.kind),
+            Some(LuaPatternErrorKind::InvalidCaptureIndex)
+        );
+        assert_eq!(
+            pattern
+                .check_replacement("%a", LuaLanguageLevel::Lua54)
+                .map(|error| error.range),
+            Some(0..2)
+        );
+        // Lua 5.1 and LuaJIT read `%a` as `a`
+        assert_eq!(
+            pattern.check_replacement("%a%", LuaLanguageLevel::LuaJIT),
+            None
+        );
+    }
+
+    #[test]
+    fn test_lua_format_string() {
+        let format = LuaFormatString::parse("%-5d%% %s %.3f %q %5.2x", LuaLanguageLevel::Lua54);
+        let kinds = format
+            .specs
+            .iter()
+            .map(|spec| spec.kind)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![
+                LuaFormatSpecKind::Integer,
+                LuaFormatSpecKind::String,
+                LuaFormatSpecKind::Float,
+                LuaFormatSpecKind::Quoted,
+                LuaFormatSpecKind::Integer,
+            ]
+        );
+        assert_eq!(format.specs[0].range, 0..4);
+        assert_eq!(format.error, None);
+
+        assert_eq!(
+            LuaFormatString::parse("%d %y", LuaLanguageLevel::Lua54).error,
+            Some(3..5)
+        );
+        assert_eq!(
+            LuaFormatString::parse("%5q", LuaLanguageLevel::Lua54).error,
+            Some(0..3)
+        );
+        assert_eq!(
+            LuaFormatString::parse("%100d", LuaLanguageLevel::Lua54).error,
+            Some(0..5)
+        );
+        assert_eq!(
+            LuaFormatString::parse("50%", LuaLanguageLevel::Lua54).error,
+            Some(2..3)
+        );
+
+        assert_eq!(
+            LuaFormatString::parse("%a %F %p", LuaLanguageLevel::Lua54).error,
+            None
+        );
+        assert_eq!(
+            LuaFormatString::parse("%a %F", LuaLanguageLevel::Lua53).error,
+            Some(3..5)
+        );
+        assert_eq!(
+            LuaFormatString::parse("%a", LuaLanguageLevel::Lua51).error,
+            Some(0..2)
+        );
+        assert_eq!(
+            LuaFormatString::parse("%p", LuaLanguageLevel::Lua52).error,
+            Some(0..2)
+        );
+    }
+
+    #[test]
+    fn test_string_value_range() {
+        let code = "a('[%d]', [==[\n%s]==], \"\\t%d\")";
+        let tree = LuaParser::parse(code, ParserConfig::default());
+        let strings = tree
+            .get_chunk_node()
+            .syntax()
+            .descendants_with_tokens()
+            .filter_map(|element| element.into_token().and_then(LuaStringToken::cast))
+            .collect::<Vec<_>>();
+        assert_eq!(strings.len(), 3);
+
+        let range = strings[0].get_value_range(1..3).unwrap();
+        assert_eq!(&code[range], "%d");
+        let range = strings[1].get_value_range(0..2).unwrap();
+        assert_eq!(&code[range], "%s");
+        assert_eq!(strings[2].get_value_range(1..3), None);
+    }
+
+    #[test]
+    fn test_embedded_text() {
+        let code = "a(\"x\\ty\\65z\", [[\nSELECT 1]])";
+        let tree = LuaParser::parse(code, ParserConfig::default());
+        let strings = tree
+            .get_chunk_node()
+            .syntax()
+            .descendants_with_tokens()
+            .filter_map(|element| element.into_token().and_then(LuaStringToken::cast))
+            .collect::<Vec<_>>();
+
+        let text = LuaEmbeddedText::new(&strings[0]);
+        assert_eq!(text.get_text(), "x\tyAz");
+        assert_eq!(
+            &code[text.to_source_range(TextRange::new(0.into(), 1.into()))],
+            "x"
+        );
+        assert_eq!(
+            &code[text.to_source_range(TextRange::new(2.into(), 3.into()))],
+            "y"
+        );
+        assert_eq!(
+            &code[text.to_source_range(TextRange::new(4.into(), 5.into()))],
+            "z"
+        );
+        assert_eq!(u32::from(text.to_source_offset(3)), 7);
+
+        let text = LuaEmbeddedText::new(&strings[1]);
+        assert_eq!(text.get_text(), "SELECT 1");
+        let range = text.to_source_range(TextRange::new(0.into(), 6.into()));
+        assert_eq!(&code[range], "SELECT");
+    }
+
+    #[test]
+    fn test_embedded_text_byte_escapes() {
+        let code = r#"a("\xC3\xA9x\233y")"#;
+        let tree = LuaParser::parse(code, ParserConfig::default());
+        let string = tree
+            .get_chunk_node()
+            .syntax()
+            .descendants_with_tokens()
+            .find_map(|element| element.into_token().and_then(LuaStringToken::cast))
+            .unwrap();
+
+        // the two escapes are the bytes of one character, `\233` alone is not UTF-8
+        let text = LuaEmbeddedText::new(&string);
+        assert_eq!(text.get_text(), "\u{e9}x\u{1a}y");
+        assert_eq!(
+            &code[text.to_source_range(TextRange::new(2.into(), 3.into()))],
+            "x"
+        );
+        assert_eq!(
+            &code[text.to_source_range(TextRange::new(4.into(), 5.into()))],
+            "y"
+        );
+    }
 }
@@ -5,6 +5,10 @@ use crate::{
     VisibilityKind,
 };
 
+use std::ops::Range;
+
+use rowan::{TextRange, TextSize};
+
 use super::{float_token_value, int_token_value, string_token_value};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -100,6 +104,51 @@ impl LuaStringToken {
     pub fn get_value(&self) -> String {
         string_token_value(&self.token).unwrap_or_default()
     }
+
+    /// The source range of `value[range]`, `None` when escapes make the value differ from the
+    /// source text
+    pub fn get_value_range(&self, range: Range<usize>) -> Option<TextRange> {
+        let (start, end) = self.get_content_bounds()?;
+        let content = self.token.text().get(start..end)?;
+        if content != self.get_value() || range.end > content.len() {
+            return None;
+        }
+        let offset = self.token.text_range().start() + TextSize::from(start as u32);
+        Some(TextRange::new(
+            offset + TextSize::from(range.start as u32),
+            offset + TextSize::from(range.end as u32),
+        ))
+    }
+
+    // byte offsets of the text between the delimiters
+    pub(crate) fn get_content_bounds(&self) -> Option<(usize, usize)> {
+        let text = self.token.text();
+        if self.is_long_string() {
+            let level = text[1..].find('[')?;
+            let mut start = level + 2;
+            for newline in ["\r\n", "\n\r", "\n", "\r"] {
+                if text.get(start..)?.starts_with(newline) {
+                    start += newline.len();
+                    break;
+                }
+            }
+            let close = format!("]{}]", "=".repeat(level));
+            match text.strip_suffix(close.as_str()) {
+                Some(content) if content.len() >= start => Some((start, content.len())),
+                _ => Some((start, text.len())),
+            }
+        } else {
+            let quote = text.chars().next()?;
+            match text.strip_suffix(quote) {
+                Some(content) if text.len() >= 2 => Some((1, content.len())),
+                _ => Some((1, text.len())),
+            }
+        }
+    }
+
+    pub fn is_long_string(&self) -> bool {
+        self.token.kind() == LuaTokenKind::TkLongString.into()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -0,0 +1,61 @@
+use rowan::TextRange;
+
+use crate::{
+    parser::{LuaParser, ParserConfig},
+    parser_error::LuaParseError,
+    syntax::node::{LuaChunk, LuaEmbeddedText, LuaStringToken},
+};
+
+use super::LuaSyntaxTree;
+
+/// Lua code in a string literal, e.g. the chunk passed to `load`, parsed as its own chunk
+///
+/// Only the syntax is available: the chunk is not part of the analysis, so its names are not
+/// resolved and it gets no diagnostics besides syntax errors.
+/// Ranges in the tree are relative to the string value, `to_source_range` maps them to the
+/// document that contains the literal.
+#[derive(Debug, Clone)]
+pub struct LuaEmbeddedChunk {
+    text: LuaEmbeddedText,
+    tree: LuaSyntaxTree,
+}
+
+impl LuaEmbeddedChunk {
+    pub fn parse(token: &LuaStringToken, config: ParserConfig) -> Self {
+        let text = LuaEmbeddedText::new(token);
+        let tree = LuaParser::parse(text.get_text(), config);
+        Self { text, tree }
+    }
+
+    pub fn get_tree(&self) -> &LuaSyntaxTree {
+        &self.tree
+    }
+
+    pub fn get_chunk_node(&self) -> LuaChunk {
+        self.tree.get_chunk_node()
+    }
+
+    pub fn get_text(&self) -> &str {
+        self.text.get_text()
+    }
+
+    pub fn to_source_range(&self, range: TextRange) -> TextRange {
+        self.text.to_source_range(range)
+    }
+
+    /// The parse errors with their ranges in the containing document
+    pub fn get_errors(&self) -> Vec<LuaParseError> {
+        self.tree
+            .get_errors()
+            .iter()
+            .map(|error| {
+                let mut error = error.clone();
+                error.range = self.to_source_range(error.range);
+                for label in &mut error.secondary {
+                    label.range = self.to_source_range(label.range);
+                }
+                error
+            })
+            .collect()
+    }
+}
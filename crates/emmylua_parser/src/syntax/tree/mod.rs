@@ -1,7 +1,9 @@
+mod lua_embedded_chunk;
 mod lua_green_builder;
 mod lua_syntax_tree;
 mod lua_tree_builder;
 mod test;
 
+pub use lua_embedded_chunk::LuaEmbeddedChunk;
 pub use lua_syntax_tree::LuaSyntaxTree;
 pub use lua_tree_builder::LuaTreeBuilder;
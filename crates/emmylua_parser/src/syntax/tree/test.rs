@@ -1,6 +1,11 @@
 #[cfg(test)]
 mod test {
-    use crate::{set_locale, LuaAstNode, LuaLanguageLevel, LuaParser, ParserConfig};
+    use crate::{
+        set_locale, LuaAssignStat, LuaAstNode, LuaAstToken, LuaEmbeddedChunk, LuaLanguageLevel,
+        LuaParseErrorCode, LuaParser, LuaStringToken, LuaSyntaxExtension, LuaSyntaxKind,
+        LuaTokenKind, ParserConfig,
+    };
+    use rowan::{TextRange, TextSize};
     // use std::time::Instant;
     use std::{collections::HashMap, thread};
 
@@ -79,4 +84,195 @@ local t
 
         let _ = LuaParser::parse(code, ParserConfig::default());
     }
+
+    #[test]
+    fn test_error_code_unclosed_block() {
+        let code = "local function f()\n    if a then\n    end\n";
+        let tree = LuaParser::parse(code, ParserConfig::default());
+        let errors = tree.get_errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, LuaParseErrorCode::UnclosedBlock);
+        assert_eq!(errors[0].secondary.len(), 1);
+        // points back at `function`
+        assert_eq!(
+            errors[0].secondary[0].range,
+            TextRange::new(TextSize::from(6), TextSize::from(14))
+        );
+    }
+
+    #[test]
+    fn test_error_code_missing_then_and_do() {
+        let tree = LuaParser::parse("if a print(a) end", ParserConfig::default());
+        let errors = tree.get_errors();
+        assert_eq!(errors[0].code, LuaParseErrorCode::MissingThen);
+        assert_eq!(
+            errors[0].secondary[0].range,
+            TextRange::new(TextSize::from(0), TextSize::from(2))
+        );
+
+        assert_eq!(errors[0].expected, vec![LuaTokenKind::TkThen]);
+
+        let tree = LuaParser::parse("if a then elseif b print(b) end", ParserConfig::default());
+        let errors = tree.get_errors();
+        assert_eq!(errors[0].code, LuaParseErrorCode::MissingThen);
+        assert_eq!(
+            errors[0].secondary[0].range,
+            TextRange::new(TextSize::from(10), TextSize::from(16))
+        );
+
+        let tree = LuaParser::parse("for i = 1, 2 print(i) end", ParserConfig::default());
+        let errors = tree.get_errors();
+        assert_eq!(errors[0].code, LuaParseErrorCode::MissingDo);
+        assert_eq!(errors[0].expected, vec![LuaTokenKind::TkDo]);
+
+        let tree = LuaParser::parse("for k in pairs(t) print(k) end", ParserConfig::default());
+        assert_eq!(tree.get_errors()[0].code, LuaParseErrorCode::MissingDo);
+
+        let tree = LuaParser::parse("while a print(a) end", ParserConfig::default());
+        let errors = tree.get_errors();
+        assert_eq!(errors[0].code, LuaParseErrorCode::MissingDo);
+        assert_eq!(
+            errors[0].secondary[0].range,
+            TextRange::new(TextSize::from(0), TextSize::from(5))
+        );
+    }
+
+    #[test]
+    fn test_error_code_lexer() {
+        let tree = LuaParser::parse("local a = 1x", ParserConfig::default());
+        assert_eq!(
+            tree.get_errors()[0].code,
+            LuaParseErrorCode::MalformedNumber
+        );
+
+        let tree = LuaParser::parse("local a = \"abc", ParserConfig::default());
+        assert_eq!(
+            tree.get_errors()[0].code,
+            LuaParseErrorCode::UnfinishedString
+        );
+
+        let tree = LuaParser::parse("local a = [[abc", ParserConfig::default());
+        assert_eq!(
+            tree.get_errors()[0].code,
+            LuaParseErrorCode::UnfinishedLongString
+        );
+
+        let tree = LuaParser::parse(
+            "local a = 1 // 2",
+            ParserConfig::with_level(LuaLanguageLevel::Lua51),
+        );
+        assert_eq!(
+            tree.get_errors()[0].code,
+            LuaParseErrorCode::UnsupportedSyntax
+        );
+    }
+
+    #[test]
+    fn test_error_code_unexpected_token() {
+        let tree = LuaParser::parse("local a = 1\nend", ParserConfig::default());
+        let errors = tree.get_errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, LuaParseErrorCode::UnexpectedToken);
+        assert_eq!(
+            errors[0].range,
+            TextRange::new(TextSize::from(12), TextSize::from(15))
+        );
+        assert!(errors[0].expected.is_empty());
+    }
+
+    #[test]
+    fn test_error_code_expected_tokens() {
+        let tree = LuaParser::parse("for i print(i) end", ParserConfig::default());
+        let errors = tree.get_errors();
+        assert_eq!(errors[0].code, LuaParseErrorCode::UnexpectedToken);
+        assert_eq!(
+            errors[0].expected,
+            vec![
+                LuaTokenKind::TkAssign,
+                LuaTokenKind::TkComma,
+                LuaTokenKind::TkIn
+            ]
+        );
+
+        let tree = LuaParser::parse("local 1", ParserConfig::default());
+        let errors = tree.get_errors();
+        assert_eq!(errors[0].code, LuaParseErrorCode::UnexpectedToken);
+        assert_eq!(
+            errors[0].expected,
+            vec![LuaTokenKind::TkFunction, LuaTokenKind::TkName]
+        );
+
+        let tree = LuaParser::parse("goto 1", ParserConfig::default());
+        let errors = tree.get_errors();
+        assert_eq!(errors[0].code, LuaParseErrorCode::UnexpectedToken);
+        assert_eq!(errors[0].expected, vec![LuaTokenKind::TkName]);
+    }
+
+    #[test]
+    fn test_syntax_extension_kinds() {
+        let config = || {
+            let extensions = [
+                LuaSyntaxExtension::CompoundAssign,
+                LuaSyntaxExtension::NotEqual,
+            ]
+            .into_iter()
+            .collect();
+            ParserConfig::with_level(LuaLanguageLevel::Lua54).with_extensions(extensions)
+        };
+        let tree = LuaParser::parse("a = b\na += b\nc = a != b", config());
+        assert!(tree.get_errors().is_empty());
+        let stats = tree
+            .get_chunk_node()
+            .descendants::<LuaAssignStat>()
+            .collect::<Vec<_>>();
+        assert_eq!(stats[0].syntax().kind(), LuaSyntaxKind::AssignStat.into());
+        assert!(!stats[0].is_compound_assign());
+        assert_eq!(
+            stats[1].syntax().kind(),
+            LuaSyntaxKind::CompoundAssignStat.into()
+        );
+        assert!(stats[1].is_compound_assign());
+        assert!(tree
+            .get_chunk_node()
+            .syntax()
+            .descendants_with_tokens()
+            .any(|element| element.kind() == LuaTokenKind::TkBangEq.into()));
+
+        let tree = LuaParser::parse("c = a ! b", config());
+        let errors = tree.get_errors();
+        assert_eq!(errors[0].code, LuaParseErrorCode::UnexpectedToken);
+        assert_eq!(errors[0].expected, vec![LuaTokenKind::TkBangEq]);
+        assert_eq!(
+            errors[0].range,
+            TextRange::new(TextSize::from(6), TextSize::from(7))
+        );
+    }
+
+    #[test]
+    fn test_error_code_unknown() {
+        let tree = LuaParser::parse("a.b", ParserConfig::default());
+        let errors = tree.get_errors();
+        assert_eq!(errors[0].code, LuaParseErrorCode::Unknown);
+        assert_eq!(LuaParseErrorCode::Unknown.get_name(), "unknown");
+    }
+
+    #[test]
+    fn test_embedded_chunk() {
+        let code = "load(\"local a = \\n(\")";
+        let tree = LuaParser::parse(code, ParserConfig::default());
+        let token = tree
+            .get_chunk_node()
+            .syntax()
+            .descendants_with_tokens()
+            .find_map(|element| element.into_token().and_then(LuaStringToken::cast))
+            .unwrap();
+        let embedded_chunk = LuaEmbeddedChunk::parse(&token, ParserConfig::default());
+        assert_eq!(embedded_chunk.get_text(), "local a = \n(");
+        assert!(embedded_chunk.get_tree().has_syntax_errors());
+        let errors = embedded_chunk.get_errors();
+        assert!(!errors.is_empty());
+        assert!(errors
+            .iter()
+            .all(|error| token.get_range().contains_range(error.range)));
+    }
 }
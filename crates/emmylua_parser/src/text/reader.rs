@@ -57,8 +57,9 @@ impl<'a> Reader<'a> {
         }
     }
 
+    // `\0` is also a valid char of the text, only the missing char marks the end
     pub fn is_eof(&self) -> bool {
-        self.current == EOF && self.start
+        self.start && self.current_char_len == 0
     }
 
     pub fn is_start_of_line(&self) -> bool {